@@ -1,11 +1,58 @@
+use bigdecimal::{BigDecimal, RoundingMode};
+use chrono::{FixedOffset, NaiveTime};
 use dotenvy::dotenv;
 use once_cell::sync::Lazy;
+use std::str::FromStr;
 
 // API Config goes here
 #[derive(Debug, Clone)]
 pub struct Config {
     pub jwt_secret: String,
     pub jwt_expiration_minutes: u64,
+    /// Service charge applied to the pre-tax subtotal, as a percentage
+    pub service_charge_percent: BigDecimal,
+    /// Rounding mode used for order money amounts
+    pub rounding_mode: RoundingMode,
+    /// Smallest unit order money amounts are rounded to (e.g. 0.01 or 0.05)
+    pub rounding_increment: BigDecimal,
+    /// Gateway used for card payments (`terminal` or `mock`)
+    pub card_gateway: String,
+    /// Gratuity added to orders of large parties, as a percentage of the discounted subtotal
+    pub auto_gratuity_percent: BigDecimal,
+    /// Smallest party size the gratuity is added for
+    pub auto_gratuity_party_size: i32,
+    /// How long a party is expected to occupy a table, in minutes
+    pub table_turn_minutes: i64,
+    /// Time of day the business day starts, so orders placed after midnight but before it
+    /// count towards the previous day
+    pub business_day_start: NaiveTime,
+    /// UTC offset of the business (e.g. `+02:00`), the server's time zone when unset
+    pub business_utc_offset: Option<FixedOffset>,
+    /// Daily opening and closing time scheduled orders must fall within, always open when
+    /// unset. Hours that close before they open run past midnight.
+    pub opening_hours: Option<(NaiveTime, NaiveTime)>,
+    /// How long before they are due scheduled orders are released to the kitchen, in minutes
+    pub schedule_lead_minutes: i64,
+    /// How often the scheduler looks for orders to release, in seconds
+    pub scheduler_interval_seconds: u64,
+    /// Whether orders that need more of an ingredient than is in stock are rejected
+    pub block_negative_stock: bool,
+    /// Locale product and category content is written in, used when no other is asked for
+    pub default_locale: String,
+    /// Locales content can be translated into and asked for, including the default one
+    pub locales: Vec<String>,
+    /// Where uploaded images are stored (`local` or `s3`)
+    pub blob_store: String,
+    /// Directory the local blob store keeps its files in
+    pub blob_dir: String,
+    /// Endpoint of the S3-compatible blob store, such as a local MinIO
+    pub s3_endpoint: String,
+    pub s3_bucket: String,
+    pub s3_region: String,
+    pub s3_access_key: String,
+    pub s3_secret_key: String,
+    /// Largest image that can be uploaded, in bytes
+    pub max_image_bytes: usize,
 }
 
 impl Config {
@@ -20,6 +67,40 @@ impl Default for Config {
     }
 }
 
+/// Parses opening hours such as `11:00-22:00` into an opening and a closing time.
+pub fn parse_opening_hours(value: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (open, close) = value.split_once('-')?;
+    let open = NaiveTime::parse_from_str(open.trim(), "%H:%M").ok()?;
+    let close = NaiveTime::parse_from_str(close.trim(), "%H:%M").ok()?;
+    Some((open, close))
+}
+
+/// Parses a comma separated list of locales such as `en,es,ja`, always including the
+/// default locale.
+pub fn parse_locales(value: &str, default_locale: &str) -> Vec<String> {
+    let mut locales = vec![default_locale.to_string()];
+    for locale in value.split(',').map(|l| l.trim().to_lowercase()) {
+        if !locale.is_empty() && !locales.contains(&locale) {
+            locales.push(locale);
+        }
+    }
+    locales
+}
+
+/// Parses a rounding mode name such as `half_even` or `half-up`.
+pub fn parse_rounding_mode(value: &str) -> Option<RoundingMode> {
+    match value.to_lowercase().replace('-', "_").as_str() {
+        "half_even" => Some(RoundingMode::HalfEven),
+        "half_up" => Some(RoundingMode::HalfUp),
+        "half_down" => Some(RoundingMode::HalfDown),
+        "up" => Some(RoundingMode::Up),
+        "down" => Some(RoundingMode::Down),
+        "ceiling" => Some(RoundingMode::Ceiling),
+        "floor" => Some(RoundingMode::Floor),
+        _ => None,
+    }
+}
+
 static CONFIG: Lazy<Config> = Lazy::new(|| {
     dotenv().ok();
 
@@ -29,10 +110,99 @@ static CONFIG: Lazy<Config> = Lazy::new(|| {
         .parse()
         .expect("JWT_EXPIRATION_MINUTES must be a valid u64");
 
+    let service_charge_percent = std::env::var("SERVICE_CHARGE_PERCENT")
+        .map(|v| BigDecimal::from_str(&v).expect("SERVICE_CHARGE_PERCENT must be a decimal"))
+        .unwrap_or_else(|_| BigDecimal::from(0));
+    let rounding_mode = std::env::var("ROUNDING_MODE")
+        .map(|v| parse_rounding_mode(&v).expect("ROUNDING_MODE must be a valid rounding mode"))
+        .unwrap_or(RoundingMode::HalfEven);
+    let rounding_increment = std::env::var("ROUNDING_INCREMENT")
+        .map(|v| BigDecimal::from_str(&v).expect("ROUNDING_INCREMENT must be a decimal"))
+        .unwrap_or_else(|_| BigDecimal::from_str("0.01").unwrap());
+    let card_gateway = std::env::var("CARD_GATEWAY").unwrap_or_else(|_| "terminal".to_string());
+    let auto_gratuity_percent = std::env::var("AUTO_GRATUITY_PERCENT")
+        .map(|v| BigDecimal::from_str(&v).expect("AUTO_GRATUITY_PERCENT must be a decimal"))
+        .unwrap_or_else(|_| BigDecimal::from(0));
+    let auto_gratuity_party_size = std::env::var("AUTO_GRATUITY_PARTY_SIZE")
+        .unwrap_or_else(|_| "8".to_string())
+        .parse()
+        .expect("AUTO_GRATUITY_PARTY_SIZE must be a valid i32");
+    let table_turn_minutes = std::env::var("TABLE_TURN_MINUTES")
+        .unwrap_or_else(|_| "90".to_string())
+        .parse()
+        .expect("TABLE_TURN_MINUTES must be a valid i64");
+    let business_day_start = std::env::var("BUSINESS_DAY_START")
+        .map(|v| {
+            NaiveTime::parse_from_str(&v, "%H:%M").expect("BUSINESS_DAY_START must be a HH:MM time")
+        })
+        .unwrap_or(NaiveTime::MIN);
+    let business_utc_offset = std::env::var("BUSINESS_UTC_OFFSET").ok().map(|v| {
+        v.parse::<FixedOffset>()
+            .expect("BUSINESS_UTC_OFFSET must be an offset such as +02:00")
+    });
+    let opening_hours = std::env::var("OPENING_HOURS").ok().map(|v| {
+        parse_opening_hours(&v).expect("OPENING_HOURS must be a range such as 11:00-22:00")
+    });
+    let schedule_lead_minutes = std::env::var("SCHEDULE_LEAD_MINUTES")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse()
+        .expect("SCHEDULE_LEAD_MINUTES must be a valid i64");
+    let scheduler_interval_seconds = std::env::var("SCHEDULER_INTERVAL_SECONDS")
+        .unwrap_or_else(|_| "60".to_string())
+        .parse()
+        .expect("SCHEDULER_INTERVAL_SECONDS must be a valid u64");
+    let block_negative_stock = std::env::var("BLOCK_NEGATIVE_STOCK")
+        .unwrap_or_else(|_| "false".to_string())
+        .parse()
+        .expect("BLOCK_NEGATIVE_STOCK must be true or false");
+    let default_locale = std::env::var("DEFAULT_LOCALE")
+        .map(|v| v.trim().to_lowercase())
+        .unwrap_or_else(|_| "en".to_string());
+    let locales = parse_locales(
+        &std::env::var("LOCALES").unwrap_or_default(),
+        &default_locale,
+    );
+
+    let blob_store = std::env::var("BLOB_STORE").unwrap_or_else(|_| "local".to_string());
+    let blob_dir = std::env::var("BLOB_DIR").unwrap_or_else(|_| "uploads".to_string());
+    let s3_endpoint =
+        std::env::var("S3_ENDPOINT").unwrap_or_else(|_| "http://127.0.0.1:9000".to_string());
+    let s3_bucket = std::env::var("S3_BUCKET").unwrap_or_else(|_| "arrow".to_string());
+    let s3_region = std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+    let s3_access_key = std::env::var("S3_ACCESS_KEY").unwrap_or_default();
+    let s3_secret_key = std::env::var("S3_SECRET_KEY").unwrap_or_default();
+    let max_image_bytes = std::env::var("MAX_IMAGE_BYTES")
+        .unwrap_or_else(|_| "5242880".to_string())
+        .parse()
+        .expect("MAX_IMAGE_BYTES must be a valid usize");
+
     tracing::info!("Config loaded");
 
     Config {
         jwt_secret,
         jwt_expiration_minutes,
+        service_charge_percent,
+        rounding_mode,
+        rounding_increment,
+        card_gateway,
+        auto_gratuity_percent,
+        auto_gratuity_party_size,
+        table_turn_minutes,
+        business_day_start,
+        business_utc_offset,
+        opening_hours,
+        schedule_lead_minutes,
+        scheduler_interval_seconds,
+        block_negative_stock,
+        default_locale,
+        locales,
+        blob_store,
+        blob_dir,
+        s3_endpoint,
+        s3_bucket,
+        s3_region,
+        s3_access_key,
+        s3_secret_key,
+        max_image_bytes,
     }
 });
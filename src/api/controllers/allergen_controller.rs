@@ -0,0 +1,80 @@
+use crate::api::request::DietaryTagsRequest;
+use crate::security::jwt::AccessClaims;
+use crate::services::allergen_service::AllergenService;
+use crate::services::errors::AllergenServiceError;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Replace the allergens and dietary tags set on a product
+pub async fn set_product_allergens(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<DietaryTagsRequest>,
+) -> impl IntoResponse {
+    let service = AllergenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_product_tags(product_id, &payload, role_id as i32)
+            .await
+        {
+            Ok(tags) => return (StatusCode::OK, Json(tags)).into_response(),
+            Err(AllergenServiceError::PermissionDenied) => continue,
+            Err(AllergenServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(e @ AllergenServiceError::UnknownValues(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to set allergens")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the allergens and dietary tags of an ingredient
+pub async fn set_ingredient_allergens(
+    claims: AccessClaims,
+    Path(ingredient_id): Path<i32>,
+    Json(payload): Json<DietaryTagsRequest>,
+) -> impl IntoResponse {
+    let service = AllergenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_ingredient_tags(ingredient_id, &payload, role_id as i32)
+            .await
+        {
+            Ok(tags) => return (StatusCode::OK, Json(tags)).into_response(),
+            Err(AllergenServiceError::PermissionDenied) => continue,
+            Err(AllergenServiceError::IngredientNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ingredient not found").into_response();
+            }
+            Err(e @ AllergenServiceError::UnknownValues(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to set allergens")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
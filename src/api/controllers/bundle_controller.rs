@@ -0,0 +1,66 @@
+use crate::api::request::BundleRequest;
+use crate::security::jwt::AccessClaims;
+use crate::services::bundle_service::BundleService;
+use crate::services::errors::BundleServiceError;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get the slots of a bundle product and their choices
+pub async fn get_bundle(claims: AccessClaims, Path(product_id): Path<i32>) -> impl IntoResponse {
+    let service = BundleService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_bundle(product_id, role_id as i32).await {
+            Ok(bundle) => return (StatusCode::OK, Json(bundle)).into_response(),
+            Err(BundleServiceError::PermissionDenied) => continue,
+            Err(BundleServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the slots of a bundle product and their choices
+pub async fn set_bundle(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<BundleRequest>,
+) -> impl IntoResponse {
+    let service = BundleService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_bundle(product_id, &payload, role_id as i32)
+            .await
+        {
+            Ok(bundle) => return (StatusCode::OK, Json(bundle)).into_response(),
+            Err(BundleServiceError::PermissionDenied) => continue,
+            Err(BundleServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(e @ BundleServiceError::InvalidBundle) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to set bundle").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -1,14 +1,17 @@
-use crate::api::request::{AssignCategoryRequest, CreateCategoryRequest, UpdateCategoryRequest};
+use crate::api::extractors::Locale;
+use crate::api::request::{
+    AssignCategoryRequest, CategoryProductsQuery, CreateCategoryRequest, UpdateCategoryRequest,
+};
 use crate::data::repos::implementors::category_repo::CategoryRepo;
 use crate::security::jwt::AccessClaims;
 use crate::services::errors::ProductCategoryServiceError;
 use crate::services::product_category_service::ProductCategoryService;
 use axum::Json;
-use axum::extract::Path;
+use axum::extract::{Path, Query};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 
-pub async fn get_categories(claims: AccessClaims) -> impl IntoResponse {
+pub async fn get_categories(claims: AccessClaims, Locale(locale): Locale) -> impl IntoResponse {
     let service = ProductCategoryService::new();
 
     if claims.roles.is_none() {
@@ -16,7 +19,7 @@ pub async fn get_categories(claims: AccessClaims) -> impl IntoResponse {
     }
     // Iterate through roles and return categories for the first role that has permission
     for role in claims.roles.unwrap() {
-        match service.get_categories(role as i32).await {
+        match service.get_categories(role as i32, &locale).await {
             Ok(categories) => {
                 let response = categories.unwrap_or_default();
                 return (StatusCode::OK, Json(response)).into_response();
@@ -31,6 +34,26 @@ pub async fn get_categories(claims: AccessClaims) -> impl IntoResponse {
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
 }
 
+pub async fn get_category_tree(claims: AccessClaims, Locale(locale): Locale) -> impl IntoResponse {
+    let service = ProductCategoryService::new();
+
+    if claims.roles.is_none() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role in claims.roles.unwrap() {
+        match service.get_category_tree(role as i32, &locale).await {
+            Ok(tree) => return (StatusCode::OK, Json(tree)).into_response(),
+            Err(ProductCategoryServiceError::PermissionDenied) => continue,
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
 pub async fn add_category(
     claims: AccessClaims,
     Json(payload): Json<CreateCategoryRequest>,
@@ -50,6 +73,9 @@ pub async fn add_category(
                 return (StatusCode::CREATED, "Category added successfully").into_response();
             }
             Err(ProductCategoryServiceError::PermissionDenied) => continue,
+            Err(ProductCategoryServiceError::InvalidParent) => {
+                return (StatusCode::BAD_REQUEST, "Invalid parent category").into_response();
+            }
             Err(_) => {
                 tracing::error!("Failed to add category {}", payload.name);
                 return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
@@ -82,6 +108,9 @@ pub async fn edit_category(
                 return (StatusCode::CREATED, "Category edited successfully").into_response();
             }
             Err(ProductCategoryServiceError::PermissionDenied) => continue,
+            Err(ProductCategoryServiceError::InvalidParent) => {
+                return (StatusCode::BAD_REQUEST, "Invalid parent category").into_response();
+            }
             Err(_) => {
                 tracing::error!("Failed to edit category {}", category_id);
                 return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
@@ -149,10 +178,13 @@ pub async fn delete_category(
     for role in claims.roles.unwrap() {
         match service.delete_category(role as i32, category_id).await {
             Ok(_) => {
-                tracing::info!("Deleted category {}", category_id);
+                tracing::info!("Archived category {}", category_id);
                 return (StatusCode::OK, "Category deleted successfully").into_response();
             }
             Err(ProductCategoryServiceError::PermissionDenied) => continue,
+            Err(ProductCategoryServiceError::CategoryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Category not found").into_response();
+            }
             Err(_) => {
                 tracing::error!("Failed to delete category {}", category_id);
                 return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
@@ -163,6 +195,58 @@ pub async fn delete_category(
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
 }
 
+pub async fn get_archived_categories(claims: AccessClaims) -> impl IntoResponse {
+    let service = ProductCategoryService::new();
+
+    if claims.roles.is_none() {
+        tracing::error!("Roles is none");
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role in claims.roles.unwrap() {
+        match service.get_archived_categories(role as i32).await {
+            Ok(categories) => return (StatusCode::OK, Json(categories)).into_response(),
+            Err(ProductCategoryServiceError::PermissionDenied) => continue,
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+pub async fn restore_category(
+    claims: AccessClaims,
+    Path(category_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = ProductCategoryService::new();
+
+    if claims.roles.is_none() {
+        tracing::error!("Roles is none");
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role in claims.roles.unwrap() {
+        match service.restore_category(role as i32, category_id).await {
+            Ok(_) => {
+                tracing::info!("Restored category {}", category_id);
+                return (StatusCode::OK, "Category restored").into_response();
+            }
+            Err(ProductCategoryServiceError::PermissionDenied) => continue,
+            Err(ProductCategoryServiceError::CategoryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Archived category not found").into_response();
+            }
+            Err(_) => {
+                tracing::error!("Failed to restore category {}", category_id);
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
 pub async fn remove_product_from_category(
     claims: AccessClaims,
     Json(payload): Json<AssignCategoryRequest>,
@@ -205,7 +289,9 @@ pub async fn remove_product_from_category(
 
 pub async fn get_products_by_category(
     claims: AccessClaims,
+    Locale(locale): Locale,
     Path(category_name): Path<String>,
+    Query(query): Query<CategoryProductsQuery>,
 ) -> impl IntoResponse {
     let service = ProductCategoryService::new();
     let category_repo = CategoryRepo::new();
@@ -216,8 +302,8 @@ pub async fn get_products_by_category(
     }
 
     let category_id = match category_repo.get_by_name(&category_name).await {
-        Ok(Some(category)) => category.category_id,
-        Ok(None) => {
+        Ok(Some(category)) if category.deleted_at.is_none() => category.category_id,
+        Ok(_) => {
             tracing::error!("Category {} not found", category_name);
             return (StatusCode::NOT_FOUND, "Category not found").into_response();
         }
@@ -229,7 +315,12 @@ pub async fn get_products_by_category(
 
     for role in claims.roles.unwrap() {
         match service
-            .get_products_by_category(role as i32, category_id)
+            .get_products_by_category(
+                role as i32,
+                category_id,
+                query.include_descendants.unwrap_or(false),
+                &locale,
+            )
             .await
         {
             Ok(products) => {
@@ -0,0 +1,102 @@
+use crate::api::request::SplitOrderRequest;
+use crate::security::jwt::AccessClaims;
+use crate::services::check_service::CheckService;
+use crate::services::errors::CheckServiceError;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get the checks of a split order
+pub async fn get_checks(claims: AccessClaims, Path(order_id): Path<i32>) -> impl IntoResponse {
+    let service = CheckService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_checks(order_id, role_id as i32).await {
+            Ok(checks) => return (StatusCode::OK, Json(checks)).into_response(),
+            Err(CheckServiceError::PermissionDenied) => continue,
+            Err(CheckServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Split an order into checks
+pub async fn split_order(
+    claims: AccessClaims,
+    Path(order_id): Path<i32>,
+    Json(payload): Json<SplitOrderRequest>,
+) -> impl IntoResponse {
+    let service = CheckService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .split_order(order_id, role_id as i32, &payload)
+            .await
+        {
+            Ok(checks) => return (StatusCode::CREATED, Json(checks)).into_response(),
+            Err(CheckServiceError::PermissionDenied) => continue,
+            Err(CheckServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(CheckServiceError::InvalidSplit) => {
+                return (StatusCode::BAD_REQUEST, "Invalid split").into_response();
+            }
+            Err(CheckServiceError::OrderNotSplittable) => {
+                return (StatusCode::CONFLICT, "Order cannot be split").into_response();
+            }
+            Err(CheckServiceError::ChecksHavePayments) => {
+                return (StatusCode::CONFLICT, "Checks already have payments").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to split order")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Remove the checks of a split order
+pub async fn remove_checks(claims: AccessClaims, Path(order_id): Path<i32>) -> impl IntoResponse {
+    let service = CheckService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.remove_checks(order_id, role_id as i32).await {
+            Ok(_) => return (StatusCode::OK, "Checks removed").into_response(),
+            Err(CheckServiceError::PermissionDenied) => continue,
+            Err(CheckServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(CheckServiceError::ChecksHavePayments) => {
+                return (StatusCode::CONFLICT, "Checks already have payments").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to remove checks")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
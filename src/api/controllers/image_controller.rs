@@ -0,0 +1,112 @@
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::ImageServiceError;
+use crate::services::image_service::ImageService;
+use axum::Json;
+use axum::extract::{Multipart, Path};
+use axum::http::StatusCode;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use axum::response::IntoResponse;
+
+/// Stored images never change, since a new upload is stored under a new key
+const IMAGE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Upload the image of a product as the `image` field of a multipart form
+pub async fn upload_product_image(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let service = ImageService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    let mut upload = None;
+    loop {
+        let mut field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return (e.status(), e.body_text()).into_response(),
+        };
+        if field.name() != Some("image") {
+            continue;
+        }
+
+        let content_type = field.content_type().unwrap_or_default().to_string();
+        let mut bytes = Vec::new();
+        loop {
+            match field.chunk().await {
+                Ok(Some(chunk)) => {
+                    // Stop reading as soon as the image is too large
+                    if bytes.len() + chunk.len() > service.max_image_bytes() {
+                        let e = ImageServiceError::ImageTooLarge;
+                        return (StatusCode::PAYLOAD_TOO_LARGE, e.to_string()).into_response();
+                    }
+                    bytes.extend_from_slice(&chunk);
+                }
+                Ok(None) => break,
+                Err(e) => return (e.status(), e.body_text()).into_response(),
+            }
+        }
+        upload = Some((content_type, bytes));
+        break;
+    }
+
+    let Some((content_type, bytes)) = upload else {
+        return (StatusCode::BAD_REQUEST, "Missing image field").into_response();
+    };
+
+    for role_id in roles {
+        match service
+            .upload_product_image(product_id, &content_type, &bytes, role_id as i32)
+            .await
+        {
+            Ok(image) => return (StatusCode::CREATED, Json(image)).into_response(),
+            Err(ImageServiceError::PermissionDenied) => continue,
+            Err(ImageServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(e @ ImageServiceError::UnsupportedImageType) => {
+                return (StatusCode::UNSUPPORTED_MEDIA_TYPE, e.to_string()).into_response();
+            }
+            Err(e @ ImageServiceError::ImageTooLarge) => {
+                return (StatusCode::PAYLOAD_TOO_LARGE, e.to_string()).into_response();
+            }
+            Err(e @ ImageServiceError::InvalidImage) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to upload image")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get a stored image or thumbnail; images are public and cached for good
+pub async fn get_image(Path(key): Path<String>) -> impl IntoResponse {
+    let service = ImageService::new();
+
+    match service.get_image(&key).await {
+        Ok(blob) => (
+            StatusCode::OK,
+            [
+                (CONTENT_TYPE, blob.content_type),
+                (CACHE_CONTROL, IMAGE_CACHE_CONTROL.to_string()),
+            ],
+            blob.bytes,
+        )
+            .into_response(),
+        Err(ImageServiceError::ImageNotFound) => {
+            (StatusCode::NOT_FOUND, "Image not found").into_response()
+        }
+        Err(e @ ImageServiceError::InvalidKey) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Failed to read image").into_response(),
+    }
+}
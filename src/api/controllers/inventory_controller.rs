@@ -0,0 +1,277 @@
+use crate::api::request::{
+    CreateIngredientRequest, RecipeRequest, StockAdjustmentRequest, UpdateIngredientRequest,
+};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::InventoryServiceError;
+use crate::services::inventory_service::InventoryService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get all ingredients with their stock
+pub async fn get_ingredients(claims: AccessClaims) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_ingredients(role_id as i32).await {
+            Ok(ingredients) => return (StatusCode::OK, Json(ingredients)).into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the ingredients running low
+pub async fn get_low_stock(claims: AccessClaims) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_low_stock(role_id as i32).await {
+            Ok(ingredients) => return (StatusCode::OK, Json(ingredients)).into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create an ingredient
+pub async fn create_ingredient(
+    claims: AccessClaims,
+    Json(payload): Json<CreateIngredientRequest>,
+) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_ingredient(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Ingredient created").into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(InventoryServiceError::InvalidIngredient) => {
+                return (StatusCode::BAD_REQUEST, "Invalid ingredient").into_response();
+            }
+            Err(InventoryServiceError::SupplierNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown supplier").into_response();
+            }
+            Err(InventoryServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Ingredient already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create ingredient",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update an ingredient
+pub async fn update_ingredient(
+    claims: AccessClaims,
+    Path(ingredient_id): Path<i32>,
+    Json(payload): Json<UpdateIngredientRequest>,
+) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_ingredient(role_id as i32, ingredient_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Ingredient updated").into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(InventoryServiceError::IngredientNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ingredient not found").into_response();
+            }
+            Err(InventoryServiceError::InvalidIngredient) => {
+                return (StatusCode::BAD_REQUEST, "Invalid ingredient").into_response();
+            }
+            Err(InventoryServiceError::SupplierNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown supplier").into_response();
+            }
+            Err(InventoryServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Ingredient already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update ingredient",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete an ingredient
+pub async fn delete_ingredient(
+    claims: AccessClaims,
+    Path(ingredient_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .delete_ingredient(role_id as i32, ingredient_id)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Ingredient deleted").into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(InventoryServiceError::IngredientNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ingredient not found").into_response();
+            }
+            Err(InventoryServiceError::IngredientInUse) => {
+                return (
+                    StatusCode::CONFLICT,
+                    "Ingredient is used in recipes or purchase orders",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete ingredient",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Add to or take from the stock of an ingredient
+pub async fn adjust_stock(
+    claims: AccessClaims,
+    Path(ingredient_id): Path<i32>,
+    Json(payload): Json<StockAdjustmentRequest>,
+) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .adjust_stock(role_id as i32, claims.sub as i32, ingredient_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::CREATED, "Stock adjusted").into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(InventoryServiceError::IngredientNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ingredient not found").into_response();
+            }
+            Err(InventoryServiceError::InvalidQuantity) => {
+                return (StatusCode::BAD_REQUEST, "Quantity must not be zero").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to adjust stock")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the recipe of a product
+pub async fn get_recipe(claims: AccessClaims, Path(product_id): Path<i32>) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_recipe(role_id as i32, product_id).await {
+            Ok(recipe) => return (StatusCode::OK, Json(recipe)).into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(InventoryServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the recipe of a product
+pub async fn set_recipe(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<RecipeRequest>,
+) -> impl IntoResponse {
+    let service = InventoryService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_recipe(role_id as i32, product_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Recipe updated").into_response(),
+            Err(InventoryServiceError::PermissionDenied) => continue,
+            Err(InventoryServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(InventoryServiceError::IngredientNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown ingredient").into_response();
+            }
+            Err(InventoryServiceError::InvalidRecipe) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Each ingredient must appear once with a positive quantity",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to update recipe")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
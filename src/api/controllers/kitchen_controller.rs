@@ -0,0 +1,240 @@
+use crate::api::request::{
+    CreateStationRequest, StationRoutingRequest, TicketQuery, UpdateStationRequest,
+};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::KitchenServiceError;
+use crate::services::kitchen_service::KitchenService;
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get all stations
+pub async fn get_stations(claims: AccessClaims) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_stations(role_id as i32).await {
+            Ok(stations) => return (StatusCode::OK, Json(stations)).into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a station
+pub async fn create_station(
+    claims: AccessClaims,
+    Json(payload): Json<CreateStationRequest>,
+) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_station(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Station created").into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(KitchenServiceError::InvalidStation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid station").into_response();
+            }
+            Err(KitchenServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Station already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create station",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a station
+pub async fn update_station(
+    claims: AccessClaims,
+    Path(station_id): Path<i32>,
+    Json(payload): Json<UpdateStationRequest>,
+) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_station(role_id as i32, station_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Station updated").into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(KitchenServiceError::StationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Station not found").into_response();
+            }
+            Err(KitchenServiceError::InvalidStation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid station").into_response();
+            }
+            Err(KitchenServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Station already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update station",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a station
+pub async fn delete_station(
+    claims: AccessClaims,
+    Path(station_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_station(role_id as i32, station_id).await {
+            Ok(_) => return (StatusCode::OK, "Station deleted").into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(KitchenServiceError::StationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Station not found").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete station",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Set the products and categories prepared at a station
+pub async fn set_station_routing(
+    claims: AccessClaims,
+    Path(station_id): Path<i32>,
+    Json(payload): Json<StationRoutingRequest>,
+) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_routing(role_id as i32, station_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Station routing updated").into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(KitchenServiceError::StationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Station not found").into_response();
+            }
+            Err(KitchenServiceError::InvalidRouting) => {
+                return (StatusCode::BAD_REQUEST, "Unknown product or category").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update station routing",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the kitchen tickets, optionally of one station
+pub async fn get_tickets(
+    claims: AccessClaims,
+    Query(query): Query<TicketQuery>,
+) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_tickets(role_id as i32, &query).await {
+            Ok(tickets) => return (StatusCode::OK, Json(tickets)).into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(KitchenServiceError::InvalidStatus) => {
+                return (StatusCode::BAD_REQUEST, "Invalid ticket status").into_response();
+            }
+            Err(KitchenServiceError::StationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Station not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Bump a kitchen ticket once its station is done with it
+pub async fn bump_ticket(claims: AccessClaims, Path(ticket_id): Path<i32>) -> impl IntoResponse {
+    let service = KitchenService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.bump_ticket(role_id as i32, ticket_id).await {
+            Ok(true) => return (StatusCode::OK, "Ticket bumped, order ready").into_response(),
+            Ok(false) => return (StatusCode::OK, "Ticket bumped").into_response(),
+            Err(KitchenServiceError::PermissionDenied) => continue,
+            Err(KitchenServiceError::TicketNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ticket not found").into_response();
+            }
+            Err(KitchenServiceError::TicketAlreadyBumped) => {
+                return (StatusCode::CONFLICT, "Ticket already bumped").into_response();
+            }
+            Err(KitchenServiceError::OrderNotActive) => {
+                return (StatusCode::CONFLICT, "Order is not in the kitchen").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to bump ticket")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
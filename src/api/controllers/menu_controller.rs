@@ -0,0 +1,248 @@
+use crate::api::extractors::Locale;
+use crate::api::request::{
+    CreateMenuRequest, MenuItemsRequest, MenuScheduleRequest, UpdateMenuRequest,
+};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::MenuServiceError;
+use crate::services::menu_service::MenuService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get all menus
+pub async fn get_menus(claims: AccessClaims) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_menus(role_id as i32).await {
+            Ok(menus) => return (StatusCode::OK, Json(menus)).into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the menus being served now and the products that can be ordered, in the locale asked
+/// for
+pub async fn get_current_menu(claims: AccessClaims, Locale(locale): Locale) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_current(role_id as i32, &locale).await {
+            Ok(current) => return (StatusCode::OK, Json(current)).into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get a menu
+pub async fn get_menu(claims: AccessClaims, Path(menu_id): Path<i32>) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_menu(role_id as i32, menu_id).await {
+            Ok(menu) => return (StatusCode::OK, Json(menu)).into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(MenuServiceError::MenuNotFound) => {
+                return (StatusCode::NOT_FOUND, "Menu not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a menu
+pub async fn create_menu(
+    claims: AccessClaims,
+    Json(payload): Json<CreateMenuRequest>,
+) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_menu(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Menu created").into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(MenuServiceError::InvalidMenu) => {
+                return (StatusCode::BAD_REQUEST, "Invalid menu").into_response();
+            }
+            Err(MenuServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Menu already exists").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to create menu")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a menu
+pub async fn update_menu(
+    claims: AccessClaims,
+    Path(menu_id): Path<i32>,
+    Json(payload): Json<UpdateMenuRequest>,
+) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.update_menu(role_id as i32, menu_id, &payload).await {
+            Ok(_) => return (StatusCode::OK, "Menu updated").into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(MenuServiceError::MenuNotFound) => {
+                return (StatusCode::NOT_FOUND, "Menu not found").into_response();
+            }
+            Err(MenuServiceError::InvalidMenu) => {
+                return (StatusCode::BAD_REQUEST, "Invalid menu").into_response();
+            }
+            Err(MenuServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Menu already exists").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to update menu")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a menu
+pub async fn delete_menu(claims: AccessClaims, Path(menu_id): Path<i32>) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_menu(role_id as i32, menu_id).await {
+            Ok(_) => return (StatusCode::OK, "Menu deleted").into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(MenuServiceError::MenuNotFound) => {
+                return (StatusCode::NOT_FOUND, "Menu not found").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to delete menu")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the weekly windows a menu is served in
+pub async fn set_menu_schedule(
+    claims: AccessClaims,
+    Path(menu_id): Path<i32>,
+    Json(payload): Json<MenuScheduleRequest>,
+) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_schedule(role_id as i32, menu_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Menu schedule updated").into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(MenuServiceError::MenuNotFound) => {
+                return (StatusCode::NOT_FOUND, "Menu not found").into_response();
+            }
+            Err(MenuServiceError::InvalidSchedule) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Windows need a day of the week and different start and end times",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update menu schedule",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the products and categories on a menu
+pub async fn set_menu_items(
+    claims: AccessClaims,
+    Path(menu_id): Path<i32>,
+    Json(payload): Json<MenuItemsRequest>,
+) -> impl IntoResponse {
+    let service = MenuService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.set_items(role_id as i32, menu_id, &payload).await {
+            Ok(_) => return (StatusCode::OK, "Menu items updated").into_response(),
+            Err(MenuServiceError::PermissionDenied) => continue,
+            Err(MenuServiceError::MenuNotFound) => {
+                return (StatusCode::NOT_FOUND, "Menu not found").into_response();
+            }
+            Err(MenuServiceError::InvalidItems) => {
+                return (StatusCode::BAD_REQUEST, "Unknown product or category").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update menu items",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -1,6 +1,24 @@
+pub mod allergen_controller;
+pub mod bundle_controller;
 pub mod category_controller;
+pub mod check_controller;
 pub mod dto;
+pub mod image_controller;
+pub mod inventory_controller;
+pub mod kitchen_controller;
+pub mod menu_controller;
+pub mod nutrition_controller;
 pub mod order_controller;
+pub mod payment_controller;
 pub mod product_controller;
+pub mod product_price_controller;
+pub mod promotion_controller;
+pub mod purchasing_controller;
+pub mod reservation_controller;
 pub mod role_controller;
+pub mod table_controller;
+pub mod tax_rate_controller;
+pub mod tip_controller;
+pub mod translation_controller;
 pub mod user_controller;
+pub mod waste_controller;
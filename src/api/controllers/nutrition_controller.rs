@@ -0,0 +1,159 @@
+use crate::api::request::{IngredientNutritionRequest, NutritionImportRequest, NutritionRequest};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::NutritionServiceError;
+use crate::services::nutrition_service::NutritionService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Replace the nutrition facts of a product
+pub async fn set_product_nutrition(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<NutritionRequest>,
+) -> impl IntoResponse {
+    let service = NutritionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_product_nutrition(product_id, &payload, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Nutrition updated").into_response(),
+            Err(NutritionServiceError::PermissionDenied) => continue,
+            Err(NutritionServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(e @ NutritionServiceError::InvalidNutrition) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update nutrition",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Remove the nutrition facts of a product
+pub async fn remove_product_nutrition(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = NutritionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .remove_product_nutrition(product_id, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Nutrition removed").into_response(),
+            Err(NutritionServiceError::PermissionDenied) => continue,
+            Err(NutritionServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to remove nutrition",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the nutrition of one unit of an ingredient
+pub async fn set_ingredient_nutrition(
+    claims: AccessClaims,
+    Path(ingredient_id): Path<i32>,
+    Json(payload): Json<IngredientNutritionRequest>,
+) -> impl IntoResponse {
+    let service = NutritionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_ingredient_nutrition(ingredient_id, &payload, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Nutrition updated").into_response(),
+            Err(NutritionServiceError::PermissionDenied) => continue,
+            Err(NutritionServiceError::IngredientNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ingredient not found").into_response();
+            }
+            Err(e @ NutritionServiceError::InvalidNutrition) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update nutrition",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Import the nutrition of many products and ingredients at once
+pub async fn import_nutrition(
+    claims: AccessClaims,
+    Json(payload): Json<NutritionImportRequest>,
+) -> impl IntoResponse {
+    let service = NutritionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.import_nutrition(&payload, role_id as i32).await {
+            Ok(imported) => return (StatusCode::OK, Json(imported)).into_response(),
+            Err(NutritionServiceError::PermissionDenied) => continue,
+            Err(e @ NutritionServiceError::InvalidNutrition) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(e @ NutritionServiceError::UnknownProducts(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(e @ NutritionServiceError::UnknownIngredients(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to import nutrition",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
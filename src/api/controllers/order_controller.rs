@@ -1,14 +1,19 @@
-use crate::api::request::{CreateOrderRequest, UpdateOrderStatusRequest};
-use crate::api::response::OrderResponse;
+use crate::api::request::{
+    CreateOrderRequest, OrderQuery, RefundPaymentRequest, UpdateOrderStatusRequest,
+    VoidItemsRequest,
+};
 use crate::data::repos::implementors::user_repo::UserRepo;
 use crate::data::repos::traits::repository::Repository;
 use crate::security::jwt::AccessClaims;
+use crate::services::allergen_service::{Allergen, parse_values};
 use crate::services::errors::OrderServiceError;
-use crate::services::order_service::{OrderService, OrderStatus};
+use crate::services::order_service::{OrderOptions, OrderService, OrderStatus, OrderType};
+use crate::utils::time::parse_datetime;
 use axum::Json;
-use axum::extract::Path;
+use axum::extract::{Path, Query};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// Get orders by role
@@ -26,12 +31,10 @@ pub async fn get_orders_by_role(
     for role_id in roles {
         match service.get_orders_by_role(&role_name, role_id as i32).await {
             Ok(orders) => {
-                let response: Vec<OrderResponse> = orders
-                    .unwrap_or_default()
-                    .into_iter()
-                    .map(OrderResponse::from)
-                    .collect();
-                return (StatusCode::OK, Json(response)).into_response();
+                return match service.to_responses(orders.unwrap_or_default()).await {
+                    Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+                    Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+                };
             }
             Err(OrderServiceError::PermissionDenied) => continue,
             Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
@@ -41,8 +44,11 @@ pub async fn get_orders_by_role(
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
 }
 
-/// Get all orders
-pub async fn get_all_orders(claims: AccessClaims) -> impl IntoResponse {
+/// Get all orders, or the orders of a business day when searching by daily number or date
+pub async fn get_all_orders(
+    claims: AccessClaims,
+    Query(query): Query<OrderQuery>,
+) -> impl IntoResponse {
     let service = OrderService::new();
     let roles = claims.roles.unwrap_or_default();
 
@@ -50,15 +56,63 @@ pub async fn get_all_orders(claims: AccessClaims) -> impl IntoResponse {
         return (StatusCode::FORBIDDEN, "Permission denied").into_response();
     }
 
+    if query.number.is_some() || query.date.is_some() {
+        for role_id in roles {
+            match service.search_orders(&query, role_id as i32).await {
+                Ok(orders) => {
+                    return match service.to_responses(orders).await {
+                        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+                        Err(_) => {
+                            (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response()
+                        }
+                    };
+                }
+                Err(OrderServiceError::PermissionDenied) => continue,
+                Err(OrderServiceError::InvalidOrderQuery) => {
+                    return (StatusCode::BAD_REQUEST, "Invalid order number or date")
+                        .into_response();
+                }
+                Err(_) => {
+                    return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response();
+                }
+            }
+        }
+
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
     for role_id in roles {
         match service.get_all_orders(role_id as i32).await {
             Ok(orders) => {
-                let response: Vec<OrderResponse> = orders
-                    .unwrap_or_default()
-                    .into_iter()
-                    .map(OrderResponse::from)
-                    .collect();
-                return (StatusCode::OK, Json(response)).into_response();
+                return match service.to_responses(orders.unwrap_or_default()).await {
+                    Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+                    Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+                };
+            }
+            Err(OrderServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the orders the kitchen is working on
+pub async fn get_kitchen_orders(claims: AccessClaims) -> impl IntoResponse {
+    let service = OrderService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_kitchen_orders(role_id as i32).await {
+            Ok(orders) => {
+                return match service.to_responses(orders).await {
+                    Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+                    Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+                };
             }
             Err(OrderServiceError::PermissionDenied) => continue,
             Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
@@ -80,7 +134,10 @@ pub async fn get_order_by_id(claims: AccessClaims, Path(order_id): Path<i32>) ->
     for role_id in roles {
         match service.get_order_by_id(order_id, role_id as i32).await {
             Ok(Some(order_data)) => {
-                return (StatusCode::OK, Json(OrderResponse::from(order_data))).into_response();
+                return match service.to_responses(vec![order_data]).await {
+                    Ok(response) => (StatusCode::OK, Json(response.into_iter().next())).into_response(),
+                    Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+                };
             }
             Ok(None) => return (StatusCode::NOT_FOUND, "Order not found").into_response(),
             Err(OrderServiceError::PermissionDenied) => continue,
@@ -121,17 +178,101 @@ pub async fn create_order(
         .map(|item| (item.product_id, item.quantity))
         .collect();
 
+    let order_type = match payload.order_type.as_deref() {
+        Some(value) => match OrderType::from_str(value) {
+            Ok(order_type) => order_type,
+            Err(_) => return (StatusCode::BAD_REQUEST, "Invalid order type").into_response(),
+        },
+        None => OrderType::default(),
+    };
+
+    let scheduled_for = match payload.scheduled_for.as_deref() {
+        Some(value) => match parse_datetime(value) {
+            Some(scheduled_for) => Some(scheduled_for),
+            None => return (StatusCode::BAD_REQUEST, "Invalid scheduled time").into_response(),
+        },
+        None => None,
+    };
+
+    let mut bundle_selections: HashMap<i32, Vec<(i32, i32)>> = HashMap::new();
+    for selection in payload.bundle_selections.unwrap_or_default() {
+        bundle_selections
+            .entry(selection.bundle_id)
+            .or_default()
+            .push((selection.slot_id, selection.product_id));
+    }
+
+    let allergies = match parse_values::<Allergen>(&payload.allergies.unwrap_or_default()) {
+        Ok(allergies) => allergies,
+        Err(unknown) => {
+            return (StatusCode::BAD_REQUEST, format!("Unknown allergens: {}", unknown.join(", ")))
+                .into_response();
+        }
+    };
+
+    let options = OrderOptions {
+        promo_code: payload.promo_code,
+        party_size: payload.party_size,
+        served_by: payload.served_by,
+        order_type,
+        table_id: payload.table_id,
+        scheduled_for,
+        bundle_selections,
+        allergies,
+    };
+
     match service
-        .create_order(user_id, role_id, items)
+        .create_order_with_options(user_id, role_id, items, options)
         .await
     {
-        Ok(_) => (StatusCode::CREATED, "Order created").into_response(),
+        Ok(alerts) if alerts.is_empty() => (StatusCode::CREATED, "Order created").into_response(),
+        Ok(alerts) => {
+            let warnings: Vec<String> = alerts
+                .iter()
+                .map(|(product_id, allergen)| {
+                    format!("product {} contains {}", product_id, allergen.as_str())
+                })
+                .collect();
+            let message = format!("Order created. Allergen warning: {}", warnings.join("; "));
+            (StatusCode::CREATED, message).into_response()
+        }
         Err(OrderServiceError::PermissionDenied) => {
             (StatusCode::FORBIDDEN, "Permission denied").into_response()
         }
         Err(OrderServiceError::OrderCreationFailed) => {
              (StatusCode::BAD_REQUEST, "Failed to create order (check products)").into_response()
         }
+        Err(OrderServiceError::InvalidPromoCode) => {
+            (StatusCode::BAD_REQUEST, "Invalid or expired promo code").into_response()
+        }
+        Err(OrderServiceError::InvalidOrderType) => {
+            (StatusCode::BAD_REQUEST, "Only dine-in orders can have a table").into_response()
+        }
+        Err(OrderServiceError::TableNotSeated) => {
+            (StatusCode::CONFLICT, "Table has no open session").into_response()
+        }
+        Err(e @ OrderServiceError::ProductsUnavailable(_)) => {
+            (StatusCode::CONFLICT, e.to_string()).into_response()
+        }
+        Err(e @ OrderServiceError::ProductsArchived(_)) => {
+            (StatusCode::CONFLICT, e.to_string()).into_response()
+        }
+        Err(e @ OrderServiceError::ProductsOffMenu(_)) => {
+            (StatusCode::CONFLICT, e.to_string()).into_response()
+        }
+        Err(e @ OrderServiceError::InsufficientStock(_)) => {
+            (StatusCode::CONFLICT, e.to_string()).into_response()
+        }
+        Err(e @ OrderServiceError::InvalidBundleSelection(_)) => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(OrderServiceError::InvalidSchedule) => {
+            (
+                StatusCode::BAD_REQUEST,
+                "Scheduled time must be in the future and within opening hours",
+            )
+                .into_response()
+        }
         Err(_) => {
             (StatusCode::INTERNAL_SERVER_ERROR, "Failed to create order").into_response()
         }
@@ -164,12 +305,10 @@ pub async fn get_user_orders_by_name(
             .await
         {
             Ok(orders) => {
-                let response: Vec<OrderResponse> = orders
-                    .unwrap_or_default()
-                    .into_iter()
-                    .map(OrderResponse::from)
-                    .collect();
-                return (StatusCode::OK, Json(response)).into_response();
+                return match service.to_responses(orders.unwrap_or_default()).await {
+                    Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+                    Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+                };
             }
             Err(OrderServiceError::PermissionDenied) => continue,
             Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
@@ -223,9 +362,135 @@ pub async fn update_order_status(
             Err(OrderServiceError::OrderNotFound) => {
                 return (StatusCode::NOT_FOUND, "Order not found").into_response();
             }
+            Err(OrderServiceError::InvalidStatusTransition) => {
+                return (StatusCode::BAD_REQUEST, "Only future-dated orders can be scheduled")
+                    .into_response();
+            }
+            Err(e @ OrderServiceError::InsufficientStock(_)) => {
+                return (StatusCode::CONFLICT, e.to_string()).into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the adjustment ledger of an order
+pub async fn get_order_adjustments(
+    claims: AccessClaims,
+    Path(order_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = OrderService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_adjustments(order_id, role_id as i32).await {
+            Ok(adjustments) => return (StatusCode::OK, Json(adjustments)).into_response(),
+            Err(OrderServiceError::PermissionDenied) => continue,
+            Err(OrderServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
             Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
         }
     }
 
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
-}
\ No newline at end of file
+}
+
+/// Void items of an order (requires REFUND permission)
+pub async fn void_order_items(
+    claims: AccessClaims,
+    Path(order_id): Path<i32>,
+    Json(payload): Json<VoidItemsRequest>,
+) -> impl IntoResponse {
+    let service = OrderService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .void_items(order_id, claims.sub as i32, role_id as i32, &payload)
+            .await
+        {
+            Ok(order) => return (StatusCode::OK, Json(order)).into_response(),
+            Err(OrderServiceError::PermissionDenied) => continue,
+            Err(OrderServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(OrderServiceError::InvalidReasonCode) => {
+                return (StatusCode::BAD_REQUEST, "Invalid reason code").into_response();
+            }
+            Err(OrderServiceError::InvalidVoid) => {
+                return (StatusCode::BAD_REQUEST, "Invalid items to void").into_response();
+            }
+            Err(OrderServiceError::OrderNotAdjustable) => {
+                return (
+                    StatusCode::CONFLICT,
+                    "Items of cancelled or split orders cannot be voided",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to void items").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Refund a payment of an order (requires REFUND permission)
+pub async fn refund_order_payment(
+    claims: AccessClaims,
+    Path(order_id): Path<i32>,
+    Json(payload): Json<RefundPaymentRequest>,
+) -> impl IntoResponse {
+    let service = OrderService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .refund_payment(order_id, claims.sub as i32, role_id as i32, &payload)
+            .await
+        {
+            Ok(order) => return (StatusCode::CREATED, Json(order)).into_response(),
+            Err(OrderServiceError::PermissionDenied) => continue,
+            Err(OrderServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(OrderServiceError::PaymentNotFound) => {
+                return (StatusCode::NOT_FOUND, "Payment not found").into_response();
+            }
+            Err(OrderServiceError::InvalidReasonCode) => {
+                return (StatusCode::BAD_REQUEST, "Invalid reason code").into_response();
+            }
+            Err(OrderServiceError::InvalidRefundAmount) => {
+                return (StatusCode::BAD_REQUEST, "Invalid refund amount").into_response();
+            }
+            Err(OrderServiceError::RefundDeclined) => {
+                return (StatusCode::PAYMENT_REQUIRED, "Refund declined").into_response();
+            }
+            Err(OrderServiceError::GatewayUnavailable) => {
+                return (StatusCode::BAD_GATEWAY, "Payment gateway unavailable").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to refund payment")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
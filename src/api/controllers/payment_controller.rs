@@ -0,0 +1,85 @@
+use crate::api::request::CapturePaymentRequest;
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::PaymentServiceError;
+use crate::services::payment_service::PaymentService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get the payments of an order
+pub async fn get_payments(claims: AccessClaims, Path(order_id): Path<i32>) -> impl IntoResponse {
+    let service = PaymentService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_payments(order_id, role_id as i32).await {
+            Ok(payments) => return (StatusCode::OK, Json(payments)).into_response(),
+            Err(PaymentServiceError::PermissionDenied) => continue,
+            Err(PaymentServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Capture a full or partial payment against an order
+pub async fn capture_payment(
+    claims: AccessClaims,
+    Path(order_id): Path<i32>,
+    Json(payload): Json<CapturePaymentRequest>,
+) -> impl IntoResponse {
+    let service = PaymentService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .capture_payment(order_id, role_id as i32, &payload)
+            .await
+        {
+            Ok(payment) => return (StatusCode::CREATED, Json(payment)).into_response(),
+            Err(PaymentServiceError::PermissionDenied) => continue,
+            Err(PaymentServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(PaymentServiceError::CheckNotFound) => {
+                return (StatusCode::NOT_FOUND, "Check not found").into_response();
+            }
+            Err(PaymentServiceError::OrderNotPayable) => {
+                return (StatusCode::CONFLICT, "Order cannot be paid").into_response();
+            }
+            Err(PaymentServiceError::InvalidAmount) => {
+                return (StatusCode::BAD_REQUEST, "Invalid payment amount").into_response();
+            }
+            Err(PaymentServiceError::InvalidPaymentMethod) => {
+                return (StatusCode::BAD_REQUEST, "Invalid payment method").into_response();
+            }
+            Err(PaymentServiceError::PaymentDeclined) => {
+                return (StatusCode::PAYMENT_REQUIRED, "Payment declined").into_response();
+            }
+            Err(PaymentServiceError::GatewayUnavailable) => {
+                return (StatusCode::BAD_GATEWAY, "Payment gateway unavailable").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to capture payment",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -1,17 +1,27 @@
-use crate::api::request::{CreateProductRequest, UpdateProductRequest};
+use crate::api::extractors::Locale;
+use crate::api::request::{
+    CreateProductRequest, ProductQuery, UpdateAvailabilityRequest, UpdatePrepTimeRequest,
+    UpdateProductRequest,
+};
 use crate::api::response::ProductResponse;
 use crate::security::jwt::AccessClaims;
 use crate::services::errors::ProductServiceError;
 use crate::services::product_category_service::ProductCategoryService;
 use crate::services::product_service::ProductService;
+use crate::utils::time::parse_datetime;
 use axum::Json;
-use axum::extract::Path;
+use axum::extract::{Path, Query};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 
 // NOTE: All routes except get_all should only be accessible by admin users.
-/// Get all products
-pub async fn get_all_products(claims: AccessClaims) -> impl IntoResponse {
+/// Get all products in the locale asked for, optionally without some allergens or suiting
+/// some diets
+pub async fn get_all_products(
+    claims: AccessClaims,
+    Locale(locale): Locale,
+    Query(query): Query<ProductQuery>,
+) -> impl IntoResponse {
     let service = ProductService::new();
     let roles = claims.roles.unwrap_or_default();
 
@@ -20,12 +30,18 @@ pub async fn get_all_products(claims: AccessClaims) -> impl IntoResponse {
     }
 
     for role_id in roles {
-        match service.get_all_products(role_id as i32).await {
+        match service
+            .get_filtered_products(&query, &locale, role_id as i32)
+            .await
+        {
             Ok(products) => {
                 let response: Vec<ProductResponse> = products.unwrap_or_default();
                 return (StatusCode::OK, Json(response)).into_response();
             }
             Err(ProductServiceError::PermissionDenied) => continue,
+            Err(e @ ProductServiceError::InvalidFilter(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
             Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
         }
     }
@@ -33,9 +49,10 @@ pub async fn get_all_products(claims: AccessClaims) -> impl IntoResponse {
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
 }
 
-/// Get product by ID
+/// Get product by ID in the locale asked for
 pub async fn get_product_by_id(
     claims: AccessClaims,
+    Locale(locale): Locale,
     Path(product_id): Path<i32>,
 ) -> impl IntoResponse {
     let service = ProductService::new();
@@ -46,7 +63,10 @@ pub async fn get_product_by_id(
     }
 
     for role_id in roles {
-        match service.get_product_by_id(product_id, role_id as i32).await {
+        match service
+            .get_localized_product(product_id, &locale, role_id as i32)
+            .await
+        {
             Ok(Some(product)) => {
                 return (StatusCode::OK, Json(product)).into_response();
             }
@@ -207,7 +227,7 @@ pub async fn update_product(
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
 }
 
-/// Delete a product
+/// Archive a product
 pub async fn delete_product(
     claims: AccessClaims,
     Path(product_id): Path<i32>,
@@ -238,3 +258,150 @@ pub async fn delete_product(
 
     (StatusCode::FORBIDDEN, "Permission denied").into_response()
 }
+
+/// Get the archived products
+pub async fn get_archived_products(claims: AccessClaims) -> impl IntoResponse {
+    let service = ProductService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_archived_products(role_id as i32).await {
+            Ok(products) => return (StatusCode::OK, Json(products)).into_response(),
+            Err(ProductServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Restore an archived product
+pub async fn restore_product(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = ProductService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.restore_product(product_id, role_id as i32).await {
+            Ok(_) => return (StatusCode::OK, "Product restored").into_response(),
+            Err(ProductServiceError::PermissionDenied) => continue,
+            Err(ProductServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Archived product not found").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to restore product",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Set how long the kitchen needs to prepare a product
+pub async fn update_product_prep_time(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<UpdatePrepTimeRequest>,
+) -> impl IntoResponse {
+    let service = ProductService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_prep_time(product_id, payload.prep_minutes, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Prep time updated").into_response(),
+            Err(ProductServiceError::PermissionDenied) => continue,
+            Err(ProductServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(ProductServiceError::InvalidPrepTime) => {
+                return (StatusCode::BAD_REQUEST, "Invalid prep time").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update prep time",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// 86 a product or make it available again
+pub async fn update_product_availability(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<UpdateAvailabilityRequest>,
+) -> impl IntoResponse {
+    let service = ProductService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    let available_again_at = match payload.available_again_at.as_deref() {
+        Some(value) => match parse_datetime(value) {
+            Some(at) => Some(at),
+            None => return (StatusCode::BAD_REQUEST, "Invalid restore time").into_response(),
+        },
+        None => None,
+    };
+
+    for role_id in roles {
+        match service
+            .set_availability(
+                product_id,
+                payload.is_available,
+                available_again_at,
+                role_id as i32,
+            )
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Product availability updated").into_response(),
+            Err(ProductServiceError::PermissionDenied) => continue,
+            Err(ProductServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(ProductServiceError::InvalidAvailability) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Restore time must be in the future and only set for an unavailable product",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update product availability",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
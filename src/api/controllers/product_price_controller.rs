@@ -0,0 +1,115 @@
+use crate::api::request::SchedulePriceRequest;
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::ProductPriceServiceError;
+use crate::services::product_price_service::ProductPriceService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get the price timeline of a product, including scheduled changes
+pub async fn get_price_timeline(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = ProductPriceService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_price_timeline(product_id, role_id as i32).await {
+            Ok(prices) => return (StatusCode::OK, Json(prices)).into_response(),
+            Err(ProductPriceServiceError::PermissionDenied) => continue,
+            Err(ProductPriceServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Change the price of a product now or schedule it for later
+pub async fn schedule_price_change(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+    Json(payload): Json<SchedulePriceRequest>,
+) -> impl IntoResponse {
+    let service = ProductPriceService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .schedule_price_change(product_id, &payload, claims.sub as i32, role_id as i32)
+            .await
+        {
+            Ok(price) => return (StatusCode::CREATED, Json(price)).into_response(),
+            Err(ProductPriceServiceError::PermissionDenied) => continue,
+            Err(ProductPriceServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(ProductPriceServiceError::InvalidPrice) => {
+                return (StatusCode::BAD_REQUEST, "Price cannot be negative").into_response();
+            }
+            Err(ProductPriceServiceError::InvalidEffectiveFrom) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "effective_from must be a date and time that has not passed",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to schedule price change",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Cancel a price change that has not taken effect yet
+pub async fn cancel_price_change(
+    claims: AccessClaims,
+    Path((product_id, price_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    let service = ProductPriceService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .cancel_price_change(product_id, price_id, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Price change cancelled").into_response(),
+            Err(ProductPriceServiceError::PermissionDenied) => continue,
+            Err(ProductPriceServiceError::PriceChangeNotFound) => {
+                return (StatusCode::NOT_FOUND, "Scheduled price change not found").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to cancel price change",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -0,0 +1,133 @@
+use crate::api::request::{CreatePromotionRequest, UpdatePromotionRequest};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::PromotionServiceError;
+use crate::services::promotion_service::PromotionService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get all promotions
+pub async fn get_promotions(claims: AccessClaims) -> impl IntoResponse {
+    let service = PromotionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_promotions(role_id as i32).await {
+            Ok(promotions) => {
+                return (StatusCode::OK, Json(promotions.unwrap_or_default())).into_response();
+            }
+            Err(PromotionServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a promotion
+pub async fn create_promotion(
+    claims: AccessClaims,
+    Json(payload): Json<CreatePromotionRequest>,
+) -> impl IntoResponse {
+    let service = PromotionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_promotion(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Promotion created").into_response(),
+            Err(PromotionServiceError::PermissionDenied) => continue,
+            Err(PromotionServiceError::InvalidPromotion) => {
+                return (StatusCode::BAD_REQUEST, "Invalid promotion").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create promotion",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a promotion
+pub async fn update_promotion(
+    claims: AccessClaims,
+    Path(promotion_id): Path<i32>,
+    Json(payload): Json<UpdatePromotionRequest>,
+) -> impl IntoResponse {
+    let service = PromotionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_promotion(role_id as i32, promotion_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Promotion updated").into_response(),
+            Err(PromotionServiceError::PermissionDenied) => continue,
+            Err(PromotionServiceError::PromotionNotFound) => {
+                return (StatusCode::NOT_FOUND, "Promotion not found").into_response();
+            }
+            Err(PromotionServiceError::InvalidPromotion) => {
+                return (StatusCode::BAD_REQUEST, "Invalid promotion").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update promotion",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a promotion
+pub async fn delete_promotion(
+    claims: AccessClaims,
+    Path(promotion_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = PromotionService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_promotion(role_id as i32, promotion_id).await {
+            Ok(_) => return (StatusCode::OK, "Promotion deleted").into_response(),
+            Err(PromotionServiceError::PermissionDenied) => continue,
+            Err(PromotionServiceError::PromotionNotFound) => {
+                return (StatusCode::NOT_FOUND, "Promotion not found").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete promotion",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -0,0 +1,449 @@
+use crate::api::request::{
+    CreatePurchaseOrderRequest, CreateSupplierRequest, PurchaseOrderQuery,
+    ReceivePurchaseOrderRequest, UpdatePurchaseOrderRequest, UpdateSupplierRequest,
+};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::PurchasingServiceError;
+use crate::services::purchasing_service::PurchasingService;
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get all suppliers
+pub async fn get_suppliers(claims: AccessClaims) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_suppliers(role_id as i32).await {
+            Ok(suppliers) => return (StatusCode::OK, Json(suppliers)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a supplier
+pub async fn create_supplier(
+    claims: AccessClaims,
+    Json(payload): Json<CreateSupplierRequest>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_supplier(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Supplier created").into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::InvalidSupplier) => {
+                return (StatusCode::BAD_REQUEST, "Invalid supplier").into_response();
+            }
+            Err(PurchasingServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Supplier already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create supplier",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a supplier
+pub async fn update_supplier(
+    claims: AccessClaims,
+    Path(supplier_id): Path<i32>,
+    Json(payload): Json<UpdateSupplierRequest>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_supplier(role_id as i32, supplier_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Supplier updated").into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::SupplierNotFound) => {
+                return (StatusCode::NOT_FOUND, "Supplier not found").into_response();
+            }
+            Err(PurchasingServiceError::InvalidSupplier) => {
+                return (StatusCode::BAD_REQUEST, "Invalid supplier").into_response();
+            }
+            Err(PurchasingServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Supplier already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update supplier",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a supplier
+pub async fn delete_supplier(
+    claims: AccessClaims,
+    Path(supplier_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_supplier(role_id as i32, supplier_id).await {
+            Ok(_) => return (StatusCode::OK, "Supplier deleted").into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::SupplierNotFound) => {
+                return (StatusCode::NOT_FOUND, "Supplier not found").into_response();
+            }
+            Err(PurchasingServiceError::SupplierInUse) => {
+                return (StatusCode::CONFLICT, "Supplier has purchase orders").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete supplier",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the purchase orders, optionally in one status or of one supplier
+pub async fn get_purchase_orders(
+    claims: AccessClaims,
+    Query(query): Query<PurchaseOrderQuery>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_purchase_orders(role_id as i32, &query).await {
+            Ok(orders) => return (StatusCode::OK, Json(orders)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::InvalidStatus) => {
+                return (StatusCode::BAD_REQUEST, "Invalid purchase order status").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get a purchase order
+pub async fn get_purchase_order(
+    claims: AccessClaims,
+    Path(purchase_order_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .get_purchase_order(role_id as i32, purchase_order_id)
+            .await
+        {
+            Ok(order) => return (StatusCode::OK, Json(order)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::PurchaseOrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Purchase order not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a draft purchase order
+pub async fn create_purchase_order(
+    claims: AccessClaims,
+    Json(payload): Json<CreatePurchaseOrderRequest>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .create_purchase_order(role_id as i32, claims.sub as i32, &payload)
+            .await
+        {
+            Ok(order) => return (StatusCode::CREATED, Json(order)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::SupplierNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown supplier").into_response();
+            }
+            Err(PurchasingServiceError::IngredientNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown ingredient").into_response();
+            }
+            Err(PurchasingServiceError::InvalidPurchaseOrder) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Each ingredient must appear once with a positive quantity",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create purchase order",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a draft purchase order
+pub async fn update_purchase_order(
+    claims: AccessClaims,
+    Path(purchase_order_id): Path<i32>,
+    Json(payload): Json<UpdatePurchaseOrderRequest>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_purchase_order(role_id as i32, purchase_order_id, &payload)
+            .await
+        {
+            Ok(order) => return (StatusCode::OK, Json(order)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::PurchaseOrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Purchase order not found").into_response();
+            }
+            Err(PurchasingServiceError::SupplierNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown supplier").into_response();
+            }
+            Err(PurchasingServiceError::IngredientNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Unknown ingredient").into_response();
+            }
+            Err(PurchasingServiceError::InvalidPurchaseOrder) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Each ingredient must appear once with a positive quantity",
+                )
+                    .into_response();
+            }
+            Err(PurchasingServiceError::NotDraft) => {
+                return (StatusCode::CONFLICT, "Purchase order is no longer a draft")
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update purchase order",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a draft purchase order
+pub async fn delete_purchase_order(
+    claims: AccessClaims,
+    Path(purchase_order_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .delete_purchase_order(role_id as i32, purchase_order_id)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Purchase order deleted").into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::PurchaseOrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Purchase order not found").into_response();
+            }
+            Err(PurchasingServiceError::NotDraft) => {
+                return (StatusCode::CONFLICT, "Purchase order is no longer a draft")
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete purchase order",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Mark a draft purchase order as sent to its supplier
+pub async fn send_purchase_order(
+    claims: AccessClaims,
+    Path(purchase_order_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .send_purchase_order(role_id as i32, purchase_order_id)
+            .await
+        {
+            Ok(order) => return (StatusCode::OK, Json(order)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::PurchaseOrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Purchase order not found").into_response();
+            }
+            Err(PurchasingServiceError::NotDraft) => {
+                return (StatusCode::CONFLICT, "Purchase order is no longer a draft")
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to send purchase order",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Receive a delivery against a purchase order
+pub async fn receive_purchase_order(
+    claims: AccessClaims,
+    Path(purchase_order_id): Path<i32>,
+    Json(payload): Json<ReceivePurchaseOrderRequest>,
+) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .receive_purchase_order(
+                role_id as i32,
+                claims.sub as i32,
+                purchase_order_id,
+                &payload,
+            )
+            .await
+        {
+            Ok(order) => return (StatusCode::OK, Json(order)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(PurchasingServiceError::PurchaseOrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Purchase order not found").into_response();
+            }
+            Err(PurchasingServiceError::InvalidReceipt) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Received quantities must be positive and within what is outstanding",
+                )
+                    .into_response();
+            }
+            Err(PurchasingServiceError::NotReceivable) => {
+                return (
+                    StatusCode::CONFLICT,
+                    "Purchase order is not awaiting delivery",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to receive purchase order",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get suggested purchase orders for the ingredients running low
+pub async fn get_purchase_suggestions(claims: AccessClaims) -> impl IntoResponse {
+    let service = PurchasingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_suggestions(role_id as i32).await {
+            Ok(suggestions) => return (StatusCode::OK, Json(suggestions)).into_response(),
+            Err(PurchasingServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -0,0 +1,379 @@
+use crate::api::request::{
+    AvailabilityQuery, CreateReservationRequest, CreateWaitlistEntryRequest, ReservationQuery,
+    SeatPartyRequest, UpdateReservationRequest, UpdateReservationStatusRequest,
+};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::ReservationServiceError;
+use crate::services::reservation_service::{ReservationService, ReservationStatus};
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use std::str::FromStr;
+
+/// Get the reservations of a day
+pub async fn get_reservations(
+    claims: AccessClaims,
+    Query(query): Query<ReservationQuery>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_reservations(role_id as i32, &query).await {
+            Ok(reservations) => return (StatusCode::OK, Json(reservations)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::InvalidReservation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid date").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Check whether a table can be booked for a party at a time slot
+pub async fn check_availability(
+    claims: AccessClaims,
+    Query(query): Query<AvailabilityQuery>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.check_availability(role_id as i32, &query).await {
+            Ok(availability) => return (StatusCode::OK, Json(availability)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::InvalidReservation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid party size or time slot")
+                    .into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Book a table for a party
+pub async fn create_reservation(
+    claims: AccessClaims,
+    Json(payload): Json<CreateReservationRequest>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .create_reservation(role_id as i32, claims.sub as i32, &payload)
+            .await
+        {
+            Ok(reservation) => return (StatusCode::CREATED, Json(reservation)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::InvalidReservation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid reservation").into_response();
+            }
+            Err(ReservationServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(ReservationServiceError::NoTableAvailable) => {
+                return (StatusCode::CONFLICT, "No table available").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create reservation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a booked reservation
+pub async fn update_reservation(
+    claims: AccessClaims,
+    Path(reservation_id): Path<i32>,
+    Json(payload): Json<UpdateReservationRequest>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_reservation(role_id as i32, reservation_id, &payload)
+            .await
+        {
+            Ok(reservation) => return (StatusCode::OK, Json(reservation)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::ReservationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Reservation not found").into_response();
+            }
+            Err(ReservationServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(ReservationServiceError::InvalidReservation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid reservation").into_response();
+            }
+            Err(ReservationServiceError::InvalidStatus) => {
+                return (StatusCode::CONFLICT, "Reservation is no longer booked").into_response();
+            }
+            Err(ReservationServiceError::NoTableAvailable) => {
+                return (StatusCode::CONFLICT, "No table available").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update reservation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Mark a reservation as a no-show or cancelled
+pub async fn update_reservation_status(
+    claims: AccessClaims,
+    Path(reservation_id): Path<i32>,
+    Json(payload): Json<UpdateReservationStatusRequest>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    let status = match ReservationStatus::from_str(&payload.status) {
+        Ok(status) => status,
+        Err(_) => {
+            return (StatusCode::BAD_REQUEST, "Invalid reservation status").into_response();
+        }
+    };
+
+    for role_id in roles {
+        match service
+            .update_status(role_id as i32, reservation_id, status)
+            .await
+        {
+            Ok(reservation) => return (StatusCode::OK, Json(reservation)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::ReservationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Reservation not found").into_response();
+            }
+            Err(ReservationServiceError::InvalidStatus) => {
+                return (
+                    StatusCode::CONFLICT,
+                    "Only booked reservations can be cancelled or marked as no-show",
+                )
+                    .into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update reservation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Seat a booked party, opening a session at its table
+pub async fn seat_reservation(
+    claims: AccessClaims,
+    Path(reservation_id): Path<i32>,
+    Json(payload): Json<SeatPartyRequest>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .seat_reservation(role_id as i32, claims.sub as i32, reservation_id, &payload)
+            .await
+        {
+            Ok(reservation) => return (StatusCode::OK, Json(reservation)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::ReservationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Reservation not found").into_response();
+            }
+            Err(ReservationServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(ReservationServiceError::TableRequired) => {
+                return (StatusCode::BAD_REQUEST, "Table is required").into_response();
+            }
+            Err(ReservationServiceError::InvalidStatus) => {
+                return (StatusCode::CONFLICT, "Reservation is no longer booked").into_response();
+            }
+            Err(ReservationServiceError::TableNotAvailable) => {
+                return (StatusCode::CONFLICT, "Table is not available").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to seat party").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the parties waiting for a table
+pub async fn get_waitlist(claims: AccessClaims) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_waitlist(role_id as i32).await {
+            Ok(entries) => return (StatusCode::OK, Json(entries)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Add a walk-in party to the waitlist and quote its wait
+pub async fn join_waitlist(
+    claims: AccessClaims,
+    Json(payload): Json<CreateWaitlistEntryRequest>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.join_waitlist(role_id as i32, &payload).await {
+            Ok(entry) => return (StatusCode::CREATED, Json(entry)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::InvalidReservation) => {
+                return (StatusCode::BAD_REQUEST, "Invalid waitlist entry").into_response();
+            }
+            Err(ReservationServiceError::NoTableAvailable) => {
+                return (StatusCode::CONFLICT, "No table seats the party").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to add party to the waitlist",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Seat a waiting party at a table
+pub async fn seat_waitlist_entry(
+    claims: AccessClaims,
+    Path(entry_id): Path<i32>,
+    Json(payload): Json<SeatPartyRequest>,
+) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .seat_waitlist_entry(role_id as i32, claims.sub as i32, entry_id, &payload)
+            .await
+        {
+            Ok(entry) => return (StatusCode::OK, Json(entry)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::EntryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Waitlist entry not found").into_response();
+            }
+            Err(ReservationServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(ReservationServiceError::TableRequired) => {
+                return (StatusCode::BAD_REQUEST, "Table is required").into_response();
+            }
+            Err(ReservationServiceError::InvalidStatus) => {
+                return (StatusCode::CONFLICT, "Party is no longer waiting").into_response();
+            }
+            Err(ReservationServiceError::TableNotAvailable) => {
+                return (StatusCode::CONFLICT, "Table is not available").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to seat party").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Remove a party that left from the waitlist
+pub async fn leave_waitlist(claims: AccessClaims, Path(entry_id): Path<i32>) -> impl IntoResponse {
+    let service = ReservationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.leave_waitlist(role_id as i32, entry_id).await {
+            Ok(entry) => return (StatusCode::OK, Json(entry)).into_response(),
+            Err(ReservationServiceError::PermissionDenied) => continue,
+            Err(ReservationServiceError::EntryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Waitlist entry not found").into_response();
+            }
+            Err(ReservationServiceError::InvalidStatus) => {
+                return (StatusCode::CONFLICT, "Party is no longer waiting").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update waitlist",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -137,7 +137,7 @@ pub async fn set_permission(
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
-                "Invalid permission. Valid values: READ, WRITE, DELETE, ADMIN",
+                "Invalid permission. Valid values: READ, WRITE, DELETE, ADMIN, REFUND",
             )
                 .into_response();
         }
@@ -175,7 +175,7 @@ pub async fn set_permission_by_name(
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
-                "Invalid permission. Valid values: READ, WRITE, DELETE, ADMIN",
+                "Invalid permission. Valid values: READ, WRITE, DELETE, ADMIN, REFUND",
             )
                 .into_response();
         }
@@ -209,7 +209,7 @@ pub async fn add_permission(
         Err(_) => {
             return (
                 StatusCode::BAD_REQUEST,
-                "Invalid permission. Valid values: READ, WRITE, DELETE, ADMIN",
+                "Invalid permission. Valid values: READ, WRITE, DELETE, ADMIN, REFUND",
             )
                 .into_response();
         }
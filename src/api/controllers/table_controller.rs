@@ -0,0 +1,416 @@
+use crate::api::request::{
+    CreateDiningAreaRequest, CreateTableRequest, OpenTableSessionRequest, TableQuery,
+    UpdateDiningAreaRequest, UpdateTableRequest, UpdateTableStatusRequest,
+};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::TableServiceError;
+use crate::services::table_service::{TableService, TableStatus};
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use std::str::FromStr;
+
+/// Get all dining areas
+pub async fn get_dining_areas(claims: AccessClaims) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_areas(role_id as i32).await {
+            Ok(areas) => return (StatusCode::OK, Json(areas)).into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a dining area
+pub async fn create_dining_area(
+    claims: AccessClaims,
+    Json(payload): Json<CreateDiningAreaRequest>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_area(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Dining area created").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::InvalidTable) => {
+                return (StatusCode::BAD_REQUEST, "Invalid dining area").into_response();
+            }
+            Err(TableServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Dining area already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create dining area",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a dining area
+pub async fn update_dining_area(
+    claims: AccessClaims,
+    Path(area_id): Path<i32>,
+    Json(payload): Json<UpdateDiningAreaRequest>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.update_area(role_id as i32, area_id, &payload).await {
+            Ok(_) => return (StatusCode::OK, "Dining area updated").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::AreaNotFound) => {
+                return (StatusCode::NOT_FOUND, "Dining area not found").into_response();
+            }
+            Err(TableServiceError::InvalidTable) => {
+                return (StatusCode::BAD_REQUEST, "Invalid dining area").into_response();
+            }
+            Err(TableServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Dining area already exists").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update dining area",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a dining area
+pub async fn delete_dining_area(
+    claims: AccessClaims,
+    Path(area_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_area(role_id as i32, area_id).await {
+            Ok(_) => return (StatusCode::OK, "Dining area deleted").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::AreaNotFound) => {
+                return (StatusCode::NOT_FOUND, "Dining area not found").into_response();
+            }
+            Err(TableServiceError::AreaNotEmpty) => {
+                return (StatusCode::CONFLICT, "Dining area still has tables").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete dining area",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get all tables, optionally of one dining area
+pub async fn get_tables(
+    claims: AccessClaims,
+    Query(query): Query<TableQuery>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_tables(role_id as i32, &query).await {
+            Ok(tables) => return (StatusCode::OK, Json(tables)).into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a table
+pub async fn create_table(
+    claims: AccessClaims,
+    Json(payload): Json<CreateTableRequest>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_table(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Table created").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::InvalidTable) => {
+                return (StatusCode::BAD_REQUEST, "Invalid table").into_response();
+            }
+            Err(TableServiceError::AreaNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Dining area not found").into_response();
+            }
+            Err(TableServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Table already exists").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to create table")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a table
+pub async fn update_table(
+    claims: AccessClaims,
+    Path(table_id): Path<i32>,
+    Json(payload): Json<UpdateTableRequest>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_table(role_id as i32, table_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Table updated").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(TableServiceError::InvalidTable) => {
+                return (StatusCode::BAD_REQUEST, "Invalid table").into_response();
+            }
+            Err(TableServiceError::AreaNotFound) => {
+                return (StatusCode::BAD_REQUEST, "Dining area not found").into_response();
+            }
+            Err(TableServiceError::DuplicateName) => {
+                return (StatusCode::CONFLICT, "Table already exists").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to update table")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a table
+pub async fn delete_table(claims: AccessClaims, Path(table_id): Path<i32>) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_table(role_id as i32, table_id).await {
+            Ok(_) => return (StatusCode::OK, "Table deleted").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(TableServiceError::TableNotAvailable) => {
+                return (StatusCode::CONFLICT, "Table is seated").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to delete table")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Mark a table free, dirty or reserved
+pub async fn update_table_status(
+    claims: AccessClaims,
+    Path(table_id): Path<i32>,
+    Json(payload): Json<UpdateTableStatusRequest>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    let status = match TableStatus::from_str(&payload.status) {
+        Ok(status) => status,
+        Err(_) => return (StatusCode::BAD_REQUEST, "Invalid table status").into_response(),
+    };
+
+    for role_id in roles {
+        match service
+            .update_status(role_id as i32, table_id, status)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Table status updated").into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(TableServiceError::InvalidStatus) => {
+                return (StatusCode::BAD_REQUEST, "Open a session to seat a table").into_response();
+            }
+            Err(TableServiceError::TableNotAvailable) => {
+                return (StatusCode::CONFLICT, "Table is seated").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update table status",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the open session of a table
+pub async fn get_table_session(
+    claims: AccessClaims,
+    Path(table_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_session(role_id as i32, table_id).await {
+            Ok(session) => return (StatusCode::OK, Json(session)).into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(TableServiceError::NoOpenSession) => {
+                return (StatusCode::NOT_FOUND, "Table has no open session").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Seat a party at a table
+pub async fn open_table_session(
+    claims: AccessClaims,
+    Path(table_id): Path<i32>,
+    Json(payload): Json<OpenTableSessionRequest>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .open_session(role_id as i32, claims.sub as i32, table_id, &payload)
+            .await
+        {
+            Ok(session) => return (StatusCode::CREATED, Json(session)).into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(TableServiceError::InvalidTable) => {
+                return (StatusCode::BAD_REQUEST, "Invalid party size").into_response();
+            }
+            Err(TableServiceError::TableNotAvailable) => {
+                return (StatusCode::CONFLICT, "Table is not available").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to seat table").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Close the open session of a table and get its combined check
+pub async fn close_table_session(
+    claims: AccessClaims,
+    Path(table_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = TableService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.close_session(role_id as i32, table_id).await {
+            Ok(session) => return (StatusCode::OK, Json(session)).into_response(),
+            Err(TableServiceError::PermissionDenied) => continue,
+            Err(TableServiceError::TableNotFound) => {
+                return (StatusCode::NOT_FOUND, "Table not found").into_response();
+            }
+            Err(TableServiceError::NoOpenSession) => {
+                return (StatusCode::NOT_FOUND, "Table has no open session").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to close table")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -0,0 +1,133 @@
+use crate::api::request::{CreateTaxRateRequest, UpdateTaxRateRequest};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::PricingServiceError;
+use crate::services::pricing_service::PricingService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get all tax rates
+pub async fn get_tax_rates(claims: AccessClaims) -> impl IntoResponse {
+    let service = PricingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_tax_rates(role_id as i32).await {
+            Ok(rates) => {
+                return (StatusCode::OK, Json(rates.unwrap_or_default())).into_response();
+            }
+            Err(PricingServiceError::PermissionDenied) => continue,
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Create a tax rate
+pub async fn create_tax_rate(
+    claims: AccessClaims,
+    Json(payload): Json<CreateTaxRateRequest>,
+) -> impl IntoResponse {
+    let service = PricingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.create_tax_rate(role_id as i32, &payload).await {
+            Ok(_) => return (StatusCode::CREATED, "Tax rate created").into_response(),
+            Err(PricingServiceError::PermissionDenied) => continue,
+            Err(PricingServiceError::InvalidTaxRate) => {
+                return (StatusCode::BAD_REQUEST, "Invalid tax rate").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to create tax rate",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Update a tax rate
+pub async fn update_tax_rate(
+    claims: AccessClaims,
+    Path(tax_rate_id): Path<i32>,
+    Json(payload): Json<UpdateTaxRateRequest>,
+) -> impl IntoResponse {
+    let service = PricingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .update_tax_rate(role_id as i32, tax_rate_id, &payload)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Tax rate updated").into_response(),
+            Err(PricingServiceError::PermissionDenied) => continue,
+            Err(PricingServiceError::TaxRateNotFound) => {
+                return (StatusCode::NOT_FOUND, "Tax rate not found").into_response();
+            }
+            Err(PricingServiceError::InvalidTaxRate) => {
+                return (StatusCode::BAD_REQUEST, "Invalid tax rate").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to update tax rate",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Delete a tax rate
+pub async fn delete_tax_rate(
+    claims: AccessClaims,
+    Path(tax_rate_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = PricingService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.delete_tax_rate(role_id as i32, tax_rate_id).await {
+            Ok(_) => return (StatusCode::OK, "Tax rate deleted").into_response(),
+            Err(PricingServiceError::PermissionDenied) => continue,
+            Err(PricingServiceError::TaxRateNotFound) => {
+                return (StatusCode::NOT_FOUND, "Tax rate not found").into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to delete tax rate",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -0,0 +1,70 @@
+use crate::api::request::{AssignServerRequest, DateRangeQuery};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::TipServiceError;
+use crate::services::tip_service::TipService;
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Assign the staff member serving an order
+pub async fn assign_server(
+    claims: AccessClaims,
+    Path(order_id): Path<i32>,
+    Json(payload): Json<AssignServerRequest>,
+) -> impl IntoResponse {
+    let service = TipService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .assign_server(order_id, payload.user_id, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Server assigned").into_response(),
+            Err(TipServiceError::PermissionDenied) => continue,
+            Err(TipServiceError::OrderNotFound) => {
+                return (StatusCode::NOT_FOUND, "Order not found").into_response();
+            }
+            Err(TipServiceError::UserNotFound) => {
+                return (StatusCode::BAD_REQUEST, "User not found").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to assign server")
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the tips of each staff member over a date range
+pub async fn get_tip_pool(
+    claims: AccessClaims,
+    Query(range): Query<DateRangeQuery>,
+) -> impl IntoResponse {
+    let service = TipService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_tip_pool(&range, role_id as i32).await {
+            Ok(report) => return (StatusCode::OK, Json(report)).into_response(),
+            Err(TipServiceError::PermissionDenied) => continue,
+            Err(TipServiceError::InvalidDateRange) => {
+                return (StatusCode::BAD_REQUEST, "Invalid date range").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
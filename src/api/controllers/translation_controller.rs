@@ -0,0 +1,232 @@
+use crate::api::request::TranslationRequest;
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::TranslationServiceError;
+use crate::services::translation_service::TranslationService;
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get every translation of a product
+pub async fn get_product_translations(
+    claims: AccessClaims,
+    Path(product_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = TranslationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .get_product_translations(product_id, role_id as i32)
+            .await
+        {
+            Ok(translations) => return (StatusCode::OK, Json(translations)).into_response(),
+            Err(TranslationServiceError::PermissionDenied) => continue,
+            Err(TranslationServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the translation of a product into a locale
+pub async fn set_product_translation(
+    claims: AccessClaims,
+    Path((product_id, locale)): Path<(i32, String)>,
+    Json(payload): Json<TranslationRequest>,
+) -> impl IntoResponse {
+    let service = TranslationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_product_translation(product_id, &locale, &payload, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Translation updated").into_response(),
+            Err(TranslationServiceError::PermissionDenied) => continue,
+            Err(TranslationServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(e @ TranslationServiceError::UnsupportedLocale(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(e @ TranslationServiceError::InvalidTranslation) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to set translation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Remove the translation of a product into a locale
+pub async fn remove_product_translation(
+    claims: AccessClaims,
+    Path((product_id, locale)): Path<(i32, String)>,
+) -> impl IntoResponse {
+    let service = TranslationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .remove_product_translation(product_id, &locale, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Translation removed").into_response(),
+            Err(TranslationServiceError::PermissionDenied) => continue,
+            Err(TranslationServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(TranslationServiceError::TranslationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Translation not found").into_response();
+            }
+            Err(e @ TranslationServiceError::UnsupportedLocale(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to remove translation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get every translation of a category
+pub async fn get_category_translations(
+    claims: AccessClaims,
+    Path(category_id): Path<i32>,
+) -> impl IntoResponse {
+    let service = TranslationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .get_category_translations(category_id, role_id as i32)
+            .await
+        {
+            Ok(translations) => return (StatusCode::OK, Json(translations)).into_response(),
+            Err(TranslationServiceError::PermissionDenied) => continue,
+            Err(TranslationServiceError::CategoryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Category not found").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Replace the translation of a category into a locale
+pub async fn set_category_translation(
+    claims: AccessClaims,
+    Path((category_id, locale)): Path<(i32, String)>,
+    Json(payload): Json<TranslationRequest>,
+) -> impl IntoResponse {
+    let service = TranslationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .set_category_translation(category_id, &locale, &payload, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Translation updated").into_response(),
+            Err(TranslationServiceError::PermissionDenied) => continue,
+            Err(TranslationServiceError::CategoryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Category not found").into_response();
+            }
+            Err(e @ TranslationServiceError::UnsupportedLocale(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(e @ TranslationServiceError::InvalidTranslation) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to set translation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Remove the translation of a category into a locale
+pub async fn remove_category_translation(
+    claims: AccessClaims,
+    Path((category_id, locale)): Path<(i32, String)>,
+) -> impl IntoResponse {
+    let service = TranslationService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .remove_category_translation(category_id, &locale, role_id as i32)
+            .await
+        {
+            Ok(_) => return (StatusCode::OK, "Translation removed").into_response(),
+            Err(TranslationServiceError::PermissionDenied) => continue,
+            Err(TranslationServiceError::CategoryNotFound) => {
+                return (StatusCode::NOT_FOUND, "Category not found").into_response();
+            }
+            Err(TranslationServiceError::TranslationNotFound) => {
+                return (StatusCode::NOT_FOUND, "Translation not found").into_response();
+            }
+            Err(e @ TranslationServiceError::UnsupportedLocale(_)) => {
+                return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            }
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to remove translation",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -0,0 +1,104 @@
+use crate::api::request::{DateRangeQuery, LogWasteRequest};
+use crate::security::jwt::AccessClaims;
+use crate::services::errors::WasteServiceError;
+use crate::services::waste_service::WasteService;
+use axum::Json;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+
+/// Get the waste logged over a date range
+pub async fn get_waste(
+    claims: AccessClaims,
+    Query(range): Query<DateRangeQuery>,
+) -> impl IntoResponse {
+    let service = WasteService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_waste(&range, role_id as i32).await {
+            Ok(entries) => return (StatusCode::OK, Json(entries)).into_response(),
+            Err(WasteServiceError::PermissionDenied) => continue,
+            Err(WasteServiceError::InvalidDateRange) => {
+                return (StatusCode::BAD_REQUEST, "Invalid date range").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Log waste of an ingredient or product, taking it out of stock
+pub async fn log_waste(
+    claims: AccessClaims,
+    Json(payload): Json<LogWasteRequest>,
+) -> impl IntoResponse {
+    let service = WasteService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service
+            .log_waste(&payload, claims.sub as i32, role_id as i32)
+            .await
+        {
+            Ok(entry) => return (StatusCode::CREATED, Json(entry)).into_response(),
+            Err(WasteServiceError::PermissionDenied) => continue,
+            Err(WasteServiceError::IngredientNotFound) => {
+                return (StatusCode::NOT_FOUND, "Ingredient not found").into_response();
+            }
+            Err(WasteServiceError::ProductNotFound) => {
+                return (StatusCode::NOT_FOUND, "Product not found").into_response();
+            }
+            Err(WasteServiceError::InvalidWaste) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    "Give either an ingredient or a product and a positive quantity",
+                )
+                    .into_response();
+            }
+            Err(WasteServiceError::InvalidReason) => {
+                return (StatusCode::BAD_REQUEST, "Invalid waste reason").into_response();
+            }
+            Err(_) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to log waste").into_response();
+            }
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
+
+/// Get the cost of waste by reason and day, and theoretical against actual usage
+pub async fn get_waste_report(
+    claims: AccessClaims,
+    Query(range): Query<DateRangeQuery>,
+) -> impl IntoResponse {
+    let service = WasteService::new();
+    let roles = claims.roles.unwrap_or_default();
+
+    if roles.is_empty() {
+        return (StatusCode::FORBIDDEN, "Permission denied").into_response();
+    }
+
+    for role_id in roles {
+        match service.get_waste_report(&range, role_id as i32).await {
+            Ok(report) => return (StatusCode::OK, Json(report)).into_response(),
+            Err(WasteServiceError::PermissionDenied) => continue,
+            Err(WasteServiceError::InvalidDateRange) => {
+                return (StatusCode::BAD_REQUEST, "Invalid date range").into_response();
+            }
+            Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Database error").into_response(),
+        }
+    }
+
+    (StatusCode::FORBIDDEN, "Permission denied").into_response()
+}
@@ -1,11 +1,16 @@
+use crate::api::config::Config;
 use crate::api::errors::APIErrors;
+use crate::api::request::LocaleQuery;
 use crate::security::jwt::{AccessClaims, JwtService};
+use crate::services::translation_service::negotiate_locale;
 use axum::RequestPartsExt;
-use axum::extract::FromRequestParts;
+use axum::extract::{FromRequestParts, Query};
+use axum::http::header::ACCEPT_LANGUAGE;
 use axum::http::request::Parts;
 use axum_extra::TypedHeader;
 use axum_extra::headers::Authorization;
 use axum_extra::headers::authorization::Bearer;
+use std::convert::Infallible;
 
 impl FromRequestParts<()> for AccessClaims {
     type Rejection = APIErrors;
@@ -15,6 +20,32 @@ impl FromRequestParts<()> for AccessClaims {
     }
 }
 
+/// Locale product and category content is shown in, asked for through the `lang` query
+/// parameter or the `Accept-Language` header and falling back to the default locale
+pub struct Locale(pub String);
+
+impl FromRequestParts<()> for Locale {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &()) -> Result<Self, Self::Rejection> {
+        let lang = Query::<LocaleQuery>::try_from_uri(&parts.uri)
+            .ok()
+            .and_then(|Query(query)| query.lang);
+        let accept_language = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok());
+
+        let config = Config::new();
+        Ok(Locale(negotiate_locale(
+            lang.as_deref(),
+            accept_language,
+            &config.locales,
+            &config.default_locale,
+        )))
+    }
+}
+
 async fn decode_token_from_request_part<T>(parts: &mut Parts) -> Result<T, APIErrors>
 where
     T: for<'de> serde::Deserialize<'de> + std::fmt::Debug + Sync + Send,
@@ -10,6 +10,18 @@ pub struct CreateProductRequest {
     pub categories: Option<Vec<String>>,
 }
 
+#[derive(Deserialize, Clone)]
+pub struct UpdatePrepTimeRequest {
+    pub prep_minutes: i32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateAvailabilityRequest {
+    pub is_available: bool,
+    /// When an unavailable product becomes available again, e.g. `2026-03-30T17:00`
+    pub available_again_at: Option<String>,
+}
+
 #[derive(Deserialize)]
 pub struct UpdateProductRequest {
     pub name: Option<String>,
@@ -19,7 +31,7 @@ pub struct UpdateProductRequest {
     pub categories: Option<Vec<String>>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 pub struct OrderItemRequest {
     pub product_id: i32,
     pub quantity: i32,
@@ -28,6 +40,29 @@ pub struct OrderItemRequest {
 #[derive(Deserialize)]
 pub struct CreateOrderRequest {
     pub products: Vec<OrderItemRequest>,
+    pub promo_code: Option<String>,
+    pub party_size: Option<i32>,
+    /// Staff member serving the order, receives its tips
+    pub served_by: Option<i32>,
+    /// `dine_in` (default), `takeout` or `delivery`
+    pub order_type: Option<String>,
+    /// Seated table the order is served at
+    pub table_id: Option<i32>,
+    /// Due time of a future-dated order, such as `2026-03-10T18:00` in business local time
+    pub scheduled_for: Option<String>,
+    /// Products chosen for the slots of the bundles ordered, one entry per pick
+    pub bundle_selections: Option<Vec<BundleSelectionRequest>>,
+    /// Allergens the guest flagged, such as `nuts`; items containing them are warned about
+    pub allergies: Option<Vec<String>>,
+}
+
+/// Fills a slot of a bundle on the order with a product. A slot that takes more than one
+/// product is given one entry per pick.
+#[derive(Deserialize, Clone)]
+pub struct BundleSelectionRequest {
+    pub bundle_id: i32,
+    pub slot_id: i32,
+    pub product_id: i32,
 }
 
 /// Struct for updating order status
@@ -40,18 +75,34 @@ pub struct UpdateOrderStatusRequest {
 pub struct CreateCategoryRequest {
     pub name: String,
     pub description: Option<String>,
+    /// Category to nest the new one under, the top level by default
+    pub parent_id: Option<i32>,
+    /// Position among the categories with the same parent, 0 by default
+    pub sort_order: Option<i32>,
 }
 
 #[derive(Deserialize, Clone)]
 pub struct UpdateCategoryRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// `null` moves the category to the top level
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub parent_id: Option<Option<i32>>,
+    pub sort_order: Option<i32>,
 }
 
 #[derive(Deserialize, Clone)]
 pub struct AssignCategoryRequest {
     pub category: String,
     pub product: String,
+    /// Position of the product within the category, 0 by default
+    pub sort_order: Option<i32>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct CategoryProductsQuery {
+    /// Also list the products of the categories nested under the category
+    pub include_descendants: Option<bool>,
 }
 
 #[derive(Deserialize)]
@@ -65,3 +116,517 @@ pub struct AddPermissionRequest {
     pub role_name: String,
     pub permission: String,
 }
+
+#[derive(Deserialize, Clone)]
+pub struct CreateTaxRateRequest {
+    pub name: String,
+    /// Percentage, e.g. 12.5 for 12.5%
+    pub rate: BigDecimal,
+    pub is_inclusive: Option<bool>,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateTaxRateRequest {
+    pub name: Option<String>,
+    pub rate: Option<BigDecimal>,
+    pub is_inclusive: Option<bool>,
+}
+
+/// Dates are `YYYY-MM-DDTHH:MM:SS` and daily times `HH:MM` in business local time
+#[derive(Deserialize, Clone)]
+pub struct CreatePromotionRequest {
+    pub name: String,
+    pub code: Option<String>,
+    /// `percentage` or `fixed`
+    pub discount_type: String,
+    pub discount_value: BigDecimal,
+    pub is_automatic: Option<bool>,
+    pub min_spend: Option<BigDecimal>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub daily_start_time: Option<String>,
+    pub daily_end_time: Option<String>,
+    pub usage_limit: Option<i32>,
+    pub product_ids: Option<Vec<i32>>,
+    pub category_ids: Option<Vec<i32>>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdatePromotionRequest {
+    pub name: Option<String>,
+    pub discount_value: Option<BigDecimal>,
+    pub is_active: Option<bool>,
+    pub min_spend: Option<BigDecimal>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub daily_start_time: Option<String>,
+    pub daily_end_time: Option<String>,
+    pub usage_limit: Option<i32>,
+    pub product_ids: Option<Vec<i32>>,
+    pub category_ids: Option<Vec<i32>>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CapturePaymentRequest {
+    /// `cash` or `card`
+    pub method: String,
+    /// Defaults to the balance due
+    pub amount: Option<BigDecimal>,
+    /// Approval code from the card terminal
+    pub reference: Option<String>,
+    /// Check of a split order the payment is for
+    pub check_id: Option<i32>,
+    /// Tip charged on top of the amount
+    pub tip: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct SplitOrderRequest {
+    /// `items`, `equal` or `shares`
+    pub mode: String,
+    /// Product ids of the order lines on each check, for `items`
+    pub items: Option<Vec<Vec<i32>>>,
+    /// Number of checks, for `equal`
+    pub count: Option<i32>,
+    /// Relative share of each check, e.g. amounts or percentages, for `shares`
+    pub shares: Option<Vec<BigDecimal>>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct VoidItemsRequest {
+    /// Order lines and the quantity of each to void
+    pub items: Vec<OrderItemRequest>,
+    /// e.g. `customer_request`, `wrong_item`, `quality_issue`
+    pub reason_code: String,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct RefundPaymentRequest {
+    pub payment_id: i32,
+    /// Defaults to what the customer is owed, up to what is left of the payment
+    pub amount: Option<BigDecimal>,
+    /// e.g. `customer_request`, `overcharge`, `quality_issue`
+    pub reason_code: String,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AssignServerRequest {
+    pub user_id: i32,
+}
+
+/// Inclusive range of dates such as `2026-02-09`
+#[derive(Deserialize, Clone)]
+pub struct DateRangeQuery {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateDiningAreaRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateDiningAreaRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateTableRequest {
+    pub area_id: i32,
+    pub name: String,
+    pub capacity: i32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateTableRequest {
+    pub area_id: Option<i32>,
+    pub name: Option<String>,
+    pub capacity: Option<i32>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateTableStatusRequest {
+    /// `free`, `dirty` or `reserved`
+    pub status: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct OpenTableSessionRequest {
+    pub party_size: Option<i32>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct TableQuery {
+    pub area_id: Option<i32>,
+}
+
+/// `reserved_for` is `YYYY-MM-DDTHH:MM` in business local time
+#[derive(Deserialize, Clone)]
+pub struct CreateReservationRequest {
+    pub contact_name: String,
+    pub contact_phone: String,
+    pub party_size: i32,
+    pub reserved_for: String,
+    /// Table to hold, by default the smallest free table that seats the party
+    pub table_id: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateReservationRequest {
+    pub contact_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub party_size: Option<i32>,
+    pub reserved_for: Option<String>,
+    pub table_id: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateReservationStatusRequest {
+    /// `no_show` or `cancelled`
+    pub status: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct SeatPartyRequest {
+    pub table_id: Option<i32>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ReservationQuery {
+    /// Day such as `2026-02-23`, today by default
+    pub date: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct AvailabilityQuery {
+    pub party_size: i32,
+    pub reserved_for: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateWaitlistEntryRequest {
+    pub contact_name: String,
+    pub contact_phone: Option<String>,
+    pub party_size: i32,
+    pub notes: Option<String>,
+}
+
+/// Finds orders by their daily number
+#[derive(Deserialize, Clone, Default)]
+pub struct OrderQuery {
+    /// Daily order number such as `7` or `#007`
+    pub number: Option<String>,
+    /// Business day such as `2026-03-02`, the current one by default
+    pub date: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateStationRequest {
+    pub name: String,
+    /// Tickets the station prepares at the same time, 1 by default
+    pub capacity: Option<i32>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateStationRequest {
+    pub name: Option<String>,
+    pub capacity: Option<i32>,
+}
+
+/// Products and categories prepared at a station, replacing the current ones
+#[derive(Deserialize, Clone)]
+pub struct StationRoutingRequest {
+    pub product_ids: Option<Vec<i32>>,
+    pub category_ids: Option<Vec<i32>>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct TicketQuery {
+    /// Only the tickets of this station
+    pub station_id: Option<i32>,
+    /// `open` or `bumped`, `open` by default
+    pub status: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateIngredientRequest {
+    pub name: String,
+    /// Unit quantities are counted in, e.g. `kg`, `l` or `pcs`
+    pub unit: String,
+    /// Stock is low at or below this level, 0 by default
+    pub reorder_level: Option<BigDecimal>,
+    /// Supplier the ingredient is usually bought from
+    pub supplier_id: Option<i32>,
+    /// Stock suggested purchase orders top up to, 0 by default
+    pub par_level: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateIngredientRequest {
+    pub name: Option<String>,
+    pub unit: Option<String>,
+    pub reorder_level: Option<BigDecimal>,
+    pub supplier_id: Option<i32>,
+    pub par_level: Option<BigDecimal>,
+}
+
+/// Change to the stock on hand of an ingredient, e.g. a delivery or a stock count correction
+#[derive(Deserialize, Clone)]
+pub struct StockAdjustmentRequest {
+    /// Added to the stock on hand, negative to take stock out
+    pub quantity: BigDecimal,
+    pub note: Option<String>,
+}
+
+/// Ingredients that go into one of a product, replacing the current recipe
+#[derive(Deserialize, Clone)]
+pub struct RecipeRequest {
+    pub items: Vec<RecipeItemRequest>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct RecipeItemRequest {
+    pub ingredient_id: i32,
+    pub quantity: BigDecimal,
+}
+
+/// Slots of a bundle product, replacing the current ones. A product without slots is not a
+/// bundle.
+#[derive(Deserialize, Clone)]
+pub struct BundleRequest {
+    pub slots: Vec<BundleSlotRequest>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct BundleSlotRequest {
+    /// Such as `Drink`
+    pub name: String,
+    /// How many products are chosen for the slot, 1 by default
+    pub quantity: Option<i32>,
+    pub choices: Vec<BundleChoiceRequest>,
+}
+
+/// Either a product or a category whose products can fill the slot
+#[derive(Deserialize, Clone)]
+pub struct BundleChoiceRequest {
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    /// Added to the bundle price per pick, none by default
+    pub upcharge: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateSupplierRequest {
+    pub name: String,
+    pub contact_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateSupplierRequest {
+    pub name: Option<String>,
+    pub contact_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreatePurchaseOrderRequest {
+    pub supplier_id: i32,
+    pub note: Option<String>,
+    pub lines: Vec<PurchaseOrderLineRequest>,
+}
+
+/// Changes to a draft purchase order; `lines` replaces all of its lines
+#[derive(Deserialize, Clone)]
+pub struct UpdatePurchaseOrderRequest {
+    pub supplier_id: Option<i32>,
+    pub note: Option<String>,
+    pub lines: Option<Vec<PurchaseOrderLineRequest>>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PurchaseOrderLineRequest {
+    pub ingredient_id: i32,
+    pub quantity: BigDecimal,
+    pub unit_cost: BigDecimal,
+}
+
+/// Stock delivered against a purchase order; everything still outstanding when `lines` is
+/// left out
+#[derive(Deserialize, Clone, Default)]
+pub struct ReceivePurchaseOrderRequest {
+    pub lines: Option<Vec<ReceiptLineRequest>>,
+    pub note: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ReceiptLineRequest {
+    pub ingredient_id: i32,
+    pub quantity: BigDecimal,
+    /// Cost actually paid per unit, the ordered unit cost by default
+    pub unit_cost: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+pub struct PurchaseOrderQuery {
+    /// `draft`, `sent`, `partially_received` or `received`
+    pub status: Option<String>,
+    pub supplier_id: Option<i32>,
+}
+
+/// Stock thrown away; either an ingredient or a number of a product, whose recipe is taken
+/// out of stock
+#[derive(Deserialize, Clone)]
+pub struct LogWasteRequest {
+    pub ingredient_id: Option<i32>,
+    pub product_id: Option<i32>,
+    pub quantity: BigDecimal,
+    /// `dropped`, `burnt`, `expired`, `spoiled` or `other`
+    pub reason: String,
+    pub note: Option<String>,
+}
+
+/// A new price for a product, taking effect at `effective_from` or straight away
+#[derive(Deserialize, Clone)]
+pub struct SchedulePriceRequest {
+    pub price: BigDecimal,
+    /// Local time such as `2026-05-11T00:00`, in the future when given
+    pub effective_from: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CreateMenuRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Offset from UTC the schedule is in, such as `+09:00`, business time by default
+    pub utc_offset: Option<String>,
+    /// True by default
+    pub is_active: Option<bool>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct UpdateMenuRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// An empty offset moves the schedule back to business time
+    pub utc_offset: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Weekly windows a menu is served in, replacing the current ones
+#[derive(Deserialize, Clone)]
+pub struct MenuScheduleRequest {
+    pub windows: Vec<MenuWindowRequest>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct MenuWindowRequest {
+    /// Day of the week the window starts on, such as `mon` or `monday`
+    pub day: String,
+    /// Time of day such as `07:00`
+    pub start: String,
+    /// Ends the next day when it is before `start`
+    pub end: String,
+}
+
+/// Products and categories on a menu, replacing the current ones
+#[derive(Deserialize, Clone)]
+pub struct MenuItemsRequest {
+    pub product_ids: Option<Vec<i32>>,
+    pub category_ids: Option<Vec<i32>>,
+}
+
+/// Filters the products listed by their allergens and diets
+#[derive(Deserialize, Clone, Default)]
+pub struct ProductQuery {
+    /// Comma separated allergens the products must not contain, such as `nuts,milk`
+    pub exclude_allergens: Option<String>,
+    /// Comma separated diets the products must suit, such as `vegan`
+    pub diet: Option<String>,
+}
+
+/// Allergens and dietary tags of a product or ingredient, replacing the current ones
+#[derive(Deserialize, Clone, Default)]
+pub struct DietaryTagsRequest {
+    /// Such as `gluten` or `nuts`, out of the 14 allergens declared in the EU
+    #[serde(default)]
+    pub allergens: Vec<String>,
+    /// `vegetarian`, `vegan`, `pescatarian`, `halal`, `kosher` or `gluten_free`
+    #[serde(default)]
+    pub dietary_tags: Vec<String>,
+}
+
+/// Nutrition facts per serving of a product, replacing the current ones
+#[derive(Deserialize, Clone, Default)]
+pub struct NutritionRequest {
+    /// Such as `1 burger (250 g)`
+    pub serving_size: Option<String>,
+    /// Sums the nutrition of the recipe ingredients instead of taking the values given, false
+    /// by default
+    pub from_recipe: Option<bool>,
+    /// The values are required unless `from_recipe` is set
+    pub kcal: Option<BigDecimal>,
+    /// Grams per serving, like the other macros and salt
+    pub protein: Option<BigDecimal>,
+    pub carbohydrates: Option<BigDecimal>,
+    pub sugars: Option<BigDecimal>,
+    pub fat: Option<BigDecimal>,
+    pub salt: Option<BigDecimal>,
+}
+
+/// Nutrition of one unit of an ingredient, in the unit its stock is counted in
+#[derive(Deserialize, Clone)]
+pub struct IngredientNutritionRequest {
+    pub kcal: BigDecimal,
+    pub protein: BigDecimal,
+    pub carbohydrates: BigDecimal,
+    pub sugars: BigDecimal,
+    pub fat: BigDecimal,
+    pub salt: BigDecimal,
+}
+
+/// Nutrition of many products and ingredients, imported all at once
+#[derive(Deserialize, Clone, Default)]
+pub struct NutritionImportRequest {
+    #[serde(default)]
+    pub products: Vec<ProductNutritionImport>,
+    #[serde(default)]
+    pub ingredients: Vec<IngredientNutritionImport>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ProductNutritionImport {
+    pub product_id: i32,
+    pub nutrition: NutritionRequest,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct IngredientNutritionImport {
+    pub ingredient_id: i32,
+    pub nutrition: IngredientNutritionRequest,
+}
+
+/// Locale asked for through the `lang` query parameter, such as `?lang=es`
+#[derive(Deserialize, Clone, Default)]
+pub struct LocaleQuery {
+    pub lang: Option<String>,
+}
+
+/// Name and description of a product or category in a locale, replacing the current ones
+#[derive(Deserialize, Clone)]
+pub struct TranslationRequest {
+    pub name: String,
+    /// The untranslated description is shown when left out
+    pub description: Option<String>,
+}
@@ -14,7 +14,39 @@ pub struct OrderResponse {
     pub user_id: i32,
     pub products: Vec<ProductResponse>,
     pub quantity: i32,
+    pub subtotal: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub discount_amount: BigDecimal,
     pub total_amount: BigDecimal,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discounts: Vec<OrderDiscountResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub party_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub served_by: Option<i32>,
+    pub gratuity_amount: BigDecimal,
+    pub tip_amount: BigDecimal,
+    pub order_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<i32>,
+    /// Number of the order within its business day, such as `#007`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_for: Option<String>,
+    /// Items containing an allergen the guest flagged
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allergen_alerts: Vec<AllergenAlertResponse>,
+    /// When the kitchen expects the order to be ready, kept up to date with its queue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_ready_at: Option<String>,
+    pub amount_paid: BigDecimal,
+    pub balance_due: BigDecimal,
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
@@ -28,7 +60,22 @@ pub struct ProductResponse {
     pub description: Option<String>,
     pub price: BigDecimal,
     pub product_image_uri: Option<String>,
+    pub prep_minutes: i32,
+    pub is_available: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_again_at: Option<String>,
     pub categories: Option<Vec<CategoryResponse>>,
+    /// Allergens set on the product or its recipe ingredients
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allergens: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dietary_tags: Vec<String>,
+    /// Nutrition facts per serving, left out when the product has none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nutrition: Option<NutritionResponse>,
+    /// When the product was archived
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -39,6 +86,12 @@ pub struct CategoryResponse {
     pub description: Option<String>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
+    /// When the category was archived
+    pub deleted_at: Option<String>,
+    pub parent_id: Option<i32>,
+    pub sort_order: i32,
+    /// Categories nested under this one, only set in the category tree
+    pub children: Option<Vec<CategoryResponse>>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -46,3 +99,502 @@ pub struct ProductCategoryResponse {
     pub product: ProductResponse,
     pub category: CategoryResponse,
 }
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct TaxRateResponse {
+    pub tax_rate_id: i32,
+    pub name: String,
+    pub rate: BigDecimal,
+    pub is_inclusive: bool,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OrderDiscountResponse {
+    pub promotion_id: Option<i32>,
+    pub name: String,
+    pub code: Option<String>,
+    pub amount: BigDecimal,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct PromotionResponse {
+    pub promotion_id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    pub discount_type: String,
+    pub discount_value: BigDecimal,
+    pub is_automatic: bool,
+    pub is_active: bool,
+    pub min_spend: Option<BigDecimal>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub daily_start_time: Option<String>,
+    pub daily_end_time: Option<String>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub product_ids: Vec<i32>,
+    pub category_ids: Vec<i32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct PaymentResponse {
+    pub payment_id: i32,
+    pub order_id: i32,
+    pub amount: BigDecimal,
+    pub tip_amount: BigDecimal,
+    pub method: String,
+    pub provider: String,
+    pub status: String,
+    pub provider_reference: Option<String>,
+    pub check_id: Option<i32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CheckResponse {
+    pub check_id: i32,
+    pub check_number: i32,
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub total_amount: BigDecimal,
+    pub gratuity_amount: BigDecimal,
+    pub amount_paid: BigDecimal,
+    pub balance_due: BigDecimal,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub product_ids: Vec<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct OrderAdjustmentResponse {
+    pub adjustment_id: i32,
+    pub order_id: i32,
+    pub adjustment_type: String,
+    pub product_id: Option<i32>,
+    pub quantity: Option<i32>,
+    pub refund_id: Option<i32>,
+    pub amount: BigDecimal,
+    pub reason_code: Option<String>,
+    pub note: Option<String>,
+    pub user_id: Option<i32>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StaffTipsResponse {
+    pub user_id: i32,
+    pub username: Option<String>,
+    pub order_count: i32,
+    pub gratuity_amount: BigDecimal,
+    pub tip_amount: BigDecimal,
+    pub total_tips: BigDecimal,
+    pub pool_share: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TipPoolResponse {
+    pub from: String,
+    pub to: String,
+    pub total_tips: BigDecimal,
+    pub staff: Vec<StaffTipsResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DiningAreaResponse {
+    pub area_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TableResponse {
+    pub table_id: i32,
+    pub area_id: i32,
+    pub name: String,
+    pub capacity: i32,
+    pub status: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// A table session with the combined check of its orders
+#[derive(Serialize, Deserialize)]
+pub struct TableSessionResponse {
+    pub session_id: i32,
+    pub table_id: i32,
+    pub party_size: Option<i32>,
+    pub opened_by: Option<i32>,
+    pub opened_at: Option<String>,
+    pub closed_at: Option<String>,
+    pub order_ids: Vec<i32>,
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub gratuity_amount: BigDecimal,
+    pub total_amount: BigDecimal,
+    pub amount_paid: BigDecimal,
+    pub balance_due: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReservationResponse {
+    pub reservation_id: i32,
+    pub contact_name: String,
+    pub contact_phone: String,
+    pub party_size: i32,
+    pub reserved_for: String,
+    pub table_id: Option<i32>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub session_id: Option<i32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AvailabilityResponse {
+    pub available: bool,
+    /// Table that would be held for the reservation
+    pub table_id: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WaitlistEntryResponse {
+    pub entry_id: i32,
+    pub contact_name: String,
+    pub contact_phone: Option<String>,
+    pub party_size: i32,
+    pub quoted_wait_minutes: i32,
+    pub status: String,
+    pub notes: Option<String>,
+    pub session_id: Option<i32>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StationResponse {
+    pub station_id: i32,
+    pub name: String,
+    pub capacity: i32,
+    pub product_ids: Vec<i32>,
+    pub category_ids: Vec<i32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// The items of an order one station prepares
+#[derive(Serialize, Deserialize)]
+pub struct KitchenTicketResponse {
+    pub ticket_id: i32,
+    pub order_id: i32,
+    pub order_number: Option<String>,
+    /// Missing for items not routed to any station
+    pub station_id: Option<i32>,
+    pub status: String,
+    pub items: Vec<KitchenTicketItemResponse>,
+    pub created_at: Option<String>,
+    pub bumped_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct KitchenTicketItemResponse {
+    pub product_id: i32,
+    pub name: String,
+    pub quantity: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IngredientResponse {
+    pub ingredient_id: i32,
+    pub name: String,
+    pub unit: String,
+    pub on_hand: BigDecimal,
+    pub reorder_level: BigDecimal,
+    /// Whether the stock is at or below the reorder level
+    pub is_low: bool,
+    pub supplier_id: Option<i32>,
+    pub par_level: BigDecimal,
+    /// Weighted average unit cost of the stock received
+    pub average_cost: BigDecimal,
+    /// Value of the stock on hand at its average cost
+    pub stock_value: BigDecimal,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RecipeResponse {
+    pub product_id: i32,
+    pub items: Vec<RecipeItemResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RecipeItemResponse {
+    pub ingredient_id: i32,
+    pub name: String,
+    pub unit: String,
+    pub quantity: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundleResponse {
+    pub product_id: i32,
+    pub name: String,
+    /// Price of the bundle before upcharges
+    pub price: BigDecimal,
+    /// Empty when the product is not a bundle
+    pub slots: Vec<BundleSlotResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundleSlotResponse {
+    pub slot_id: i32,
+    pub name: String,
+    pub quantity: i32,
+    pub choices: Vec<BundleChoiceResponse>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct BundleChoiceResponse {
+    pub choice_id: i32,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub upcharge: BigDecimal,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct NutritionResponse {
+    pub serving_size: Option<String>,
+    pub kcal: BigDecimal,
+    pub protein: BigDecimal,
+    pub carbohydrates: BigDecimal,
+    pub sugars: BigDecimal,
+    pub fat: BigDecimal,
+    pub salt: BigDecimal,
+    /// Whether the facts are summed from the recipe ingredients
+    pub from_recipe: bool,
+}
+
+/// How many products and ingredients a nutrition import updated
+#[derive(Serialize, Deserialize)]
+pub struct NutritionImportResponse {
+    pub products: usize,
+    pub ingredients: usize,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct TranslationResponse {
+    pub locale: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DietaryTagsResponse {
+    pub allergens: Vec<String>,
+    pub dietary_tags: Vec<String>,
+}
+
+/// An item of an order containing an allergen the guest flagged
+#[derive(Serialize, Deserialize)]
+pub struct AllergenAlertResponse {
+    pub product_id: i32,
+    pub allergen: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SupplierResponse {
+    pub supplier_id: i32,
+    pub name: String,
+    pub contact_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PurchaseOrderResponse {
+    pub purchase_order_id: i32,
+    pub supplier_id: i32,
+    pub supplier_name: String,
+    pub status: String,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+    pub lines: Vec<PurchaseOrderLineResponse>,
+    /// Ordered quantities at their unit costs
+    pub total_cost: BigDecimal,
+    pub sent_at: Option<String>,
+    pub received_at: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PurchaseOrderLineResponse {
+    pub ingredient_id: i32,
+    pub name: String,
+    pub unit: String,
+    pub quantity: BigDecimal,
+    pub unit_cost: BigDecimal,
+    pub received_quantity: BigDecimal,
+}
+
+/// Ingredients running low, grouped by the supplier they are usually bought from
+#[derive(Serialize, Deserialize)]
+pub struct PurchaseSuggestionResponse {
+    /// Missing for ingredients without a supplier
+    pub supplier_id: Option<i32>,
+    pub supplier_name: Option<String>,
+    pub lines: Vec<SuggestedLineResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SuggestedLineResponse {
+    pub ingredient_id: i32,
+    pub name: String,
+    pub unit: String,
+    pub on_hand: BigDecimal,
+    /// Still to be received on open purchase orders
+    pub on_order: BigDecimal,
+    pub reorder_level: BigDecimal,
+    pub par_level: BigDecimal,
+    /// Quantity to order
+    pub quantity: BigDecimal,
+    /// The average cost of the ingredient
+    pub unit_cost: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasteEntryResponse {
+    pub waste_id: i32,
+    pub ingredient_id: Option<i32>,
+    pub product_id: Option<i32>,
+    /// Name of the ingredient or product
+    pub name: Option<String>,
+    /// Unit of the ingredient, missing for products
+    pub unit: Option<String>,
+    pub quantity: BigDecimal,
+    pub reason: String,
+    pub cost: BigDecimal,
+    pub note: Option<String>,
+    pub user_id: Option<i32>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasteReportResponse {
+    pub from: String,
+    pub to: String,
+    pub total_cost: BigDecimal,
+    pub by_reason: Vec<WasteTotalResponse>,
+    pub by_day: Vec<WasteTotalResponse>,
+    /// Theoretical against actual usage of each ingredient that moved over the period
+    pub usage: Vec<IngredientUsageResponse>,
+}
+
+/// Waste logged under one reason or on one day
+#[derive(Serialize, Deserialize)]
+pub struct WasteTotalResponse {
+    /// The reason, or the date such as `2026-04-20`
+    pub key: String,
+    pub entry_count: i32,
+    pub cost: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IngredientUsageResponse {
+    pub ingredient_id: i32,
+    pub name: String,
+    pub unit: String,
+    /// What the recipes of the orders used
+    pub theoretical: BigDecimal,
+    /// What was logged as waste
+    pub waste: BigDecimal,
+    /// Everything that left stock other than through purchases, including count corrections
+    pub actual: BigDecimal,
+    /// Actual less theoretical usage
+    pub variance: BigDecimal,
+    /// The variance at the average cost of the ingredient
+    pub variance_cost: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProductPriceResponse {
+    pub price_id: i32,
+    pub product_id: i32,
+    pub price: BigDecimal,
+    pub effective_from: String,
+    /// Missing for the latest price
+    pub effective_to: Option<String>,
+    /// Whether this is the price the product is sold at now
+    pub is_current: bool,
+    pub created_by: Option<i32>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MenuResponse {
+    pub menu_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub utc_offset: Option<String>,
+    pub is_active: bool,
+    /// Whether the menu is being served now
+    pub is_open: bool,
+    pub windows: Vec<MenuWindowResponse>,
+    pub product_ids: Vec<i32>,
+    pub category_ids: Vec<i32>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MenuWindowResponse {
+    pub day: String,
+    pub start: String,
+    pub end: String,
+}
+
+/// What can be ordered at a moment
+#[derive(Serialize, Deserialize)]
+pub struct CurrentMenuResponse {
+    /// Business time the menus were resolved at
+    pub at: String,
+    /// The menus being served
+    pub menus: Vec<MenuResponse>,
+    /// Products that can be ordered: those on a menu being served and those on no menu
+    pub products: Vec<ProductResponse>,
+}
+
+/// An uploaded product image and its thumbnails
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ProductImageResponse {
+    pub product_image_uri: String,
+    pub thumbnails: Vec<ThumbnailResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ThumbnailResponse {
+    /// Longest side of the thumbnail, in pixels
+    pub size: u32,
+    pub image_uri: String,
+}
@@ -1,4 +1,4 @@
-use crate::api::controllers::category_controller;
+use crate::api::controllers::{category_controller, translation_controller};
 use axum::Router;
 use axum::routing::{delete, get, post, put};
 
@@ -6,8 +6,26 @@ pub fn routes() -> Router {
     Router::new()
         .route("/", get(category_controller::get_categories))
         .route("/", post(category_controller::add_category))
+        .route("/tree", get(category_controller::get_category_tree))
+        .route(
+            "/archived",
+            get(category_controller::get_archived_categories),
+        )
         .route("/{id}", put(category_controller::edit_category))
         .route("/{id}", delete(category_controller::delete_category))
+        .route("/{id}/restore", post(category_controller::restore_category))
+        .route(
+            "/{id}/translations",
+            get(translation_controller::get_category_translations),
+        )
+        .route(
+            "/{id}/translations/{locale}",
+            put(translation_controller::set_category_translation),
+        )
+        .route(
+            "/{id}/translations/{locale}",
+            delete(translation_controller::remove_category_translation),
+        )
         .route(
             "/product",
             post(category_controller::add_product_to_category),
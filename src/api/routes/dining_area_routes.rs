@@ -0,0 +1,11 @@
+use crate::api::controllers::table_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(table_controller::get_dining_areas))
+        .route("/", post(table_controller::create_dining_area))
+        .route("/{id}", put(table_controller::update_dining_area))
+        .route("/{id}", delete(table_controller::delete_dining_area))
+}
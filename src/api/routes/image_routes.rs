@@ -0,0 +1,7 @@
+use crate::api::controllers::image_controller;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router {
+    Router::new().route("/{*key}", get(image_controller::get_image))
+}
@@ -0,0 +1,24 @@
+use crate::api::controllers::{allergen_controller, inventory_controller, nutrition_controller};
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(inventory_controller::get_ingredients))
+        .route("/", post(inventory_controller::create_ingredient))
+        .route("/low-stock", get(inventory_controller::get_low_stock))
+        .route("/{id}", put(inventory_controller::update_ingredient))
+        .route("/{id}", delete(inventory_controller::delete_ingredient))
+        .route(
+            "/{id}/adjustments",
+            post(inventory_controller::adjust_stock),
+        )
+        .route(
+            "/{id}/allergens",
+            put(allergen_controller::set_ingredient_allergens),
+        )
+        .route(
+            "/{id}/nutrition",
+            put(nutrition_controller::set_ingredient_nutrition),
+        )
+}
@@ -0,0 +1,15 @@
+use crate::api::controllers::menu_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(menu_controller::get_menus))
+        .route("/", post(menu_controller::create_menu))
+        .route("/current", get(menu_controller::get_current_menu))
+        .route("/{id}", get(menu_controller::get_menu))
+        .route("/{id}", put(menu_controller::update_menu))
+        .route("/{id}", delete(menu_controller::delete_menu))
+        .route("/{id}/schedule", put(menu_controller::set_menu_schedule))
+        .route("/{id}/items", put(menu_controller::set_menu_items))
+}
@@ -1,6 +1,21 @@
 pub mod auth_routes;
 pub mod category_routes;
+pub mod dining_area_routes;
+pub mod image_routes;
+pub mod ingredient_routes;
+pub mod menu_routes;
 pub mod order_routes;
 pub mod product_routes;
+pub mod promotion_routes;
+pub mod purchase_order_routes;
+pub mod reservation_routes;
 pub mod role_routes;
+pub mod station_routes;
+pub mod supplier_routes;
+pub mod table_routes;
+pub mod tax_rate_routes;
+pub mod ticket_routes;
+pub mod tip_routes;
 pub mod user_routes;
+pub mod waitlist_routes;
+pub mod waste_routes;
@@ -1,13 +1,31 @@
-use crate::api::controllers::order_controller;
+use crate::api::controllers::{
+    check_controller, order_controller, payment_controller, tip_controller,
+};
 use axum::Router;
-use axum::routing::{get, post};
+use axum::routing::{delete, get, post, put};
 
 pub fn routes() -> Router {
     Router::new()
         .route("/", get(order_controller::get_all_orders))
         .route("/", post(order_controller::create_order))
+        .route("/kitchen", get(order_controller::get_kitchen_orders))
         .route("/{id}", get(order_controller::get_order_by_id))
         .route("/{id}", post(order_controller::update_order_status))
+        .route("/{id}/payments", get(payment_controller::get_payments))
+        .route("/{id}/payments", post(payment_controller::capture_payment))
+        .route("/{id}/checks", get(check_controller::get_checks))
+        .route("/{id}/checks", post(check_controller::split_order))
+        .route("/{id}/checks", delete(check_controller::remove_checks))
+        .route("/{id}/voids", post(order_controller::void_order_items))
+        .route(
+            "/{id}/refunds",
+            post(order_controller::refund_order_payment),
+        )
+        .route("/{id}/server", put(tip_controller::assign_server))
+        .route(
+            "/{id}/adjustments",
+            get(order_controller::get_order_adjustments),
+        )
         .route(
             "/user/{username}",
             get(order_controller::get_user_orders_by_name),
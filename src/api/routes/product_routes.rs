@@ -1,12 +1,81 @@
-use crate::api::controllers::product_controller;
+use crate::api::config::Config;
+use crate::api::controllers::{
+    allergen_controller, bundle_controller, image_controller, inventory_controller,
+    nutrition_controller, product_controller, product_price_controller, translation_controller,
+};
 use axum::Router;
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{delete, get, post, put};
 
+/// Room for the rest of a multipart form around an uploaded image
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
 pub fn routes() -> Router {
+    let image_body_limit = Config::new().max_image_bytes + MULTIPART_OVERHEAD_BYTES;
+
     Router::new()
         .route("/", get(product_controller::get_all_products))
         .route("/", post(product_controller::create_product))
+        .route("/archived", get(product_controller::get_archived_products))
+        .route(
+            "/nutrition/import",
+            post(nutrition_controller::import_nutrition),
+        )
         .route("/{id}", get(product_controller::get_product_by_id))
         .route("/{id}", put(product_controller::update_product))
         .route("/{id}", delete(product_controller::delete_product))
+        .route(
+            "/{id}/image",
+            post(image_controller::upload_product_image)
+                .layer(DefaultBodyLimit::max(image_body_limit)),
+        )
+        .route(
+            "/{id}/prep-time",
+            put(product_controller::update_product_prep_time),
+        )
+        .route(
+            "/{id}/availability",
+            put(product_controller::update_product_availability),
+        )
+        .route(
+            "/{id}/prices",
+            get(product_price_controller::get_price_timeline),
+        )
+        .route(
+            "/{id}/prices",
+            post(product_price_controller::schedule_price_change),
+        )
+        .route(
+            "/{id}/prices/{price_id}",
+            delete(product_price_controller::cancel_price_change),
+        )
+        .route(
+            "/{id}/allergens",
+            put(allergen_controller::set_product_allergens),
+        )
+        .route(
+            "/{id}/nutrition",
+            put(nutrition_controller::set_product_nutrition),
+        )
+        .route(
+            "/{id}/nutrition",
+            delete(nutrition_controller::remove_product_nutrition),
+        )
+        .route("/{id}/bundle", get(bundle_controller::get_bundle))
+        .route("/{id}/bundle", put(bundle_controller::set_bundle))
+        .route("/{id}/recipe", get(inventory_controller::get_recipe))
+        .route("/{id}/recipe", put(inventory_controller::set_recipe))
+        .route(
+            "/{id}/translations",
+            get(translation_controller::get_product_translations),
+        )
+        .route(
+            "/{id}/translations/{locale}",
+            put(translation_controller::set_product_translation),
+        )
+        .route(
+            "/{id}/translations/{locale}",
+            delete(translation_controller::remove_product_translation),
+        )
+        .route("/{id}/restore", post(product_controller::restore_product))
 }
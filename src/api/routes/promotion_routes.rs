@@ -0,0 +1,11 @@
+use crate::api::controllers::promotion_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(promotion_controller::get_promotions))
+        .route("/", post(promotion_controller::create_promotion))
+        .route("/{id}", put(promotion_controller::update_promotion))
+        .route("/{id}", delete(promotion_controller::delete_promotion))
+}
@@ -0,0 +1,27 @@
+use crate::api::controllers::purchasing_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(purchasing_controller::get_purchase_orders))
+        .route("/", post(purchasing_controller::create_purchase_order))
+        .route(
+            "/suggestions",
+            get(purchasing_controller::get_purchase_suggestions),
+        )
+        .route("/{id}", get(purchasing_controller::get_purchase_order))
+        .route("/{id}", put(purchasing_controller::update_purchase_order))
+        .route(
+            "/{id}",
+            delete(purchasing_controller::delete_purchase_order),
+        )
+        .route(
+            "/{id}/send",
+            post(purchasing_controller::send_purchase_order),
+        )
+        .route(
+            "/{id}/receive",
+            post(purchasing_controller::receive_purchase_order),
+        )
+}
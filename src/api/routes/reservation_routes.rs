@@ -0,0 +1,19 @@
+use crate::api::controllers::reservation_controller;
+use axum::Router;
+use axum::routing::{get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(reservation_controller::get_reservations))
+        .route("/", post(reservation_controller::create_reservation))
+        .route(
+            "/availability",
+            get(reservation_controller::check_availability),
+        )
+        .route("/{id}", put(reservation_controller::update_reservation))
+        .route(
+            "/{id}/status",
+            post(reservation_controller::update_reservation_status),
+        )
+        .route("/{id}/seat", post(reservation_controller::seat_reservation))
+}
@@ -0,0 +1,15 @@
+use crate::api::controllers::kitchen_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(kitchen_controller::get_stations))
+        .route("/", post(kitchen_controller::create_station))
+        .route("/{id}", put(kitchen_controller::update_station))
+        .route("/{id}", delete(kitchen_controller::delete_station))
+        .route(
+            "/{id}/routing",
+            put(kitchen_controller::set_station_routing),
+        )
+}
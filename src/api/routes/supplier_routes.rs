@@ -0,0 +1,11 @@
+use crate::api::controllers::purchasing_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(purchasing_controller::get_suppliers))
+        .route("/", post(purchasing_controller::create_supplier))
+        .route("/{id}", put(purchasing_controller::update_supplier))
+        .route("/{id}", delete(purchasing_controller::delete_supplier))
+}
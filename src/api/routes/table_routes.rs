@@ -0,0 +1,18 @@
+use crate::api::controllers::table_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(table_controller::get_tables))
+        .route("/", post(table_controller::create_table))
+        .route("/{id}", put(table_controller::update_table))
+        .route("/{id}", delete(table_controller::delete_table))
+        .route("/{id}/status", put(table_controller::update_table_status))
+        .route("/{id}/session", get(table_controller::get_table_session))
+        .route("/{id}/session", post(table_controller::open_table_session))
+        .route(
+            "/{id}/session/close",
+            post(table_controller::close_table_session),
+        )
+}
@@ -0,0 +1,11 @@
+use crate::api::controllers::tax_rate_controller;
+use axum::Router;
+use axum::routing::{delete, get, post, put};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(tax_rate_controller::get_tax_rates))
+        .route("/", post(tax_rate_controller::create_tax_rate))
+        .route("/{id}", put(tax_rate_controller::update_tax_rate))
+        .route("/{id}", delete(tax_rate_controller::delete_tax_rate))
+}
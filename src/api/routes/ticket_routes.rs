@@ -0,0 +1,9 @@
+use crate::api::controllers::kitchen_controller;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(kitchen_controller::get_tickets))
+        .route("/{id}/bump", post(kitchen_controller::bump_ticket))
+}
@@ -0,0 +1,7 @@
+use crate::api::controllers::tip_controller;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router {
+    Router::new().route("/pool", get(tip_controller::get_tip_pool))
+}
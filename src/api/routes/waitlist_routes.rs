@@ -0,0 +1,14 @@
+use crate::api::controllers::reservation_controller;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(reservation_controller::get_waitlist))
+        .route("/", post(reservation_controller::join_waitlist))
+        .route(
+            "/{id}/seat",
+            post(reservation_controller::seat_waitlist_entry),
+        )
+        .route("/{id}/leave", post(reservation_controller::leave_waitlist))
+}
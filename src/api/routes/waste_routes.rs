@@ -0,0 +1,10 @@
+use crate::api::controllers::waste_controller;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(waste_controller::get_waste))
+        .route("/", post(waste_controller::log_waste))
+        .route("/report", get(waste_controller::get_waste_report))
+}
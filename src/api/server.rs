@@ -1,6 +1,10 @@
 use crate::api::routes::{
-    auth_routes, category_routes, order_routes, product_routes, role_routes, user_routes,
+    auth_routes, category_routes, dining_area_routes, image_routes, ingredient_routes, menu_routes,
+    order_routes, product_routes, promotion_routes, purchase_order_routes, reservation_routes,
+    role_routes, station_routes, supplier_routes, table_routes, tax_rate_routes, ticket_routes,
+    tip_routes, user_routes, waitlist_routes, waste_routes,
 };
+use crate::services::order_scheduler::OrderScheduler;
 use axum::body::Body;
 use axum::extract::Request;
 use axum::middleware::Next;
@@ -19,8 +23,23 @@ pub async fn start() {
         .nest("/api/v1/users", user_routes::routes())
         .nest("/api/v1/roles", role_routes::routes())
         .nest("/api/v1/products", product_routes::routes())
+        .nest("/api/v1/images", image_routes::routes())
         .nest("/api/v1/categories", category_routes::routes())
+        .nest("/api/v1/menus", menu_routes::routes())
         .nest("/api/v1/orders", order_routes::routes())
+        .nest("/api/v1/tax-rates", tax_rate_routes::routes())
+        .nest("/api/v1/promotions", promotion_routes::routes())
+        .nest("/api/v1/tips", tip_routes::routes())
+        .nest("/api/v1/dining-areas", dining_area_routes::routes())
+        .nest("/api/v1/tables", table_routes::routes())
+        .nest("/api/v1/reservations", reservation_routes::routes())
+        .nest("/api/v1/waitlist", waitlist_routes::routes())
+        .nest("/api/v1/stations", station_routes::routes())
+        .nest("/api/v1/tickets", ticket_routes::routes())
+        .nest("/api/v1/ingredients", ingredient_routes::routes())
+        .nest("/api/v1/suppliers", supplier_routes::routes())
+        .nest("/api/v1/purchase-orders", purchase_order_routes::routes())
+        .nest("/api/v1/waste", waste_routes::routes())
         .with_state::<()>(())
         .layer(cors_layer)
         .layer(middleware::from_fn(logging_middleware));
@@ -31,6 +50,8 @@ pub async fn start() {
 
     tracing::info!("Listening on port 3000");
 
+    OrderScheduler::new().spawn();
+
     axum::serve(listener, router)
         .await
         .expect("Failed to start the server");
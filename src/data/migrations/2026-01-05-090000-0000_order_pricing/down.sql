@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `orders`
+    DROP COLUMN subtotal,
+    DROP COLUMN tax_amount,
+    DROP COLUMN service_charge;
+
+DROP TABLE `tax_rates`;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE `tax_rates` (
+    tax_rate_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL,
+    rate DECIMAL(6, 3) NOT NULL, -- Percentage, e.g. 12.000 for 12%
+    is_inclusive BOOLEAN NOT NULL DEFAULT FALSE,
+    product_id INT,
+    category_id INT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES categories(category_id) ON DELETE CASCADE
+);
+
+ALTER TABLE `orders`
+    ADD COLUMN subtotal DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    ADD COLUMN tax_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    ADD COLUMN service_charge DECIMAL(10, 2) NOT NULL DEFAULT 0.00;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `orders`
+    DROP COLUMN discount_amount;
+
+DROP TABLE `order_discounts`;
+DROP TABLE `promotion_categories`;
+DROP TABLE `promotion_products`;
+DROP TABLE `promotions`;
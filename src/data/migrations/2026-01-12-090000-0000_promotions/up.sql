@@ -0,0 +1,50 @@
+-- Your SQL goes here
+CREATE TABLE `promotions` (
+    promotion_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL,
+    code VARCHAR(50) UNIQUE, -- NULL for automatic promotions
+    discount_type VARCHAR(20) NOT NULL, -- 'percentage' or 'fixed'
+    discount_value DECIMAL(10, 2) NOT NULL,
+    is_automatic BOOLEAN NOT NULL DEFAULT FALSE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    min_spend DECIMAL(10, 2),
+    starts_at DATETIME,
+    ends_at DATETIME,
+    daily_start_time TIME, -- e.g. happy hour from 17:00
+    daily_end_time TIME,   -- to 19:00
+    usage_limit INT,
+    usage_count INT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+CREATE TABLE `promotion_products` (
+    promotion_id INT NOT NULL,
+    product_id INT NOT NULL,
+    PRIMARY KEY (promotion_id, product_id),
+    FOREIGN KEY (promotion_id) REFERENCES promotions(promotion_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `promotion_categories` (
+    promotion_id INT NOT NULL,
+    category_id INT NOT NULL,
+    PRIMARY KEY (promotion_id, category_id),
+    FOREIGN KEY (promotion_id) REFERENCES promotions(promotion_id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES categories(category_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `order_discounts` (
+    order_discount_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    order_id INT NOT NULL,
+    promotion_id INT,
+    name VARCHAR(100) NOT NULL,
+    code VARCHAR(50),
+    amount DECIMAL(10, 2) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE,
+    FOREIGN KEY (promotion_id) REFERENCES promotions(promotion_id) ON DELETE SET NULL
+);
+
+ALTER TABLE `orders`
+    ADD COLUMN discount_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00;
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE `payments` (
+    payment_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    order_id INT NOT NULL,
+    amount DECIMAL(10, 2) NOT NULL,
+    method VARCHAR(20) NOT NULL, -- 'cash' or 'card'
+    provider VARCHAR(50) NOT NULL, -- Gateway that processed the payment
+    status VARCHAR(20) NOT NULL, -- 'captured', 'failed', 'partially_refunded' or 'refunded'
+    provider_reference VARCHAR(255),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `refunds` (
+    refund_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    payment_id INT NOT NULL,
+    amount DECIMAL(10, 2) NOT NULL,
+    reason VARCHAR(255),
+    provider_reference VARCHAR(255),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (payment_id) REFERENCES payments(payment_id) ON DELETE CASCADE
+);
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `payments`
+    DROP FOREIGN KEY fk_payments_check,
+    DROP COLUMN check_id;
+
+DROP TABLE `order_check_items`;
+DROP TABLE `order_checks`;
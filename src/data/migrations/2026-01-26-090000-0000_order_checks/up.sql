@@ -0,0 +1,27 @@
+-- Your SQL goes here
+CREATE TABLE `order_checks` (
+    check_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    order_id INT NOT NULL,
+    check_number INT NOT NULL,
+    subtotal DECIMAL(10, 2) NOT NULL,
+    discount_amount DECIMAL(10, 2) NOT NULL,
+    tax_amount DECIMAL(10, 2) NOT NULL,
+    service_charge DECIMAL(10, 2) NOT NULL,
+    total_amount DECIMAL(10, 2) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (order_id, check_number),
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE
+);
+
+-- Order lines assigned to a check when splitting by item
+CREATE TABLE `order_check_items` (
+    check_id INT NOT NULL,
+    product_id INT NOT NULL,
+    PRIMARY KEY (check_id, product_id),
+    FOREIGN KEY (check_id) REFERENCES order_checks(check_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+ALTER TABLE `payments`
+    ADD COLUMN check_id INT,
+    ADD CONSTRAINT fk_payments_check FOREIGN KEY (check_id) REFERENCES order_checks(check_id) ON DELETE SET NULL;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE `order_adjustments`;
+
+ALTER TABLE `roles`
+    MODIFY permissions SET('READ', 'WRITE', 'DELETE', 'ADMIN') DEFAULT 'READ';
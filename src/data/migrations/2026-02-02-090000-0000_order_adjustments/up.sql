@@ -0,0 +1,21 @@
+-- Your SQL goes here
+ALTER TABLE `roles`
+    MODIFY permissions SET('READ', 'WRITE', 'DELETE', 'ADMIN', 'REFUND') DEFAULT 'READ';
+
+CREATE TABLE `order_adjustments` (
+    adjustment_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    order_id INT NOT NULL,
+    adjustment_type VARCHAR(20) NOT NULL, -- 'cancellation', 'void' or 'refund'
+    product_id INT, -- Voided order line
+    quantity INT, -- Voided quantity
+    refund_id INT, -- Refund issued to the customer
+    amount DECIMAL(10, 2) NOT NULL, -- Negative change to the order total or the amount paid
+    reason_code VARCHAR(50),
+    note VARCHAR(255),
+    user_id INT, -- Staff member who authorized the adjustment
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE SET NULL,
+    FOREIGN KEY (refund_id) REFERENCES refunds(refund_id) ON DELETE SET NULL,
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE SET NULL
+);
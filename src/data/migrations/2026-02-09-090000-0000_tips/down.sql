@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `payments`
+    DROP COLUMN tip_amount;
+
+ALTER TABLE `orders`
+    DROP FOREIGN KEY fk_orders_served_by,
+    DROP COLUMN party_size,
+    DROP COLUMN served_by,
+    DROP COLUMN gratuity_amount,
+    DROP COLUMN tip_amount;
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TABLE `orders`
+    ADD COLUMN party_size INT,
+    ADD COLUMN served_by INT, -- Staff member the order's tips are attributed to
+    ADD COLUMN gratuity_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00, -- Auto-applied for large parties
+    ADD COLUMN tip_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00, -- Sum of tips added to payments
+    ADD CONSTRAINT fk_orders_served_by FOREIGN KEY (served_by) REFERENCES users(user_id) ON DELETE SET NULL;
+
+ALTER TABLE `payments`
+    ADD COLUMN tip_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00; -- Charged on top of `amount`
@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `orders`
+    DROP FOREIGN KEY fk_orders_session,
+    DROP FOREIGN KEY fk_orders_table,
+    DROP COLUMN session_id,
+    DROP COLUMN table_id,
+    DROP COLUMN order_type;
+
+DROP TABLE `table_sessions`;
+DROP TABLE `dining_tables`;
+DROP TABLE `dining_areas`;
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE `dining_areas` (
+    area_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    description TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+CREATE TABLE `dining_tables` (
+    table_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    area_id INT NOT NULL,
+    name VARCHAR(50) NOT NULL, -- e.g. T12
+    capacity INT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'free', -- 'free', 'seated', 'dirty' or 'reserved'
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE (area_id, name),
+    FOREIGN KEY (area_id) REFERENCES dining_areas(area_id)
+);
+
+CREATE TABLE `table_sessions` (
+    session_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    table_id INT NOT NULL,
+    party_size INT,
+    opened_by INT,
+    opened_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    closed_at TIMESTAMP NULL DEFAULT NULL, -- NULL while the table is seated
+    -- Combined check of the session's orders, stored when it is closed
+    subtotal DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    discount_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    tax_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    service_charge DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    gratuity_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    total_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00,
+    FOREIGN KEY (table_id) REFERENCES dining_tables(table_id) ON DELETE CASCADE,
+    FOREIGN KEY (opened_by) REFERENCES users(user_id) ON DELETE SET NULL
+);
+
+ALTER TABLE `orders`
+    ADD COLUMN order_type VARCHAR(20) NOT NULL DEFAULT 'dine_in', -- 'dine_in', 'takeout' or 'delivery'
+    ADD COLUMN table_id INT,
+    ADD COLUMN session_id INT,
+    ADD CONSTRAINT fk_orders_table FOREIGN KEY (table_id) REFERENCES dining_tables(table_id) ON DELETE SET NULL,
+    ADD CONSTRAINT fk_orders_session FOREIGN KEY (session_id) REFERENCES table_sessions(session_id) ON DELETE SET NULL;
@@ -0,0 +1,33 @@
+-- Your SQL goes here
+CREATE TABLE `reservations` (
+    reservation_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    contact_name VARCHAR(100) NOT NULL,
+    contact_phone VARCHAR(30) NOT NULL,
+    party_size INT NOT NULL,
+    reserved_for DATETIME NOT NULL, -- Start of the time slot, in business local time
+    table_id INT, -- Table held for the reservation
+    status VARCHAR(20) NOT NULL DEFAULT 'booked', -- 'booked', 'seated', 'no_show' or 'cancelled'
+    notes TEXT,
+    session_id INT, -- Table session the party was seated in
+    created_by INT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_reservations_reserved_for (reserved_for),
+    FOREIGN KEY (table_id) REFERENCES dining_tables(table_id) ON DELETE SET NULL,
+    FOREIGN KEY (session_id) REFERENCES table_sessions(session_id) ON DELETE SET NULL,
+    FOREIGN KEY (created_by) REFERENCES users(user_id) ON DELETE SET NULL
+);
+
+CREATE TABLE `waitlist_entries` (
+    entry_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    contact_name VARCHAR(100) NOT NULL,
+    contact_phone VARCHAR(30),
+    party_size INT NOT NULL,
+    quoted_wait_minutes INT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'waiting', -- 'waiting', 'seated' or 'left'
+    notes TEXT,
+    session_id INT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (session_id) REFERENCES table_sessions(session_id) ON DELETE SET NULL
+);
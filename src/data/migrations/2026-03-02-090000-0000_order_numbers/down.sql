@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `orders`
+    DROP INDEX uq_orders_business_number,
+    DROP COLUMN order_number,
+    DROP COLUMN business_date;
+
+DROP TABLE `order_number_sequences`;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+-- Last order number handed out on each business day
+CREATE TABLE `order_number_sequences` (
+    business_date DATE PRIMARY KEY,
+    last_number INT NOT NULL DEFAULT 0
+);
+
+ALTER TABLE `orders`
+    ADD COLUMN business_date DATE,
+    ADD COLUMN order_number INT,
+    ADD CONSTRAINT uq_orders_business_number UNIQUE (business_date, order_number);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `orders`
+    DROP INDEX idx_orders_status_scheduled,
+    DROP COLUMN scheduled_for;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE `orders`
+    ADD COLUMN scheduled_for DATETIME, -- when a future-dated order is due
+    ADD INDEX idx_orders_status_scheduled (status, scheduled_for);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE `kitchen_ticket_items`;
+DROP TABLE `kitchen_tickets`;
+DROP TABLE `station_categories`;
+DROP TABLE `station_products`;
+DROP TABLE `stations`;
@@ -0,0 +1,43 @@
+-- Your SQL goes here
+CREATE TABLE `stations` (
+    station_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL UNIQUE, -- e.g. grill, fryer or bar
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+-- A product is prepared at one station; a product mapping wins over its categories' mappings
+CREATE TABLE `station_products` (
+    product_id INT PRIMARY KEY NOT NULL,
+    station_id INT NOT NULL,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE,
+    FOREIGN KEY (station_id) REFERENCES stations(station_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `station_categories` (
+    category_id INT PRIMARY KEY NOT NULL,
+    station_id INT NOT NULL,
+    FOREIGN KEY (category_id) REFERENCES categories(category_id) ON DELETE CASCADE,
+    FOREIGN KEY (station_id) REFERENCES stations(station_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `kitchen_tickets` (
+    ticket_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    order_id INT NOT NULL,
+    station_id INT, -- NULL for items not routed to any station
+    status VARCHAR(20) NOT NULL DEFAULT 'open', -- 'open' or 'bumped'
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    bumped_at TIMESTAMP NULL DEFAULT NULL,
+    INDEX idx_kitchen_tickets_station_status (station_id, status),
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE,
+    FOREIGN KEY (station_id) REFERENCES stations(station_id) ON DELETE SET NULL
+);
+
+CREATE TABLE `kitchen_ticket_items` (
+    ticket_id INT NOT NULL,
+    product_id INT NOT NULL,
+    quantity INT NOT NULL,
+    PRIMARY KEY (ticket_id, product_id),
+    FOREIGN KEY (ticket_id) REFERENCES kitchen_tickets(ticket_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
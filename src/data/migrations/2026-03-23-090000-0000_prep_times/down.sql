@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `orders`
+    DROP COLUMN estimated_ready_at;
+
+ALTER TABLE `stations`
+    DROP COLUMN capacity;
+
+ALTER TABLE `products`
+    DROP COLUMN prep_minutes;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE `products`
+    ADD COLUMN prep_minutes INT NOT NULL DEFAULT 0;
+
+ALTER TABLE `stations`
+    ADD COLUMN capacity INT NOT NULL DEFAULT 1; -- tickets the station prepares at the same time
+
+ALTER TABLE `orders`
+    ADD COLUMN estimated_ready_at DATETIME; -- promised ready time, kept up to date with the kitchen queue
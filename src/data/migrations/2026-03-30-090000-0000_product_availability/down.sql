@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `products`
+    DROP COLUMN available_again_at,
+    DROP COLUMN is_available;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE `products`
+    ADD COLUMN is_available BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN available_again_at DATETIME; -- automatic restore time of an 86'd product
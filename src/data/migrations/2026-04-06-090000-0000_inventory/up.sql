@@ -0,0 +1,35 @@
+-- Your SQL goes here
+CREATE TABLE `ingredients` (
+    ingredient_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    unit VARCHAR(20) NOT NULL, -- e.g. kg, l or pcs
+    on_hand DECIMAL(12, 3) NOT NULL DEFAULT 0,
+    reorder_level DECIMAL(12, 3) NOT NULL DEFAULT 0, -- stock is low at or below this level
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+-- Quantity of each ingredient that goes into one of a product
+CREATE TABLE `recipes` (
+    product_id INT NOT NULL,
+    ingredient_id INT NOT NULL,
+    quantity DECIMAL(12, 3) NOT NULL,
+    PRIMARY KEY (product_id, ingredient_id),
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE RESTRICT
+);
+
+-- Ledger of every change to the stock on hand
+CREATE TABLE `stock_movements` (
+    movement_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    ingredient_id INT NOT NULL,
+    order_id INT, -- set for the stock used by an order and restored on its cancellation
+    quantity DECIMAL(12, 3) NOT NULL, -- negative when stock is used
+    reason VARCHAR(20) NOT NULL, -- 'order', 'cancellation' or 'adjustment'
+    note TEXT,
+    user_id INT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE CASCADE,
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE SET NULL,
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE SET NULL
+);
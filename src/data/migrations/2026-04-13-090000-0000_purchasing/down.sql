@@ -0,0 +1,16 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `stock_movements`
+    DROP FOREIGN KEY fk_stock_movements_purchase_order,
+    DROP COLUMN unit_cost,
+    DROP COLUMN purchase_order_id;
+
+DROP TABLE `purchase_order_lines`;
+DROP TABLE `purchase_orders`;
+
+ALTER TABLE `ingredients`
+    DROP FOREIGN KEY fk_ingredients_supplier,
+    DROP COLUMN average_cost,
+    DROP COLUMN par_level,
+    DROP COLUMN supplier_id;
+
+DROP TABLE `suppliers`;
@@ -0,0 +1,47 @@
+-- Your SQL goes here
+CREATE TABLE `suppliers` (
+    supplier_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    contact_name VARCHAR(100),
+    email VARCHAR(255),
+    phone VARCHAR(50),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+ALTER TABLE `ingredients`
+    ADD COLUMN supplier_id INT, -- supplier the ingredient is usually bought from
+    ADD COLUMN par_level DECIMAL(12, 3) NOT NULL DEFAULT 0, -- stock purchase orders top up to
+    ADD COLUMN average_cost DECIMAL(12, 4) NOT NULL DEFAULT 0, -- weighted average unit cost
+    ADD CONSTRAINT fk_ingredients_supplier FOREIGN KEY (supplier_id) REFERENCES suppliers(supplier_id) ON DELETE SET NULL;
+
+CREATE TABLE `purchase_orders` (
+    purchase_order_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    supplier_id INT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'draft', -- 'draft', 'sent', 'partially_received' or 'received'
+    note TEXT,
+    created_by INT,
+    sent_at TIMESTAMP NULL DEFAULT NULL,
+    received_at TIMESTAMP NULL DEFAULT NULL, -- NULL until every line is received in full
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_purchase_orders_status (status),
+    FOREIGN KEY (supplier_id) REFERENCES suppliers(supplier_id) ON DELETE RESTRICT,
+    FOREIGN KEY (created_by) REFERENCES users(user_id) ON DELETE SET NULL
+);
+
+CREATE TABLE `purchase_order_lines` (
+    purchase_order_id INT NOT NULL,
+    ingredient_id INT NOT NULL,
+    quantity DECIMAL(12, 3) NOT NULL,
+    unit_cost DECIMAL(12, 4) NOT NULL,
+    received_quantity DECIMAL(12, 3) NOT NULL DEFAULT 0,
+    PRIMARY KEY (purchase_order_id, ingredient_id),
+    FOREIGN KEY (purchase_order_id) REFERENCES purchase_orders(purchase_order_id) ON DELETE CASCADE,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE RESTRICT
+);
+
+ALTER TABLE `stock_movements`
+    ADD COLUMN purchase_order_id INT, -- set for the stock received against a purchase order
+    ADD COLUMN unit_cost DECIMAL(12, 4), -- cost paid per unit of the stock received
+    ADD CONSTRAINT fk_stock_movements_purchase_order FOREIGN KEY (purchase_order_id) REFERENCES purchase_orders(purchase_order_id) ON DELETE SET NULL;
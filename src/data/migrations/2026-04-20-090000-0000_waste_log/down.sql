@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `stock_movements`
+    DROP FOREIGN KEY fk_stock_movements_waste,
+    DROP COLUMN waste_id;
+
+DROP TABLE `waste_entries`;
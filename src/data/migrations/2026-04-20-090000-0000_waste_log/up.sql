@@ -0,0 +1,21 @@
+-- Your SQL goes here
+-- Stock thrown away; either an ingredient or a prepared product, whose recipe leaves stock
+CREATE TABLE `waste_entries` (
+    waste_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    ingredient_id INT,
+    product_id INT,
+    quantity DECIMAL(12, 3) NOT NULL, -- in the unit of the ingredient, or a number of products
+    reason VARCHAR(20) NOT NULL, -- 'dropped', 'burnt', 'expired', 'spoiled' or 'other'
+    cost DECIMAL(12, 2) NOT NULL DEFAULT 0, -- stock lost at its average cost when logged
+    note TEXT,
+    user_id INT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_waste_entries_created_at (created_at),
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE SET NULL
+);
+
+ALTER TABLE `stock_movements`
+    ADD COLUMN waste_id INT, -- set for the stock taken out by a waste entry
+    ADD CONSTRAINT fk_stock_movements_waste FOREIGN KEY (waste_id) REFERENCES waste_entries(waste_id) ON DELETE CASCADE;
@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `order_products`
+    DROP FOREIGN KEY fk_order_products_product,
+    ADD CONSTRAINT order_products_ibfk_2 FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE;
+
+ALTER TABLE `categories`
+    DROP COLUMN deleted_at;
+
+ALTER TABLE `products`
+    DROP INDEX idx_products_deleted_at,
+    DROP COLUMN deleted_at;
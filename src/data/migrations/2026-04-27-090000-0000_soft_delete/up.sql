@@ -0,0 +1,13 @@
+-- Your SQL goes here
+-- Archived products and categories are hidden from the menu but keep their order history
+ALTER TABLE `products`
+    ADD COLUMN deleted_at TIMESTAMP NULL DEFAULT NULL,
+    ADD INDEX idx_products_deleted_at (deleted_at);
+
+ALTER TABLE `categories`
+    ADD COLUMN deleted_at TIMESTAMP NULL DEFAULT NULL;
+
+-- Products on orders can no longer be removed along with their order lines
+ALTER TABLE `order_products`
+    DROP FOREIGN KEY order_products_ibfk_2,
+    ADD CONSTRAINT fk_order_products_product FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE RESTRICT;
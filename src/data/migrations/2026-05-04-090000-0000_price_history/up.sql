@@ -0,0 +1,19 @@
+-- Your SQL goes here
+-- Each row is the price of a product from `effective_from` until `effective_to`, open-ended
+-- for the last one. Rows starting in the future are scheduled price changes.
+CREATE TABLE `product_prices` (
+    price_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    product_id INT NOT NULL,
+    price DECIMAL(10, 2) NOT NULL,
+    effective_from DATETIME NOT NULL, -- local business time, like scheduled orders
+    effective_to DATETIME,
+    created_by INT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (product_id, effective_from),
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users(user_id) ON DELETE SET NULL
+);
+
+-- The current prices start the history
+INSERT INTO `product_prices` (product_id, price, effective_from)
+SELECT product_id, price, COALESCE(created_at, CURRENT_TIMESTAMP) FROM `products`;
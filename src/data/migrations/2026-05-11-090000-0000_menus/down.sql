@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE `menu_categories`;
+DROP TABLE `menu_products`;
+DROP TABLE `menu_schedules`;
+DROP TABLE `menus`;
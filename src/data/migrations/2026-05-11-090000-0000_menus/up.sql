@@ -0,0 +1,39 @@
+-- Your SQL goes here
+CREATE TABLE `menus` (
+    menu_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    name VARCHAR(100) NOT NULL UNIQUE, -- e.g. breakfast, lunch or happy hour
+    description TEXT,
+    utc_offset VARCHAR(6), -- e.g. +09:00, the business offset when NULL
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
+
+-- Weekly windows a menu is served in; a window ending before it starts runs past midnight
+-- into the next day. A menu without windows is served all day.
+CREATE TABLE `menu_schedules` (
+    schedule_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    menu_id INT NOT NULL,
+    day_of_week INT NOT NULL, -- 0 for Monday to 6 for Sunday
+    start_time TIME NOT NULL,
+    end_time TIME NOT NULL,
+    FOREIGN KEY (menu_id) REFERENCES menus(menu_id) ON DELETE CASCADE
+);
+
+-- Products on a menu, directly or through their categories, can only be ordered while one
+-- of their menus is served. Products on no menu can always be ordered.
+CREATE TABLE `menu_products` (
+    menu_id INT NOT NULL,
+    product_id INT NOT NULL,
+    PRIMARY KEY (menu_id, product_id),
+    FOREIGN KEY (menu_id) REFERENCES menus(menu_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `menu_categories` (
+    menu_id INT NOT NULL,
+    category_id INT NOT NULL,
+    PRIMARY KEY (menu_id, category_id),
+    FOREIGN KEY (menu_id) REFERENCES menus(menu_id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES categories(category_id) ON DELETE CASCADE
+);
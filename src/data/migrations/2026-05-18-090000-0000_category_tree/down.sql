@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `product_categories`
+    DROP COLUMN sort_order;
+
+ALTER TABLE `categories`
+    DROP FOREIGN KEY fk_categories_parent,
+    DROP COLUMN sort_order,
+    DROP COLUMN parent_id;
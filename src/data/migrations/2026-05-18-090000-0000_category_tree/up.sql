@@ -0,0 +1,11 @@
+-- Your SQL goes here
+-- Categories nest under a parent, e.g. Drinks > Hot > Coffee, and are listed by sort_order
+ALTER TABLE `categories`
+    ADD COLUMN parent_id INT NULL,
+    ADD COLUMN sort_order INT NOT NULL DEFAULT 0,
+    ADD CONSTRAINT fk_categories_parent
+        FOREIGN KEY (parent_id) REFERENCES categories(category_id) ON DELETE SET NULL;
+
+-- Position of a product within a category
+ALTER TABLE `product_categories`
+    ADD COLUMN sort_order INT NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE `order_bundle_items`;
+DROP TABLE `bundle_slot_choices`;
+DROP TABLE `bundle_slots`;
@@ -0,0 +1,37 @@
+-- Your SQL goes here
+-- A product with slots is a bundle, such as a combo meal, sold at its own price. Every slot
+-- is filled with a choice of products, given directly or through their categories.
+CREATE TABLE `bundle_slots` (
+    slot_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    bundle_id INT NOT NULL, -- the bundle product
+    name VARCHAR(100) NOT NULL, -- e.g. main, side or drink
+    quantity INT NOT NULL DEFAULT 1, -- how many products are chosen for the slot
+    sort_order INT NOT NULL DEFAULT 0,
+    FOREIGN KEY (bundle_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `bundle_slot_choices` (
+    choice_id INT PRIMARY KEY NOT NULL AUTO_INCREMENT,
+    slot_id INT NOT NULL,
+    product_id INT, -- either a product
+    category_id INT, -- or any product of a category
+    upcharge DECIMAL(10, 2) NOT NULL DEFAULT 0.00, -- added to the bundle price per pick
+    FOREIGN KEY (slot_id) REFERENCES bundle_slots(slot_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES categories(category_id) ON DELETE CASCADE
+);
+
+-- The products chosen for the bundles of an order, per bundle. The bundle line of the
+-- order carries the price; these child lines are what the kitchen prepares and what is
+-- taken out of stock.
+CREATE TABLE `order_bundle_items` (
+    order_id INT NOT NULL,
+    bundle_id INT NOT NULL,
+    product_id INT NOT NULL,
+    quantity INT NOT NULL, -- per bundle
+    upcharge DECIMAL(10, 2) NOT NULL DEFAULT 0.00, -- per bundle
+    PRIMARY KEY (order_id, bundle_id, product_id),
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE,
+    FOREIGN KEY (bundle_id) REFERENCES products(product_id),
+    FOREIGN KEY (product_id) REFERENCES products(product_id)
+);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE `order_allergen_alerts`;
+DROP TABLE `ingredient_dietary_tags`;
+DROP TABLE `ingredient_allergens`;
+DROP TABLE `product_dietary_tags`;
+DROP TABLE `product_allergens`;
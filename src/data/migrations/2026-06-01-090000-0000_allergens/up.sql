@@ -0,0 +1,42 @@
+-- Your SQL goes here
+-- Allergens are the 14 the EU requires to be declared, such as gluten or nuts; dietary tags
+-- are vegan, halal and so on. A product has the allergens set on it and those of the
+-- ingredients in its recipe, and the dietary tags set on it or shared by every ingredient
+-- in its recipe.
+CREATE TABLE `product_allergens` (
+    product_id INT NOT NULL,
+    allergen VARCHAR(20) NOT NULL,
+    PRIMARY KEY (product_id, allergen),
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `product_dietary_tags` (
+    product_id INT NOT NULL,
+    tag VARCHAR(20) NOT NULL,
+    PRIMARY KEY (product_id, tag),
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `ingredient_allergens` (
+    ingredient_id INT NOT NULL,
+    allergen VARCHAR(20) NOT NULL,
+    PRIMARY KEY (ingredient_id, allergen),
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `ingredient_dietary_tags` (
+    ingredient_id INT NOT NULL,
+    tag VARCHAR(20) NOT NULL,
+    PRIMARY KEY (ingredient_id, tag),
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE CASCADE
+);
+
+-- Items of an order containing an allergen the guest flagged when ordering
+CREATE TABLE `order_allergen_alerts` (
+    order_id INT NOT NULL,
+    product_id INT NOT NULL,
+    allergen VARCHAR(20) NOT NULL,
+    PRIMARY KEY (order_id, product_id, allergen),
+    FOREIGN KEY (order_id) REFERENCES orders(order_id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id)
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE `ingredient_nutrition`;
+DROP TABLE `product_nutrition`;
@@ -0,0 +1,27 @@
+-- Your SQL goes here
+-- Nutrition facts per serving of a product. A product computed from its recipe sums the
+-- nutrition of its ingredients instead of the values stored here.
+CREATE TABLE `product_nutrition` (
+    product_id INT PRIMARY KEY,
+    serving_size VARCHAR(50),
+    kcal DECIMAL(10,2) NOT NULL DEFAULT 0,
+    protein DECIMAL(10,2) NOT NULL DEFAULT 0,
+    carbohydrates DECIMAL(10,2) NOT NULL DEFAULT 0,
+    sugars DECIMAL(10,2) NOT NULL DEFAULT 0,
+    fat DECIMAL(10,2) NOT NULL DEFAULT 0,
+    salt DECIMAL(10,2) NOT NULL DEFAULT 0,
+    from_recipe BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+-- Nutrition of one unit of an ingredient, in the unit its stock is counted in
+CREATE TABLE `ingredient_nutrition` (
+    ingredient_id INT PRIMARY KEY,
+    kcal DECIMAL(12,4) NOT NULL DEFAULT 0,
+    protein DECIMAL(12,4) NOT NULL DEFAULT 0,
+    carbohydrates DECIMAL(12,4) NOT NULL DEFAULT 0,
+    sugars DECIMAL(12,4) NOT NULL DEFAULT 0,
+    fat DECIMAL(12,4) NOT NULL DEFAULT 0,
+    salt DECIMAL(12,4) NOT NULL DEFAULT 0,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(ingredient_id) ON DELETE CASCADE
+);
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+-- Names and descriptions of products and categories in other languages. Products and
+-- categories themselves hold the content in the default locale, which is used for anything
+-- not translated.
+CREATE TABLE `product_translations` (
+    product_id INT NOT NULL,
+    locale VARCHAR(10) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    PRIMARY KEY (product_id, locale),
+    FOREIGN KEY (product_id) REFERENCES products(product_id) ON DELETE CASCADE
+);
+
+CREATE TABLE `category_translations` (
+    category_id INT NOT NULL,
+    locale VARCHAR(10) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    PRIMARY KEY (category_id, locale),
+    FOREIGN KEY (category_id) REFERENCES categories(category_id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `order_checks`
+    DROP COLUMN gratuity_amount;
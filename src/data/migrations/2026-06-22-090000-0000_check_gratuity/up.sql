@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE `order_checks`
+    ADD COLUMN gratuity_amount DECIMAL(10, 2) NOT NULL DEFAULT 0.00; -- Share of the order's auto gratuity, due on top of `total_amount`
@@ -0,0 +1,49 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// An allergen set on a product
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = product_allergens)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct ProductAllergen {
+    pub product_id: i32,
+    pub allergen: String,
+}
+
+/// A dietary tag set on a product
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = product_dietary_tags)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct ProductDietaryTag {
+    pub product_id: i32,
+    pub tag: String,
+}
+
+/// An allergen of an ingredient, which every product with the ingredient in its recipe has
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = ingredient_allergens)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct IngredientAllergen {
+    pub ingredient_id: i32,
+    pub allergen: String,
+}
+
+/// A dietary tag of an ingredient, which products have when every ingredient in their
+/// recipe has it
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = ingredient_dietary_tags)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct IngredientDietaryTag {
+    pub ingredient_id: i32,
+    pub tag: String,
+}
+
+/// An item of an order containing an allergen the guest flagged
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_allergen_alerts)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct OrderAllergenAlert {
+    pub order_id: i32,
+    pub product_id: i32,
+    pub allergen: String,
+}
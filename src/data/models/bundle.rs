@@ -0,0 +1,65 @@
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// A slot of a bundle product, such as the drink of a combo meal
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = bundle_slots)]
+#[diesel(primary_key(slot_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct BundleSlot {
+    pub slot_id: i32,
+    /// The bundle product
+    pub bundle_id: i32,
+    pub name: String,
+    /// How many products are chosen for the slot in every bundle
+    pub quantity: i32,
+    pub sort_order: i32,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = bundle_slots)]
+pub struct NewBundleSlot {
+    pub bundle_id: i32,
+    pub name: String,
+    pub quantity: i32,
+    pub sort_order: i32,
+}
+
+/// A product, or the products of a category, a bundle slot can be filled with
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = bundle_slot_choices)]
+#[diesel(primary_key(choice_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct BundleSlotChoice {
+    pub choice_id: i32,
+    pub slot_id: i32,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    /// Added to the price of the bundle every time the choice is picked
+    pub upcharge: BigDecimal,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = bundle_slot_choices)]
+pub struct NewBundleSlotChoice {
+    pub slot_id: i32,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub upcharge: BigDecimal,
+}
+
+/// A product chosen for the bundles of an order line, which the kitchen prepares and which
+/// is taken out of stock in place of the bundle
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_bundle_items)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct OrderBundleItem {
+    pub order_id: i32,
+    pub bundle_id: i32,
+    pub product_id: i32,
+    /// How many go into one bundle
+    pub quantity: i32,
+    /// What the picks add to the price of one bundle
+    pub upcharge: BigDecimal,
+}
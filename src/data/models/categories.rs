@@ -12,6 +12,12 @@ pub struct Category {
     pub description: Option<String>,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    /// When the category was archived
+    pub deleted_at: Option<chrono::NaiveDateTime>,
+    /// Category this one is nested under, none at the top level
+    pub parent_id: Option<i32>,
+    /// Categories with the same parent are listed by ascending sort order
+    pub sort_order: i32,
 }
 
 #[derive(Insertable, PartialEq, Debug)]
@@ -19,6 +25,9 @@ pub struct Category {
 pub struct NewCategory<'a> {
     pub name: &'a str,
     pub description: Option<&'a str>,
+    pub parent_id: Option<i32>,
+    /// 0 when not given
+    pub sort_order: Option<i32>,
 }
 
 #[derive(AsChangeset, PartialEq, Debug)]
@@ -26,4 +35,17 @@ pub struct NewCategory<'a> {
 pub struct UpdateCategory<'a> {
     pub name: Option<&'a str>,
     pub description: Option<&'a str>,
+    /// `Some(None)` moves the category to the top level
+    pub parent_id: Option<Option<i32>>,
+    pub sort_order: Option<i32>,
+}
+
+impl UpdateCategory<'_> {
+    /// Whether the form changes anything, as an empty changeset cannot be saved
+    pub fn has_changes(&self) -> bool {
+        self.name.is_some()
+            || self.description.is_some()
+            || self.parent_id.is_some()
+            || self.sort_order.is_some()
+    }
 }
@@ -0,0 +1,30 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// A part of the restaurant tables are grouped in, such as the terrace or the bar
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = dining_areas)]
+#[diesel(primary_key(area_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct DiningArea {
+    pub area_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = dining_areas)]
+pub struct NewDiningArea<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = dining_areas)]
+pub struct UpdateDiningArea<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+}
@@ -0,0 +1,36 @@
+use crate::data::models::dining_area::DiningArea;
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// A table guests are seated at. `status` is `free`, `seated`, `dirty` or `reserved`.
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = dining_tables)]
+#[diesel(primary_key(table_id))]
+#[diesel(belongs_to(DiningArea, foreign_key = area_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct DiningTable {
+    pub table_id: i32,
+    pub area_id: i32,
+    pub name: String,
+    pub capacity: i32,
+    pub status: String,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = dining_tables)]
+pub struct NewDiningTable<'a> {
+    pub area_id: i32,
+    pub name: &'a str,
+    pub capacity: i32,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = dining_tables)]
+pub struct UpdateDiningTable<'a> {
+    pub area_id: Option<i32>,
+    pub name: Option<&'a str>,
+    pub capacity: Option<i32>,
+    pub status: Option<&'a str>,
+}
@@ -0,0 +1,112 @@
+use crate::data::models::schema::*;
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
+use diesel::prelude::*;
+
+/// Stock of something the kitchen uses up, such as salmon or burger buns
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = ingredients)]
+#[diesel(primary_key(ingredient_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Ingredient {
+    pub ingredient_id: i32,
+    pub name: String,
+    /// Unit the quantities of the ingredient are counted in, e.g. `kg` or `pcs`
+    pub unit: String,
+    pub on_hand: BigDecimal,
+    /// Stock is low once it is at or below this level
+    pub reorder_level: BigDecimal,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+    /// Supplier the ingredient is usually bought from
+    pub supplier_id: Option<i32>,
+    /// Stock that suggested purchase orders top the ingredient up to
+    pub par_level: BigDecimal,
+    /// Weighted average of the unit costs paid for the stock on hand
+    pub average_cost: BigDecimal,
+}
+
+impl Ingredient {
+    /// The average unit cost once `quantity` more is received at `unit_cost`. Stock at or
+    /// below zero has no cost left to average with, so it takes the new unit cost.
+    pub fn average_cost_after(&self, quantity: &BigDecimal, unit_cost: &BigDecimal) -> BigDecimal {
+        if self.on_hand <= BigDecimal::zero() {
+            return unit_cost.clone();
+        }
+
+        let total = &self.on_hand + quantity;
+        ((&self.on_hand * &self.average_cost + quantity * unit_cost) / total)
+            .with_scale_round(4, RoundingMode::HalfEven)
+    }
+
+    /// Value of the stock on hand at its average cost
+    pub fn stock_value(&self) -> BigDecimal {
+        (&self.on_hand * &self.average_cost).with_scale_round(2, RoundingMode::HalfEven)
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = ingredients)]
+pub struct NewIngredient<'a> {
+    pub name: &'a str,
+    pub unit: &'a str,
+    pub reorder_level: Option<BigDecimal>,
+    pub supplier_id: Option<i32>,
+    pub par_level: Option<BigDecimal>,
+}
+
+/// The stock on hand only changes through stock movements
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = ingredients)]
+pub struct UpdateIngredient<'a> {
+    pub name: Option<&'a str>,
+    pub unit: Option<&'a str>,
+    pub reorder_level: Option<BigDecimal>,
+    pub supplier_id: Option<i32>,
+    pub par_level: Option<BigDecimal>,
+}
+
+/// Quantity of an ingredient that goes into one of a product
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = recipes)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Recipe {
+    pub product_id: i32,
+    pub ingredient_id: i32,
+    pub quantity: BigDecimal,
+}
+
+/// Ledger entry recording a change to the stock of an ingredient.
+/// Quantities are negative when stock is used.
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = stock_movements)]
+#[diesel(primary_key(movement_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct StockMovement {
+    pub movement_id: i32,
+    pub ingredient_id: i32,
+    pub order_id: Option<i32>,
+    pub quantity: BigDecimal,
+    pub reason: String,
+    pub note: Option<String>,
+    pub user_id: Option<i32>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub purchase_order_id: Option<i32>,
+    /// Cost paid per unit, set for stock received against a purchase order
+    pub unit_cost: Option<BigDecimal>,
+    /// Waste entry the stock was thrown away under
+    pub waste_id: Option<i32>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = stock_movements)]
+pub struct NewStockMovement<'a> {
+    pub ingredient_id: i32,
+    pub order_id: Option<i32>,
+    pub quantity: BigDecimal,
+    pub reason: &'a str,
+    pub note: Option<&'a str>,
+    pub user_id: Option<i32>,
+    pub purchase_order_id: Option<i32>,
+    pub unit_cost: Option<BigDecimal>,
+    pub waste_id: Option<i32>,
+}
@@ -0,0 +1,46 @@
+use crate::data::models::order::Order;
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// The part of an order one station prepares. The order is ready once all of its tickets
+/// are bumped.
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = kitchen_tickets)]
+#[diesel(primary_key(ticket_id))]
+#[diesel(belongs_to(Order, foreign_key = order_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct KitchenTicket {
+    pub ticket_id: i32,
+    pub order_id: i32,
+    pub station_id: Option<i32>,
+    pub status: String,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub bumped_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = kitchen_tickets)]
+pub struct NewKitchenTicket {
+    pub order_id: i32,
+    pub station_id: Option<i32>,
+}
+
+#[derive(
+    Queryable, Selectable, Identifiable, Associations, Insertable, PartialEq, Debug, Clone,
+)]
+#[diesel(table_name = kitchen_ticket_items)]
+#[diesel(primary_key(ticket_id, product_id))]
+#[diesel(belongs_to(KitchenTicket, foreign_key = ticket_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct KitchenTicketItem {
+    pub ticket_id: i32,
+    pub product_id: i32,
+    pub quantity: i32,
+}
+
+/// The items (product_id, quantity) of a new order one station prepares
+#[derive(PartialEq, Debug, Clone)]
+pub struct RoutedTicket {
+    pub station_id: Option<i32>,
+    pub items: Vec<(i32, i32)>,
+}
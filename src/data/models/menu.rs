@@ -0,0 +1,106 @@
+use crate::data::models::schema::*;
+use chrono::{Datelike, Days, FixedOffset, NaiveDateTime, NaiveTime};
+use diesel::prelude::*;
+
+/// A menu served at certain times of the week, such as breakfast or happy hour
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = menus)]
+#[diesel(primary_key(menu_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Menu {
+    pub menu_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    /// Offset from UTC the schedule of the menu is in, such as `+09:00`. The schedule is in
+    /// business time when it is not set.
+    pub utc_offset: Option<String>,
+    /// Inactive menus are not served, whatever their schedule
+    pub is_active: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+impl Menu {
+    /// The offset of the schedule, none when it is in business time
+    pub fn offset(&self) -> Option<FixedOffset> {
+        self.utc_offset.as_deref().and_then(|o| o.parse().ok())
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = menus)]
+pub struct NewMenu<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub utc_offset: Option<&'a str>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = menus)]
+pub struct UpdateMenu<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    /// `Some(None)` moves the schedule back to business time
+    pub utc_offset: Option<Option<&'a str>>,
+    pub is_active: Option<bool>,
+}
+
+/// A weekly window a menu is served in
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = menu_schedules)]
+#[diesel(primary_key(schedule_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MenuSchedule {
+    pub schedule_id: i32,
+    pub menu_id: i32,
+    /// 0 for Monday to 6 for Sunday
+    pub day_of_week: i32,
+    pub start_time: NaiveTime,
+    /// A window ending before it starts runs past midnight into the next day
+    pub end_time: NaiveTime,
+}
+
+impl MenuSchedule {
+    /// Whether the window is open at `at`, in the time of the menu
+    pub fn is_open_at(&self, at: NaiveDateTime) -> bool {
+        let time = at.time();
+        let starts_on = |date: chrono::NaiveDate| {
+            date.weekday().num_days_from_monday() as i32 == self.day_of_week
+        };
+
+        if self.start_time <= self.end_time {
+            starts_on(at.date()) && time >= self.start_time && time < self.end_time
+        } else {
+            (starts_on(at.date()) && time >= self.start_time)
+                || (starts_on(at.date() - Days::new(1)) && time < self.end_time)
+        }
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = menu_schedules)]
+pub struct NewMenuSchedule {
+    pub menu_id: i32,
+    pub day_of_week: i32,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+/// Puts a product on a menu
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = menu_products)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MenuProduct {
+    pub menu_id: i32,
+    pub product_id: i32,
+}
+
+/// Puts the products of a category on a menu
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = menu_categories)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct MenuCategory {
+    pub menu_id: i32,
+    pub category_id: i32,
+}
@@ -1,10 +1,33 @@
+pub mod allergen;
+pub mod bundle;
 pub mod categories;
+pub mod dining_area;
+pub mod dining_table;
+pub mod ingredient;
+pub mod kitchen_ticket;
+pub mod menu;
+pub mod nutrition;
 pub mod order;
+pub mod order_adjustment;
+pub mod order_check;
+pub mod order_discount;
 pub mod order_product;
+pub mod payment;
 pub mod product;
 pub mod product_category;
+pub mod product_price;
+pub mod promotion;
+pub mod purchase_order;
+pub mod refund;
+pub mod reservation;
 pub mod roles;
 pub mod schema;
+pub mod station;
+pub mod supplier;
+pub mod table_session;
+pub mod tax_rate;
+pub mod translation;
 pub mod user;
 pub mod user_roles;
-
+pub mod waitlist_entry;
+pub mod waste_entry;
@@ -0,0 +1,38 @@
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// Nutrition facts per serving of a product
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = product_nutrition)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct ProductNutrition {
+    pub product_id: i32,
+    /// Such as `1 burger (250 g)`
+    pub serving_size: Option<String>,
+    pub kcal: BigDecimal,
+    /// Grams of protein
+    pub protein: BigDecimal,
+    pub carbohydrates: BigDecimal,
+    /// Grams of sugars, which count towards the carbohydrates
+    pub sugars: BigDecimal,
+    pub fat: BigDecimal,
+    pub salt: BigDecimal,
+    /// Whether the facts are summed from the nutrition of the recipe ingredients rather
+    /// than the values stored
+    pub from_recipe: bool,
+}
+
+/// Nutrition of one unit of an ingredient, in the unit its stock is counted in
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = ingredient_nutrition)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct IngredientNutrition {
+    pub ingredient_id: i32,
+    pub kcal: BigDecimal,
+    pub protein: BigDecimal,
+    pub carbohydrates: BigDecimal,
+    pub sugars: BigDecimal,
+    pub fat: BigDecimal,
+    pub salt: BigDecimal,
+}
@@ -1,9 +1,13 @@
+use crate::data::models::allergen::OrderAllergenAlert;
+use crate::data::models::bundle::OrderBundleItem;
+use crate::data::models::kitchen_ticket::RoutedTicket;
+use crate::data::models::order_discount::NewOrderDiscount;
 use crate::data::models::schema::*;
 use crate::data::models::user::User;
 use bigdecimal::BigDecimal;
 use diesel::prelude::*;
 
-#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug)]
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
 #[diesel(table_name = orders)]
 #[diesel(primary_key(order_id))]
 #[diesel(belongs_to(User, foreign_key = user_id))]
@@ -16,6 +20,35 @@ pub struct Order {
     pub status: Option<String>,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    pub subtotal: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub party_size: Option<i32>,
+    /// Staff member the tips of the order are attributed to
+    pub served_by: Option<i32>,
+    pub gratuity_amount: BigDecimal,
+    /// Sum of the tips added to captured payments
+    pub tip_amount: BigDecimal,
+    pub order_type: String,
+    pub table_id: Option<i32>,
+    /// Table session the order was placed in
+    pub session_id: Option<i32>,
+    /// Business day the order was placed on
+    pub business_date: Option<chrono::NaiveDate>,
+    /// Number of the order within its business day, starting at 1
+    pub order_number: Option<i32>,
+    /// When a future-dated order is due
+    pub scheduled_for: Option<chrono::NaiveDateTime>,
+    /// When the kitchen expects the order to be ready
+    pub estimated_ready_at: Option<chrono::NaiveDateTime>,
+}
+
+impl Order {
+    /// What the customer is charged: the order total plus any auto gratuity
+    pub fn amount_due(&self) -> BigDecimal {
+        &self.total_amount + &self.gratuity_amount
+    }
 }
 
 #[derive(Insertable, PartialEq, Debug)]
@@ -32,4 +65,55 @@ pub struct UpdateOrder<'a> {
     pub user_id: Option<i32>,
     pub total_amount: Option<BigDecimal>,
     pub status: Option<&'a str>,
-}
\ No newline at end of file
+}
+
+/// Price breakdown stored alongside an order's `total_amount`.
+#[derive(AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = orders)]
+pub struct OrderPricing {
+    pub subtotal: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub total_amount: BigDecimal,
+}
+
+/// Party size, serving staff and auto-applied gratuity of an order.
+/// Fields left as `None` are not changed.
+#[derive(AsChangeset, PartialEq, Debug, Clone, Default)]
+#[diesel(table_name = orders)]
+pub struct OrderTipping {
+    pub party_size: Option<i32>,
+    pub served_by: Option<i32>,
+    pub gratuity_amount: Option<BigDecimal>,
+}
+
+/// Order type, the table an order is served at and when it is due
+#[derive(AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = orders)]
+#[diesel(treat_none_as_null = true)]
+pub struct OrderSeating {
+    pub order_type: String,
+    pub table_id: Option<i32>,
+    pub session_id: Option<i32>,
+    pub scheduled_for: Option<chrono::NaiveDateTime>,
+}
+
+/// Everything saved when an order is placed. The order id of the lines is filled in once the
+/// order is created.
+#[derive(PartialEq, Debug)]
+pub struct OrderPlacement {
+    pub order: NewOrder,
+    pub pricing: OrderPricing,
+    pub tipping: OrderTipping,
+    pub seating: OrderSeating,
+    /// Business day the order is numbered in
+    pub business_day: chrono::NaiveDate,
+    /// product_id, quantity and unit price of each line
+    pub items: Vec<(i32, i32, BigDecimal)>,
+    /// Products chosen for the bundles on the order
+    pub bundle_items: Vec<OrderBundleItem>,
+    pub allergen_alerts: Vec<OrderAllergenAlert>,
+    pub discounts: Vec<NewOrderDiscount>,
+    pub tickets: Vec<RoutedTicket>,
+}
@@ -0,0 +1,41 @@
+use crate::data::models::order::Order;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// Ledger entry recording a cancellation, voided order line or refund.
+/// Amounts are negative: the change to the order total for cancellations and voids,
+/// the money returned for refunds.
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_adjustments)]
+#[diesel(primary_key(adjustment_id))]
+#[diesel(belongs_to(Order, foreign_key = order_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct OrderAdjustment {
+    pub adjustment_id: i32,
+    pub order_id: i32,
+    pub adjustment_type: String,
+    pub product_id: Option<i32>,
+    pub quantity: Option<i32>,
+    pub refund_id: Option<i32>,
+    pub amount: BigDecimal,
+    pub reason_code: Option<String>,
+    pub note: Option<String>,
+    pub user_id: Option<i32>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = order_adjustments)]
+pub struct NewOrderAdjustment<'a> {
+    pub order_id: i32,
+    pub adjustment_type: &'a str,
+    pub product_id: Option<i32>,
+    pub quantity: Option<i32>,
+    pub refund_id: Option<i32>,
+    pub amount: BigDecimal,
+    pub reason_code: Option<&'a str>,
+    pub note: Option<&'a str>,
+    pub user_id: Option<i32>,
+}
@@ -0,0 +1,54 @@
+use crate::data::models::order::Order;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// One of the checks an order is split into, with its share of the order totals
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_checks)]
+#[diesel(primary_key(check_id))]
+#[diesel(belongs_to(Order, foreign_key = order_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct OrderCheck {
+    pub check_id: i32,
+    pub order_id: i32,
+    pub check_number: i32,
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub total_amount: BigDecimal,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    /// Share of the order's auto gratuity
+    pub gratuity_amount: BigDecimal,
+}
+
+impl OrderCheck {
+    /// What is charged on the check: its total plus its share of the auto gratuity
+    pub fn amount_due(&self) -> BigDecimal {
+        &self.total_amount + &self.gratuity_amount
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_checks)]
+pub struct NewOrderCheck {
+    pub order_id: i32,
+    pub check_number: i32,
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub total_amount: BigDecimal,
+    pub gratuity_amount: BigDecimal,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Insertable, PartialEq, Debug)]
+#[diesel(table_name = order_check_items)]
+#[diesel(primary_key(check_id, product_id))]
+#[diesel(belongs_to(OrderCheck, foreign_key = check_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct OrderCheckItem {
+    pub check_id: i32,
+    pub product_id: i32,
+}
@@ -0,0 +1,31 @@
+use crate::data::models::order::Order;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// A discount line recorded against an order when a promotion is applied
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_discounts)]
+#[diesel(primary_key(order_discount_id))]
+#[diesel(belongs_to(Order, foreign_key = order_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct OrderDiscount {
+    pub order_discount_id: i32,
+    pub order_id: i32,
+    pub promotion_id: Option<i32>,
+    pub name: String,
+    pub code: Option<String>,
+    pub amount: BigDecimal,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = order_discounts)]
+pub struct NewOrderDiscount {
+    pub order_id: i32,
+    pub promotion_id: Option<i32>,
+    pub name: String,
+    pub code: Option<String>,
+    pub amount: BigDecimal,
+}
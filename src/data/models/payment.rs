@@ -0,0 +1,45 @@
+use crate::data::models::order::Order;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// A payment captured (or attempted) against an order through a payment gateway
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = payments)]
+#[diesel(primary_key(payment_id))]
+#[diesel(belongs_to(Order, foreign_key = order_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct Payment {
+    pub payment_id: i32,
+    pub order_id: i32,
+    pub amount: BigDecimal,
+    pub method: String,
+    pub provider: String,
+    pub status: String,
+    pub provider_reference: Option<String>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+    pub check_id: Option<i32>,
+    /// Tip charged on top of the amount
+    pub tip_amount: BigDecimal,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = payments)]
+pub struct NewPayment<'a> {
+    pub order_id: i32,
+    pub amount: BigDecimal,
+    pub method: &'a str,
+    pub provider: &'a str,
+    pub status: &'a str,
+    pub provider_reference: Option<&'a str>,
+    pub check_id: Option<i32>,
+    pub tip_amount: BigDecimal,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = payments)]
+pub struct UpdatePayment<'a> {
+    pub status: Option<&'a str>,
+}
@@ -15,6 +15,24 @@ pub struct Product {
     pub price: BigDecimal,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    /// Minutes the kitchen needs to prepare the product
+    pub prep_minutes: i32,
+    /// Whether the product can be ordered, false once it is 86'd
+    pub is_available: bool,
+    /// When an 86'd product becomes available again on its own
+    pub available_again_at: Option<chrono::NaiveDateTime>,
+    /// When the product was archived; archived products are off the menu but stay on the
+    /// orders they were sold on
+    pub deleted_at: Option<chrono::NaiveDateTime>,
+}
+
+impl Product {
+    /// Whether the product can be ordered at the given time, taking its restore time into
+    /// account. Archived products cannot be ordered.
+    pub fn is_available_at(&self, at: chrono::NaiveDateTime) -> bool {
+        self.deleted_at.is_none()
+            && (self.is_available || self.available_again_at.is_some_and(|again| again <= at))
+    }
 }
 
 #[derive(Insertable, PartialEq, Debug)]
@@ -15,6 +15,8 @@ pub struct ProductCategory {
     pub category_id: i32,
     pub created_at: Option<chrono::NaiveDateTime>,
     pub updated_at: Option<chrono::NaiveDateTime>,
+    /// Products of a category are listed by ascending sort order
+    pub sort_order: i32,
 }
 
 #[derive(Insertable, PartialEq, Debug)]
@@ -0,0 +1,39 @@
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+/// Price of a product over a period of time. Rows starting in the future are scheduled
+/// price changes, which take over from the row before them once they are due.
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = product_prices)]
+#[diesel(primary_key(price_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct ProductPrice {
+    pub price_id: i32,
+    pub product_id: i32,
+    pub price: BigDecimal,
+    /// Local time the price starts to apply
+    pub effective_from: NaiveDateTime,
+    /// Local time the next price takes over, none for the latest price
+    pub effective_to: Option<NaiveDateTime>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+impl ProductPrice {
+    /// Whether this is the price of the product at `at`
+    pub fn is_effective_at(&self, at: NaiveDateTime) -> bool {
+        self.effective_from <= at && self.effective_to.is_none_or(|to| at < to)
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = product_prices)]
+pub struct NewProductPrice {
+    pub product_id: i32,
+    pub price: BigDecimal,
+    pub effective_from: NaiveDateTime,
+    pub effective_to: Option<NaiveDateTime>,
+    pub created_by: Option<i32>,
+}
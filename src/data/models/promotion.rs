@@ -0,0 +1,83 @@
+use crate::data::models::categories::Category;
+use crate::data::models::product::Product;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// A percentage or fixed discount, either applied automatically or redeemed with a code.
+/// Products and categories linked to a promotion restrict which order lines it applies to.
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = promotions)]
+#[diesel(primary_key(promotion_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct Promotion {
+    pub promotion_id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    pub discount_type: String,
+    pub discount_value: BigDecimal,
+    pub is_automatic: bool,
+    pub is_active: bool,
+    pub min_spend: Option<BigDecimal>,
+    pub starts_at: Option<chrono::NaiveDateTime>,
+    pub ends_at: Option<chrono::NaiveDateTime>,
+    pub daily_start_time: Option<chrono::NaiveTime>,
+    pub daily_end_time: Option<chrono::NaiveTime>,
+    pub usage_limit: Option<i32>,
+    pub usage_count: i32,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = promotions)]
+pub struct NewPromotion<'a> {
+    pub name: &'a str,
+    pub code: Option<&'a str>,
+    pub discount_type: &'a str,
+    pub discount_value: BigDecimal,
+    pub is_automatic: bool,
+    pub min_spend: Option<BigDecimal>,
+    pub starts_at: Option<chrono::NaiveDateTime>,
+    pub ends_at: Option<chrono::NaiveDateTime>,
+    pub daily_start_time: Option<chrono::NaiveTime>,
+    pub daily_end_time: Option<chrono::NaiveTime>,
+    pub usage_limit: Option<i32>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = promotions)]
+pub struct UpdatePromotion<'a> {
+    pub name: Option<&'a str>,
+    pub discount_value: Option<BigDecimal>,
+    pub is_active: Option<bool>,
+    pub min_spend: Option<BigDecimal>,
+    pub starts_at: Option<chrono::NaiveDateTime>,
+    pub ends_at: Option<chrono::NaiveDateTime>,
+    pub daily_start_time: Option<chrono::NaiveTime>,
+    pub daily_end_time: Option<chrono::NaiveTime>,
+    pub usage_limit: Option<i32>,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Insertable, PartialEq, Debug)]
+#[diesel(table_name = promotion_products)]
+#[diesel(primary_key(promotion_id, product_id))]
+#[diesel(belongs_to(Promotion, foreign_key = promotion_id))]
+#[diesel(belongs_to(Product, foreign_key = product_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PromotionProduct {
+    pub promotion_id: i32,
+    pub product_id: i32,
+}
+
+#[derive(Queryable, Selectable, Identifiable, Associations, Insertable, PartialEq, Debug)]
+#[diesel(table_name = promotion_categories)]
+#[diesel(primary_key(promotion_id, category_id))]
+#[diesel(belongs_to(Promotion, foreign_key = promotion_id))]
+#[diesel(belongs_to(Category, foreign_key = category_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PromotionCategory {
+    pub promotion_id: i32,
+    pub category_id: i32,
+}
@@ -0,0 +1,64 @@
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// Order of ingredients placed with a supplier to restock
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = purchase_orders)]
+#[diesel(primary_key(purchase_order_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PurchaseOrder {
+    pub purchase_order_id: i32,
+    pub supplier_id: i32,
+    pub status: String,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+    pub sent_at: Option<chrono::NaiveDateTime>,
+    /// Set once every line is received in full
+    pub received_at: Option<chrono::NaiveDateTime>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = purchase_orders)]
+pub struct NewPurchaseOrder<'a> {
+    pub supplier_id: i32,
+    pub note: Option<&'a str>,
+    pub created_by: Option<i32>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = purchase_orders)]
+pub struct UpdatePurchaseOrder<'a> {
+    pub supplier_id: Option<i32>,
+    pub note: Option<&'a str>,
+}
+
+/// Quantity of an ingredient ordered on a purchase order, and how much of it has arrived
+#[derive(Queryable, Selectable, PartialEq, Debug, Clone)]
+#[diesel(table_name = purchase_order_lines)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct PurchaseOrderLine {
+    pub purchase_order_id: i32,
+    pub ingredient_id: i32,
+    pub quantity: BigDecimal,
+    pub unit_cost: BigDecimal,
+    pub received_quantity: BigDecimal,
+}
+
+impl PurchaseOrderLine {
+    /// Quantity still to be received
+    pub fn outstanding(&self) -> BigDecimal {
+        &self.quantity - &self.received_quantity
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = purchase_order_lines)]
+pub struct NewPurchaseOrderLine {
+    pub purchase_order_id: i32,
+    pub ingredient_id: i32,
+    pub quantity: BigDecimal,
+    pub unit_cost: BigDecimal,
+}
@@ -0,0 +1,29 @@
+use crate::data::models::payment::Payment;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// Money returned to the customer against a captured payment
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = refunds)]
+#[diesel(primary_key(refund_id))]
+#[diesel(belongs_to(Payment, foreign_key = payment_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct Refund {
+    pub refund_id: i32,
+    pub payment_id: i32,
+    pub amount: BigDecimal,
+    pub reason: Option<String>,
+    pub provider_reference: Option<String>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = refunds)]
+pub struct NewRefund<'a> {
+    pub payment_id: i32,
+    pub amount: BigDecimal,
+    pub reason: Option<&'a str>,
+    pub provider_reference: Option<&'a str>,
+}
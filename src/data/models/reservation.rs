@@ -0,0 +1,48 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// A table booked for a party at a given time.
+/// `status` is `booked`, `seated`, `no_show` or `cancelled`.
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = reservations)]
+#[diesel(primary_key(reservation_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Reservation {
+    pub reservation_id: i32,
+    pub contact_name: String,
+    pub contact_phone: String,
+    pub party_size: i32,
+    pub reserved_for: chrono::NaiveDateTime,
+    pub table_id: Option<i32>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub session_id: Option<i32>,
+    pub created_by: Option<i32>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = reservations)]
+pub struct NewReservation<'a> {
+    pub contact_name: &'a str,
+    pub contact_phone: &'a str,
+    pub party_size: i32,
+    pub reserved_for: chrono::NaiveDateTime,
+    pub table_id: Option<i32>,
+    pub notes: Option<&'a str>,
+    pub created_by: Option<i32>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug, Default)]
+#[diesel(table_name = reservations)]
+pub struct UpdateReservation<'a> {
+    pub contact_name: Option<&'a str>,
+    pub contact_phone: Option<&'a str>,
+    pub party_size: Option<i32>,
+    pub reserved_for: Option<chrono::NaiveDateTime>,
+    pub table_id: Option<i32>,
+    pub status: Option<&'a str>,
+    pub notes: Option<&'a str>,
+    pub session_id: Option<i32>,
+}
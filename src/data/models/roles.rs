@@ -88,6 +88,8 @@ pub enum RolePermissions {
     Write,
     Delete,
     Admin,
+    /// Void order lines and refund payments
+    Refund,
 }
 
 impl RolePermissions {
@@ -97,6 +99,7 @@ impl RolePermissions {
             RolePermissions::Write => "WRITE",
             RolePermissions::Delete => "DELETE",
             RolePermissions::Admin => "ADMIN",
+            RolePermissions::Refund => "REFUND",
         }
     }
 }
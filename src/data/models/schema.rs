@@ -6,6 +6,27 @@ pub mod sql_types {
     pub struct RolesPermissionsSet;
 }
 
+diesel::table! {
+    bundle_slot_choices (choice_id) {
+        choice_id -> Integer,
+        slot_id -> Integer,
+        product_id -> Nullable<Integer>,
+        category_id -> Nullable<Integer>,
+        upcharge -> Decimal,
+    }
+}
+
+diesel::table! {
+    bundle_slots (slot_id) {
+        slot_id -> Integer,
+        bundle_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        quantity -> Integer,
+        sort_order -> Integer,
+    }
+}
+
 diesel::table! {
     categories (category_id) {
         category_id -> Integer,
@@ -14,6 +35,229 @@ diesel::table! {
         description -> Nullable<Text>,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        deleted_at -> Nullable<Timestamp>,
+        parent_id -> Nullable<Integer>,
+        sort_order -> Integer,
+    }
+}
+
+diesel::table! {
+    category_translations (category_id, locale) {
+        category_id -> Integer,
+        #[max_length = 10]
+        locale -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    dining_areas (area_id) {
+        area_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    dining_tables (table_id) {
+        table_id -> Integer,
+        area_id -> Integer,
+        #[max_length = 50]
+        name -> Varchar,
+        capacity -> Integer,
+        #[max_length = 20]
+        status -> Varchar,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    ingredient_allergens (ingredient_id, allergen) {
+        ingredient_id -> Integer,
+        #[max_length = 20]
+        allergen -> Varchar,
+    }
+}
+
+diesel::table! {
+    ingredient_dietary_tags (ingredient_id, tag) {
+        ingredient_id -> Integer,
+        #[max_length = 20]
+        tag -> Varchar,
+    }
+}
+
+diesel::table! {
+    ingredient_nutrition (ingredient_id) {
+        ingredient_id -> Integer,
+        kcal -> Decimal,
+        protein -> Decimal,
+        carbohydrates -> Decimal,
+        sugars -> Decimal,
+        fat -> Decimal,
+        salt -> Decimal,
+    }
+}
+
+diesel::table! {
+    ingredients (ingredient_id) {
+        ingredient_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 20]
+        unit -> Varchar,
+        on_hand -> Decimal,
+        reorder_level -> Decimal,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        supplier_id -> Nullable<Integer>,
+        par_level -> Decimal,
+        average_cost -> Decimal,
+    }
+}
+
+diesel::table! {
+    kitchen_ticket_items (ticket_id, product_id) {
+        ticket_id -> Integer,
+        product_id -> Integer,
+        quantity -> Integer,
+    }
+}
+
+diesel::table! {
+    kitchen_tickets (ticket_id) {
+        ticket_id -> Integer,
+        order_id -> Integer,
+        station_id -> Nullable<Integer>,
+        #[max_length = 20]
+        status -> Varchar,
+        created_at -> Nullable<Timestamp>,
+        bumped_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    menu_categories (menu_id, category_id) {
+        menu_id -> Integer,
+        category_id -> Integer,
+    }
+}
+
+diesel::table! {
+    menu_products (menu_id, product_id) {
+        menu_id -> Integer,
+        product_id -> Integer,
+    }
+}
+
+diesel::table! {
+    menu_schedules (schedule_id) {
+        schedule_id -> Integer,
+        menu_id -> Integer,
+        day_of_week -> Integer,
+        start_time -> Time,
+        end_time -> Time,
+    }
+}
+
+diesel::table! {
+    menus (menu_id) {
+        menu_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        #[max_length = 6]
+        utc_offset -> Nullable<Varchar>,
+        is_active -> Bool,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    order_adjustments (adjustment_id) {
+        adjustment_id -> Integer,
+        order_id -> Integer,
+        #[max_length = 20]
+        adjustment_type -> Varchar,
+        product_id -> Nullable<Integer>,
+        quantity -> Nullable<Integer>,
+        refund_id -> Nullable<Integer>,
+        amount -> Decimal,
+        #[max_length = 50]
+        reason_code -> Nullable<Varchar>,
+        #[max_length = 255]
+        note -> Nullable<Varchar>,
+        user_id -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    order_allergen_alerts (order_id, product_id, allergen) {
+        order_id -> Integer,
+        product_id -> Integer,
+        #[max_length = 20]
+        allergen -> Varchar,
+    }
+}
+
+diesel::table! {
+    order_bundle_items (order_id, bundle_id, product_id) {
+        order_id -> Integer,
+        bundle_id -> Integer,
+        product_id -> Integer,
+        quantity -> Integer,
+        upcharge -> Decimal,
+    }
+}
+
+diesel::table! {
+    order_check_items (check_id, product_id) {
+        check_id -> Integer,
+        product_id -> Integer,
+    }
+}
+
+diesel::table! {
+    order_checks (check_id) {
+        check_id -> Integer,
+        order_id -> Integer,
+        check_number -> Integer,
+        subtotal -> Decimal,
+        discount_amount -> Decimal,
+        tax_amount -> Decimal,
+        service_charge -> Decimal,
+        total_amount -> Decimal,
+        created_at -> Nullable<Timestamp>,
+        gratuity_amount -> Decimal,
+    }
+}
+
+diesel::table! {
+    order_discounts (order_discount_id) {
+        order_discount_id -> Integer,
+        order_id -> Integer,
+        promotion_id -> Nullable<Integer>,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 50]
+        code -> Nullable<Varchar>,
+        amount -> Decimal,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    order_number_sequences (business_date) {
+        business_date -> Date,
+        last_number -> Integer,
     }
 }
 
@@ -38,6 +282,50 @@ diesel::table! {
         status -> Nullable<Varchar>,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        subtotal -> Decimal,
+        tax_amount -> Decimal,
+        service_charge -> Decimal,
+        discount_amount -> Decimal,
+        party_size -> Nullable<Integer>,
+        served_by -> Nullable<Integer>,
+        gratuity_amount -> Decimal,
+        tip_amount -> Decimal,
+        #[max_length = 20]
+        order_type -> Varchar,
+        table_id -> Nullable<Integer>,
+        session_id -> Nullable<Integer>,
+        business_date -> Nullable<Date>,
+        order_number -> Nullable<Integer>,
+        scheduled_for -> Nullable<Datetime>,
+        estimated_ready_at -> Nullable<Datetime>,
+    }
+}
+
+diesel::table! {
+    payments (payment_id) {
+        payment_id -> Integer,
+        order_id -> Integer,
+        amount -> Decimal,
+        #[max_length = 20]
+        method -> Varchar,
+        #[max_length = 50]
+        provider -> Varchar,
+        #[max_length = 20]
+        status -> Varchar,
+        #[max_length = 255]
+        provider_reference -> Nullable<Varchar>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        check_id -> Nullable<Integer>,
+        tip_amount -> Decimal,
+    }
+}
+
+diesel::table! {
+    product_allergens (product_id, allergen) {
+        product_id -> Integer,
+        #[max_length = 20]
+        allergen -> Varchar,
     }
 }
 
@@ -47,6 +335,53 @@ diesel::table! {
         category_id -> Integer,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        sort_order -> Integer,
+    }
+}
+
+diesel::table! {
+    product_dietary_tags (product_id, tag) {
+        product_id -> Integer,
+        #[max_length = 20]
+        tag -> Varchar,
+    }
+}
+
+diesel::table! {
+    product_nutrition (product_id) {
+        product_id -> Integer,
+        #[max_length = 50]
+        serving_size -> Nullable<Varchar>,
+        kcal -> Decimal,
+        protein -> Decimal,
+        carbohydrates -> Decimal,
+        sugars -> Decimal,
+        fat -> Decimal,
+        salt -> Decimal,
+        from_recipe -> Bool,
+    }
+}
+
+diesel::table! {
+    product_prices (price_id) {
+        price_id -> Integer,
+        product_id -> Integer,
+        price -> Decimal,
+        effective_from -> Datetime,
+        effective_to -> Nullable<Datetime>,
+        created_by -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    product_translations (product_id, locale) {
+        product_id -> Integer,
+        #[max_length = 10]
+        locale -> Varchar,
+        #[max_length = 100]
+        name -> Varchar,
+        description -> Nullable<Text>,
     }
 }
 
@@ -61,6 +396,114 @@ diesel::table! {
         price -> Decimal,
         created_at -> Nullable<Timestamp>,
         updated_at -> Nullable<Timestamp>,
+        prep_minutes -> Integer,
+        is_available -> Bool,
+        available_again_at -> Nullable<Datetime>,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    promotion_categories (promotion_id, category_id) {
+        promotion_id -> Integer,
+        category_id -> Integer,
+    }
+}
+
+diesel::table! {
+    promotion_products (promotion_id, product_id) {
+        promotion_id -> Integer,
+        product_id -> Integer,
+    }
+}
+
+diesel::table! {
+    promotions (promotion_id) {
+        promotion_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 50]
+        code -> Nullable<Varchar>,
+        #[max_length = 20]
+        discount_type -> Varchar,
+        discount_value -> Decimal,
+        is_automatic -> Bool,
+        is_active -> Bool,
+        min_spend -> Nullable<Decimal>,
+        starts_at -> Nullable<Datetime>,
+        ends_at -> Nullable<Datetime>,
+        daily_start_time -> Nullable<Time>,
+        daily_end_time -> Nullable<Time>,
+        usage_limit -> Nullable<Integer>,
+        usage_count -> Integer,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    purchase_order_lines (purchase_order_id, ingredient_id) {
+        purchase_order_id -> Integer,
+        ingredient_id -> Integer,
+        quantity -> Decimal,
+        unit_cost -> Decimal,
+        received_quantity -> Decimal,
+    }
+}
+
+diesel::table! {
+    purchase_orders (purchase_order_id) {
+        purchase_order_id -> Integer,
+        supplier_id -> Integer,
+        #[max_length = 20]
+        status -> Varchar,
+        note -> Nullable<Text>,
+        created_by -> Nullable<Integer>,
+        sent_at -> Nullable<Timestamp>,
+        received_at -> Nullable<Timestamp>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    recipes (product_id, ingredient_id) {
+        product_id -> Integer,
+        ingredient_id -> Integer,
+        quantity -> Decimal,
+    }
+}
+
+diesel::table! {
+    refunds (refund_id) {
+        refund_id -> Integer,
+        payment_id -> Integer,
+        amount -> Decimal,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 255]
+        provider_reference -> Nullable<Varchar>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    reservations (reservation_id) {
+        reservation_id -> Integer,
+        #[max_length = 100]
+        contact_name -> Varchar,
+        #[max_length = 30]
+        contact_phone -> Varchar,
+        party_size -> Integer,
+        reserved_for -> Datetime,
+        table_id -> Nullable<Integer>,
+        #[max_length = 20]
+        status -> Varchar,
+        notes -> Nullable<Text>,
+        session_id -> Nullable<Integer>,
+        created_by -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
     }
 }
 
@@ -72,7 +515,7 @@ diesel::table! {
         role_id -> Integer,
         #[max_length = 50]
         name -> Varchar,
-        #[max_length = 23]
+        #[max_length = 30]
         permissions -> Nullable<RolesPermissionsSet>,
         description -> Nullable<Text>,
         created_at -> Nullable<Timestamp>,
@@ -80,6 +523,95 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    station_categories (category_id) {
+        category_id -> Integer,
+        station_id -> Integer,
+    }
+}
+
+diesel::table! {
+    station_products (product_id) {
+        product_id -> Integer,
+        station_id -> Integer,
+    }
+}
+
+diesel::table! {
+    stations (station_id) {
+        station_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        capacity -> Integer,
+    }
+}
+
+diesel::table! {
+    stock_movements (movement_id) {
+        movement_id -> Integer,
+        ingredient_id -> Integer,
+        order_id -> Nullable<Integer>,
+        quantity -> Decimal,
+        #[max_length = 20]
+        reason -> Varchar,
+        note -> Nullable<Text>,
+        user_id -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+        purchase_order_id -> Nullable<Integer>,
+        unit_cost -> Nullable<Decimal>,
+        waste_id -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    suppliers (supplier_id) {
+        supplier_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 100]
+        contact_name -> Nullable<Varchar>,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        #[max_length = 50]
+        phone -> Nullable<Varchar>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    table_sessions (session_id) {
+        session_id -> Integer,
+        table_id -> Integer,
+        party_size -> Nullable<Integer>,
+        opened_by -> Nullable<Integer>,
+        opened_at -> Nullable<Timestamp>,
+        closed_at -> Nullable<Timestamp>,
+        subtotal -> Decimal,
+        discount_amount -> Decimal,
+        tax_amount -> Decimal,
+        service_charge -> Decimal,
+        gratuity_amount -> Decimal,
+        total_amount -> Decimal,
+    }
+}
+
+diesel::table! {
+    tax_rates (tax_rate_id) {
+        tax_rate_id -> Integer,
+        #[max_length = 100]
+        name -> Varchar,
+        rate -> Decimal,
+        is_inclusive -> Bool,
+        product_id -> Nullable<Integer>,
+        category_id -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     user_roles (user_id, role_id) {
         role_id -> Integer,
@@ -101,21 +633,171 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    waitlist_entries (entry_id) {
+        entry_id -> Integer,
+        #[max_length = 100]
+        contact_name -> Varchar,
+        #[max_length = 30]
+        contact_phone -> Nullable<Varchar>,
+        party_size -> Integer,
+        quoted_wait_minutes -> Integer,
+        #[max_length = 20]
+        status -> Varchar,
+        notes -> Nullable<Text>,
+        session_id -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    waste_entries (waste_id) {
+        waste_id -> Integer,
+        ingredient_id -> Nullable<Integer>,
+        product_id -> Nullable<Integer>,
+        quantity -> Decimal,
+        #[max_length = 20]
+        reason -> Varchar,
+        cost -> Decimal,
+        note -> Nullable<Text>,
+        user_id -> Nullable<Integer>,
+        created_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::joinable!(bundle_slot_choices -> bundle_slots (slot_id));
+diesel::joinable!(bundle_slot_choices -> categories (category_id));
+diesel::joinable!(bundle_slot_choices -> products (product_id));
+diesel::joinable!(bundle_slots -> products (bundle_id));
+diesel::joinable!(category_translations -> categories (category_id));
+diesel::joinable!(dining_tables -> dining_areas (area_id));
+diesel::joinable!(ingredient_allergens -> ingredients (ingredient_id));
+diesel::joinable!(ingredient_dietary_tags -> ingredients (ingredient_id));
+diesel::joinable!(ingredient_nutrition -> ingredients (ingredient_id));
+diesel::joinable!(ingredients -> suppliers (supplier_id));
+diesel::joinable!(kitchen_ticket_items -> kitchen_tickets (ticket_id));
+diesel::joinable!(kitchen_ticket_items -> products (product_id));
+diesel::joinable!(kitchen_tickets -> orders (order_id));
+diesel::joinable!(kitchen_tickets -> stations (station_id));
+diesel::joinable!(menu_categories -> categories (category_id));
+diesel::joinable!(menu_categories -> menus (menu_id));
+diesel::joinable!(menu_products -> menus (menu_id));
+diesel::joinable!(menu_products -> products (product_id));
+diesel::joinable!(menu_schedules -> menus (menu_id));
+diesel::joinable!(order_adjustments -> orders (order_id));
+diesel::joinable!(order_adjustments -> products (product_id));
+diesel::joinable!(order_adjustments -> refunds (refund_id));
+diesel::joinable!(order_adjustments -> users (user_id));
+diesel::joinable!(order_allergen_alerts -> orders (order_id));
+diesel::joinable!(order_allergen_alerts -> products (product_id));
+diesel::joinable!(order_bundle_items -> orders (order_id));
+diesel::joinable!(order_check_items -> order_checks (check_id));
+diesel::joinable!(order_check_items -> products (product_id));
+diesel::joinable!(order_checks -> orders (order_id));
+diesel::joinable!(order_discounts -> orders (order_id));
+diesel::joinable!(order_discounts -> promotions (promotion_id));
 diesel::joinable!(order_products -> orders (order_id));
 diesel::joinable!(order_products -> products (product_id));
+diesel::joinable!(orders -> dining_tables (table_id));
+diesel::joinable!(orders -> table_sessions (session_id));
 diesel::joinable!(orders -> users (user_id));
+diesel::joinable!(payments -> order_checks (check_id));
+diesel::joinable!(payments -> orders (order_id));
+diesel::joinable!(product_allergens -> products (product_id));
 diesel::joinable!(product_categories -> categories (category_id));
 diesel::joinable!(product_categories -> products (product_id));
+diesel::joinable!(product_dietary_tags -> products (product_id));
+diesel::joinable!(product_nutrition -> products (product_id));
+diesel::joinable!(product_prices -> products (product_id));
+diesel::joinable!(product_prices -> users (created_by));
+diesel::joinable!(product_translations -> products (product_id));
+diesel::joinable!(promotion_categories -> categories (category_id));
+diesel::joinable!(promotion_categories -> promotions (promotion_id));
+diesel::joinable!(promotion_products -> products (product_id));
+diesel::joinable!(promotion_products -> promotions (promotion_id));
+diesel::joinable!(purchase_order_lines -> ingredients (ingredient_id));
+diesel::joinable!(purchase_order_lines -> purchase_orders (purchase_order_id));
+diesel::joinable!(purchase_orders -> suppliers (supplier_id));
+diesel::joinable!(purchase_orders -> users (created_by));
+diesel::joinable!(recipes -> ingredients (ingredient_id));
+diesel::joinable!(recipes -> products (product_id));
+diesel::joinable!(refunds -> payments (payment_id));
+diesel::joinable!(reservations -> dining_tables (table_id));
+diesel::joinable!(reservations -> table_sessions (session_id));
+diesel::joinable!(reservations -> users (created_by));
+diesel::joinable!(station_categories -> categories (category_id));
+diesel::joinable!(station_categories -> stations (station_id));
+diesel::joinable!(station_products -> products (product_id));
+diesel::joinable!(station_products -> stations (station_id));
+diesel::joinable!(stock_movements -> ingredients (ingredient_id));
+diesel::joinable!(stock_movements -> orders (order_id));
+diesel::joinable!(stock_movements -> purchase_orders (purchase_order_id));
+diesel::joinable!(stock_movements -> users (user_id));
+diesel::joinable!(stock_movements -> waste_entries (waste_id));
+diesel::joinable!(table_sessions -> dining_tables (table_id));
+diesel::joinable!(table_sessions -> users (opened_by));
+diesel::joinable!(tax_rates -> categories (category_id));
+diesel::joinable!(tax_rates -> products (product_id));
 diesel::joinable!(user_roles -> roles (role_id));
 diesel::joinable!(user_roles -> users (user_id));
+diesel::joinable!(waitlist_entries -> table_sessions (session_id));
+diesel::joinable!(waste_entries -> ingredients (ingredient_id));
+diesel::joinable!(waste_entries -> products (product_id));
+diesel::joinable!(waste_entries -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    bundle_slot_choices,
+    bundle_slots,
     categories,
+    category_translations,
+    dining_areas,
+    dining_tables,
+    ingredient_allergens,
+    ingredient_dietary_tags,
+    ingredient_nutrition,
+    ingredients,
+    kitchen_ticket_items,
+    kitchen_tickets,
+    menu_categories,
+    menu_products,
+    menu_schedules,
+    menus,
+    order_adjustments,
+    order_allergen_alerts,
+    order_bundle_items,
+    order_check_items,
+    order_checks,
+    order_discounts,
+    order_number_sequences,
     order_products,
     orders,
+    payments,
+    product_allergens,
     product_categories,
+    product_dietary_tags,
+    product_nutrition,
+    product_prices,
+    product_translations,
     products,
+    promotion_categories,
+    promotion_products,
+    promotions,
+    purchase_order_lines,
+    purchase_orders,
+    recipes,
+    refunds,
+    reservations,
     roles,
+    station_categories,
+    station_products,
+    stations,
+    stock_movements,
+    suppliers,
+    table_sessions,
+    tax_rates,
     user_roles,
     users,
+    waitlist_entries,
+    waste_entries,
 );
@@ -0,0 +1,48 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// A part of the kitchen or bar that prepares its own items, such as the grill or the fryer
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = stations)]
+#[diesel(primary_key(station_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Station {
+    pub station_id: i32,
+    pub name: String,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+    /// Number of tickets the station prepares at the same time
+    pub capacity: i32,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = stations)]
+pub struct NewStation<'a> {
+    pub name: &'a str,
+    pub capacity: Option<i32>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = stations)]
+pub struct UpdateStation<'a> {
+    pub name: Option<&'a str>,
+    pub capacity: Option<i32>,
+}
+
+/// Routes a product to the station that prepares it
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = station_products)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct StationProduct {
+    pub product_id: i32,
+    pub station_id: i32,
+}
+
+/// Routes the products of a category to a station, unless they are routed themselves
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = station_categories)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct StationCategory {
+    pub category_id: i32,
+    pub station_id: i32,
+}
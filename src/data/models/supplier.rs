@@ -0,0 +1,35 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// A business ingredients are bought from
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = suppliers)]
+#[diesel(primary_key(supplier_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct Supplier {
+    pub supplier_id: i32,
+    pub name: String,
+    pub contact_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = suppliers)]
+pub struct NewSupplier<'a> {
+    pub name: &'a str,
+    pub contact_name: Option<&'a str>,
+    pub email: Option<&'a str>,
+    pub phone: Option<&'a str>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = suppliers)]
+pub struct UpdateSupplier<'a> {
+    pub name: Option<&'a str>,
+    pub contact_name: Option<&'a str>,
+    pub email: Option<&'a str>,
+    pub phone: Option<&'a str>,
+}
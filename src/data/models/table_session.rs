@@ -0,0 +1,46 @@
+use crate::data::models::dining_table::DiningTable;
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// The time a party spends at a table, from being seated until the table is closed.
+/// The totals hold the combined check of the session's orders once it is closed.
+#[derive(Queryable, Selectable, Identifiable, Associations, PartialEq, Debug, Clone)]
+#[diesel(table_name = table_sessions)]
+#[diesel(primary_key(session_id))]
+#[diesel(belongs_to(DiningTable, foreign_key = table_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct TableSession {
+    pub session_id: i32,
+    pub table_id: i32,
+    pub party_size: Option<i32>,
+    pub opened_by: Option<i32>,
+    pub opened_at: Option<chrono::NaiveDateTime>,
+    pub closed_at: Option<chrono::NaiveDateTime>,
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub gratuity_amount: BigDecimal,
+    pub total_amount: BigDecimal,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = table_sessions)]
+pub struct NewTableSession {
+    pub table_id: i32,
+    pub party_size: Option<i32>,
+    pub opened_by: Option<i32>,
+}
+
+/// Combined check stored on a session when it is closed
+#[derive(AsChangeset, PartialEq, Debug, Clone)]
+#[diesel(table_name = table_sessions)]
+pub struct SessionTotals {
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub gratuity_amount: BigDecimal,
+    pub total_amount: BigDecimal,
+}
@@ -0,0 +1,53 @@
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// A tax rate expressed as a percentage.
+/// A rate with a `product_id` applies to that product only, a rate with a `category_id`
+/// applies to every product in that category, and a rate with neither is the default rate.
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = tax_rates)]
+#[diesel(primary_key(tax_rate_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+#[diesel(treat_none_as_null = true)]
+pub struct TaxRate {
+    pub tax_rate_id: i32,
+    pub name: String,
+    pub rate: BigDecimal,
+    pub is_inclusive: bool,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+impl TaxRate {
+    /// Keeps the rates of a single category out of the category rates of a product: those of
+    /// its lowest category id that has any. A product in two taxed categories is taxed by one
+    /// of them, while the rates of that category still stack.
+    pub fn of_one_category(rates: Vec<TaxRate>) -> Vec<TaxRate> {
+        let first = rates.iter().filter_map(|r| r.category_id).min();
+        rates
+            .into_iter()
+            .filter(|r| r.category_id.is_some() && r.category_id == first)
+            .collect()
+    }
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = tax_rates)]
+pub struct NewTaxRate<'a> {
+    pub name: &'a str,
+    pub rate: BigDecimal,
+    pub is_inclusive: bool,
+    pub product_id: Option<i32>,
+    pub category_id: Option<i32>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug)]
+#[diesel(table_name = tax_rates)]
+pub struct UpdateTaxRate<'a> {
+    pub name: Option<&'a str>,
+    pub rate: Option<BigDecimal>,
+    pub is_inclusive: Option<bool>,
+}
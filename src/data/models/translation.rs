@@ -0,0 +1,26 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// Name and description of a product in a locale other than the default one
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = product_translations)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct ProductTranslation {
+    pub product_id: i32,
+    /// Language code such as `es` or `ja`
+    pub locale: String,
+    pub name: String,
+    /// The product's own description is shown when none is translated
+    pub description: Option<String>,
+}
+
+/// Name and description of a category in a locale other than the default one
+#[derive(Queryable, Selectable, Insertable, PartialEq, Debug, Clone)]
+#[diesel(table_name = category_translations)]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct CategoryTranslation {
+    pub category_id: i32,
+    pub locale: String,
+    pub name: String,
+    pub description: Option<String>,
+}
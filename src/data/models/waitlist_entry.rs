@@ -0,0 +1,38 @@
+use crate::data::models::schema::*;
+use diesel::prelude::*;
+
+/// A walk-in party waiting for a table. `status` is `waiting`, `seated` or `left`.
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = waitlist_entries)]
+#[diesel(primary_key(entry_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct WaitlistEntry {
+    pub entry_id: i32,
+    pub contact_name: String,
+    pub contact_phone: Option<String>,
+    pub party_size: i32,
+    /// Wait quoted to the party when it joined the waitlist
+    pub quoted_wait_minutes: i32,
+    pub status: String,
+    pub notes: Option<String>,
+    pub session_id: Option<i32>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+    pub updated_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = waitlist_entries)]
+pub struct NewWaitlistEntry<'a> {
+    pub contact_name: &'a str,
+    pub contact_phone: Option<&'a str>,
+    pub party_size: i32,
+    pub quoted_wait_minutes: i32,
+    pub notes: Option<&'a str>,
+}
+
+#[derive(AsChangeset, PartialEq, Debug, Default)]
+#[diesel(table_name = waitlist_entries)]
+pub struct UpdateWaitlistEntry<'a> {
+    pub status: Option<&'a str>,
+    pub session_id: Option<i32>,
+}
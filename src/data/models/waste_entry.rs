@@ -0,0 +1,34 @@
+use crate::data::models::schema::*;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+
+/// Stock thrown away instead of being sold, either an ingredient or prepared products
+#[derive(Queryable, Selectable, Identifiable, PartialEq, Debug, Clone)]
+#[diesel(table_name = waste_entries)]
+#[diesel(primary_key(waste_id))]
+#[diesel(check_for_backend(diesel::mysql::Mysql))]
+pub struct WasteEntry {
+    pub waste_id: i32,
+    pub ingredient_id: Option<i32>,
+    pub product_id: Option<i32>,
+    /// In the unit of the ingredient, or a number of products
+    pub quantity: BigDecimal,
+    pub reason: String,
+    /// Stock lost, at the average cost of the ingredients when the waste was logged
+    pub cost: BigDecimal,
+    pub note: Option<String>,
+    pub user_id: Option<i32>,
+    pub created_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Insertable, PartialEq, Debug)]
+#[diesel(table_name = waste_entries)]
+pub struct NewWasteEntry<'a> {
+    pub ingredient_id: Option<i32>,
+    pub product_id: Option<i32>,
+    pub quantity: BigDecimal,
+    pub reason: &'a str,
+    pub cost: BigDecimal,
+    pub note: Option<&'a str>,
+    pub user_id: Option<i32>,
+}
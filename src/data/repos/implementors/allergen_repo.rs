@@ -0,0 +1,276 @@
+use crate::data::database::Database;
+use crate::data::models::allergen::{
+    IngredientAllergen, IngredientDietaryTag, OrderAllergenAlert, ProductAllergen,
+    ProductDietaryTag,
+};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct AllergenRepo;
+
+impl AllergenRepo {
+    pub fn new() -> Self {
+        AllergenRepo
+    }
+
+    /// Retrieves the allergens set on the given products.
+    pub async fn get_product_allergens(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<ProductAllergen>, result::Error> {
+        use crate::data::models::schema::product_allergens::dsl::{product_allergens, product_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_allergens
+            .filter(product_id.eq_any(ids))
+            .load::<ProductAllergen>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the dietary tags set on the given products.
+    pub async fn get_product_tags(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<ProductDietaryTag>, result::Error> {
+        use crate::data::models::schema::product_dietary_tags::dsl::{
+            product_dietary_tags, product_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_dietary_tags
+            .filter(product_id.eq_any(ids))
+            .load::<ProductDietaryTag>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the allergens of the given ingredients.
+    pub async fn get_ingredient_allergens(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<IngredientAllergen>, result::Error> {
+        use crate::data::models::schema::ingredient_allergens::dsl::{
+            ingredient_allergens, ingredient_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        ingredient_allergens
+            .filter(ingredient_id.eq_any(ids))
+            .load::<IngredientAllergen>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the dietary tags of the given ingredients.
+    pub async fn get_ingredient_tags(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<IngredientDietaryTag>, result::Error> {
+        use crate::data::models::schema::ingredient_dietary_tags::dsl::{
+            ingredient_dietary_tags, ingredient_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        ingredient_dietary_tags
+            .filter(ingredient_id.eq_any(ids))
+            .load::<IngredientDietaryTag>(&mut conn)
+            .await
+    }
+
+    /// Replaces the allergens and dietary tags set on a product in a single transaction.
+    pub async fn replace_for_product(
+        &self,
+        id: i32,
+        allergens: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::product_allergens::dsl::{
+            product_allergens, product_id as allergen_owner,
+        };
+        use crate::data::models::schema::product_dietary_tags::dsl::{
+            product_dietary_tags, product_id as tag_owner,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(product_allergens.filter(allergen_owner.eq(id)))
+                    .execute(connection)
+                    .await?;
+                diesel::delete(product_dietary_tags.filter(tag_owner.eq(id)))
+                    .execute(connection)
+                    .await?;
+
+                let allergens: Vec<ProductAllergen> = allergens
+                    .into_iter()
+                    .map(|allergen| ProductAllergen {
+                        product_id: id,
+                        allergen,
+                    })
+                    .collect();
+                if !allergens.is_empty() {
+                    diesel::insert_into(product_allergens)
+                        .values(&allergens)
+                        .execute(connection)
+                        .await?;
+                }
+
+                let tags: Vec<ProductDietaryTag> = tags
+                    .into_iter()
+                    .map(|tag| ProductDietaryTag {
+                        product_id: id,
+                        tag,
+                    })
+                    .collect();
+                if !tags.is_empty() {
+                    diesel::insert_into(product_dietary_tags)
+                        .values(&tags)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Replaces the allergens and dietary tags of an ingredient in a single transaction.
+    pub async fn replace_for_ingredient(
+        &self,
+        id: i32,
+        allergens: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::ingredient_allergens::dsl::{
+            ingredient_allergens, ingredient_id as allergen_owner,
+        };
+        use crate::data::models::schema::ingredient_dietary_tags::dsl::{
+            ingredient_dietary_tags, ingredient_id as tag_owner,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(ingredient_allergens.filter(allergen_owner.eq(id)))
+                    .execute(connection)
+                    .await?;
+                diesel::delete(ingredient_dietary_tags.filter(tag_owner.eq(id)))
+                    .execute(connection)
+                    .await?;
+
+                let allergens: Vec<IngredientAllergen> = allergens
+                    .into_iter()
+                    .map(|allergen| IngredientAllergen {
+                        ingredient_id: id,
+                        allergen,
+                    })
+                    .collect();
+                if !allergens.is_empty() {
+                    diesel::insert_into(ingredient_allergens)
+                        .values(&allergens)
+                        .execute(connection)
+                        .await?;
+                }
+
+                let tags: Vec<IngredientDietaryTag> = tags
+                    .into_iter()
+                    .map(|tag| IngredientDietaryTag {
+                        ingredient_id: id,
+                        tag,
+                    })
+                    .collect();
+                if !tags.is_empty() {
+                    diesel::insert_into(ingredient_dietary_tags)
+                        .values(&tags)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the allergen alerts of the given orders.
+    pub async fn get_order_alerts(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<OrderAllergenAlert>, result::Error> {
+        use crate::data::models::schema::order_allergen_alerts::dsl::{
+            order_allergen_alerts, order_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        order_allergen_alerts
+            .filter(order_id.eq_any(ids))
+            .load::<OrderAllergenAlert>(&mut conn)
+            .await
+    }
+}
+
+impl Default for AllergenRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
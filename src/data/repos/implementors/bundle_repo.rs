@@ -0,0 +1,147 @@
+use crate::data::database::Database;
+use crate::data::models::bundle::{
+    BundleSlot, BundleSlotChoice, NewBundleSlot, NewBundleSlotChoice, OrderBundleItem,
+};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct BundleRepo;
+
+impl BundleRepo {
+    pub fn new() -> Self {
+        BundleRepo
+    }
+
+    /// Retrieves the slots of the given bundle products, by their sort order.
+    pub async fn get_slots(&self, ids: Vec<i32>) -> Result<Vec<BundleSlot>, result::Error> {
+        use crate::data::models::schema::bundle_slots::dsl::{
+            bundle_id, bundle_slots, slot_id, sort_order,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        bundle_slots
+            .filter(bundle_id.eq_any(ids))
+            .order((sort_order.asc(), slot_id.asc()))
+            .load::<BundleSlot>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the choices of the given slots.
+    pub async fn get_choices(&self, ids: Vec<i32>) -> Result<Vec<BundleSlotChoice>, result::Error> {
+        use crate::data::models::schema::bundle_slot_choices::dsl::{
+            bundle_slot_choices, choice_id, slot_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        bundle_slot_choices
+            .filter(slot_id.eq_any(ids))
+            .order(choice_id.asc())
+            .load::<BundleSlotChoice>(&mut conn)
+            .await
+    }
+
+    /// Replaces the slots of a bundle product and their choices in a single transaction.
+    /// Without slots the product is no longer a bundle.
+    pub async fn replace_slots(
+        &self,
+        bundle: i32,
+        slots: Vec<(NewBundleSlot, Vec<NewBundleSlotChoice>)>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::bundle_slot_choices::dsl::bundle_slot_choices;
+        use crate::data::models::schema::bundle_slots::dsl::{bundle_id, bundle_slots};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                // Choices go with their slots
+                diesel::delete(bundle_slots.filter(bundle_id.eq(bundle)))
+                    .execute(connection)
+                    .await?;
+
+                for (slot, choices) in slots {
+                    diesel::insert_into(bundle_slots)
+                        .values(&slot)
+                        .execute(connection)
+                        .await?;
+
+                    let new_id: i32 = diesel::select(
+                        diesel::dsl::sql::<diesel::sql_types::Integer>("LAST_INSERT_ID()"),
+                    )
+                    .get_result(connection)
+                    .await?;
+
+                    let choices: Vec<NewBundleSlotChoice> = choices
+                        .into_iter()
+                        .map(|choice| NewBundleSlotChoice {
+                            slot_id: new_id,
+                            ..choice
+                        })
+                        .collect();
+                    diesel::insert_into(bundle_slot_choices)
+                        .values(&choices)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the products chosen for the bundles of an order.
+    pub async fn get_order_items(&self, order: i32) -> Result<Vec<OrderBundleItem>, result::Error> {
+        use crate::data::models::schema::order_bundle_items::dsl::{
+            bundle_id, order_bundle_items, order_id, product_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        order_bundle_items
+            .filter(order_id.eq(order))
+            .order((bundle_id.asc(), product_id.asc()))
+            .load::<OrderBundleItem>(&mut conn)
+            .await
+    }
+}
+
+impl Default for BundleRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -38,6 +38,201 @@ impl CategoryRepo {
             Err(e) => Err(e),
         }
     }
+
+    /// Retrieves the categories that are not archived.
+    pub async fn get_active(&self) -> Result<Option<Vec<Category>>, result::Error> {
+        use crate::data::models::schema::categories::dsl::{categories, deleted_at};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match categories
+            .filter(deleted_at.is_null())
+            .load::<Category>(&mut conn)
+            .await
+        {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Adds a category, first checking its parent, when it has one, against the active
+    /// categories. They stay locked until the category is added, so a concurrent move cannot
+    /// make the parent invalid in between. Returns whether the parent was valid.
+    pub async fn add_nested<'a>(
+        &self,
+        item: NewCategory<'a>,
+        valid_parent: impl FnOnce(&[Category]) -> bool + Send + 'a,
+    ) -> Result<bool, result::Error> {
+        use crate::data::models::schema::categories::dsl::{categories, deleted_at};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                if item.parent_id.is_some() {
+                    let active = categories
+                        .filter(deleted_at.is_null())
+                        .for_update()
+                        .load::<Category>(connection)
+                        .await?;
+                    if !valid_parent(&active) {
+                        return Ok(false);
+                    }
+                }
+
+                diesel::insert_into(categories)
+                    .values(&item)
+                    .execute(connection)
+                    .await?;
+                Ok(true)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Updates a category, first checking the parent it is moved under, when it is, against
+    /// the active categories, which stay locked until the update. Returns whether the parent
+    /// was valid.
+    pub async fn update_nested<'a>(
+        &self,
+        id: i32,
+        item: UpdateCategory<'a>,
+        valid_parent: impl FnOnce(&[Category]) -> bool + Send + 'a,
+    ) -> Result<bool, result::Error> {
+        use crate::data::models::schema::categories::dsl::{categories, category_id, deleted_at};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                if let Some(Some(_)) = item.parent_id {
+                    let active = categories
+                        .filter(deleted_at.is_null())
+                        .for_update()
+                        .load::<Category>(connection)
+                        .await?;
+                    if !valid_parent(&active) {
+                        return Ok(false);
+                    }
+                }
+
+                if item.has_changes() {
+                    diesel::update(categories.filter(category_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                }
+                Ok(true)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the archived categories, most recently archived first.
+    pub async fn get_archived(&self) -> Result<Vec<Category>, result::Error> {
+        use crate::data::models::schema::categories::dsl::{categories, deleted_at};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        categories
+            .filter(deleted_at.is_not_null())
+            .order(deleted_at.desc())
+            .load::<Category>(&mut conn)
+            .await
+    }
+
+    /// Archives a category, keeping it for the history that refers to it.
+    /// Returns whether it was not archived yet.
+    pub async fn archive(&self, id: i32) -> Result<bool, result::Error> {
+        use crate::data::models::schema::categories::dsl::{categories, category_id, deleted_at};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let updated = diesel::update(
+                    categories
+                        .filter(category_id.eq(id))
+                        .filter(deleted_at.is_null()),
+                )
+                .set(deleted_at.eq(diesel::dsl::now.nullable()))
+                .execute(connection)
+                .await?;
+                Ok(updated > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Brings back an archived category. Returns whether it was archived.
+    pub async fn restore(&self, id: i32) -> Result<bool, result::Error> {
+        use crate::data::models::schema::categories::dsl::{categories, category_id, deleted_at};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let updated = diesel::update(
+                    categories
+                        .filter(category_id.eq(id))
+                        .filter(deleted_at.is_not_null()),
+                )
+                .set(deleted_at.eq(None::<chrono::NaiveDateTime>))
+                .execute(connection)
+                .await?;
+                Ok(updated > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
 }
 
 #[async_trait]
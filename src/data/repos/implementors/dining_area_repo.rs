@@ -0,0 +1,167 @@
+use crate::data::database::Database;
+use crate::data::models::dining_area::{DiningArea, NewDiningArea, UpdateDiningArea};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct DiningAreaRepo;
+
+impl DiningAreaRepo {
+    pub fn new() -> Self {
+        DiningAreaRepo
+    }
+}
+
+#[async_trait]
+impl Repository for DiningAreaRepo {
+    type Id = i32;
+    type Item = DiningArea;
+    type NewItem<'a> = NewDiningArea<'a>;
+    type UpdateForm<'a> = UpdateDiningArea<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::dining_areas::dsl::dining_areas;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match dining_areas.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::dining_areas::dsl::{area_id, dining_areas};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match dining_areas
+            .filter(area_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::dining_areas::dsl::dining_areas;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(dining_areas)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::dining_areas::dsl::{area_id, dining_areas};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(dining_areas.filter(area_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::dining_areas::dsl::{area_id, dining_areas};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(dining_areas.filter(area_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for DiningAreaRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
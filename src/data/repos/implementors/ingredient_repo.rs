@@ -0,0 +1,606 @@
+use crate::data::database::Database;
+use crate::data::models::ingredient::{
+    Ingredient, NewIngredient, NewStockMovement, Recipe, StockMovement, UpdateIngredient,
+};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use bigdecimal::{BigDecimal, Signed, Zero};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+use std::collections::BTreeMap;
+
+pub struct IngredientRepo;
+
+impl IngredientRepo {
+    pub fn new() -> Self {
+        IngredientRepo
+    }
+
+    /// Retrieves the ingredients at or below their reorder level, by name.
+    pub async fn get_low_stock(&self) -> Result<Vec<Ingredient>, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{
+            ingredients, name, on_hand, reorder_level,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        ingredients
+            .filter(on_hand.le(reorder_level))
+            .order(name.asc())
+            .load::<Ingredient>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the ingredients with the given ids.
+    pub async fn get_by_ids(&self, ids: Vec<i32>) -> Result<Vec<Ingredient>, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        ingredients
+            .filter(ingredient_id.eq_any(ids))
+            .load::<Ingredient>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the recipes of the given products.
+    pub async fn get_recipes(&self, product_ids: Vec<i32>) -> Result<Vec<Recipe>, result::Error> {
+        use crate::data::models::schema::recipes::dsl::{product_id, recipes};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        recipes
+            .filter(product_id.eq_any(product_ids))
+            .load::<Recipe>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the recipe of a product with its ingredients.
+    pub async fn get_recipe_items(
+        &self,
+        product: i32,
+    ) -> Result<Vec<(Recipe, Ingredient)>, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredients, name};
+        use crate::data::models::schema::recipes::dsl::{product_id, recipes};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        recipes
+            .inner_join(ingredients)
+            .filter(product_id.eq(product))
+            .order(name.asc())
+            .select((Recipe::as_select(), Ingredient::as_select()))
+            .load::<(Recipe, Ingredient)>(&mut conn)
+            .await
+    }
+
+    /// Checks whether any recipe or purchase order uses an ingredient.
+    pub async fn is_used(&self, ingredient: i32) -> Result<bool, result::Error> {
+        use crate::data::models::schema::purchase_order_lines::dsl::{
+            ingredient_id as line_ingredient_id, purchase_order_lines,
+        };
+        use crate::data::models::schema::recipes::dsl::{ingredient_id, recipes};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let in_recipes: i64 = recipes
+            .filter(ingredient_id.eq(ingredient))
+            .count()
+            .get_result(&mut conn)
+            .await?;
+        let on_orders: i64 = purchase_order_lines
+            .filter(line_ingredient_id.eq(ingredient))
+            .count()
+            .get_result(&mut conn)
+            .await?;
+
+        Ok(in_recipes > 0 || on_orders > 0)
+    }
+
+    /// Retrieves the stock movements recorded from `from` up to, but not including, `to`.
+    pub async fn get_movements_between(
+        &self,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<StockMovement>, result::Error> {
+        use crate::data::models::schema::stock_movements::dsl::{created_at, stock_movements};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        stock_movements
+            .filter(created_at.ge(from))
+            .filter(created_at.lt(to))
+            .load::<StockMovement>(&mut conn)
+            .await
+    }
+
+    /// Replaces the recipe of a product in a single transaction.
+    pub async fn replace_recipe(
+        &self,
+        product: i32,
+        items: Vec<Recipe>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::recipes::dsl::{product_id, recipes};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(recipes.filter(product_id.eq(product)))
+                    .execute(connection)
+                    .await?;
+
+                if !items.is_empty() {
+                    diesel::insert_into(recipes)
+                        .values(&items)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Changes the stock of an ingredient by the quantity of a movement and records the
+    /// movement in a single transaction.
+    pub async fn record_movement(
+        &self,
+        movement: NewStockMovement<'_>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients, on_hand};
+        use crate::data::models::schema::stock_movements::dsl::stock_movements;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(ingredients.filter(ingredient_id.eq(movement.ingredient_id)))
+                    .set(on_hand.eq(on_hand + movement.quantity.clone()))
+                    .execute(connection)
+                    .await?;
+
+                diesel::insert_into(stock_movements)
+                    .values(&movement)
+                    .execute(connection)
+                    .await?;
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Moves an order to the `accepted` status and deducts the ingredients it `needs` from
+    /// stock in a single transaction, recording a movement with the `reason` for each.
+    /// Stock already deducted for the order is not deducted again.
+    /// Unless `allow_negative` is set, nothing is changed when any ingredient is short of
+    /// stock. Returns the ingredients that were short in that case.
+    pub async fn deduct_for_order(
+        &self,
+        order: i32,
+        accepted: &str,
+        needs: Vec<(i32, BigDecimal)>, // ingredient_id, quantity
+        reason: &str,
+        allow_negative: bool,
+    ) -> Result<Vec<i32>, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients, on_hand};
+        use crate::data::models::schema::orders::dsl::{
+            order_id as orders_order_id, orders, status,
+        };
+        use crate::data::models::schema::stock_movements::dsl::{
+            order_id, quantity, stock_movements,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let deducted: Option<BigDecimal> = stock_movements
+                    .filter(order_id.eq(order))
+                    .select(diesel::dsl::sum(quantity))
+                    .get_result(connection)
+                    .await?;
+
+                if deducted.is_none_or(|total| total.is_zero()) && !needs.is_empty() {
+                    // Lock the stock so concurrent orders cannot both take the last of it
+                    let stock: Vec<Ingredient> = ingredients
+                        .filter(
+                            ingredient_id
+                                .eq_any(needs.iter().map(|(id, _)| *id).collect::<Vec<_>>()),
+                        )
+                        .for_update()
+                        .load(connection)
+                        .await?;
+
+                    let short: Vec<i32> = needs
+                        .iter()
+                        .filter(|(id, need)| {
+                            stock
+                                .iter()
+                                .find(|ingredient| ingredient.ingredient_id == *id)
+                                .is_none_or(|ingredient| &ingredient.on_hand < need)
+                        })
+                        .map(|(id, _)| *id)
+                        .collect();
+                    if !allow_negative && !short.is_empty() {
+                        return Ok(short);
+                    }
+
+                    for (ingredient, need) in &needs {
+                        diesel::update(ingredients.filter(ingredient_id.eq(*ingredient)))
+                            .set(on_hand.eq(on_hand - need.clone()))
+                            .execute(connection)
+                            .await?;
+                    }
+
+                    let movements: Vec<NewStockMovement> = needs
+                        .iter()
+                        .map(|(ingredient, need)| NewStockMovement {
+                            ingredient_id: *ingredient,
+                            order_id: Some(order),
+                            quantity: -need.clone(),
+                            reason,
+                            note: None,
+                            user_id: None,
+                            purchase_order_id: None,
+                            unit_cost: None,
+                            waste_id: None,
+                        })
+                        .collect();
+                    diesel::insert_into(stock_movements)
+                        .values(&movements)
+                        .execute(connection)
+                        .await?;
+                }
+
+                diesel::update(orders.filter(orders_order_id.eq(order)))
+                    .set(status.eq(accepted))
+                    .execute(connection)
+                    .await?;
+
+                Ok(Vec::new())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Puts back the stock still deducted for an order in a single transaction, recording a
+    /// movement with the `reason` for each ingredient. Restoring an order twice changes
+    /// nothing.
+    pub async fn restore_for_order(&self, order: i32, reason: &str) -> Result<(), result::Error> {
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move { restore_order_stock(connection, order, reason).await }.scope_boxed()
+        })
+        .await
+    }
+}
+
+/// Puts back the stock still deducted for an order on a connection already in a transaction,
+/// so the change that gives the stock back can be saved together with it
+pub(crate) async fn restore_order_stock(
+    connection: &mut AsyncMysqlConnection,
+    order: i32,
+    reason: &str,
+) -> Result<(), result::Error> {
+    for (ingredient, deducted) in deducted_stock(connection, order).await? {
+        put_back_stock(connection, order, ingredient, deducted, reason).await?;
+    }
+
+    Ok(())
+}
+
+/// Puts back the stock deducted for voided order items, the ingredients they `needed`, on a
+/// connection already in a transaction. No more is put back than is still deducted for the
+/// order, so nothing is put back when its stock was never taken out.
+pub(crate) async fn restore_voided_stock(
+    connection: &mut AsyncMysqlConnection,
+    order: i32,
+    needed: &[(i32, BigDecimal)], // ingredient_id, quantity
+    reason: &str,
+) -> Result<(), result::Error> {
+    let deducted = deducted_stock(connection, order).await?;
+
+    for (ingredient, need) in needed {
+        let Some(still_deducted) = deducted.get(ingredient) else {
+            continue;
+        };
+        let restored = need.min(still_deducted).clone();
+        if restored.is_positive() {
+            put_back_stock(connection, order, *ingredient, restored, reason).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// How much of each ingredient is still deducted for an order, locking its movements
+async fn deducted_stock(
+    connection: &mut AsyncMysqlConnection,
+    order: i32,
+) -> Result<BTreeMap<i32, BigDecimal>, result::Error> {
+    use crate::data::models::schema::stock_movements::dsl::{
+        ingredient_id, order_id, quantity, stock_movements,
+    };
+
+    let movements: Vec<(i32, BigDecimal)> = stock_movements
+        .filter(order_id.eq(order))
+        .select((ingredient_id, quantity))
+        .for_update()
+        .load(connection)
+        .await?;
+
+    let mut net: BTreeMap<i32, BigDecimal> = BTreeMap::new();
+    for (ingredient, moved) in movements {
+        *net.entry(ingredient).or_default() += moved;
+    }
+
+    Ok(net
+        .into_iter()
+        .filter(|(_, moved)| moved.is_negative())
+        .map(|(ingredient, moved)| (ingredient, -moved))
+        .collect())
+}
+
+async fn put_back_stock(
+    connection: &mut AsyncMysqlConnection,
+    order: i32,
+    ingredient: i32,
+    restored: BigDecimal,
+    reason: &str,
+) -> Result<(), result::Error> {
+    use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients, on_hand};
+    use crate::data::models::schema::stock_movements::dsl::stock_movements;
+
+    diesel::update(ingredients.filter(ingredient_id.eq(ingredient)))
+        .set(on_hand.eq(on_hand + restored.clone()))
+        .execute(connection)
+        .await?;
+
+    diesel::insert_into(stock_movements)
+        .values(&NewStockMovement {
+            ingredient_id: ingredient,
+            order_id: Some(order),
+            quantity: restored,
+            reason,
+            note: None,
+            user_id: None,
+            purchase_order_id: None,
+            unit_cost: None,
+            waste_id: None,
+        })
+        .execute(connection)
+        .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl Repository for IngredientRepo {
+    type Id = i32;
+    type Item = Ingredient;
+    type NewItem<'a> = NewIngredient<'a>;
+    type UpdateForm<'a> = UpdateIngredient<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::ingredients;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match ingredients.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match ingredients
+            .filter(ingredient_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::ingredients::dsl::ingredients;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(ingredients)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(ingredients.filter(ingredient_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(ingredients.filter(ingredient_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for IngredientRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
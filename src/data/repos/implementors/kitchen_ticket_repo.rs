@@ -0,0 +1,258 @@
+use crate::data::database::Database;
+use crate::data::models::kitchen_ticket::{KitchenTicket, KitchenTicketItem};
+use crate::data::models::order::Order;
+use crate::data::models::product::Product;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+/// Tickets are created together with their order by `OrderRepo::create_with_pricing`
+pub struct KitchenTicketRepo;
+
+impl KitchenTicketRepo {
+    pub fn new() -> Self {
+        KitchenTicketRepo
+    }
+
+    /// Retrieves a ticket by its id.
+    pub async fn get_by_id(&self, id: i32) -> Result<Option<KitchenTicket>, result::Error> {
+        use crate::data::models::schema::kitchen_tickets::dsl::{kitchen_tickets, ticket_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match kitchen_tickets
+            .filter(ticket_id.eq(id))
+            .first::<KitchenTicket>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Retrieves the tickets of an order.
+    pub async fn get_by_order_id(&self, order: i32) -> Result<Vec<KitchenTicket>, result::Error> {
+        use crate::data::models::schema::kitchen_tickets::dsl::{
+            kitchen_tickets, order_id, ticket_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        kitchen_tickets
+            .filter(order_id.eq(order))
+            .order(ticket_id.asc())
+            .load::<KitchenTicket>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the tickets in the given status of orders in any of the given statuses,
+    /// with their orders, oldest first, optionally only those of one station.
+    pub async fn get_by_status(
+        &self,
+        station: Option<i32>,
+        ticket_status: &str,
+        order_statuses: Vec<&str>,
+    ) -> Result<Vec<(KitchenTicket, Order)>, result::Error> {
+        use crate::data::models::schema::kitchen_tickets::dsl::{
+            kitchen_tickets, station_id, status, ticket_id,
+        };
+        use crate::data::models::schema::orders::dsl::{orders, status as order_status};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let mut query = kitchen_tickets
+            .inner_join(orders)
+            .filter(status.eq(ticket_status))
+            .filter(order_status.eq_any(order_statuses))
+            .select((KitchenTicket::as_select(), Order::as_select()))
+            .order(ticket_id.asc())
+            .into_boxed();
+        if let Some(station) = station {
+            query = query.filter(station_id.eq(station));
+        }
+
+        query.load::<(KitchenTicket, Order)>(&mut conn).await
+    }
+
+    /// Retrieves the items of the given tickets with their products.
+    pub async fn get_items(
+        &self,
+        ticket_ids: Vec<i32>,
+    ) -> Result<Vec<(KitchenTicketItem, Product)>, result::Error> {
+        use crate::data::models::schema::kitchen_ticket_items::dsl::{
+            kitchen_ticket_items, ticket_id,
+        };
+        use crate::data::models::schema::products::dsl::products;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        kitchen_ticket_items
+            .inner_join(products)
+            .filter(ticket_id.eq_any(ticket_ids))
+            .load::<(KitchenTicketItem, Product)>(&mut conn)
+            .await
+    }
+
+    /// Moves a ticket to the `bumped` status in a single transaction. Once no ticket of its
+    /// order is left in another status, the order is moved from any of the `active`
+    /// statuses to the `ready` status.
+    /// Returns whether the order became ready.
+    pub async fn bump(
+        &self,
+        ticket: i32,
+        bumped: &str,
+        ready: &str,
+        active: Vec<&str>,
+    ) -> Result<bool, result::Error> {
+        use crate::data::models::schema::kitchen_tickets::dsl::{
+            bumped_at, kitchen_tickets, order_id, status, ticket_id,
+        };
+        use crate::data::models::schema::orders::dsl::{
+            order_id as orders_order_id, orders, status as order_status,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let order: i32 = kitchen_tickets
+                    .filter(ticket_id.eq(ticket))
+                    .select(order_id)
+                    .first(connection)
+                    .await?;
+
+                // Lock the order so two stations bumping its last tickets at the same time
+                // see each other's bump
+                orders
+                    .filter(orders_order_id.eq(order))
+                    .select(orders_order_id)
+                    .for_update()
+                    .first::<i32>(connection)
+                    .await?;
+
+                diesel::update(kitchen_tickets.filter(ticket_id.eq(ticket)))
+                    .set((status.eq(bumped), bumped_at.eq(diesel::dsl::now.nullable())))
+                    .execute(connection)
+                    .await?;
+
+                let open: i64 = kitchen_tickets
+                    .filter(order_id.eq(order))
+                    .filter(status.ne(bumped))
+                    .count()
+                    .get_result(connection)
+                    .await?;
+                if open > 0 {
+                    return Ok(false);
+                }
+
+                let updated = diesel::update(
+                    orders
+                        .filter(orders_order_id.eq(order))
+                        .filter(order_status.eq_any(active)),
+                )
+                .set(order_status.eq(ready))
+                .execute(connection)
+                .await?;
+
+                Ok(updated > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+/// Takes voided items (product_id, quantity) off the kitchen tickets of an order on a
+/// connection already in a transaction, from its earliest tickets first. Items with none
+/// left are removed; the tickets themselves are kept so bumping them still readies the order.
+pub(crate) async fn remove_ticket_items(
+    connection: &mut AsyncMysqlConnection,
+    order: i32,
+    voided: &[(i32, i32)],
+) -> Result<(), result::Error> {
+    use crate::data::models::schema::kitchen_ticket_items::dsl::{
+        kitchen_ticket_items, product_id, quantity, ticket_id,
+    };
+    use crate::data::models::schema::kitchen_tickets::dsl::{
+        kitchen_tickets, order_id, ticket_id as tickets_ticket_id,
+    };
+
+    let items: Vec<KitchenTicketItem> = kitchen_ticket_items
+        .inner_join(kitchen_tickets)
+        .filter(order_id.eq(order))
+        .filter(product_id.eq_any(voided.iter().map(|(pid, _)| *pid).collect::<Vec<_>>()))
+        .order(tickets_ticket_id.asc())
+        .select(KitchenTicketItem::as_select())
+        .for_update()
+        .load(connection)
+        .await?;
+
+    for (product, voided_quantity) in voided {
+        let mut left_to_remove = *voided_quantity;
+        for item in items.iter().filter(|item| item.product_id == *product) {
+            if left_to_remove <= 0 {
+                break;
+            }
+            let removed = left_to_remove.min(item.quantity);
+            left_to_remove -= removed;
+
+            let line = kitchen_ticket_items
+                .filter(ticket_id.eq(item.ticket_id))
+                .filter(product_id.eq(item.product_id));
+            if removed < item.quantity {
+                diesel::update(line)
+                    .set(quantity.eq(item.quantity - removed))
+                    .execute(connection)
+                    .await?;
+            } else {
+                diesel::delete(line).execute(connection).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Default for KitchenTicketRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,326 @@
+use crate::data::database::Database;
+use crate::data::models::menu::{
+    Menu, MenuCategory, MenuProduct, MenuSchedule, NewMenu, NewMenuSchedule, UpdateMenu,
+};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct MenuRepo;
+
+impl MenuRepo {
+    pub fn new() -> Self {
+        MenuRepo
+    }
+
+    /// Retrieves the weekly windows of every menu.
+    pub async fn get_schedules(&self) -> Result<Vec<MenuSchedule>, result::Error> {
+        use crate::data::models::schema::menu_schedules::dsl::{
+            day_of_week, menu_schedules, start_time,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        menu_schedules
+            .order((day_of_week.asc(), start_time.asc()))
+            .load::<MenuSchedule>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the products put on every menu.
+    pub async fn get_products(&self) -> Result<Vec<MenuProduct>, result::Error> {
+        use crate::data::models::schema::menu_products::dsl::menu_products;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        menu_products.load::<MenuProduct>(&mut conn).await
+    }
+
+    /// Retrieves the categories put on every menu.
+    pub async fn get_categories(&self) -> Result<Vec<MenuCategory>, result::Error> {
+        use crate::data::models::schema::menu_categories::dsl::menu_categories;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        menu_categories.load::<MenuCategory>(&mut conn).await
+    }
+
+    /// Replaces the weekly windows of a menu in a single transaction.
+    pub async fn replace_schedule(
+        &self,
+        menu: i32,
+        windows: Vec<NewMenuSchedule>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::menu_schedules::dsl::{menu_id, menu_schedules};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(menu_schedules.filter(menu_id.eq(menu)))
+                    .execute(connection)
+                    .await?;
+
+                if !windows.is_empty() {
+                    diesel::insert_into(menu_schedules)
+                        .values(&windows)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Replaces the products and categories on a menu in a single transaction.
+    pub async fn replace_items(
+        &self,
+        menu: i32,
+        product_ids: Vec<i32>,
+        category_ids: Vec<i32>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::menu_categories::dsl::{
+            menu_categories, menu_id as mc_menu_id,
+        };
+        use crate::data::models::schema::menu_products::dsl::{
+            menu_id as mp_menu_id, menu_products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(menu_products.filter(mp_menu_id.eq(menu)))
+                    .execute(connection)
+                    .await?;
+                diesel::delete(menu_categories.filter(mc_menu_id.eq(menu)))
+                    .execute(connection)
+                    .await?;
+
+                let products: Vec<MenuProduct> = product_ids
+                    .into_iter()
+                    .map(|product| MenuProduct {
+                        menu_id: menu,
+                        product_id: product,
+                    })
+                    .collect();
+                if !products.is_empty() {
+                    diesel::insert_into(menu_products)
+                        .values(&products)
+                        .execute(connection)
+                        .await?;
+                }
+
+                let categories: Vec<MenuCategory> = category_ids
+                    .into_iter()
+                    .map(|category| MenuCategory {
+                        menu_id: menu,
+                        category_id: category,
+                    })
+                    .collect();
+                if !categories.is_empty() {
+                    diesel::insert_into(menu_categories)
+                        .values(&categories)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Repository for MenuRepo {
+    type Id = i32;
+    type Item = Menu;
+    type NewItem<'a> = NewMenu<'a>;
+    type UpdateForm<'a> = UpdateMenu<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::menus::dsl::menus;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match menus.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::menus::dsl::{menu_id, menus};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match menus
+            .filter(menu_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::menus::dsl::menus;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(menus)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::menus::dsl::{menu_id, menus};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(menus.filter(menu_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::menus::dsl::{menu_id, menus};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(menus.filter(menu_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for MenuRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
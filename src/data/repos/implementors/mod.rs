@@ -1,9 +1,32 @@
+pub mod allergen_repo;
+pub mod bundle_repo;
 pub mod category_repo;
+pub mod dining_area_repo;
+pub mod ingredient_repo;
+pub mod kitchen_ticket_repo;
+pub mod menu_repo;
+pub mod nutrition_repo;
+pub mod order_adjustment_repo;
+pub mod order_check_repo;
+pub mod order_discount_repo;
 pub mod order_product_repo;
 pub mod order_repo;
+pub mod payment_repo;
 pub mod product_category_repo;
+pub mod product_price_repo;
 pub mod product_repo;
+pub mod promotion_repo;
+pub mod purchase_order_repo;
+pub mod refund_repo;
+pub mod reservation_repo;
 pub mod role_repo;
+pub mod station_repo;
+pub mod supplier_repo;
+pub mod table_repo;
+pub mod table_session_repo;
+pub mod tax_rate_repo;
+pub mod translation_repo;
 pub mod user_repo;
 pub mod user_role_repo;
-
+pub mod waitlist_repo;
+pub mod waste_repo;
@@ -0,0 +1,143 @@
+use crate::data::database::Database;
+use crate::data::models::nutrition::{IngredientNutrition, ProductNutrition};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct NutritionRepo;
+
+impl NutritionRepo {
+    pub fn new() -> Self {
+        NutritionRepo
+    }
+
+    /// Retrieves the nutrition facts of the given products.
+    pub async fn get_products(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<ProductNutrition>, result::Error> {
+        use crate::data::models::schema::product_nutrition::dsl::{product_id, product_nutrition};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_nutrition
+            .filter(product_id.eq_any(ids))
+            .load::<ProductNutrition>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the nutrition of the given ingredients.
+    pub async fn get_ingredients(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<IngredientNutrition>, result::Error> {
+        use crate::data::models::schema::ingredient_nutrition::dsl::{
+            ingredient_id, ingredient_nutrition,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        ingredient_nutrition
+            .filter(ingredient_id.eq_any(ids))
+            .load::<IngredientNutrition>(&mut conn)
+            .await
+    }
+
+    /// Replaces the nutrition of the given products and ingredients in a single transaction.
+    pub async fn save(
+        &self,
+        products: Vec<ProductNutrition>,
+        ingredients: Vec<IngredientNutrition>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::ingredient_nutrition::dsl::{
+            ingredient_id, ingredient_nutrition,
+        };
+        use crate::data::models::schema::product_nutrition::dsl::{product_id, product_nutrition};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                if !products.is_empty() {
+                    let ids: Vec<i32> = products.iter().map(|p| p.product_id).collect();
+                    diesel::delete(product_nutrition.filter(product_id.eq_any(ids)))
+                        .execute(connection)
+                        .await?;
+                    diesel::insert_into(product_nutrition)
+                        .values(&products)
+                        .execute(connection)
+                        .await?;
+                }
+
+                if !ingredients.is_empty() {
+                    let ids: Vec<i32> = ingredients.iter().map(|i| i.ingredient_id).collect();
+                    diesel::delete(ingredient_nutrition.filter(ingredient_id.eq_any(ids)))
+                        .execute(connection)
+                        .await?;
+                    diesel::insert_into(ingredient_nutrition)
+                        .values(&ingredients)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Removes the nutrition facts of a product.
+    pub async fn delete_product(&self, id: i32) -> Result<(), result::Error> {
+        use crate::data::models::schema::product_nutrition::dsl::{product_id, product_nutrition};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(product_nutrition.filter(product_id.eq(id)))
+                    .execute(connection)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for NutritionRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
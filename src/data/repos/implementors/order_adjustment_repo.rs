@@ -0,0 +1,214 @@
+use crate::data::database::Database;
+use crate::data::models::order::{OrderPricing, OrderTipping};
+use crate::data::models::order_adjustment::{NewOrderAdjustment, OrderAdjustment};
+use crate::data::models::refund::NewRefund;
+use crate::data::repos::implementors::ingredient_repo::{
+    restore_order_stock, restore_voided_stock,
+};
+use crate::data::repos::implementors::kitchen_ticket_repo::remove_ticket_items;
+use bigdecimal::BigDecimal;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+/// Ledger entries are only ever written together with the change they record
+pub struct OrderAdjustmentRepo;
+
+impl OrderAdjustmentRepo {
+    pub fn new() -> Self {
+        OrderAdjustmentRepo
+    }
+
+    /// Retrieves the ledger entries of the given orders, oldest first.
+    pub async fn get_by_order_ids(
+        &self,
+        order_ids: Vec<i32>,
+    ) -> Result<Vec<OrderAdjustment>, result::Error> {
+        use crate::data::models::schema::order_adjustments::dsl::{
+            adjustment_id, order_adjustments, order_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        order_adjustments
+            .filter(order_id.eq_any(order_ids))
+            .order(adjustment_id.asc())
+            .load::<OrderAdjustment>(&mut conn)
+            .await
+    }
+
+    /// Sets the status of an order, records the cancellation and puts back the stock deducted
+    /// for it, recording the stock movements with `stock_reason`, in a single transaction.
+    pub async fn record_cancellation(
+        &self,
+        order: i32,
+        cancelled_status: &str,
+        entry: NewOrderAdjustment<'_>,
+        stock_reason: &str,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::order_adjustments::dsl::order_adjustments;
+        use crate::data::models::schema::orders::dsl::{order_id, orders, status};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(orders.filter(order_id.eq(order)))
+                    .set(status.eq(cancelled_status))
+                    .execute(connection)
+                    .await?;
+
+                diesel::insert_into(order_adjustments)
+                    .values(&entry)
+                    .execute(connection)
+                    .await?;
+
+                restore_order_stock(connection, order, stock_reason).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Voids order lines in a single transaction: sets the remaining quantity of each line
+    /// (removing lines with none left), stores the new price breakdown and gratuity, records
+    /// the voids, takes the `voided` items off the kitchen tickets and puts back the stock
+    /// they `needed`, recording the stock movements with `stock_reason`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_voids(
+        &self,
+        order: i32,
+        remaining: Vec<(i32, i32)>, // product_id, quantity
+        pricing: OrderPricing,
+        tipping: OrderTipping,
+        entries: Vec<NewOrderAdjustment<'_>>,
+        voided: Vec<(i32, i32)>,        // product_id, quantity
+        needed: Vec<(i32, BigDecimal)>, // ingredient_id, quantity
+        stock_reason: &str,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::order_adjustments::dsl::order_adjustments;
+        use crate::data::models::schema::order_products::dsl::{
+            order_id as line_order_id, order_products, product_id, quantity,
+        };
+        use crate::data::models::schema::orders::dsl::{order_id, orders};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                for (product, left) in remaining {
+                    let line = order_products
+                        .filter(line_order_id.eq(order))
+                        .filter(product_id.eq(product));
+
+                    if left > 0 {
+                        diesel::update(line)
+                            .set(quantity.eq(left))
+                            .execute(connection)
+                            .await?;
+                    } else {
+                        diesel::delete(line).execute(connection).await?;
+                    }
+                }
+
+                diesel::update(orders.filter(order_id.eq(order)))
+                    .set((&pricing, &tipping))
+                    .execute(connection)
+                    .await?;
+
+                diesel::insert_into(order_adjustments)
+                    .values(&entries)
+                    .execute(connection)
+                    .await?;
+
+                remove_ticket_items(connection, order, &voided).await?;
+                restore_voided_stock(connection, order, &needed, stock_reason).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Stores a refund, updates the status of the refunded payment and records the refund
+    /// in a single transaction.
+    pub async fn record_refund(
+        &self,
+        new_refund: NewRefund<'_>,
+        payment_status: &str,
+        entry: NewOrderAdjustment<'_>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::order_adjustments::dsl::order_adjustments;
+        use crate::data::models::schema::payments::dsl::{payment_id, payments, status};
+        use crate::data::models::schema::refunds::dsl::refunds;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::insert_into(refunds)
+                    .values(&new_refund)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                diesel::update(payments.filter(payment_id.eq(new_refund.payment_id)))
+                    .set(status.eq(payment_status))
+                    .execute(connection)
+                    .await?;
+
+                diesel::insert_into(order_adjustments)
+                    .values(&NewOrderAdjustment {
+                        refund_id: Some(new_id),
+                        ..entry
+                    })
+                    .execute(connection)
+                    .await?;
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for OrderAdjustmentRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
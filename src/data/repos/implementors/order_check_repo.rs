@@ -0,0 +1,152 @@
+use crate::data::database::Database;
+use crate::data::models::order_check::{NewOrderCheck, OrderCheck, OrderCheckItem};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+/// Checks are always written as a full set for an order, see `replace_for_order`
+pub struct OrderCheckRepo;
+
+impl OrderCheckRepo {
+    pub fn new() -> Self {
+        OrderCheckRepo
+    }
+
+    /// Retrieves the checks of an order ordered by check number.
+    pub async fn get_by_order_id(&self, order: i32) -> Result<Vec<OrderCheck>, result::Error> {
+        use crate::data::models::schema::order_checks::dsl::{
+            check_number, order_checks, order_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        order_checks
+            .filter(order_id.eq(order))
+            .order(check_number.asc())
+            .load::<OrderCheck>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the order lines assigned to the given checks.
+    pub async fn get_items(
+        &self,
+        check_ids: Vec<i32>,
+    ) -> Result<Vec<OrderCheckItem>, result::Error> {
+        use crate::data::models::schema::order_check_items::dsl::{check_id, order_check_items};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        order_check_items
+            .filter(check_id.eq_any(check_ids))
+            .load::<OrderCheckItem>(&mut conn)
+            .await
+    }
+
+    /// Replaces the checks of an order. Each check comes with the product ids of the order
+    /// lines assigned to it, which is empty for even or share splits.
+    pub async fn replace_for_order(
+        &self,
+        order: i32,
+        checks: Vec<(NewOrderCheck, Vec<i32>)>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::order_check_items::dsl::order_check_items;
+        use crate::data::models::schema::order_checks::dsl::{order_checks, order_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(order_checks.filter(order_id.eq(order)))
+                    .execute(connection)
+                    .await?;
+
+                for (new_check, product_ids) in checks {
+                    diesel::insert_into(order_checks)
+                        .values(&new_check)
+                        .execute(connection)
+                        .await?;
+
+                    if product_ids.is_empty() {
+                        continue;
+                    }
+
+                    let new_id: i32 = diesel::select(
+                        diesel::dsl::sql::<diesel::sql_types::Integer>("LAST_INSERT_ID()"),
+                    )
+                    .get_result(connection)
+                    .await?;
+
+                    let items: Vec<OrderCheckItem> = product_ids
+                        .into_iter()
+                        .map(|product_id| OrderCheckItem {
+                            check_id: new_id,
+                            product_id,
+                        })
+                        .collect();
+
+                    diesel::insert_into(order_check_items)
+                        .values(&items)
+                        .execute(connection)
+                        .await?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Removes every check of an order.
+    pub async fn delete_by_order_id(&self, order: i32) -> Result<(), result::Error> {
+        use crate::data::models::schema::order_checks::dsl::{order_checks, order_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(order_checks.filter(order_id.eq(order)))
+                    .execute(connection)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for OrderCheckRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,43 @@
+use crate::data::database::Database;
+use crate::data::models::order_discount::OrderDiscount;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::{AsyncMysqlConnection, RunQueryDsl};
+
+/// Discount lines are written together with their order by `OrderRepo::create_with_pricing`
+pub struct OrderDiscountRepo;
+
+impl OrderDiscountRepo {
+    pub fn new() -> Self {
+        OrderDiscountRepo
+    }
+
+    /// Retrieves the discount lines of the given orders.
+    pub async fn get_by_order_ids(
+        &self,
+        order_ids: Vec<i32>,
+    ) -> Result<Vec<OrderDiscount>, result::Error> {
+        use crate::data::models::schema::order_discounts::dsl::{order_discounts, order_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        order_discounts
+            .filter(order_id.eq_any(order_ids))
+            .load::<OrderDiscount>(&mut conn)
+            .await
+    }
+}
+
+impl Default for OrderDiscountRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,11 +1,16 @@
 use std::collections::HashMap;
 use crate::data::database::Database;
-use crate::data::models::order::{NewOrder, Order, UpdateOrder};
+use crate::data::models::allergen::OrderAllergenAlert;
+use crate::data::models::bundle::OrderBundleItem;
+use crate::data::models::kitchen_ticket::{KitchenTicketItem, NewKitchenTicket};
+use crate::data::models::order::{NewOrder, Order, OrderPlacement, OrderTipping, UpdateOrder};
+use crate::data::models::order_discount::NewOrderDiscount;
 use crate::data::models::order_product::{NewOrderProduct, OrderProduct};
 use crate::data::models::product::Product;
 use crate::data::repos::traits::repository::Repository;
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, NaiveDateTime};
 use diesel::prelude::*;
 use diesel::result;
 use diesel_async::pooled_connection::deadpool::Object;
@@ -170,6 +175,393 @@ impl OrderRepo {
         .await
     }
 
+    /// Creates an order with its items, the products chosen for its bundles, allergen
+    /// alerts, price breakdown, party details, table, discount lines and kitchen tickets in a
+    /// single transaction, counting one use of every applied promotion and giving the order the
+    /// next number of its business day.
+    /// Returns the id of the newly created order.
+    pub async fn create_with_pricing(
+        &self,
+        placement: OrderPlacement,
+    ) -> Result<i32, result::Error> {
+        use crate::data::models::schema::order_allergen_alerts::dsl::order_allergen_alerts;
+        use crate::data::models::schema::order_bundle_items::dsl::order_bundle_items;
+        use crate::data::models::schema::kitchen_ticket_items::dsl::kitchen_ticket_items;
+        use crate::data::models::schema::kitchen_tickets::dsl::kitchen_tickets;
+        use crate::data::models::schema::order_discounts::dsl::order_discounts;
+        use crate::data::models::schema::order_number_sequences::dsl::{
+            last_number, order_number_sequences,
+        };
+        use crate::data::models::schema::order_products::dsl::order_products;
+        use crate::data::models::schema::orders::dsl::{
+            business_date, order_id, order_number, orders,
+        };
+        use diesel::sql_query;
+        use diesel::sql_types::Date;
+        use crate::data::models::schema::promotions::dsl::{
+            promotion_id, promotions, usage_count, usage_limit,
+        };
+
+        let OrderPlacement {
+            order: new_order,
+            pricing,
+            tipping,
+            seating,
+            business_day,
+            items,
+            bundle_items,
+            allergen_alerts,
+            discounts,
+            tickets,
+        } = placement;
+
+        let db = Database::new().await;
+        let mut conn = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction::<_, result::Error, _>(|connection| {
+            async move {
+                diesel::insert_into(orders)
+                    .values(&new_order)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                // The sequence row stays locked until the transaction ends, so concurrent
+                // orders of the same day get consecutive numbers
+                sql_query(
+                    "INSERT INTO order_number_sequences (business_date, last_number) VALUES (?, 1) \
+                     ON DUPLICATE KEY UPDATE last_number = last_number + 1",
+                )
+                .bind::<Date, _>(business_day)
+                .execute(connection)
+                .await?;
+
+                let number: i32 = order_number_sequences
+                    .find(business_day)
+                    .select(last_number)
+                    .first(connection)
+                    .await?;
+
+                diesel::update(orders.filter(order_id.eq(new_id)))
+                    .set((
+                        &pricing,
+                        &tipping,
+                        &seating,
+                        business_date.eq(business_day),
+                        order_number.eq(number),
+                    ))
+                    .execute(connection)
+                    .await?;
+
+                let new_items: Vec<NewOrderProduct> = items
+                    .into_iter()
+                    .map(|(pid, qty, price)| NewOrderProduct {
+                        order_id: new_id,
+                        product_id: pid,
+                        quantity: qty,
+                        unit_price: price,
+                    })
+                    .collect();
+
+                diesel::insert_into(order_products)
+                    .values(&new_items)
+                    .execute(connection)
+                    .await?;
+
+                let bundle_items: Vec<OrderBundleItem> = bundle_items
+                    .into_iter()
+                    .map(|item| OrderBundleItem {
+                        order_id: new_id,
+                        ..item
+                    })
+                    .collect();
+                if !bundle_items.is_empty() {
+                    diesel::insert_into(order_bundle_items)
+                        .values(&bundle_items)
+                        .execute(connection)
+                        .await?;
+                }
+
+                let allergen_alerts: Vec<OrderAllergenAlert> = allergen_alerts
+                    .into_iter()
+                    .map(|alert| OrderAllergenAlert {
+                        order_id: new_id,
+                        ..alert
+                    })
+                    .collect();
+                if !allergen_alerts.is_empty() {
+                    diesel::insert_into(order_allergen_alerts)
+                        .values(&allergen_alerts)
+                        .execute(connection)
+                        .await?;
+                }
+
+                for discount in discounts {
+                    // Guarded increment so concurrent orders cannot exceed the usage limit
+                    if let Some(promotion) = discount.promotion_id {
+                        let updated = diesel::update(
+                            promotions.filter(promotion_id.eq(promotion)).filter(
+                                usage_limit
+                                    .is_null()
+                                    .or(usage_limit.gt(usage_count.nullable())),
+                            ),
+                        )
+                        .set(usage_count.eq(usage_count + 1))
+                        .execute(connection)
+                        .await?;
+
+                        if updated == 0 {
+                            return Err(result::Error::RollbackTransaction);
+                        }
+                    }
+
+                    diesel::insert_into(order_discounts)
+                        .values(&NewOrderDiscount {
+                            order_id: new_id,
+                            ..discount
+                        })
+                        .execute(connection)
+                        .await?;
+                }
+
+                for routed in tickets {
+                    diesel::insert_into(kitchen_tickets)
+                        .values(&NewKitchenTicket {
+                            order_id: new_id,
+                            station_id: routed.station_id,
+                        })
+                        .execute(connection)
+                        .await?;
+
+                    let ticket: i32 = diesel::select(
+                        diesel::dsl::sql::<diesel::sql_types::Integer>("LAST_INSERT_ID()"),
+                    )
+                    .get_result(connection)
+                    .await?;
+
+                    let ticket_items: Vec<KitchenTicketItem> = routed
+                        .items
+                        .into_iter()
+                        .map(|(pid, qty)| KitchenTicketItem {
+                            ticket_id: ticket,
+                            product_id: pid,
+                            quantity: qty,
+                        })
+                        .collect();
+
+                    diesel::insert_into(kitchen_ticket_items)
+                        .values(&ticket_items)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(new_id)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the orders created within `[from, to)`.
+    pub async fn get_created_between(
+        &self,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<Order>, result::Error> {
+        use crate::data::models::schema::orders::dsl::{created_at, orders};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        orders
+            .filter(created_at.ge(from))
+            .filter(created_at.lt(to))
+            .load::<Order>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the orders of a business day in number order, or only the one with the
+    /// given number.
+    pub async fn get_by_business_date(
+        &self,
+        day: NaiveDate,
+        number: Option<i32>,
+    ) -> Result<Vec<Order>, result::Error> {
+        use crate::data::models::schema::orders::dsl::{business_date, order_number, orders};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let mut query = orders
+            .filter(business_date.eq(day))
+            .order(order_number.asc())
+            .into_boxed();
+        if let Some(number) = number {
+            query = query.filter(order_number.eq(number));
+        }
+
+        query.load::<Order>(&mut conn).await
+    }
+
+    /// Retrieves the orders in any of the given statuses, oldest first.
+    pub async fn get_by_statuses(&self, statuses: Vec<&str>) -> Result<Vec<Order>, result::Error> {
+        use crate::data::models::schema::orders::dsl::{order_id, orders, status};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        orders
+            .filter(status.eq_any(statuses))
+            .order(order_id.asc())
+            .load::<Order>(&mut conn)
+            .await
+    }
+
+    /// Moves the orders in the `scheduled` status that are due by `until` to the `released`
+    /// status in one statement, so an order is never released twice.
+    /// Returns the number of released orders.
+    pub async fn release_scheduled(
+        &self,
+        until: NaiveDateTime,
+        scheduled: &str,
+        released: &str,
+    ) -> Result<usize, result::Error> {
+        use crate::data::models::schema::orders::dsl::{orders, scheduled_for, status};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(
+                    orders
+                        .filter(status.eq(scheduled))
+                        .filter(scheduled_for.le(until)),
+                )
+                .set(status.eq(released))
+                .execute(connection)
+                .await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the orders placed in a table session, oldest first.
+    pub async fn get_by_session_id(&self, session: i32) -> Result<Vec<Order>, result::Error> {
+        use crate::data::models::schema::orders::dsl::{order_id, orders, session_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        orders
+            .filter(session_id.eq(session))
+            .order(order_id.asc())
+            .load::<Order>(&mut conn)
+            .await
+    }
+
+    /// Updates the party size, serving staff or gratuity of an order.
+    /// Stores the estimated ready time of each given order in a single transaction.
+    pub async fn set_estimated_ready_at(
+        &self,
+        estimates: Vec<(i32, NaiveDateTime)>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::orders::dsl::{estimated_ready_at, order_id, orders};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                for (id, ready_at) in estimates {
+                    diesel::update(orders.filter(order_id.eq(id)))
+                        .set(estimated_ready_at.eq(ready_at))
+                        .execute(connection)
+                        .await?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    pub async fn update_tipping(
+        &self,
+        id: i32,
+        tipping: OrderTipping,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::orders::dsl::{order_id, orders};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(orders.filter(order_id.eq(id)))
+                    .set(&tipping)
+                    .execute(connection)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
     pub async fn attach_products(
         &self,
         orders_list: Vec<Order>,
@@ -0,0 +1,339 @@
+use crate::data::database::Database;
+use crate::data::models::payment::{NewPayment, Payment, UpdatePayment};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use bigdecimal::{BigDecimal, Zero};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct PaymentRepo;
+
+impl PaymentRepo {
+    pub fn new() -> Self {
+        PaymentRepo
+    }
+
+    /// Retrieves the payments of the given orders.
+    pub async fn get_by_order_ids(
+        &self,
+        order_ids: Vec<i32>,
+    ) -> Result<Vec<Payment>, result::Error> {
+        use crate::data::models::schema::payments::dsl::{order_id, payments};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        payments
+            .filter(order_id.eq_any(order_ids))
+            .load::<Payment>(&mut conn)
+            .await
+    }
+
+    /// Records a payment before it is taken through the gateway, in a single transaction
+    /// that locks its order so concurrent payments cannot both take the same balance.
+    /// The payment is only stored when its amount is within `order_due`, and `check_due`
+    /// when it is against a check, less what the order (or check) was already paid by
+    /// payments that are not `failed`, net of their refunds.
+    /// Returns the stored row, none when the amount is over the balance.
+    pub async fn reserve(
+        &self,
+        new_payment: NewPayment<'_>,
+        order_due: BigDecimal,
+        check_due: Option<BigDecimal>,
+        failed: &str,
+    ) -> Result<Option<Payment>, result::Error> {
+        use crate::data::models::schema::orders::dsl::{order_id as orders_order_id, orders};
+        use crate::data::models::schema::payments::dsl::{order_id, payment_id, payments, status};
+        use crate::data::models::schema::refunds::dsl::{
+            amount as refund_amount, payment_id as refund_payment_id, refunds,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                // Payments against the checks of an order lock the order as well, so the
+                // order row guards the balances of its checks too
+                orders
+                    .filter(orders_order_id.eq(new_payment.order_id))
+                    .select(orders_order_id)
+                    .for_update()
+                    .first::<i32>(connection)
+                    .await?;
+
+                let settled: Vec<Payment> = payments
+                    .filter(order_id.eq(new_payment.order_id))
+                    .filter(status.ne(failed))
+                    .load(connection)
+                    .await?;
+                let refunded: Vec<(i32, BigDecimal)> = refunds
+                    .filter(refund_payment_id.eq_any(settled.iter().map(|p| p.payment_id)))
+                    .select((refund_payment_id, refund_amount))
+                    .load(connection)
+                    .await?;
+
+                let paid = |check: Option<i32>| -> BigDecimal {
+                    settled
+                        .iter()
+                        .filter(|p| check.is_none() || p.check_id == check)
+                        .map(|p| {
+                            let returned: BigDecimal = refunded
+                                .iter()
+                                .filter(|(id, _)| *id == p.payment_id)
+                                .map(|(_, amount)| amount.clone())
+                                .sum();
+                            &p.amount - returned
+                        })
+                        .sum()
+                };
+
+                if new_payment.amount > order_due - paid(None) {
+                    return Ok(None);
+                }
+                if let Some(check_due) = check_due
+                    && new_payment.amount > check_due - paid(new_payment.check_id)
+                {
+                    return Ok(None);
+                }
+
+                diesel::insert_into(payments)
+                    .values(&new_payment)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                payments
+                    .filter(payment_id.eq(new_id))
+                    .first::<Payment>(connection)
+                    .await
+                    .map(Some)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Stores the outcome of a reserved payment and returns the stored row.
+    /// A tip on the payment is added to the tip total of its order in the same transaction.
+    pub async fn settle(
+        &self,
+        payment: i32,
+        new_status: &str,
+        reference: Option<&str>,
+        tip: BigDecimal,
+    ) -> Result<Payment, result::Error> {
+        use crate::data::models::schema::orders::dsl::{order_id, orders, tip_amount};
+        use crate::data::models::schema::payments::dsl::{
+            payment_id, payments, provider_reference, status, tip_amount as payment_tip_amount,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(payments.filter(payment_id.eq(payment)))
+                    .set((
+                        status.eq(new_status),
+                        provider_reference.eq(reference),
+                        payment_tip_amount.eq(tip.clone()),
+                    ))
+                    .execute(connection)
+                    .await?;
+
+                let stored = payments
+                    .filter(payment_id.eq(payment))
+                    .first::<Payment>(connection)
+                    .await?;
+
+                if tip > BigDecimal::zero() {
+                    diesel::update(orders.filter(order_id.eq(stored.order_id)))
+                        .set(tip_amount.eq(tip_amount + tip))
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(stored)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Repository for PaymentRepo {
+    type Id = i32;
+    type Item = Payment;
+    type NewItem<'a> = NewPayment<'a>;
+    type UpdateForm<'a> = UpdatePayment<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::payments::dsl::payments;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match payments.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::payments::dsl::{payment_id, payments};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match payments
+            .filter(payment_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::payments::dsl::payments;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(payments)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::payments::dsl::{payment_id, payments};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(payments.filter(payment_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::payments::dsl::{payment_id, payments};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(payments.filter(payment_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for PaymentRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
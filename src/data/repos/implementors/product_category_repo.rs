@@ -15,14 +15,15 @@ impl ProductCategoryRepo {
         ProductCategoryRepo
     }
 
+    /// Retrieves the products of a category that are not archived, by their sort order.
     pub async fn get_products_by_category_id(
         &self,
         id: i32,
     ) -> Result<Option<Vec<crate::data::models::product::Product>>, result::Error> {
         use crate::data::models::schema::product_categories::dsl::{
-            category_id, product_categories,
+            category_id, product_categories, sort_order,
         };
-        use crate::data::models::schema::products::dsl::products;
+        use crate::data::models::schema::products::dsl::{deleted_at, products};
 
         let db = Database::new().await;
 
@@ -36,6 +37,8 @@ impl ProductCategoryRepo {
         match product_categories
             .filter(category_id.eq(id))
             .inner_join(products)
+            .filter(deleted_at.is_null())
+            .order(sort_order.asc())
             .select(crate::data::models::product::Product::as_select())
             .load::<crate::data::models::product::Product>(&mut conn)
             .await
@@ -47,11 +50,82 @@ impl ProductCategoryRepo {
         }
     }
 
+    /// Retrieves the products that are not archived in any of the given categories, with
+    /// the category each is in, by their sort order within the category.
+    pub async fn get_products_by_category_ids(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<(i32, crate::data::models::product::Product)>, result::Error> {
+        use crate::data::models::schema::product_categories::dsl::{
+            category_id, product_categories, sort_order,
+        };
+        use crate::data::models::schema::products::dsl::{deleted_at, products};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_categories
+            .filter(category_id.eq_any(ids))
+            .inner_join(products)
+            .filter(deleted_at.is_null())
+            .order(sort_order.asc())
+            .select((
+                category_id,
+                crate::data::models::product::Product::as_select(),
+            ))
+            .load::<(i32, crate::data::models::product::Product)>(&mut conn)
+            .await
+    }
+
+    /// Sets the position of a product within a category.
+    pub async fn set_sort_order(
+        &self,
+        product: i32,
+        category: i32,
+        order: i32,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::product_categories::dsl::{
+            category_id, product_categories, product_id, sort_order,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(
+                    product_categories
+                        .filter(product_id.eq(product))
+                        .filter(category_id.eq(category)),
+                )
+                .set(sort_order.eq(order))
+                .execute(connection)
+                .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the categories of a product that are not archived.
     pub async fn get_categories_by_product_id(
         &self,
         id: i32,
     ) -> Result<Option<Vec<crate::data::models::categories::Category>>, result::Error> {
-        use crate::data::models::schema::categories::dsl::categories;
+        use crate::data::models::schema::categories::dsl::{categories, deleted_at};
         use crate::data::models::schema::product_categories::dsl::{
             product_categories, product_id,
         };
@@ -68,6 +142,7 @@ impl ProductCategoryRepo {
         match product_categories
             .filter(product_id.eq(id))
             .inner_join(categories)
+            .filter(deleted_at.is_null())
             .select(crate::data::models::categories::Category::as_select())
             .load::<crate::data::models::categories::Category>(&mut conn)
             .await
@@ -79,6 +154,31 @@ impl ProductCategoryRepo {
         }
     }
 
+    /// Retrieves (product_id, category_id) pairs for the given products.
+    pub async fn get_category_ids_by_product_ids(
+        &self,
+        product_ids: Vec<i32>,
+    ) -> Result<Vec<(i32, i32)>, result::Error> {
+        use crate::data::models::schema::product_categories::dsl::{
+            category_id, product_categories, product_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_categories
+            .filter(product_id.eq_any(product_ids))
+            .select((product_id, category_id))
+            .load::<(i32, i32)>(&mut conn)
+            .await
+    }
+
     pub async fn delete_by_product_id(&self, product_id: i32) -> Result<(), result::Error> {
         use crate::data::models::schema::product_categories::dsl::{
             product_categories, product_id as pc_product_id,
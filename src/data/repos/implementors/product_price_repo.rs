@@ -0,0 +1,282 @@
+use crate::data::database::Database;
+use crate::data::models::product_price::{NewProductPrice, ProductPrice};
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct ProductPriceRepo;
+
+impl ProductPriceRepo {
+    pub fn new() -> Self {
+        ProductPriceRepo
+    }
+
+    /// Retrieves the price timeline of a product, oldest first.
+    pub async fn get_by_product(&self, product: i32) -> Result<Vec<ProductPrice>, result::Error> {
+        use crate::data::models::schema::product_prices::dsl::{
+            effective_from, product_id, product_prices,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_prices
+            .filter(product_id.eq(product))
+            .order(effective_from.asc())
+            .load::<ProductPrice>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the prices in effect at `at` for the given products.
+    pub async fn get_effective(
+        &self,
+        ids: &[i32],
+        at: NaiveDateTime,
+    ) -> Result<Vec<ProductPrice>, result::Error> {
+        use crate::data::models::schema::product_prices::dsl::{
+            effective_from, effective_to, product_id, product_prices,
+        };
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_prices
+            .filter(product_id.eq_any(ids))
+            .filter(effective_from.le(at))
+            .filter(effective_to.is_null().or(effective_to.gt(at)))
+            .load::<ProductPrice>(&mut conn)
+            .await
+    }
+
+    /// Adds a price to the timeline of a product from `price.effective_from`, in a single
+    /// transaction. A price already starting at that time is replaced, otherwise the price
+    /// before it ends where the new one starts and the new one ends where the next begins.
+    /// The price on the product is updated too when the new price is already in effect at
+    /// `now`. Returns the id of the price row.
+    pub async fn schedule(
+        &self,
+        price: NewProductPrice,
+        now: NaiveDateTime,
+    ) -> Result<i32, result::Error> {
+        use crate::data::models::schema::product_prices::dsl::{
+            created_by, effective_from, effective_to, price as price_col, price_id, product_id,
+            product_prices,
+        };
+        use crate::data::models::schema::products::dsl::{
+            price as product_price, product_id as products_id, products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let timeline: Vec<ProductPrice> = product_prices
+                    .filter(product_id.eq(price.product_id))
+                    .order(effective_from.asc())
+                    .for_update()
+                    .load(connection)
+                    .await?;
+
+                let id = match timeline
+                    .iter()
+                    .find(|p| p.effective_from == price.effective_from)
+                {
+                    Some(existing) => {
+                        diesel::update(product_prices.filter(price_id.eq(existing.price_id)))
+                            .set((
+                                price_col.eq(price.price.clone()),
+                                created_by.eq(price.created_by),
+                            ))
+                            .execute(connection)
+                            .await?;
+                        existing.price_id
+                    }
+                    None => {
+                        let previous = timeline
+                            .iter()
+                            .rev()
+                            .find(|p| p.effective_from < price.effective_from);
+                        let next = timeline
+                            .iter()
+                            .find(|p| p.effective_from > price.effective_from);
+
+                        if let Some(previous) = previous {
+                            diesel::update(product_prices.filter(price_id.eq(previous.price_id)))
+                                .set(effective_to.eq(Some(price.effective_from)))
+                                .execute(connection)
+                                .await?;
+                        }
+
+                        diesel::insert_into(product_prices)
+                            .values(&NewProductPrice {
+                                price: price.price.clone(),
+                                effective_to: next.map(|p| p.effective_from),
+                                ..price
+                            })
+                            .execute(connection)
+                            .await?;
+
+                        diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                            "LAST_INSERT_ID()",
+                        ))
+                        .get_result(connection)
+                        .await?
+                    }
+                };
+
+                let in_effect = timeline
+                    .iter()
+                    .filter(|p| p.effective_from > price.effective_from)
+                    .all(|p| p.effective_from > now);
+                if price.effective_from <= now && in_effect {
+                    diesel::update(products.filter(products_id.eq(price.product_id)))
+                        .set(product_price.eq(price.price.clone()))
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(id)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Removes a price change of a product that has not taken effect by `now`, in a single
+    /// transaction, handing its period back to the price before it. Returns false when there
+    /// is no such scheduled change.
+    pub async fn cancel(
+        &self,
+        product: i32,
+        id: i32,
+        now: NaiveDateTime,
+    ) -> Result<bool, result::Error> {
+        use crate::data::models::schema::product_prices::dsl::{
+            effective_from, effective_to, price_id, product_id, product_prices,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let timeline: Vec<ProductPrice> = product_prices
+                    .filter(product_id.eq(product))
+                    .order(effective_from.asc())
+                    .for_update()
+                    .load(connection)
+                    .await?;
+
+                let Some(scheduled) = timeline
+                    .iter()
+                    .find(|p| p.price_id == id && p.effective_from > now)
+                else {
+                    return Ok(false);
+                };
+
+                if let Some(previous) = timeline
+                    .iter()
+                    .rev()
+                    .find(|p| p.effective_from < scheduled.effective_from)
+                {
+                    diesel::update(product_prices.filter(price_id.eq(previous.price_id)))
+                        .set(effective_to.eq(scheduled.effective_to))
+                        .execute(connection)
+                        .await?;
+                }
+
+                diesel::delete(product_prices.filter(price_id.eq(id)))
+                    .execute(connection)
+                    .await?;
+
+                Ok(true)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Sets the price on every product whose price in effect at `now` differs from it,
+    /// returning the number of products updated.
+    pub async fn apply_due(&self, now: NaiveDateTime) -> Result<usize, result::Error> {
+        use crate::data::models::schema::product_prices::dsl::{
+            effective_from, effective_to, price, product_id, product_prices,
+        };
+        use crate::data::models::schema::products::dsl::{
+            price as product_price, product_id as products_id, products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let due: Vec<(i32, BigDecimal)> = product_prices
+                    .inner_join(products)
+                    .filter(effective_from.le(now))
+                    .filter(effective_to.is_null().or(effective_to.gt(now)))
+                    .filter(price.ne(product_price))
+                    .select((product_id, price))
+                    .load(connection)
+                    .await?;
+
+                let mut updated = 0;
+                for (product, new_price) in due {
+                    updated += diesel::update(products.filter(products_id.eq(product)))
+                        .set(product_price.eq(new_price))
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(updated)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for ProductPriceRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,6 +2,7 @@ use crate::data::database::Database;
 use crate::data::models::product::{NewProduct, Product, UpdateProduct};
 use crate::data::repos::traits::repository::Repository;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use diesel::result;
 use diesel_async::pooled_connection::deadpool::Object;
@@ -38,6 +39,171 @@ impl ProductRepo {
             Err(e) => Err(e),
         }
     }
+
+    /// Sets the minutes the kitchen needs to prepare a product.
+    pub async fn update_prep_time(&self, id: i32, minutes: i32) -> Result<(), result::Error> {
+        use crate::data::models::schema::products::dsl::{prep_minutes, product_id, products};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(products.filter(product_id.eq(id)))
+                    .set(prep_minutes.eq(minutes))
+                    .execute(connection)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Sets whether a product can be ordered and when it becomes available again on its own.
+    pub async fn set_availability(
+        &self,
+        id: i32,
+        available: bool,
+        again_at: Option<NaiveDateTime>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::products::dsl::{
+            available_again_at, is_available, product_id, products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(products.filter(product_id.eq(id)))
+                    .set((is_available.eq(available), available_again_at.eq(again_at)))
+                    .execute(connection)
+                    .await?;
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Retrieves the products that are not archived.
+    pub async fn get_active(&self) -> Result<Option<Vec<Product>>, result::Error> {
+        use crate::data::models::schema::products::dsl::{deleted_at, products};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match products
+            .filter(deleted_at.is_null())
+            .load::<Product>(&mut conn)
+            .await
+        {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Retrieves the archived products, most recently archived first.
+    pub async fn get_archived(&self) -> Result<Vec<Product>, result::Error> {
+        use crate::data::models::schema::products::dsl::{deleted_at, products};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        products
+            .filter(deleted_at.is_not_null())
+            .order(deleted_at.desc())
+            .load::<Product>(&mut conn)
+            .await
+    }
+
+    /// Archives a product, keeping it for the history that refers to it.
+    /// Returns whether it was not archived yet.
+    pub async fn archive(&self, id: i32) -> Result<bool, result::Error> {
+        use crate::data::models::schema::products::dsl::{deleted_at, product_id, products};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let updated = diesel::update(
+                    products
+                        .filter(product_id.eq(id))
+                        .filter(deleted_at.is_null()),
+                )
+                .set(deleted_at.eq(diesel::dsl::now.nullable()))
+                .execute(connection)
+                .await?;
+                Ok(updated > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Brings back an archived product. Returns whether it was archived.
+    pub async fn restore(&self, id: i32) -> Result<bool, result::Error> {
+        use crate::data::models::schema::products::dsl::{deleted_at, product_id, products};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let updated = diesel::update(
+                    products
+                        .filter(product_id.eq(id))
+                        .filter(deleted_at.is_not_null()),
+                )
+                .set(deleted_at.eq(None::<chrono::NaiveDateTime>))
+                .execute(connection)
+                .await?;
+                Ok(updated > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,381 @@
+use crate::data::database::Database;
+use crate::data::models::promotion::{
+    NewPromotion, Promotion, PromotionCategory, PromotionProduct, UpdatePromotion,
+};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct PromotionRepo;
+
+impl PromotionRepo {
+    pub fn new() -> Self {
+        PromotionRepo
+    }
+
+    /// Retrieves a promotion by its redemption code (case-insensitive).
+    pub async fn get_by_code(&self, code_query: &str) -> Result<Option<Promotion>, result::Error> {
+        use crate::data::models::schema::promotions::dsl::{code, promotions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match promotions
+            .filter(code.eq(code_query))
+            .first::<Promotion>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Retrieves all active promotions.
+    /// Time windows and usage limits are checked by the caller.
+    pub async fn get_active(&self) -> Result<Vec<Promotion>, result::Error> {
+        use crate::data::models::schema::promotions::dsl::{is_active, promotions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        promotions
+            .filter(is_active.eq(true))
+            .load::<Promotion>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the product and category conditions of the given promotions.
+    pub async fn get_conditions(
+        &self,
+        promotion_ids: Vec<i32>,
+    ) -> Result<(Vec<PromotionProduct>, Vec<PromotionCategory>), result::Error> {
+        use crate::data::models::schema::promotion_categories::dsl::{
+            promotion_categories, promotion_id as pc_promotion_id,
+        };
+        use crate::data::models::schema::promotion_products::dsl::{
+            promotion_id as pp_promotion_id, promotion_products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let products = promotion_products
+            .filter(pp_promotion_id.eq_any(promotion_ids.clone()))
+            .load::<PromotionProduct>(&mut conn)
+            .await?;
+
+        let categories = promotion_categories
+            .filter(pc_promotion_id.eq_any(promotion_ids))
+            .load::<PromotionCategory>(&mut conn)
+            .await?;
+
+        Ok((products, categories))
+    }
+
+    /// Creates a promotion together with its product and category conditions.
+    /// Returns the id of the new promotion.
+    pub async fn create_with_conditions(
+        &self,
+        new_promotion: NewPromotion<'_>,
+        product_ids: Vec<i32>,
+        category_ids: Vec<i32>,
+    ) -> Result<i32, result::Error> {
+        use crate::data::models::schema::promotion_categories::dsl::promotion_categories;
+        use crate::data::models::schema::promotion_products::dsl::promotion_products;
+        use crate::data::models::schema::promotions::dsl::promotions;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::insert_into(promotions)
+                    .values(&new_promotion)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                let new_products: Vec<PromotionProduct> = product_ids
+                    .into_iter()
+                    .map(|product_id| PromotionProduct {
+                        promotion_id: new_id,
+                        product_id,
+                    })
+                    .collect();
+                let new_categories: Vec<PromotionCategory> = category_ids
+                    .into_iter()
+                    .map(|category_id| PromotionCategory {
+                        promotion_id: new_id,
+                        category_id,
+                    })
+                    .collect();
+
+                if !new_products.is_empty() {
+                    diesel::insert_into(promotion_products)
+                        .values(&new_products)
+                        .execute(connection)
+                        .await?;
+                }
+                if !new_categories.is_empty() {
+                    diesel::insert_into(promotion_categories)
+                        .values(&new_categories)
+                        .execute(connection)
+                        .await?;
+                }
+                Ok(new_id)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Replaces the product and category conditions of a promotion.
+    pub async fn set_conditions(
+        &self,
+        promotion: i32,
+        product_ids: Vec<i32>,
+        category_ids: Vec<i32>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::promotion_categories::dsl::{
+            promotion_categories, promotion_id as pc_promotion_id,
+        };
+        use crate::data::models::schema::promotion_products::dsl::{
+            promotion_id as pp_promotion_id, promotion_products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let new_products: Vec<PromotionProduct> = product_ids
+            .into_iter()
+            .map(|product_id| PromotionProduct {
+                promotion_id: promotion,
+                product_id,
+            })
+            .collect();
+        let new_categories: Vec<PromotionCategory> = category_ids
+            .into_iter()
+            .map(|category_id| PromotionCategory {
+                promotion_id: promotion,
+                category_id,
+            })
+            .collect();
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(promotion_products.filter(pp_promotion_id.eq(promotion)))
+                    .execute(connection)
+                    .await?;
+                diesel::delete(promotion_categories.filter(pc_promotion_id.eq(promotion)))
+                    .execute(connection)
+                    .await?;
+
+                if !new_products.is_empty() {
+                    diesel::insert_into(promotion_products)
+                        .values(&new_products)
+                        .execute(connection)
+                        .await?;
+                }
+                if !new_categories.is_empty() {
+                    diesel::insert_into(promotion_categories)
+                        .values(&new_categories)
+                        .execute(connection)
+                        .await?;
+                }
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Repository for PromotionRepo {
+    type Id = i32;
+    type Item = Promotion;
+    type NewItem<'a> = NewPromotion<'a>;
+    type UpdateForm<'a> = UpdatePromotion<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::promotions::dsl::promotions;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match promotions.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::promotions::dsl::{promotion_id, promotions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match promotions
+            .filter(promotion_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::promotions::dsl::promotions;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(promotions)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::promotions::dsl::{promotion_id, promotions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(promotions.filter(promotion_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::promotions::dsl::{promotion_id, promotions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(promotions.filter(promotion_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for PromotionRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,588 @@
+use crate::data::database::Database;
+use crate::data::models::ingredient::{Ingredient, NewStockMovement};
+use crate::data::models::purchase_order::{
+    NewPurchaseOrder, NewPurchaseOrderLine, PurchaseOrder, PurchaseOrderLine, UpdatePurchaseOrder,
+};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use bigdecimal::{BigDecimal, Signed};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct PurchaseOrderRepo;
+
+impl PurchaseOrderRepo {
+    pub fn new() -> Self {
+        PurchaseOrderRepo
+    }
+
+    /// Retrieves the purchase orders, newest first, optionally only those in a status or of
+    /// a supplier.
+    pub async fn get_filtered(
+        &self,
+        order_status: Option<&str>,
+        supplier: Option<i32>,
+    ) -> Result<Vec<PurchaseOrder>, result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders, status, supplier_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let mut query = purchase_orders.order(purchase_order_id.desc()).into_boxed();
+        if let Some(order_status) = order_status {
+            query = query.filter(status.eq(order_status));
+        }
+        if let Some(supplier) = supplier {
+            query = query.filter(supplier_id.eq(supplier));
+        }
+
+        query.load::<PurchaseOrder>(&mut conn).await
+    }
+
+    /// Retrieves the lines of the given purchase orders.
+    pub async fn get_lines(
+        &self,
+        order_ids: Vec<i32>,
+    ) -> Result<Vec<PurchaseOrderLine>, result::Error> {
+        use crate::data::models::schema::purchase_order_lines::dsl::{
+            ingredient_id, purchase_order_id, purchase_order_lines,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        purchase_order_lines
+            .filter(purchase_order_id.eq_any(order_ids))
+            .order((purchase_order_id.asc(), ingredient_id.asc()))
+            .load::<PurchaseOrderLine>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the lines of the purchase orders in any of the given statuses.
+    pub async fn get_lines_by_status(
+        &self,
+        statuses: Vec<&str>,
+    ) -> Result<Vec<PurchaseOrderLine>, result::Error> {
+        use crate::data::models::schema::purchase_order_lines::dsl::purchase_order_lines;
+        use crate::data::models::schema::purchase_orders::dsl::{purchase_orders, status};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        purchase_order_lines
+            .inner_join(purchase_orders)
+            .filter(status.eq_any(statuses))
+            .select(PurchaseOrderLine::as_select())
+            .load::<PurchaseOrderLine>(&mut conn)
+            .await
+    }
+
+    /// Inserts a purchase order with its lines in a single transaction and returns its id.
+    pub async fn create(
+        &self,
+        item: NewPurchaseOrder<'_>,
+        lines: Vec<(i32, BigDecimal, BigDecimal)>, // ingredient_id, quantity, unit_cost
+    ) -> Result<i32, result::Error> {
+        use crate::data::models::schema::purchase_order_lines::dsl::purchase_order_lines;
+        use crate::data::models::schema::purchase_orders::dsl::purchase_orders;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::insert_into(purchase_orders)
+                    .values(&item)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                let new_lines: Vec<NewPurchaseOrderLine> = lines
+                    .into_iter()
+                    .map(|(ingredient, quantity, unit_cost)| NewPurchaseOrderLine {
+                        purchase_order_id: new_id,
+                        ingredient_id: ingredient,
+                        quantity,
+                        unit_cost,
+                    })
+                    .collect();
+                diesel::insert_into(purchase_order_lines)
+                    .values(&new_lines)
+                    .execute(connection)
+                    .await?;
+
+                Ok(new_id)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Updates a purchase order still in the `draft` status, replacing its lines when new
+    /// ones are given, in a single transaction.
+    /// Returns whether the purchase order was still a draft.
+    pub async fn update_draft(
+        &self,
+        order: i32,
+        draft: &str,
+        item: UpdatePurchaseOrder<'_>,
+        lines: Option<Vec<(i32, BigDecimal, BigDecimal)>>, // ingredient_id, quantity, unit_cost
+    ) -> Result<bool, result::Error> {
+        use crate::data::models::schema::purchase_order_lines::dsl::{
+            purchase_order_id as line_purchase_order_id, purchase_order_lines,
+        };
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders, status,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let current: String = purchase_orders
+                    .filter(purchase_order_id.eq(order))
+                    .select(status)
+                    .for_update()
+                    .first(connection)
+                    .await?;
+                if current != draft {
+                    return Ok(false);
+                }
+
+                if item.supplier_id.is_some() || item.note.is_some() {
+                    diesel::update(purchase_orders.filter(purchase_order_id.eq(order)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                }
+
+                if let Some(lines) = lines {
+                    diesel::delete(purchase_order_lines.filter(line_purchase_order_id.eq(order)))
+                        .execute(connection)
+                        .await?;
+
+                    let new_lines: Vec<NewPurchaseOrderLine> = lines
+                        .into_iter()
+                        .map(|(ingredient, quantity, unit_cost)| NewPurchaseOrderLine {
+                            purchase_order_id: order,
+                            ingredient_id: ingredient,
+                            quantity,
+                            unit_cost,
+                        })
+                        .collect();
+                    diesel::insert_into(purchase_order_lines)
+                        .values(&new_lines)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(true)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Moves a purchase order from the `from` status to the `to` status, stamping when it
+    /// was sent. Returns whether it was in the `from` status.
+    pub async fn send(&self, order: i32, from: &str, to: &str) -> Result<bool, result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders, sent_at, status,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let updated = diesel::update(
+                    purchase_orders
+                        .filter(purchase_order_id.eq(order))
+                        .filter(status.eq(from)),
+                )
+                .set((status.eq(to), sent_at.eq(diesel::dsl::now.nullable())))
+                .execute(connection)
+                .await?;
+
+                Ok(updated > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Deletes a purchase order still in the `draft` status with its lines.
+    /// Returns whether it was still a draft.
+    pub async fn delete_draft(&self, order: i32, draft: &str) -> Result<bool, result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders, status,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                let deleted = diesel::delete(
+                    purchase_orders
+                        .filter(purchase_order_id.eq(order))
+                        .filter(status.eq(draft)),
+                )
+                .execute(connection)
+                .await?;
+
+                Ok(deleted > 0)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Receives stock against a purchase order in one of the `receivable` statuses in a
+    /// single transaction. Each receipt adds to the received quantity of its line and to the
+    /// stock on hand, averages its unit cost into the cost of the stock and is recorded as a
+    /// stock movement with the `reason`. The purchase order then moves to the `received`
+    /// status once every line is received in full, or else to the `partially` status.
+    /// Returns false, changing nothing, when the purchase order is no longer receivable or a
+    /// receipt exceeds what is still to be received of its line.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn receive(
+        &self,
+        order: i32,
+        receipts: Vec<(i32, BigDecimal, BigDecimal)>, // ingredient_id, quantity, unit_cost
+        receivable: Vec<&str>,
+        partially: &str,
+        received: &str,
+        reason: &str,
+        user: Option<i32>,
+        note: Option<&str>,
+    ) -> Result<bool, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{
+            average_cost, ingredient_id as ingredients_ingredient_id, ingredients, on_hand,
+        };
+        use crate::data::models::schema::purchase_order_lines::dsl::{
+            ingredient_id, purchase_order_id as line_purchase_order_id, purchase_order_lines,
+            received_quantity,
+        };
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders, received_at, status,
+        };
+        use crate::data::models::schema::stock_movements::dsl::stock_movements;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                // Lock the purchase order so two deliveries cannot receive the same line twice
+                let current: String = purchase_orders
+                    .filter(purchase_order_id.eq(order))
+                    .select(status)
+                    .for_update()
+                    .first(connection)
+                    .await?;
+                if !receivable.contains(&current.as_str()) {
+                    return Ok(false);
+                }
+
+                let mut lines: Vec<PurchaseOrderLine> = purchase_order_lines
+                    .filter(line_purchase_order_id.eq(order))
+                    .load(connection)
+                    .await?;
+                for (ingredient, quantity, _) in &receipts {
+                    match lines
+                        .iter_mut()
+                        .find(|line| line.ingredient_id == *ingredient)
+                    {
+                        Some(line) if quantity <= &line.outstanding() => {
+                            line.received_quantity += quantity;
+                        }
+                        _ => return Ok(false),
+                    }
+                }
+
+                let stock: Vec<Ingredient> = ingredients
+                    .filter(
+                        ingredients_ingredient_id
+                            .eq_any(receipts.iter().map(|(id, _, _)| *id).collect::<Vec<_>>()),
+                    )
+                    .for_update()
+                    .load(connection)
+                    .await?;
+
+                for (ingredient, quantity, unit_cost) in &receipts {
+                    let Some(stocked) = stock.iter().find(|i| i.ingredient_id == *ingredient)
+                    else {
+                        return Err(result::Error::NotFound);
+                    };
+
+                    diesel::update(ingredients.filter(ingredients_ingredient_id.eq(*ingredient)))
+                        .set((
+                            on_hand.eq(on_hand + quantity.clone()),
+                            average_cost.eq(stocked.average_cost_after(quantity, unit_cost)),
+                        ))
+                        .execute(connection)
+                        .await?;
+
+                    diesel::update(
+                        purchase_order_lines
+                            .filter(line_purchase_order_id.eq(order))
+                            .filter(ingredient_id.eq(*ingredient)),
+                    )
+                    .set(received_quantity.eq(received_quantity + quantity.clone()))
+                    .execute(connection)
+                    .await?;
+
+                    diesel::insert_into(stock_movements)
+                        .values(&NewStockMovement {
+                            ingredient_id: *ingredient,
+                            order_id: None,
+                            quantity: quantity.clone(),
+                            reason,
+                            note,
+                            user_id: user,
+                            purchase_order_id: Some(order),
+                            unit_cost: Some(unit_cost.clone()),
+                            waste_id: None,
+                        })
+                        .execute(connection)
+                        .await?;
+                }
+
+                if lines.iter().all(|line| !line.outstanding().is_positive()) {
+                    diesel::update(purchase_orders.filter(purchase_order_id.eq(order)))
+                        .set((
+                            status.eq(received),
+                            received_at.eq(diesel::dsl::now.nullable()),
+                        ))
+                        .execute(connection)
+                        .await?;
+                } else {
+                    diesel::update(purchase_orders.filter(purchase_order_id.eq(order)))
+                        .set(status.eq(partially))
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(true)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Repository for PurchaseOrderRepo {
+    type Id = i32;
+    type Item = PurchaseOrder;
+    type NewItem<'a> = NewPurchaseOrder<'a>;
+    type UpdateForm<'a> = UpdatePurchaseOrder<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::purchase_orders;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match purchase_orders.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match purchase_orders
+            .filter(purchase_order_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::purchase_orders;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(purchase_orders)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(purchase_orders.filter(purchase_order_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::purchase_orders::dsl::{
+            purchase_order_id, purchase_orders,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(purchase_orders.filter(purchase_order_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for PurchaseOrderRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
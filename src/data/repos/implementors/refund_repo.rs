@@ -0,0 +1,42 @@
+use crate::data::database::Database;
+use crate::data::models::refund::Refund;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::{AsyncMysqlConnection, RunQueryDsl};
+
+pub struct RefundRepo;
+
+impl RefundRepo {
+    pub fn new() -> Self {
+        RefundRepo
+    }
+
+    /// Retrieves the refunds of the given payments.
+    pub async fn get_by_payment_ids(
+        &self,
+        payment_ids: Vec<i32>,
+    ) -> Result<Vec<Refund>, result::Error> {
+        use crate::data::models::schema::refunds::dsl::{payment_id, refunds};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        refunds
+            .filter(payment_id.eq_any(payment_ids))
+            .load::<Refund>(&mut conn)
+            .await
+    }
+}
+
+impl Default for RefundRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
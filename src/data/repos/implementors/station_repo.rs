@@ -0,0 +1,282 @@
+use crate::data::database::Database;
+use crate::data::models::station::{
+    NewStation, Station, StationCategory, StationProduct, UpdateStation,
+};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct StationRepo;
+
+impl StationRepo {
+    pub fn new() -> Self {
+        StationRepo
+    }
+
+    /// Retrieves the station every routed product is prepared at.
+    pub async fn get_product_routes(&self) -> Result<Vec<StationProduct>, result::Error> {
+        use crate::data::models::schema::station_products::dsl::station_products;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        station_products.load::<StationProduct>(&mut conn).await
+    }
+
+    /// Retrieves the station the products of every routed category are prepared at.
+    pub async fn get_category_routes(&self) -> Result<Vec<StationCategory>, result::Error> {
+        use crate::data::models::schema::station_categories::dsl::station_categories;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        station_categories.load::<StationCategory>(&mut conn).await
+    }
+
+    /// Replaces the products and categories routed to a station in a single transaction.
+    /// Products and categories routed to another station are moved to this one.
+    pub async fn replace_routing(
+        &self,
+        station: i32,
+        product_ids: Vec<i32>,
+        category_ids: Vec<i32>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::station_categories::dsl::{
+            category_id, station_categories, station_id as sc_station_id,
+        };
+        use crate::data::models::schema::station_products::dsl::{
+            product_id, station_id as sp_station_id, station_products,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(
+                    station_products.filter(
+                        sp_station_id
+                            .eq(station)
+                            .or(product_id.eq_any(product_ids.clone())),
+                    ),
+                )
+                .execute(connection)
+                .await?;
+
+                diesel::delete(
+                    station_categories.filter(
+                        sc_station_id
+                            .eq(station)
+                            .or(category_id.eq_any(category_ids.clone())),
+                    ),
+                )
+                .execute(connection)
+                .await?;
+
+                let products: Vec<StationProduct> = product_ids
+                    .into_iter()
+                    .map(|product| StationProduct {
+                        product_id: product,
+                        station_id: station,
+                    })
+                    .collect();
+                if !products.is_empty() {
+                    diesel::insert_into(station_products)
+                        .values(&products)
+                        .execute(connection)
+                        .await?;
+                }
+
+                let categories: Vec<StationCategory> = category_ids
+                    .into_iter()
+                    .map(|category| StationCategory {
+                        category_id: category,
+                        station_id: station,
+                    })
+                    .collect();
+                if !categories.is_empty() {
+                    diesel::insert_into(station_categories)
+                        .values(&categories)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Repository for StationRepo {
+    type Id = i32;
+    type Item = Station;
+    type NewItem<'a> = NewStation<'a>;
+    type UpdateForm<'a> = UpdateStation<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::stations::dsl::stations;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match stations.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::stations::dsl::{station_id, stations};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match stations
+            .filter(station_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::stations::dsl::stations;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(stations)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::stations::dsl::{station_id, stations};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(stations.filter(station_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::stations::dsl::{station_id, stations};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(stations.filter(station_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for StationRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,164 @@
+use crate::data::database::Database;
+use crate::data::models::table_session::{NewTableSession, SessionTotals, TableSession};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+/// Opening and closing a session also moves its table to `seated` and `dirty`
+pub struct TableSessionRepo;
+
+impl TableSessionRepo {
+    pub fn new() -> Self {
+        TableSessionRepo
+    }
+
+    /// Retrieves the session a table is currently seated in, if any.
+    pub async fn get_open_by_table_id(
+        &self,
+        table: i32,
+    ) -> Result<Option<TableSession>, result::Error> {
+        use crate::data::models::schema::table_sessions::dsl::{
+            closed_at, table_id, table_sessions,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match table_sessions
+            .filter(table_id.eq(table))
+            .filter(closed_at.is_null())
+            .first::<TableSession>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Retrieves the sessions of every table that is currently seated.
+    pub async fn get_open(&self) -> Result<Vec<TableSession>, result::Error> {
+        use crate::data::models::schema::table_sessions::dsl::{closed_at, table_sessions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        table_sessions
+            .filter(closed_at.is_null())
+            .load::<TableSession>(&mut conn)
+            .await
+    }
+
+    /// Inserts a session and marks its table as seated in a single transaction.
+    /// Returns the stored session.
+    pub async fn open(
+        &self,
+        new_session: NewTableSession,
+        seated_status: &str,
+    ) -> Result<TableSession, result::Error> {
+        use crate::data::models::schema::dining_tables::dsl::{dining_tables, status, table_id};
+        use crate::data::models::schema::table_sessions::dsl::{session_id, table_sessions};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::insert_into(table_sessions)
+                    .values(&new_session)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                diesel::update(dining_tables.filter(table_id.eq(new_session.table_id)))
+                    .set(status.eq(seated_status))
+                    .execute(connection)
+                    .await?;
+
+                table_sessions
+                    .filter(session_id.eq(new_id))
+                    .first::<TableSession>(connection)
+                    .await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Closes a session with its combined check and marks its table as dirty in a single
+    /// transaction. Returns the stored session.
+    pub async fn close(
+        &self,
+        session: &TableSession,
+        totals: SessionTotals,
+        dirty_status: &str,
+    ) -> Result<TableSession, result::Error> {
+        use crate::data::models::schema::dining_tables::dsl::{dining_tables, status, table_id};
+        use crate::data::models::schema::table_sessions::dsl::{
+            closed_at, session_id, table_sessions,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let (session, table) = (session.session_id, session.table_id);
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::update(table_sessions.filter(session_id.eq(session)))
+                    .set((&totals, closed_at.eq(diesel::dsl::now.nullable())))
+                    .execute(connection)
+                    .await?;
+
+                diesel::update(dining_tables.filter(table_id.eq(table)))
+                    .set(status.eq(dirty_status))
+                    .execute(connection)
+                    .await?;
+
+                table_sessions
+                    .filter(session_id.eq(session))
+                    .first::<TableSession>(connection)
+                    .await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for TableSessionRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,224 @@
+use crate::data::database::Database;
+use crate::data::models::tax_rate::{NewTaxRate, TaxRate, UpdateTaxRate};
+use crate::data::repos::traits::repository::Repository;
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct TaxRateRepo;
+
+impl TaxRateRepo {
+    pub fn new() -> Self {
+        TaxRateRepo
+    }
+
+    /// Retrieves the tax rates that apply to a product.
+    /// Product-specific rates take precedence over category rates, which take precedence
+    /// over the default rates. All rates found at the winning level are returned so that
+    /// stacked taxes (e.g. state and city) are both applied. A product in several taxed
+    /// categories takes the rates of the lowest category id.
+    pub async fn get_for_product(
+        &self,
+        product_id_query: i32,
+    ) -> Result<Vec<TaxRate>, result::Error> {
+        use crate::data::models::schema::product_categories::dsl::{
+            category_id as pc_category_id, product_categories, product_id as pc_product_id,
+        };
+        use crate::data::models::schema::tax_rates::dsl::{category_id, product_id, tax_rates};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        let product_rates = tax_rates
+            .filter(product_id.eq(product_id_query))
+            .load::<TaxRate>(&mut conn)
+            .await?;
+
+        if !product_rates.is_empty() {
+            return Ok(product_rates);
+        }
+
+        let category_ids = product_categories
+            .filter(pc_product_id.eq(product_id_query))
+            .select(pc_category_id)
+            .load::<i32>(&mut conn)
+            .await?;
+
+        if !category_ids.is_empty() {
+            let category_rates = tax_rates
+                .filter(product_id.is_null())
+                .filter(category_id.eq_any(category_ids))
+                .load::<TaxRate>(&mut conn)
+                .await?;
+
+            if !category_rates.is_empty() {
+                return Ok(TaxRate::of_one_category(category_rates));
+            }
+        }
+
+        tax_rates
+            .filter(product_id.is_null())
+            .filter(category_id.is_null())
+            .load::<TaxRate>(&mut conn)
+            .await
+    }
+}
+
+#[async_trait]
+impl Repository for TaxRateRepo {
+    type Id = i32;
+    type Item = TaxRate;
+    type NewItem<'a> = NewTaxRate<'a>;
+    type UpdateForm<'a> = UpdateTaxRate<'a>;
+
+    async fn get_all(&self) -> Result<Option<Vec<Self::Item>>, result::Error> {
+        use crate::data::models::schema::tax_rates::dsl::tax_rates;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match tax_rates.load::<Self::Item>(&mut conn).await {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_by_id(&self, id: Self::Id) -> Result<Option<Self::Item>, result::Error> {
+        use crate::data::models::schema::tax_rates::dsl::{tax_rate_id, tax_rates};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match tax_rates
+            .filter(tax_rate_id.eq(id))
+            .first::<Self::Item>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn add<'a>(&self, item: Self::NewItem<'a>) -> Result<(), result::Error> {
+        use crate::data::models::schema::tax_rates::dsl::tax_rates;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::insert_into(tax_rates)
+                        .values(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn update<'a>(
+        &self,
+        id: Self::Id,
+        item: Self::UpdateForm<'a>,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::tax_rates::dsl::{tax_rate_id, tax_rates};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::update(tax_rates.filter(tax_rate_id.eq(id)))
+                        .set(&item)
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, id: Self::Id) -> Result<(), result::Error> {
+        use crate::data::models::schema::tax_rates::dsl::{tax_rate_id, tax_rates};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match conn
+            .transaction(|connection| {
+                async move {
+                    diesel::delete(tax_rates.filter(tax_rate_id.eq(id)))
+                        .execute(connection)
+                        .await?;
+                    Ok(())
+                }
+                .scope_boxed()
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for TaxRateRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,255 @@
+use crate::data::database::Database;
+use crate::data::models::translation::{CategoryTranslation, ProductTranslation};
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct TranslationRepo;
+
+impl TranslationRepo {
+    pub fn new() -> Self {
+        TranslationRepo
+    }
+
+    /// Retrieves the translations of the given products into a locale.
+    pub async fn get_products(
+        &self,
+        ids: Vec<i32>,
+        lang: &str,
+    ) -> Result<Vec<ProductTranslation>, result::Error> {
+        use crate::data::models::schema::product_translations::dsl::{
+            locale, product_id, product_translations,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_translations
+            .filter(product_id.eq_any(ids))
+            .filter(locale.eq(lang))
+            .load::<ProductTranslation>(&mut conn)
+            .await
+    }
+
+    /// Retrieves the translations of the given categories into a locale.
+    pub async fn get_categories(
+        &self,
+        ids: Vec<i32>,
+        lang: &str,
+    ) -> Result<Vec<CategoryTranslation>, result::Error> {
+        use crate::data::models::schema::category_translations::dsl::{
+            category_id, category_translations, locale,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        category_translations
+            .filter(category_id.eq_any(ids))
+            .filter(locale.eq(lang))
+            .load::<CategoryTranslation>(&mut conn)
+            .await
+    }
+
+    /// Retrieves every translation of a product, by locale.
+    pub async fn get_for_product(&self, id: i32) -> Result<Vec<ProductTranslation>, result::Error> {
+        use crate::data::models::schema::product_translations::dsl::{
+            locale, product_id, product_translations,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        product_translations
+            .filter(product_id.eq(id))
+            .order(locale.asc())
+            .load::<ProductTranslation>(&mut conn)
+            .await
+    }
+
+    /// Retrieves every translation of a category, by locale.
+    pub async fn get_for_category(
+        &self,
+        id: i32,
+    ) -> Result<Vec<CategoryTranslation>, result::Error> {
+        use crate::data::models::schema::category_translations::dsl::{
+            category_id, category_translations, locale,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        category_translations
+            .filter(category_id.eq(id))
+            .order(locale.asc())
+            .load::<CategoryTranslation>(&mut conn)
+            .await
+    }
+
+    /// Replaces the translation of a product into its locale.
+    pub async fn save_product(&self, translation: ProductTranslation) -> Result<(), result::Error> {
+        use crate::data::models::schema::product_translations::dsl::{
+            locale, product_id, product_translations,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(
+                    product_translations
+                        .filter(product_id.eq(translation.product_id))
+                        .filter(locale.eq(&translation.locale)),
+                )
+                .execute(connection)
+                .await?;
+                diesel::insert_into(product_translations)
+                    .values(&translation)
+                    .execute(connection)
+                    .await?;
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Replaces the translation of a category into its locale.
+    pub async fn save_category(
+        &self,
+        translation: CategoryTranslation,
+    ) -> Result<(), result::Error> {
+        use crate::data::models::schema::category_translations::dsl::{
+            category_id, category_translations, locale,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(
+                    category_translations
+                        .filter(category_id.eq(translation.category_id))
+                        .filter(locale.eq(&translation.locale)),
+                )
+                .execute(connection)
+                .await?;
+                diesel::insert_into(category_translations)
+                    .values(&translation)
+                    .execute(connection)
+                    .await?;
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Removes the translation of a product into a locale, returning how many were removed.
+    pub async fn delete_product(&self, id: i32, lang: &str) -> Result<usize, result::Error> {
+        use crate::data::models::schema::product_translations::dsl::{
+            locale, product_id, product_translations,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(
+                    product_translations
+                        .filter(product_id.eq(id))
+                        .filter(locale.eq(lang)),
+                )
+                .execute(connection)
+                .await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Removes the translation of a category into a locale, returning how many were removed.
+    pub async fn delete_category(&self, id: i32, lang: &str) -> Result<usize, result::Error> {
+        use crate::data::models::schema::category_translations::dsl::{
+            category_id, category_translations, locale,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::delete(
+                    category_translations
+                        .filter(category_id.eq(id))
+                        .filter(locale.eq(lang)),
+                )
+                .execute(connection)
+                .await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for TranslationRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::data::database::Database;
+use crate::data::models::ingredient::NewStockMovement;
+use crate::data::models::waste_entry::{NewWasteEntry, WasteEntry};
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use diesel::result;
+use diesel_async::pooled_connection::deadpool::Object;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncMysqlConnection, RunQueryDsl};
+
+pub struct WasteRepo;
+
+impl WasteRepo {
+    pub fn new() -> Self {
+        WasteRepo
+    }
+
+    /// Retrieves a waste entry by id.
+    pub async fn get_by_id(&self, id: i32) -> Result<Option<WasteEntry>, result::Error> {
+        use crate::data::models::schema::waste_entries::dsl::{waste_entries, waste_id};
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        match waste_entries
+            .filter(waste_id.eq(id))
+            .first::<WasteEntry>(&mut conn)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(result::Error::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Retrieves the waste logged from `from` up to, but not including, `to`, oldest first.
+    pub async fn get_between(
+        &self,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<WasteEntry>, result::Error> {
+        use crate::data::models::schema::waste_entries::dsl::{
+            created_at, waste_entries, waste_id,
+        };
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        waste_entries
+            .filter(created_at.ge(from))
+            .filter(created_at.lt(to))
+            .order((created_at.asc(), waste_id.asc()))
+            .load::<WasteEntry>(&mut conn)
+            .await
+    }
+
+    /// Logs waste and takes the ingredients it `uses` out of stock in a single transaction,
+    /// recording a movement with the `reason` for each. Returns the id of the new entry.
+    pub async fn record(
+        &self,
+        entry: NewWasteEntry<'_>,
+        uses: Vec<(i32, BigDecimal)>, // ingredient_id, quantity
+        reason: &str,
+    ) -> Result<i32, result::Error> {
+        use crate::data::models::schema::ingredients::dsl::{ingredient_id, ingredients, on_hand};
+        use crate::data::models::schema::stock_movements::dsl::stock_movements;
+        use crate::data::models::schema::waste_entries::dsl::waste_entries;
+
+        let db = Database::new().await;
+
+        let mut conn: Object<AsyncMysqlConnection> = db.get_connection().await.map_err(|e| {
+            result::Error::DatabaseError(
+                result::DatabaseErrorKind::UnableToSendCommand,
+                Box::new(e.to_string()),
+            )
+        })?;
+
+        conn.transaction(|connection| {
+            async move {
+                diesel::insert_into(waste_entries)
+                    .values(&entry)
+                    .execute(connection)
+                    .await?;
+
+                let new_id: i32 = diesel::select(diesel::dsl::sql::<diesel::sql_types::Integer>(
+                    "LAST_INSERT_ID()",
+                ))
+                .get_result(connection)
+                .await?;
+
+                for (ingredient, used) in &uses {
+                    diesel::update(ingredients.filter(ingredient_id.eq(*ingredient)))
+                        .set(on_hand.eq(on_hand - used.clone()))
+                        .execute(connection)
+                        .await?;
+                }
+
+                let movements: Vec<NewStockMovement> = uses
+                    .iter()
+                    .map(|(ingredient, used)| NewStockMovement {
+                        ingredient_id: *ingredient,
+                        order_id: None,
+                        quantity: -used.clone(),
+                        reason,
+                        note: entry.note,
+                        user_id: entry.user_id,
+                        purchase_order_id: None,
+                        unit_cost: None,
+                        waste_id: Some(new_id),
+                    })
+                    .collect();
+                if !movements.is_empty() {
+                    diesel::insert_into(stock_movements)
+                        .values(&movements)
+                        .execute(connection)
+                        .await?;
+                }
+
+                Ok(new_id)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
+
+impl Default for WasteRepo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,453 @@
+use crate::api::request::DietaryTagsRequest;
+use crate::api::response::DietaryTagsResponse;
+use crate::data::models::allergen::{
+    IngredientAllergen, IngredientDietaryTag, ProductAllergen, ProductDietaryTag,
+};
+use crate::data::models::ingredient::Recipe;
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::allergen_repo::AllergenRepo;
+use crate::data::repos::implementors::ingredient_repo::IngredientRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::AllergenServiceError;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+/// The 14 allergens that have to be declared in the EU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Allergen {
+    Celery,
+    /// Cereals containing gluten
+    Gluten,
+    Crustaceans,
+    Eggs,
+    Fish,
+    Lupin,
+    Milk,
+    Molluscs,
+    Mustard,
+    /// Tree nuts
+    Nuts,
+    Peanuts,
+    Sesame,
+    Soya,
+    /// Sulphur dioxide and sulphites
+    Sulphites,
+}
+
+impl Allergen {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Allergen::Celery => "celery",
+            Allergen::Gluten => "gluten",
+            Allergen::Crustaceans => "crustaceans",
+            Allergen::Eggs => "eggs",
+            Allergen::Fish => "fish",
+            Allergen::Lupin => "lupin",
+            Allergen::Milk => "milk",
+            Allergen::Molluscs => "molluscs",
+            Allergen::Mustard => "mustard",
+            Allergen::Nuts => "nuts",
+            Allergen::Peanuts => "peanuts",
+            Allergen::Sesame => "sesame",
+            Allergen::Soya => "soya",
+            Allergen::Sulphites => "sulphites",
+        }
+    }
+}
+
+impl FromStr for Allergen {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "celery" => Ok(Allergen::Celery),
+            "gluten" => Ok(Allergen::Gluten),
+            "crustaceans" => Ok(Allergen::Crustaceans),
+            "eggs" => Ok(Allergen::Eggs),
+            "fish" => Ok(Allergen::Fish),
+            "lupin" => Ok(Allergen::Lupin),
+            "milk" => Ok(Allergen::Milk),
+            "molluscs" => Ok(Allergen::Molluscs),
+            "mustard" => Ok(Allergen::Mustard),
+            "nuts" => Ok(Allergen::Nuts),
+            "peanuts" => Ok(Allergen::Peanuts),
+            "sesame" => Ok(Allergen::Sesame),
+            "soya" => Ok(Allergen::Soya),
+            "sulphites" => Ok(Allergen::Sulphites),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A diet a product is suitable for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DietaryTag {
+    Vegetarian,
+    Vegan,
+    Pescatarian,
+    Halal,
+    Kosher,
+    GlutenFree,
+}
+
+impl DietaryTag {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DietaryTag::Vegetarian => "vegetarian",
+            DietaryTag::Vegan => "vegan",
+            DietaryTag::Pescatarian => "pescatarian",
+            DietaryTag::Halal => "halal",
+            DietaryTag::Kosher => "kosher",
+            DietaryTag::GlutenFree => "gluten_free",
+        }
+    }
+}
+
+impl FromStr for DietaryTag {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "vegetarian" => Ok(DietaryTag::Vegetarian),
+            "vegan" => Ok(DietaryTag::Vegan),
+            "pescatarian" => Ok(DietaryTag::Pescatarian),
+            "halal" => Ok(DietaryTag::Halal),
+            "kosher" => Ok(DietaryTag::Kosher),
+            "gluten_free" | "gluten-free" => Ok(DietaryTag::GlutenFree),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Parses values of the vocabulary, returning the ones that are not part of it as invalid
+pub fn parse_values<T: FromStr + Ord>(values: &[String]) -> Result<Vec<T>, Vec<String>> {
+    let mut parsed = BTreeSet::new();
+    let mut invalid = Vec::new();
+    for value in values {
+        match T::from_str(value) {
+            Ok(v) => {
+                parsed.insert(v);
+            }
+            Err(_) => invalid.push(value.clone()),
+        }
+    }
+
+    if invalid.is_empty() {
+        Ok(parsed.into_iter().collect())
+    } else {
+        Err(invalid)
+    }
+}
+
+/// The allergens and diets of a product
+#[derive(Debug, Clone, PartialEq)]
+pub struct DietaryProfile {
+    pub product_id: i32,
+    pub allergens: Vec<Allergen>,
+    pub dietary_tags: Vec<DietaryTag>,
+}
+
+/// Works out the profiles of products from what is set on them and their recipes.
+///
+/// A product has the allergens set on it and those of every ingredient in its recipe. It
+/// has the dietary tags set on it and, when it has a recipe, the tags all of its ingredients
+/// share. It is never gluten free while it contains gluten. Values outside the vocabulary
+/// are ignored.
+pub fn dietary_profiles(
+    product_ids: &[i32],
+    product_allergens: &[ProductAllergen],
+    product_tags: &[ProductDietaryTag],
+    recipes: &[Recipe],
+    ingredient_allergens: &[IngredientAllergen],
+    ingredient_tags: &[IngredientDietaryTag],
+) -> Vec<DietaryProfile> {
+    product_ids
+        .iter()
+        .map(|&product_id| {
+            let ingredients: Vec<i32> = recipes
+                .iter()
+                .filter(|r| r.product_id == product_id)
+                .map(|r| r.ingredient_id)
+                .collect();
+
+            let allergens: BTreeSet<Allergen> = product_allergens
+                .iter()
+                .filter(|a| a.product_id == product_id)
+                .map(|a| a.allergen.as_str())
+                .chain(
+                    ingredient_allergens
+                        .iter()
+                        .filter(|a| ingredients.contains(&a.ingredient_id))
+                        .map(|a| a.allergen.as_str()),
+                )
+                .filter_map(|a| Allergen::from_str(a).ok())
+                .collect();
+
+            let mut tags: BTreeSet<DietaryTag> = product_tags
+                .iter()
+                .filter(|t| t.product_id == product_id)
+                .filter_map(|t| DietaryTag::from_str(&t.tag).ok())
+                .collect();
+            if let Some((first, rest)) = ingredients.split_first() {
+                let tags_of = |ingredient: i32| -> BTreeSet<DietaryTag> {
+                    ingredient_tags
+                        .iter()
+                        .filter(|t| t.ingredient_id == ingredient)
+                        .filter_map(|t| DietaryTag::from_str(&t.tag).ok())
+                        .collect()
+                };
+                let shared = rest.iter().fold(tags_of(*first), |shared, ingredient| {
+                    shared
+                        .intersection(&tags_of(*ingredient))
+                        .copied()
+                        .collect()
+                });
+                tags.extend(shared);
+            }
+            if allergens.contains(&Allergen::Gluten) {
+                tags.remove(&DietaryTag::GlutenFree);
+            }
+
+            DietaryProfile {
+                product_id,
+                allergens: allergens.into_iter().collect(),
+                dietary_tags: tags.into_iter().collect(),
+            }
+        })
+        .collect()
+}
+
+/// Which products a menu query keeps: those without any of the excluded allergens that suit
+/// every required diet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DietaryFilter {
+    pub exclude: Vec<Allergen>,
+    pub require: Vec<DietaryTag>,
+}
+
+impl DietaryFilter {
+    /// Parses comma separated allergens to exclude and diets to require, returning the
+    /// values that are not part of the vocabulary as invalid
+    pub fn parse(exclude: Option<&str>, require: Option<&str>) -> Result<Self, Vec<String>> {
+        let split = |value: Option<&str>| -> Vec<String> {
+            value
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+
+        let exclude = parse_values(&split(exclude));
+        let require = parse_values(&split(require));
+        match (exclude, require) {
+            (Ok(exclude), Ok(require)) => Ok(DietaryFilter { exclude, require }),
+            (exclude, require) => Err(exclude
+                .err()
+                .unwrap_or_default()
+                .into_iter()
+                .chain(require.err().unwrap_or_default())
+                .collect()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exclude.is_empty() && self.require.is_empty()
+    }
+
+    pub fn matches(&self, profile: &DietaryProfile) -> bool {
+        !self.exclude.iter().any(|a| profile.allergens.contains(a))
+            && self
+                .require
+                .iter()
+                .all(|t| profile.dietary_tags.contains(t))
+    }
+}
+
+/// The items of an order (product ids) containing an allergen the guest flagged, as
+/// (product_id, allergen) ordered by product
+pub fn allergen_alerts(
+    product_ids: &[i32],
+    profiles: &[DietaryProfile],
+    flagged: &[Allergen],
+) -> Vec<(i32, Allergen)> {
+    let alerts: BTreeSet<(i32, Allergen)> = profiles
+        .iter()
+        .filter(|p| product_ids.contains(&p.product_id))
+        .flat_map(|p| {
+            p.allergens
+                .iter()
+                .filter(|a| flagged.contains(a))
+                .map(move |a| (p.product_id, *a))
+        })
+        .collect();
+
+    alerts.into_iter().collect()
+}
+
+pub struct AllergenService;
+
+impl AllergenService {
+    pub fn new() -> Self {
+        AllergenService
+    }
+
+    /// Works out the allergens and diets of products. Used while listing products and
+    /// creating orders, so no permission is checked.
+    pub async fn get_profiles(
+        &self,
+        product_ids: Vec<i32>,
+    ) -> Result<Vec<DietaryProfile>, AllergenServiceError> {
+        if product_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let repo = AllergenRepo::new();
+        let product_allergens = repo
+            .get_product_allergens(product_ids.clone())
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?;
+        let product_tags = repo
+            .get_product_tags(product_ids.clone())
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?;
+        let recipes = IngredientRepo::new()
+            .get_recipes(product_ids.clone())
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?;
+
+        let ingredient_ids: Vec<i32> = recipes.iter().map(|r| r.ingredient_id).collect();
+        let (ingredient_allergens, ingredient_tags) = if ingredient_ids.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            (
+                repo.get_ingredient_allergens(ingredient_ids.clone())
+                    .await
+                    .map_err(|_| AllergenServiceError::DatabaseError)?,
+                repo.get_ingredient_tags(ingredient_ids)
+                    .await
+                    .map_err(|_| AllergenServiceError::DatabaseError)?,
+            )
+        };
+
+        Ok(dietary_profiles(
+            &product_ids,
+            &product_allergens,
+            &product_tags,
+            &recipes,
+            &ingredient_allergens,
+            &ingredient_tags,
+        ))
+    }
+
+    /// Replaces the allergens and dietary tags set on a product and returns what it has with
+    /// its recipe taken into account (requires WRITE permission or Admin)
+    pub async fn set_product_tags(
+        &self,
+        product_id: i32,
+        request: &DietaryTagsRequest,
+        role_id: i32,
+    ) -> Result<DietaryTagsResponse, AllergenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(AllergenServiceError::PermissionDenied);
+        }
+
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or(AllergenServiceError::ProductNotFound)?;
+
+        let (allergens, tags) = Self::parse_request(request)?;
+        AllergenRepo::new()
+            .replace_for_product(product_id, allergens, tags)
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?;
+
+        let profile = self
+            .get_profiles(vec![product_id])
+            .await?
+            .pop()
+            .ok_or(AllergenServiceError::ProductNotFound)?;
+        Ok(DietaryTagsResponse::from(profile))
+    }
+
+    /// Replaces the allergens and dietary tags of an ingredient, which every product with it
+    /// in its recipe takes on (requires Admin)
+    pub async fn set_ingredient_tags(
+        &self,
+        ingredient_id: i32,
+        request: &DietaryTagsRequest,
+        role_id: i32,
+    ) -> Result<DietaryTagsResponse, AllergenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(AllergenServiceError::PermissionDenied);
+        }
+
+        IngredientRepo::new()
+            .get_by_id(ingredient_id)
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?
+            .ok_or(AllergenServiceError::IngredientNotFound)?;
+
+        let (allergens, tags) = Self::parse_request(request)?;
+        let response = DietaryTagsResponse {
+            allergens: allergens.clone(),
+            dietary_tags: tags.clone(),
+        };
+        AllergenRepo::new()
+            .replace_for_ingredient(ingredient_id, allergens, tags)
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?;
+
+        Ok(response)
+    }
+
+    /// Checks the values of a request against the vocabulary, returning them as stored
+    fn parse_request(
+        request: &DietaryTagsRequest,
+    ) -> Result<(Vec<String>, Vec<String>), AllergenServiceError> {
+        let allergens = parse_values::<Allergen>(&request.allergens);
+        let tags = parse_values::<DietaryTag>(&request.dietary_tags);
+        match (allergens, tags) {
+            (Ok(allergens), Ok(tags)) => Ok((
+                allergens.iter().map(|a| a.as_str().to_string()).collect(),
+                tags.iter().map(|t| t.as_str().to_string()).collect(),
+            )),
+            (allergens, tags) => Err(AllergenServiceError::UnknownValues(
+                allergens
+                    .err()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(tags.err().unwrap_or_default())
+                    .collect(),
+            )),
+        }
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, AllergenServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| AllergenServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for AllergenService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
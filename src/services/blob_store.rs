@@ -0,0 +1,360 @@
+use crate::api::config::Config;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Client, StatusCode, Url};
+use sha2::{Digest, Sha256};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A stored file and its content type
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blob {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlobStoreError {
+    NotFound,
+    InvalidKey,
+    Unavailable,
+}
+
+/// Storage for uploaded files such as product images, addressed by keys like
+/// `products/12/3f2a9c.jpg`
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Stores a file under a key, replacing any file already stored there
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), BlobStoreError>;
+
+    async fn get(&self, key: &str) -> Result<Blob, BlobStoreError>;
+
+    /// Removes the file stored under a key. Removing a missing file succeeds.
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError>;
+}
+
+/// Whether a key can address a file: `/` separated segments of letters, digits, `.`, `-` and
+/// `_`, none of them empty or starting with a dot
+pub fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.split('/').all(|segment| {
+            !segment.is_empty()
+                && !segment.starts_with('.')
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        })
+}
+
+/// Content type of a file by the extension of its key
+pub fn content_type_for_key(key: &str) -> &'static str {
+    match key.rsplit_once('.').map(|(_, ext)| ext.to_lowercase()) {
+        Some(ext) if ext == "jpg" || ext == "jpeg" => "image/jpeg",
+        Some(ext) if ext == "png" => "image/png",
+        Some(ext) if ext == "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Numbers the partial files being written, so concurrent writes never share one
+static PARTIAL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Keeps files in a directory on the local disk, one file per key
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        LocalBlobStore { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, BlobStoreError> {
+        if !is_valid_key(key) {
+            return Err(BlobStoreError::InvalidKey);
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(
+        &self,
+        key: &str,
+        _content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), BlobStoreError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                tracing::error!("Failed to create {}: {}", parent.display(), e);
+                BlobStoreError::Unavailable
+            })?;
+        }
+
+        // Written next to the file and renamed over it, so readers never see part of a file
+        let mut partial_name = path.file_name().unwrap_or_default().to_os_string();
+        partial_name.push(format!(
+            ".partial-{}-{}",
+            std::process::id(),
+            PARTIAL_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let partial = path.with_file_name(partial_name);
+        let written = match tokio::fs::write(&partial, bytes).await {
+            Ok(()) => tokio::fs::rename(&partial, &path).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = written {
+            tracing::error!("Failed to write {}: {}", path.display(), e);
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(BlobStoreError::Unavailable);
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, BlobStoreError> {
+        let path = self.path(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Blob {
+                content_type: content_type_for_key(key).to_string(),
+                bytes,
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(BlobStoreError::NotFound),
+            Err(e) => {
+                tracing::error!("Failed to read {}: {}", path.display(), e);
+                Err(BlobStoreError::Unavailable)
+            }
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        let path = self.path(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => {
+                tracing::error!("Failed to remove {}: {}", path.display(), e);
+                Err(BlobStoreError::Unavailable)
+            }
+        }
+    }
+}
+
+/// Keeps files in a bucket of an S3-compatible store, such as S3 itself or a local MinIO.
+/// Requests address the bucket in the path and are signed with AWS Signature Version 4.
+pub struct S3BlobStore {
+    client: Client,
+    endpoint: String,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+}
+
+impl S3BlobStore {
+    pub fn new(
+        endpoint: &str,
+        bucket: &str,
+        region: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Self {
+        S3BlobStore {
+            client: Client::new(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            bucket: bucket.to_string(),
+            region: region.to_string(),
+            access_key: access_key.to_string(),
+            secret_key: secret_key.to_string(),
+        }
+    }
+
+    /// The `Authorization` header signing a request. `headers` are the lowercase names and
+    /// values of the headers to sign, which must include `host`, `x-amz-content-sha256` and
+    /// `x-amz-date`; `amz_date` is the time of the request such as `20130524T000000Z`.
+    pub fn authorization(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &[(&str, &str)],
+        payload_hash: &str,
+        amz_date: &str,
+    ) -> String {
+        let mut headers = headers.to_vec();
+        headers.sort();
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "{}\n{}\n\n{}\n{}\n{}",
+            method, path, canonical_headers, signed_headers, payload_hash
+        );
+
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let mut key = hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), date);
+        for part in [self.region.as_str(), "s3", "aws4_request"] {
+            key = hmac_sha256(&key, part);
+        }
+        let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{},SignedHeaders={},Signature={}",
+            self.access_key, scope, signed_headers, signature
+        )
+    }
+
+    /// Sends a signed request for the file under a key
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        content_type: Option<&str>,
+        bytes: Vec<u8>,
+    ) -> Result<reqwest::Response, BlobStoreError> {
+        if !is_valid_key(key) {
+            return Err(BlobStoreError::InvalidKey);
+        }
+
+        let path = format!("/{}/{}", self.bucket, key);
+        let url = Url::parse(&format!("{}{}", self.endpoint, path)).map_err(|e| {
+            tracing::error!("Invalid S3 endpoint {}: {}", self.endpoint, e);
+            BlobStoreError::Unavailable
+        })?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(BlobStoreError::Unavailable),
+        };
+        let payload_hash = hex::encode(Sha256::digest(&bytes));
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let authorization = self.authorization(
+            method.as_str(),
+            &path,
+            &[
+                ("host", &host),
+                ("x-amz-content-sha256", &payload_hash),
+                ("x-amz-date", &amz_date),
+            ],
+            &payload_hash,
+            &amz_date,
+        );
+
+        let mut request = self
+            .client
+            .request(method, url)
+            .header("x-amz-content-sha256", &payload_hash)
+            .header("x-amz-date", &amz_date)
+            .header("authorization", authorization);
+        if let Some(content_type) = content_type {
+            request = request.header(CONTENT_TYPE, content_type);
+        }
+
+        request.body(bytes).send().await.map_err(|e| {
+            tracing::error!("S3 request for {} failed: {}", key, e);
+            BlobStoreError::Unavailable
+        })
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), BlobStoreError> {
+        let response = self
+            .send(reqwest::Method::PUT, key, Some(content_type), bytes)
+            .await?;
+        if !response.status().is_success() {
+            tracing::error!("S3 refused to store {}: {}", key, response.status());
+            return Err(BlobStoreError::Unavailable);
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, BlobStoreError> {
+        let response = self
+            .send(reqwest::Method::GET, key, None, Vec::new())
+            .await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => return Err(BlobStoreError::NotFound),
+            status if !status.is_success() => {
+                tracing::error!("S3 refused to read {}: {}", key, status);
+                return Err(BlobStoreError::Unavailable);
+            }
+            _ => {}
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(content_type_for_key(key))
+            .to_string();
+        let bytes = response.bytes().await.map_err(|e| {
+            tracing::error!("Failed to read {} from S3: {}", key, e);
+            BlobStoreError::Unavailable
+        })?;
+
+        Ok(Blob {
+            content_type,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        let response = self
+            .send(reqwest::Method::DELETE, key, None, Vec::new())
+            .await?;
+        if !response.status().is_success() && response.status() != StatusCode::NOT_FOUND {
+            tracing::error!("S3 refused to remove {}: {}", key, response.status());
+            return Err(BlobStoreError::Unavailable);
+        }
+        Ok(())
+    }
+}
+
+/// Creates the blob store named in the config (`local` or `s3`)
+pub fn blob_store(config: &Config) -> Option<Box<dyn BlobStore>> {
+    match config.blob_store.to_lowercase().as_str() {
+        "local" => Some(Box::new(LocalBlobStore::new(&config.blob_dir))),
+        "s3" => Some(Box::new(S3BlobStore::new(
+            &config.s3_endpoint,
+            &config.s3_bucket,
+            &config.s3_region,
+            &config.s3_access_key,
+            &config.s3_secret_key,
+        ))),
+        _ => None,
+    }
+}
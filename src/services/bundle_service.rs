@@ -0,0 +1,378 @@
+use crate::api::request::BundleRequest;
+use crate::api::response::{BundleChoiceResponse, BundleResponse, BundleSlotResponse};
+use crate::data::models::bundle::{
+    BundleSlot, BundleSlotChoice, NewBundleSlot, NewBundleSlotChoice, OrderBundleItem,
+};
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::bundle_repo::BundleRepo;
+use crate::data::repos::implementors::category_repo::CategoryRepo;
+use crate::data::repos::implementors::product_category_repo::ProductCategoryRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::BundleServiceError;
+use bigdecimal::{BigDecimal, Signed, Zero};
+use std::collections::{HashMap, HashSet};
+
+/// Whether slots can make up the bundle `product_id`: every slot is named, takes at least
+/// one product and has choices, and every choice is either a product other than the bundle
+/// or a category, with an upcharge that is not negative
+pub fn is_valid_bundle(product_id: i32, request: &BundleRequest) -> bool {
+    request.slots.iter().all(|slot| {
+        !slot.name.trim().is_empty()
+            && slot.quantity.unwrap_or(1) > 0
+            && !slot.choices.is_empty()
+            && slot.choices.iter().all(|choice| {
+                choice.product_id.is_some() != choice.category_id.is_some()
+                    && choice.product_id != Some(product_id)
+                    && choice.upcharge.as_ref().is_none_or(|u| !u.is_negative())
+            })
+    })
+}
+
+/// What picking a product for a slot adds to the bundle price: the upcharge of the product
+/// when it is a choice of its own, and otherwise the lowest upcharge of the categories it is
+/// chosen through. None when the product is not a choice of the slot.
+fn pick_upcharge(
+    slot_id: i32,
+    product_id: i32,
+    choices: &[BundleSlotChoice],
+    product_categories: &[(i32, i32)], // product_id, category_id
+) -> Option<BigDecimal> {
+    let choices: Vec<&BundleSlotChoice> = choices.iter().filter(|c| c.slot_id == slot_id).collect();
+
+    choices
+        .iter()
+        .find(|c| c.product_id == Some(product_id))
+        .map(|c| c.upcharge.clone())
+        .or_else(|| {
+            choices
+                .iter()
+                .filter(|c| {
+                    c.category_id.is_some_and(|category| {
+                        product_categories.contains(&(product_id, category))
+                    })
+                })
+                .map(|c| c.upcharge.clone())
+                .min()
+        })
+}
+
+/// Works out the products picked for a bundle from its slots and selections (slot_id,
+/// product_id), merged per product with what they add to the price of one bundle.
+///
+/// None unless every slot of the bundle gets exactly as many picks as it takes, each one
+/// of its choices, and every selection is for one of its slots.
+pub fn choose_items(
+    bundle_id: i32,
+    slots: &[BundleSlot],
+    choices: &[BundleSlotChoice],
+    product_categories: &[(i32, i32)], // product_id, category_id
+    selections: &[(i32, i32)],
+) -> Option<Vec<OrderBundleItem>> {
+    let slots: Vec<&BundleSlot> = slots.iter().filter(|s| s.bundle_id == bundle_id).collect();
+    if slots.is_empty()
+        || selections
+            .iter()
+            .any(|(slot_id, _)| !slots.iter().any(|s| s.slot_id == *slot_id))
+    {
+        return None;
+    }
+
+    let mut items: Vec<OrderBundleItem> = Vec::new();
+    for slot in slots {
+        let picks: Vec<i32> = selections
+            .iter()
+            .filter(|(slot_id, _)| *slot_id == slot.slot_id)
+            .map(|(_, product_id)| *product_id)
+            .collect();
+        if picks.len() != slot.quantity as usize {
+            return None;
+        }
+
+        for product_id in picks {
+            let upcharge = pick_upcharge(slot.slot_id, product_id, choices, product_categories)?;
+            match items.iter_mut().find(|item| item.product_id == product_id) {
+                Some(item) => {
+                    item.quantity += 1;
+                    item.upcharge += upcharge;
+                }
+                None => items.push(OrderBundleItem {
+                    order_id: 0,
+                    bundle_id,
+                    product_id,
+                    quantity: 1,
+                    upcharge,
+                }),
+            }
+        }
+    }
+
+    Some(items)
+}
+
+/// Replaces the bundles among order lines (product_id, quantity) with the products picked
+/// for them, so each product is prepared and taken out of stock on its own
+pub fn expand_lines(lines: &[(i32, i32)], bundle_items: &[OrderBundleItem]) -> Vec<(i32, i32)> {
+    lines
+        .iter()
+        .flat_map(|&(product_id, quantity)| {
+            let picked: Vec<(i32, i32)> = bundle_items
+                .iter()
+                .filter(|item| item.bundle_id == product_id)
+                .map(|item| (item.product_id, item.quantity * quantity))
+                .collect();
+            if picked.is_empty() {
+                vec![(product_id, quantity)]
+            } else {
+                picked
+            }
+        })
+        .collect()
+}
+
+pub struct BundleService;
+
+impl BundleService {
+    pub fn new() -> Self {
+        BundleService
+    }
+
+    /// Gets the slots of a product and their choices, none when it is not a bundle
+    /// (requires READ permission or Admin)
+    pub async fn get_bundle(
+        &self,
+        product_id: i32,
+        role_id: i32,
+    ) -> Result<BundleResponse, BundleServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(BundleServiceError::PermissionDenied);
+        }
+
+        self.bundle_response(product_id).await
+    }
+
+    /// Replaces the slots of a bundle product and their choices (requires Admin). Choices
+    /// cannot be bundles themselves.
+    pub async fn set_bundle(
+        &self,
+        product_id: i32,
+        request: &BundleRequest,
+        role_id: i32,
+    ) -> Result<BundleResponse, BundleServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(BundleServiceError::PermissionDenied);
+        }
+
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or(BundleServiceError::ProductNotFound)?;
+
+        if !is_valid_bundle(product_id, request) {
+            return Err(BundleServiceError::InvalidBundle);
+        }
+
+        let choices = request.slots.iter().flat_map(|slot| &slot.choices);
+        let product_ids: HashSet<i32> = choices.clone().filter_map(|c| c.product_id).collect();
+        let category_ids: HashSet<i32> = choices.filter_map(|c| c.category_id).collect();
+
+        let products: HashSet<i32> = ProductRepo::new()
+            .get_active()
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| p.product_id)
+            .collect();
+        let categories: HashSet<i32> = CategoryRepo::new()
+            .get_active()
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| c.category_id)
+            .collect();
+        if !product_ids.is_subset(&products) || !category_ids.is_subset(&categories) {
+            return Err(BundleServiceError::InvalidBundle);
+        }
+
+        let repo = BundleRepo::new();
+        let nested = repo
+            .get_slots(product_ids.into_iter().collect())
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+        if !nested.is_empty() {
+            return Err(BundleServiceError::InvalidBundle);
+        }
+
+        let slots = request
+            .slots
+            .iter()
+            .enumerate()
+            .map(|(position, slot)| {
+                (
+                    NewBundleSlot {
+                        bundle_id: product_id,
+                        name: slot.name.trim().to_string(),
+                        quantity: slot.quantity.unwrap_or(1),
+                        sort_order: position as i32,
+                    },
+                    slot.choices
+                        .iter()
+                        .map(|choice| NewBundleSlotChoice {
+                            slot_id: 0,
+                            product_id: choice.product_id,
+                            category_id: choice.category_id,
+                            upcharge: choice.upcharge.clone().unwrap_or_else(BigDecimal::zero),
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        repo.replace_slots(product_id, slots)
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+
+        self.bundle_response(product_id).await
+    }
+
+    /// Works out the products picked for the bundles among order lines (product_id,
+    /// quantity) from the selections (slot_id, product_id) given per bundle. Returns the
+    /// bundles whose selections are missing or do not fit their slots, and selections for
+    /// products that are not bundles ordered, as invalid. Called while creating an order,
+    /// so no permission is checked.
+    pub async fn choose_for_order(
+        &self,
+        lines: &[(i32, i32)],
+        selections: &HashMap<i32, Vec<(i32, i32)>>,
+    ) -> Result<Vec<OrderBundleItem>, BundleServiceError> {
+        let repo = BundleRepo::new();
+        let slots = repo
+            .get_slots(lines.iter().map(|(product_id, _)| *product_id).collect())
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+
+        let mut bundle_ids: Vec<i32> = slots.iter().map(|s| s.bundle_id).collect();
+        bundle_ids.sort_unstable();
+        bundle_ids.dedup();
+
+        let mut invalid: Vec<i32> = selections
+            .keys()
+            .filter(|id| !bundle_ids.contains(id))
+            .copied()
+            .collect();
+        if bundle_ids.is_empty() && invalid.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let choices = repo
+            .get_choices(slots.iter().map(|s| s.slot_id).collect())
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+        let picked: Vec<i32> = selections
+            .values()
+            .flatten()
+            .map(|(_, product_id)| *product_id)
+            .collect();
+        let product_categories = ProductCategoryRepo::new()
+            .get_category_ids_by_product_ids(picked.clone())
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+        // A bundle cannot be picked for another one, even through a category
+        let picked_bundles: HashSet<i32> = repo
+            .get_slots(picked)
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?
+            .into_iter()
+            .map(|s| s.bundle_id)
+            .collect();
+
+        let mut items = Vec::new();
+        for bundle_id in bundle_ids {
+            let selected = selections.get(&bundle_id).map(Vec::as_slice).unwrap_or(&[]);
+            let chosen = choose_items(bundle_id, &slots, &choices, &product_categories, selected)
+                .filter(|_| {
+                    selected
+                        .iter()
+                        .all(|(_, product_id)| !picked_bundles.contains(product_id))
+                });
+            match chosen {
+                Some(chosen) => items.extend(chosen),
+                None => invalid.push(bundle_id),
+            }
+        }
+
+        if !invalid.is_empty() {
+            invalid.sort_unstable();
+            return Err(BundleServiceError::InvalidSelection(invalid));
+        }
+        Ok(items)
+    }
+
+    async fn bundle_response(&self, product_id: i32) -> Result<BundleResponse, BundleServiceError> {
+        let product = ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?
+            .ok_or(BundleServiceError::ProductNotFound)?;
+
+        let repo = BundleRepo::new();
+        let slots = repo
+            .get_slots(vec![product_id])
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+        let choices = repo
+            .get_choices(slots.iter().map(|s| s.slot_id).collect())
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?;
+
+        Ok(BundleResponse {
+            product_id,
+            name: product.name,
+            price: product.price,
+            slots: slots
+                .into_iter()
+                .map(|slot| BundleSlotResponse {
+                    choices: choices
+                        .iter()
+                        .filter(|c| c.slot_id == slot.slot_id)
+                        .cloned()
+                        .map(BundleChoiceResponse::from)
+                        .collect(),
+                    slot_id: slot.slot_id,
+                    name: slot.name,
+                    quantity: slot.quantity,
+                })
+                .collect(),
+        })
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, BundleServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| BundleServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for BundleService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
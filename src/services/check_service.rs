@@ -0,0 +1,380 @@
+use crate::api::request::SplitOrderRequest;
+use crate::api::response::CheckResponse;
+use crate::data::models::order::Order;
+use crate::data::models::order_check::NewOrderCheck;
+use crate::data::models::order_product::OrderProduct;
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::order_check_repo::OrderCheckRepo;
+use crate::data::repos::implementors::order_repo::OrderRepo;
+use crate::data::repos::implementors::payment_repo::PaymentRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::CheckServiceError;
+use crate::services::order_service::OrderStatus;
+use crate::services::payment_service::{PaymentService, PaymentStatus};
+use crate::services::pricing_service::{
+    PriceBreakdown, PriceComponents, PricingEngine, RoundingRule,
+};
+use bigdecimal::{BigDecimal, Zero};
+use std::collections::HashSet;
+
+/// How an order is divided into checks
+#[derive(Debug, Clone, PartialEq)]
+pub enum SplitMode {
+    /// Product ids of the order lines on each check
+    Items(Vec<Vec<i32>>),
+    /// Number of equal checks
+    Equal(usize),
+    /// Relative share of each check
+    Shares(Vec<BigDecimal>),
+}
+
+impl TryFrom<&SplitOrderRequest> for SplitMode {
+    type Error = CheckServiceError;
+
+    fn try_from(request: &SplitOrderRequest) -> Result<Self, Self::Error> {
+        let mode = match request.mode.to_lowercase().as_str() {
+            "items" => SplitMode::Items(request.items.clone().unwrap_or_default()),
+            "equal" => SplitMode::Equal(request.count.unwrap_or(0).max(0) as usize),
+            "shares" => SplitMode::Shares(request.shares.clone().unwrap_or_default()),
+            _ => return Err(CheckServiceError::InvalidSplit),
+        };
+
+        let valid = match &mode {
+            SplitMode::Items(checks) => {
+                checks.len() >= 2 && checks.iter().all(|items| !items.is_empty())
+            }
+            SplitMode::Equal(count) => *count >= 2,
+            SplitMode::Shares(shares) => {
+                shares.len() >= 2 && shares.iter().all(|share| *share > BigDecimal::zero())
+            }
+        };
+
+        if valid {
+            Ok(mode)
+        } else {
+            Err(CheckServiceError::InvalidSplit)
+        }
+    }
+}
+
+/// Divides the totals of an order between checks.
+///
+/// Every component (subtotal, discount, inclusive and exclusive tax, service charge) is
+/// allocated separately in proportion to the matching component of each check's weights, so
+/// an item split charges each check the tax of its own items. Allocation works in whole
+/// rounding increments, which makes every component, and therefore the totals, of the checks
+/// add up exactly to the order's. Components with no weight fall back to the subtotal weights.
+pub fn split_components(
+    order: &PriceComponents,
+    weights: &[PriceComponents],
+    rounding: &RoundingRule,
+) -> Vec<PriceBreakdown> {
+    let subtotal_weights: Vec<BigDecimal> = weights.iter().map(|w| w.subtotal.clone()).collect();
+
+    let allocate = |amount: &BigDecimal, pick: fn(&PriceComponents) -> &BigDecimal| {
+        let component_weights: Vec<BigDecimal> = weights.iter().map(|w| pick(w).clone()).collect();
+        if component_weights.iter().all(|w| w.is_zero()) {
+            rounding.allocate(amount, &subtotal_weights)
+        } else {
+            rounding.allocate(amount, &component_weights)
+        }
+    };
+
+    let subtotals = allocate(&order.subtotal, |c| &c.subtotal);
+    let discounts = allocate(&order.discount, |c| &c.discount);
+    let inclusive_taxes = allocate(&order.inclusive_tax, |c| &c.inclusive_tax);
+    let exclusive_taxes = allocate(&order.exclusive_tax, |c| &c.exclusive_tax);
+    let service_charges = allocate(&order.service_charge, |c| &c.service_charge);
+
+    (0..weights.len())
+        .map(|i| {
+            PriceBreakdown::from(PriceComponents {
+                subtotal: subtotals[i].clone(),
+                discount: discounts[i].clone(),
+                inclusive_tax: inclusive_taxes[i].clone(),
+                exclusive_tax: exclusive_taxes[i].clone(),
+                service_charge: service_charges[i].clone(),
+            })
+        })
+        .collect()
+}
+
+/// Divides an order into the checks to save: its totals as `split_components` does, and its
+/// auto gratuity in proportion to the discounted subtotal of each check, so the amounts due
+/// on the checks add up exactly to the amount due on the order.
+pub fn split_order_checks(
+    order: &Order,
+    weights: &[PriceComponents],
+    rounding: &RoundingRule,
+) -> Vec<NewOrderCheck> {
+    let breakdowns = split_components(&PriceComponents::from(order), weights, rounding);
+    let gratuity_weights: Vec<BigDecimal> = breakdowns
+        .iter()
+        .map(|b| &b.subtotal - &b.discount_amount)
+        .collect();
+    let gratuities = rounding.allocate(&order.gratuity_amount, &gratuity_weights);
+
+    breakdowns
+        .into_iter()
+        .zip(gratuities)
+        .enumerate()
+        .map(|(i, (breakdown, gratuity_amount))| NewOrderCheck {
+            order_id: order.order_id,
+            check_number: i as i32 + 1,
+            subtotal: breakdown.subtotal,
+            discount_amount: breakdown.discount_amount,
+            tax_amount: breakdown.tax_amount,
+            service_charge: breakdown.service_charge,
+            total_amount: breakdown.total_amount,
+            gratuity_amount,
+        })
+        .collect()
+}
+
+pub struct CheckService;
+
+impl CheckService {
+    pub fn new() -> Self {
+        CheckService
+    }
+
+    /// Gets the checks of an order with how much of each has been paid
+    /// (requires READ permission or Admin)
+    pub async fn get_checks(
+        &self,
+        order_id: i32,
+        role_id: i32,
+    ) -> Result<Vec<CheckResponse>, CheckServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(CheckServiceError::PermissionDenied);
+        }
+
+        OrderRepo::new()
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?
+            .ok_or(CheckServiceError::OrderNotFound)?;
+
+        let repo = OrderCheckRepo::new();
+        let checks = repo
+            .get_by_order_id(order_id)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?;
+
+        if checks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let items = repo
+            .get_items(checks.iter().map(|c| c.check_id).collect())
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?;
+        let mut summaries = PaymentService::new()
+            .get_check_summaries(order_id, &checks)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?;
+
+        Ok(checks
+            .into_iter()
+            .map(|check| {
+                let mut response = CheckResponse::from(check);
+                response.product_ids = items
+                    .iter()
+                    .filter(|item| item.check_id == response.check_id)
+                    .map(|item| item.product_id)
+                    .collect();
+                if let Some(summary) = summaries.remove(&response.check_id) {
+                    response.amount_paid = summary.amount_paid;
+                    response.balance_due = summary.balance_due;
+                }
+                response
+            })
+            .collect())
+    }
+
+    /// Splits an order into checks, replacing any previous split
+    /// (requires WRITE permission or Admin)
+    pub async fn split_order(
+        &self,
+        order_id: i32,
+        role_id: i32,
+        request: &SplitOrderRequest,
+    ) -> Result<Vec<CheckResponse>, CheckServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(CheckServiceError::PermissionDenied);
+        }
+
+        let mode = SplitMode::try_from(request)?;
+
+        let order_repo = OrderRepo::new();
+        let order = order_repo
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?
+            .ok_or(CheckServiceError::OrderNotFound)?;
+
+        if order.status.as_deref() == Some(OrderStatus::Cancelled.as_str()) {
+            return Err(CheckServiceError::OrderNotSplittable);
+        }
+
+        self.ensure_no_check_payments(order_id).await?;
+
+        let engine = PricingEngine::from_config();
+
+        let (weights, assignments) = match mode {
+            SplitMode::Equal(count) => (
+                vec![PriceComponents::uniform(BigDecimal::from(1)); count],
+                vec![Vec::new(); count],
+            ),
+            SplitMode::Shares(shares) => (
+                shares.into_iter().map(PriceComponents::uniform).collect(),
+                Vec::new(),
+            ),
+            SplitMode::Items(checks) => {
+                let products = order_repo
+                    .attach_products(vec![order.clone()])
+                    .await
+                    .map_err(|_| CheckServiceError::DatabaseError)?
+                    .into_iter()
+                    .next()
+                    .map(|(_, products)| products)
+                    .unwrap_or_default();
+                let lines: Vec<OrderProduct> = products.into_iter().map(|(op, _)| op).collect();
+
+                let weights = self.item_weights(&engine, &order, &lines, &checks).await?;
+                (weights, checks)
+            }
+        };
+
+        let new_checks: Vec<(NewOrderCheck, Vec<i32>)> =
+            split_order_checks(&order, &weights, &engine.rounding)
+                .into_iter()
+                .enumerate()
+                .map(|(i, check)| (check, assignments.get(i).cloned().unwrap_or_default()))
+                .collect();
+
+        OrderCheckRepo::new()
+            .replace_for_order(order_id, new_checks)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?;
+
+        self.get_checks(order_id, role_id).await
+    }
+
+    /// Removes the split of an order (requires WRITE permission or Admin)
+    pub async fn remove_checks(
+        &self,
+        order_id: i32,
+        role_id: i32,
+    ) -> Result<(), CheckServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(CheckServiceError::PermissionDenied);
+        }
+
+        OrderRepo::new()
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?
+            .ok_or(CheckServiceError::OrderNotFound)?;
+
+        self.ensure_no_check_payments(order_id).await?;
+
+        OrderCheckRepo::new()
+            .delete_by_order_id(order_id)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)
+    }
+
+    /// Prices the lines of each check to weigh every component by what the check contains.
+    /// Every order line must be on exactly one check. The order discount is spread over the
+    /// lines in proportion to their price, as promotions are.
+    async fn item_weights(
+        &self,
+        engine: &PricingEngine,
+        order: &Order,
+        lines: &[OrderProduct],
+        checks: &[Vec<i32>],
+    ) -> Result<Vec<PriceComponents>, CheckServiceError> {
+        let order_product_ids: HashSet<i32> = lines.iter().map(|l| l.product_id).collect();
+        let assigned: Vec<i32> = checks.iter().flatten().copied().collect();
+        let assigned_ids: HashSet<i32> = assigned.iter().copied().collect();
+
+        if assigned.len() != assigned_ids.len() || assigned_ids != order_product_ids {
+            return Err(CheckServiceError::InvalidSplit);
+        }
+
+        let items: Vec<(i32, i32, BigDecimal)> = lines
+            .iter()
+            .map(|l| (l.product_id, l.quantity, l.unit_price.clone()))
+            .collect();
+        let mut priced = engine
+            .build_lines(&items)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?;
+
+        let gross_total: BigDecimal = priced.iter().map(|line| line.gross()).sum();
+        if gross_total > BigDecimal::zero() {
+            for line in priced.iter_mut() {
+                line.discount = &order.discount_amount * line.gross() / &gross_total;
+            }
+        }
+
+        Ok(checks
+            .iter()
+            .map(|product_ids| {
+                let check_lines: Vec<_> = priced
+                    .iter()
+                    .filter(|line| product_ids.contains(&line.product_id))
+                    .cloned()
+                    .collect();
+                engine.components(&check_lines)
+            })
+            .collect())
+    }
+
+    async fn ensure_no_check_payments(&self, order_id: i32) -> Result<(), CheckServiceError> {
+        let payments = PaymentRepo::new()
+            .get_by_order_ids(vec![order_id])
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?;
+
+        if payments
+            .iter()
+            .any(|p| p.check_id.is_some() && p.status != PaymentStatus::Failed.as_str())
+        {
+            return Err(CheckServiceError::ChecksHavePayments);
+        }
+
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, CheckServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| CheckServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for CheckService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
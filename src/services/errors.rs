@@ -29,6 +29,23 @@ pub enum OrderServiceError {
     OrderDeletionFailed,
     PermissionDenied,
     InvalidStatusTransition,
+    InvalidPromoCode,
+    OrderNotAdjustable,
+    PaymentNotFound,
+    InvalidReasonCode,
+    InvalidVoid,
+    InvalidRefundAmount,
+    RefundDeclined,
+    GatewayUnavailable,
+    InvalidOrderType,
+    TableNotSeated,
+    InvalidOrderQuery,
+    InvalidSchedule,
+    ProductsUnavailable(Vec<i32>),
+    ProductsArchived(Vec<i32>),
+    ProductsOffMenu(Vec<i32>),
+    InsufficientStock(Vec<i32>),
+    InvalidBundleSelection(Vec<i32>),
     DatabaseError,
 }
 
@@ -43,6 +60,38 @@ impl std::fmt::Display for OrderServiceError {
             OrderServiceError::OrderDeletionFailed => write!(f, "Order deletion failed"),
             OrderServiceError::PermissionDenied => write!(f, "Permission denied"),
             OrderServiceError::InvalidStatusTransition => write!(f, "Invalid status transition"),
+            OrderServiceError::InvalidPromoCode => write!(f, "Invalid promo code"),
+            OrderServiceError::OrderNotAdjustable => write!(f, "Order cannot be adjusted"),
+            OrderServiceError::PaymentNotFound => write!(f, "Payment not found"),
+            OrderServiceError::InvalidReasonCode => write!(f, "Invalid reason code"),
+            OrderServiceError::InvalidVoid => write!(f, "Invalid void"),
+            OrderServiceError::InvalidRefundAmount => write!(f, "Invalid refund amount"),
+            OrderServiceError::RefundDeclined => write!(f, "Refund declined"),
+            OrderServiceError::GatewayUnavailable => write!(f, "Payment gateway unavailable"),
+            OrderServiceError::InvalidOrderType => write!(f, "Invalid order type"),
+            OrderServiceError::TableNotSeated => write!(f, "Table has no open session"),
+            OrderServiceError::InvalidOrderQuery => write!(f, "Invalid order number or date"),
+            OrderServiceError::InvalidSchedule => write!(f, "Invalid scheduled time"),
+            OrderServiceError::ProductsUnavailable(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Products unavailable: {}", ids.join(", "))
+            }
+            OrderServiceError::ProductsArchived(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Products archived: {}", ids.join(", "))
+            }
+            OrderServiceError::ProductsOffMenu(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Products not on a menu served now: {}", ids.join(", "))
+            }
+            OrderServiceError::InsufficientStock(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Insufficient stock of ingredients: {}", ids.join(", "))
+            }
+            OrderServiceError::InvalidBundleSelection(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Invalid selections for bundles: {}", ids.join(", "))
+            }
             OrderServiceError::DatabaseError => write!(f, "Database error"),
         }
     }
@@ -55,6 +104,9 @@ pub enum ProductServiceError {
     ProductCreationFailed,
     ProductUpdateFailed,
     ProductDeletionFailed,
+    InvalidPrepTime,
+    InvalidAvailability,
+    InvalidFilter(Vec<String>),
     PermissionDenied,
     DatabaseError,
 }
@@ -69,6 +121,11 @@ impl std::fmt::Display for ProductServiceError {
             ProductServiceError::ProductCreationFailed => write!(f, "Product creation failed"),
             ProductServiceError::ProductUpdateFailed => write!(f, "Product update failed"),
             ProductServiceError::ProductDeletionFailed => write!(f, "Product deletion failed"),
+            ProductServiceError::InvalidPrepTime => write!(f, "Invalid prep time"),
+            ProductServiceError::InvalidAvailability => write!(f, "Invalid availability"),
+            ProductServiceError::InvalidFilter(values) => {
+                write!(f, "Unknown allergens or diets: {}", values.join(", "))
+            }
             ProductServiceError::PermissionDenied => write!(f, "Permission denied"),
             ProductServiceError::DatabaseError => write!(f, "Database error"),
         }
@@ -84,6 +141,7 @@ pub enum ProductCategoryServiceError {
     PermissionDenied,
     DatabaseError,
     ProductNotFound,
+    InvalidParent,
 }
 
 impl std::error::Error for ProductCategoryServiceError {}
@@ -104,6 +162,538 @@ impl std::fmt::Display for ProductCategoryServiceError {
             ProductCategoryServiceError::PermissionDenied => write!(f, "Permission denied"),
             ProductCategoryServiceError::DatabaseError => write!(f, "Database error"),
             ProductCategoryServiceError::ProductNotFound => write!(f, "Product not found"),
+            ProductCategoryServiceError::InvalidParent => write!(f, "Invalid parent category"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PricingServiceError {
+    TaxRateNotFound,
+    InvalidTaxRate,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for PricingServiceError {}
+
+impl std::fmt::Display for PricingServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PricingServiceError::TaxRateNotFound => write!(f, "Tax rate not found"),
+            PricingServiceError::InvalidTaxRate => write!(f, "Invalid tax rate"),
+            PricingServiceError::PermissionDenied => write!(f, "Permission denied"),
+            PricingServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PromotionServiceError {
+    PromotionNotFound,
+    InvalidPromotion,
+    InvalidPromoCode,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for PromotionServiceError {}
+
+impl std::fmt::Display for PromotionServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PromotionServiceError::PromotionNotFound => write!(f, "Promotion not found"),
+            PromotionServiceError::InvalidPromotion => write!(f, "Invalid promotion"),
+            PromotionServiceError::InvalidPromoCode => write!(f, "Invalid promo code"),
+            PromotionServiceError::PermissionDenied => write!(f, "Permission denied"),
+            PromotionServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PaymentServiceError {
+    OrderNotFound,
+    CheckNotFound,
+    OrderNotPayable,
+    InvalidAmount,
+    InvalidPaymentMethod,
+    PaymentDeclined,
+    GatewayUnavailable,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for PaymentServiceError {}
+
+impl std::fmt::Display for PaymentServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentServiceError::OrderNotFound => write!(f, "Order not found"),
+            PaymentServiceError::CheckNotFound => write!(f, "Check not found"),
+            PaymentServiceError::OrderNotPayable => write!(f, "Order cannot be paid"),
+            PaymentServiceError::InvalidAmount => write!(f, "Invalid payment amount"),
+            PaymentServiceError::InvalidPaymentMethod => write!(f, "Invalid payment method"),
+            PaymentServiceError::PaymentDeclined => write!(f, "Payment declined"),
+            PaymentServiceError::GatewayUnavailable => write!(f, "Payment gateway unavailable"),
+            PaymentServiceError::PermissionDenied => write!(f, "Permission denied"),
+            PaymentServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CheckServiceError {
+    OrderNotFound,
+    OrderNotSplittable,
+    InvalidSplit,
+    ChecksHavePayments,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for CheckServiceError {}
+
+impl std::fmt::Display for CheckServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckServiceError::OrderNotFound => write!(f, "Order not found"),
+            CheckServiceError::OrderNotSplittable => write!(f, "Order cannot be split"),
+            CheckServiceError::InvalidSplit => write!(f, "Invalid split"),
+            CheckServiceError::ChecksHavePayments => write!(f, "Checks already have payments"),
+            CheckServiceError::PermissionDenied => write!(f, "Permission denied"),
+            CheckServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TipServiceError {
+    OrderNotFound,
+    UserNotFound,
+    InvalidDateRange,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for TipServiceError {}
+
+impl std::fmt::Display for TipServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TipServiceError::OrderNotFound => write!(f, "Order not found"),
+            TipServiceError::UserNotFound => write!(f, "User not found"),
+            TipServiceError::InvalidDateRange => write!(f, "Invalid date range"),
+            TipServiceError::PermissionDenied => write!(f, "Permission denied"),
+            TipServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TableServiceError {
+    AreaNotFound,
+    AreaNotEmpty,
+    TableNotFound,
+    DuplicateName,
+    InvalidTable,
+    InvalidStatus,
+    TableNotAvailable,
+    NoOpenSession,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for TableServiceError {}
+
+impl std::fmt::Display for TableServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableServiceError::AreaNotFound => write!(f, "Dining area not found"),
+            TableServiceError::AreaNotEmpty => write!(f, "Dining area still has tables"),
+            TableServiceError::TableNotFound => write!(f, "Table not found"),
+            TableServiceError::DuplicateName => write!(f, "Name already in use"),
+            TableServiceError::InvalidTable => write!(f, "Invalid table"),
+            TableServiceError::InvalidStatus => write!(f, "Invalid table status"),
+            TableServiceError::TableNotAvailable => write!(f, "Table is not available"),
+            TableServiceError::NoOpenSession => write!(f, "Table has no open session"),
+            TableServiceError::PermissionDenied => write!(f, "Permission denied"),
+            TableServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReservationServiceError {
+    ReservationNotFound,
+    EntryNotFound,
+    InvalidReservation,
+    InvalidStatus,
+    NoTableAvailable,
+    TableRequired,
+    TableNotFound,
+    TableNotAvailable,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for ReservationServiceError {}
+
+impl std::fmt::Display for ReservationServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReservationServiceError::ReservationNotFound => write!(f, "Reservation not found"),
+            ReservationServiceError::EntryNotFound => write!(f, "Waitlist entry not found"),
+            ReservationServiceError::InvalidReservation => write!(f, "Invalid reservation"),
+            ReservationServiceError::InvalidStatus => write!(f, "Invalid reservation status"),
+            ReservationServiceError::NoTableAvailable => write!(f, "No table available"),
+            ReservationServiceError::TableRequired => write!(f, "Table is required"),
+            ReservationServiceError::TableNotFound => write!(f, "Table not found"),
+            ReservationServiceError::TableNotAvailable => write!(f, "Table is not available"),
+            ReservationServiceError::PermissionDenied => write!(f, "Permission denied"),
+            ReservationServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum KitchenServiceError {
+    StationNotFound,
+    TicketNotFound,
+    DuplicateName,
+    InvalidStation,
+    InvalidRouting,
+    InvalidStatus,
+    TicketAlreadyBumped,
+    OrderNotActive,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for KitchenServiceError {}
+
+impl std::fmt::Display for KitchenServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KitchenServiceError::StationNotFound => write!(f, "Station not found"),
+            KitchenServiceError::TicketNotFound => write!(f, "Ticket not found"),
+            KitchenServiceError::DuplicateName => write!(f, "Name already in use"),
+            KitchenServiceError::InvalidStation => write!(f, "Invalid station"),
+            KitchenServiceError::InvalidRouting => write!(f, "Unknown product or category"),
+            KitchenServiceError::InvalidStatus => write!(f, "Invalid ticket status"),
+            KitchenServiceError::TicketAlreadyBumped => write!(f, "Ticket already bumped"),
+            KitchenServiceError::OrderNotActive => write!(f, "Order is not in the kitchen"),
+            KitchenServiceError::PermissionDenied => write!(f, "Permission denied"),
+            KitchenServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InventoryServiceError {
+    IngredientNotFound,
+    ProductNotFound,
+    SupplierNotFound,
+    DuplicateName,
+    InvalidIngredient,
+    InvalidRecipe,
+    InvalidQuantity,
+    IngredientInUse,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for InventoryServiceError {}
+
+impl std::fmt::Display for InventoryServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InventoryServiceError::IngredientNotFound => write!(f, "Ingredient not found"),
+            InventoryServiceError::ProductNotFound => write!(f, "Product not found"),
+            InventoryServiceError::SupplierNotFound => write!(f, "Supplier not found"),
+            InventoryServiceError::DuplicateName => write!(f, "Name already in use"),
+            InventoryServiceError::InvalidIngredient => write!(f, "Invalid ingredient"),
+            InventoryServiceError::InvalidRecipe => write!(f, "Invalid recipe"),
+            InventoryServiceError::InvalidQuantity => write!(f, "Invalid quantity"),
+            InventoryServiceError::IngredientInUse => {
+                write!(f, "Ingredient is used in recipes or purchase orders")
+            }
+            InventoryServiceError::PermissionDenied => write!(f, "Permission denied"),
+            InventoryServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PurchasingServiceError {
+    SupplierNotFound,
+    PurchaseOrderNotFound,
+    IngredientNotFound,
+    DuplicateName,
+    InvalidSupplier,
+    InvalidPurchaseOrder,
+    InvalidReceipt,
+    InvalidStatus,
+    NotDraft,
+    NotReceivable,
+    SupplierInUse,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for PurchasingServiceError {}
+
+impl std::fmt::Display for PurchasingServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PurchasingServiceError::SupplierNotFound => write!(f, "Supplier not found"),
+            PurchasingServiceError::PurchaseOrderNotFound => write!(f, "Purchase order not found"),
+            PurchasingServiceError::IngredientNotFound => write!(f, "Ingredient not found"),
+            PurchasingServiceError::DuplicateName => write!(f, "Name already in use"),
+            PurchasingServiceError::InvalidSupplier => write!(f, "Invalid supplier"),
+            PurchasingServiceError::InvalidPurchaseOrder => write!(f, "Invalid purchase order"),
+            PurchasingServiceError::InvalidReceipt => write!(f, "Invalid receipt"),
+            PurchasingServiceError::InvalidStatus => write!(f, "Invalid purchase order status"),
+            PurchasingServiceError::NotDraft => write!(f, "Purchase order is no longer a draft"),
+            PurchasingServiceError::NotReceivable => {
+                write!(f, "Purchase order is not awaiting delivery")
+            }
+            PurchasingServiceError::SupplierInUse => write!(f, "Supplier has purchase orders"),
+            PurchasingServiceError::PermissionDenied => write!(f, "Permission denied"),
+            PurchasingServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WasteServiceError {
+    IngredientNotFound,
+    ProductNotFound,
+    InvalidWaste,
+    InvalidReason,
+    InvalidDateRange,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for WasteServiceError {}
+
+impl std::fmt::Display for WasteServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WasteServiceError::IngredientNotFound => write!(f, "Ingredient not found"),
+            WasteServiceError::ProductNotFound => write!(f, "Product not found"),
+            WasteServiceError::InvalidWaste => write!(f, "Invalid waste entry"),
+            WasteServiceError::InvalidReason => write!(f, "Invalid waste reason"),
+            WasteServiceError::InvalidDateRange => write!(f, "Invalid date range"),
+            WasteServiceError::PermissionDenied => write!(f, "Permission denied"),
+            WasteServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ProductPriceServiceError {
+    ProductNotFound,
+    InvalidPrice,
+    InvalidEffectiveFrom,
+    PriceChangeNotFound,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for ProductPriceServiceError {}
+
+impl std::fmt::Display for ProductPriceServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProductPriceServiceError::ProductNotFound => write!(f, "Product not found"),
+            ProductPriceServiceError::InvalidPrice => write!(f, "Invalid price"),
+            ProductPriceServiceError::InvalidEffectiveFrom => {
+                write!(f, "Invalid effective from time")
+            }
+            ProductPriceServiceError::PriceChangeNotFound => {
+                write!(f, "Scheduled price change not found")
+            }
+            ProductPriceServiceError::PermissionDenied => write!(f, "Permission denied"),
+            ProductPriceServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MenuServiceError {
+    MenuNotFound,
+    DuplicateName,
+    InvalidMenu,
+    InvalidSchedule,
+    InvalidItems,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for MenuServiceError {}
+
+impl std::fmt::Display for MenuServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MenuServiceError::MenuNotFound => write!(f, "Menu not found"),
+            MenuServiceError::DuplicateName => write!(f, "Menu name already exists"),
+            MenuServiceError::InvalidMenu => write!(f, "Invalid menu"),
+            MenuServiceError::InvalidSchedule => write!(f, "Invalid menu schedule"),
+            MenuServiceError::InvalidItems => write!(f, "Invalid menu products or categories"),
+            MenuServiceError::PermissionDenied => write!(f, "Permission denied"),
+            MenuServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BundleServiceError {
+    ProductNotFound,
+    InvalidBundle,
+    InvalidSelection(Vec<i32>),
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for BundleServiceError {}
+
+impl std::fmt::Display for BundleServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BundleServiceError::ProductNotFound => write!(f, "Product not found"),
+            BundleServiceError::InvalidBundle => write!(f, "Invalid bundle slots or choices"),
+            BundleServiceError::InvalidSelection(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Invalid selections for bundles: {}", ids.join(", "))
+            }
+            BundleServiceError::PermissionDenied => write!(f, "Permission denied"),
+            BundleServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AllergenServiceError {
+    ProductNotFound,
+    IngredientNotFound,
+    UnknownValues(Vec<String>),
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for AllergenServiceError {}
+
+impl std::fmt::Display for AllergenServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllergenServiceError::ProductNotFound => write!(f, "Product not found"),
+            AllergenServiceError::IngredientNotFound => write!(f, "Ingredient not found"),
+            AllergenServiceError::UnknownValues(values) => {
+                write!(
+                    f,
+                    "Unknown allergens or dietary tags: {}",
+                    values.join(", ")
+                )
+            }
+            AllergenServiceError::PermissionDenied => write!(f, "Permission denied"),
+            AllergenServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NutritionServiceError {
+    ProductNotFound,
+    IngredientNotFound,
+    UnknownProducts(Vec<i32>),
+    UnknownIngredients(Vec<i32>),
+    InvalidNutrition,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for NutritionServiceError {}
+
+impl std::fmt::Display for NutritionServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NutritionServiceError::ProductNotFound => write!(f, "Product not found"),
+            NutritionServiceError::IngredientNotFound => write!(f, "Ingredient not found"),
+            NutritionServiceError::UnknownProducts(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Unknown products: {}", ids.join(", "))
+            }
+            NutritionServiceError::UnknownIngredients(ids) => {
+                let ids: Vec<String> = ids.iter().map(i32::to_string).collect();
+                write!(f, "Unknown ingredients: {}", ids.join(", "))
+            }
+            NutritionServiceError::InvalidNutrition => write!(f, "Invalid nutrition values"),
+            NutritionServiceError::PermissionDenied => write!(f, "Permission denied"),
+            NutritionServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TranslationServiceError {
+    ProductNotFound,
+    CategoryNotFound,
+    TranslationNotFound,
+    UnsupportedLocale(String),
+    InvalidTranslation,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for TranslationServiceError {}
+
+impl std::fmt::Display for TranslationServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranslationServiceError::ProductNotFound => write!(f, "Product not found"),
+            TranslationServiceError::CategoryNotFound => write!(f, "Category not found"),
+            TranslationServiceError::TranslationNotFound => write!(f, "Translation not found"),
+            TranslationServiceError::UnsupportedLocale(locale) => {
+                write!(f, "Unsupported locale: {}", locale)
+            }
+            TranslationServiceError::InvalidTranslation => write!(f, "Invalid translation"),
+            TranslationServiceError::PermissionDenied => write!(f, "Permission denied"),
+            TranslationServiceError::DatabaseError => write!(f, "Database error"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ImageServiceError {
+    UnsupportedImageType,
+    ImageTooLarge,
+    InvalidImage,
+    ImageNotFound,
+    InvalidKey,
+    StorageFailed,
+    ProductNotFound,
+    PermissionDenied,
+    DatabaseError,
+}
+
+impl std::error::Error for ImageServiceError {}
+
+impl std::fmt::Display for ImageServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageServiceError::UnsupportedImageType => {
+                write!(f, "Unsupported image type, expected JPEG, PNG or WebP")
+            }
+            ImageServiceError::ImageTooLarge => write!(f, "Image too large"),
+            ImageServiceError::InvalidImage => write!(f, "Invalid image"),
+            ImageServiceError::ImageNotFound => write!(f, "Image not found"),
+            ImageServiceError::InvalidKey => write!(f, "Invalid image path"),
+            ImageServiceError::StorageFailed => write!(f, "Failed to store image"),
+            ImageServiceError::ProductNotFound => write!(f, "Product not found"),
+            ImageServiceError::PermissionDenied => write!(f, "Permission denied"),
+            ImageServiceError::DatabaseError => write!(f, "Database error"),
         }
     }
 }
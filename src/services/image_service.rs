@@ -0,0 +1,287 @@
+use crate::api::config::Config;
+use crate::api::response::{ProductImageResponse, ThumbnailResponse};
+use crate::data::models::product::UpdateProduct;
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::blob_store::{
+    Blob, BlobStore, BlobStoreError, LocalBlobStore, blob_store, content_type_for_key,
+};
+use crate::services::errors::ImageServiceError;
+use image::{DynamicImage, ImageError, ImageFormat, ImageReader, Limits};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+
+/// Longest side of the thumbnails made of every product image, in pixels
+pub const THUMBNAIL_SIZES: [u32; 2] = [160, 480];
+
+/// Longest side of an image that can be uploaded, in pixels
+pub const MAX_IMAGE_SIDE: u32 = 8192;
+
+/// Most memory decoding an uploaded image may take, in bytes
+pub const MAX_DECODE_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Path stored images are served from
+pub const IMAGE_ROUTE: &str = "/api/v1/images/";
+
+/// Format and file extension of the images that can be uploaded, by content type
+pub fn image_format(content_type: &str) -> Option<(ImageFormat, &'static str)> {
+    let essence = content_type.split(';').next()?.trim().to_lowercase();
+    match essence.as_str() {
+        "image/jpeg" | "image/jpg" => Some((ImageFormat::Jpeg, "jpg")),
+        "image/png" => Some((ImageFormat::Png, "png")),
+        "image/webp" => Some((ImageFormat::WebP, "webp")),
+        _ => None,
+    }
+}
+
+/// Dimensions of the thumbnail of an image that fits in a square of `size` pixels, keeping its
+/// aspect ratio. Images that already fit keep their dimensions.
+pub fn thumbnail_dimensions(width: u32, height: u32, size: u32) -> (u32, u32) {
+    if width <= size && height <= size {
+        return (width, height);
+    }
+
+    let scale = |side: u32, longest: u32| {
+        let scaled =
+            (u64::from(side) * u64::from(size) + u64::from(longest) / 2) / u64::from(longest);
+        (scaled as u32).max(1)
+    };
+    if width >= height {
+        (size, scale(height, width))
+    } else {
+        (scale(width, height), size)
+    }
+}
+
+/// Key of the thumbnail of a stored image, such as `products/3/ab12-160.jpg` for
+/// `products/3/ab12.jpg`
+pub fn thumbnail_key(key: &str, size: u32) -> String {
+    match key.rsplit_once('.') {
+        Some((stem, ext)) => format!("{}-{}.{}", stem, size, ext),
+        None => format!("{}-{}", key, size),
+    }
+}
+
+/// URI an image stored under a key is served from
+pub fn image_uri(key: &str) -> String {
+    format!("{}{}", IMAGE_ROUTE, key)
+}
+
+pub struct ImageService {
+    store: Box<dyn BlobStore>,
+    max_image_bytes: usize,
+}
+
+impl ImageService {
+    /// Creates an image service using the blob store from the config
+    pub fn new() -> Self {
+        let config = Config::new();
+        let store =
+            blob_store(&config).unwrap_or_else(|| Box::new(LocalBlobStore::new(&config.blob_dir)));
+        ImageService::with_store(store, config.max_image_bytes)
+    }
+
+    pub fn with_store(store: Box<dyn BlobStore>, max_image_bytes: usize) -> Self {
+        ImageService {
+            store,
+            max_image_bytes,
+        }
+    }
+
+    /// Largest image that can be uploaded, in bytes
+    pub fn max_image_bytes(&self) -> usize {
+        self.max_image_bytes
+    }
+
+    /// Uploads the image of a product along with its thumbnails, and shows it on the product
+    /// (requires WRITE permission or Admin). The image it replaces is removed.
+    pub async fn upload_product_image(
+        &self,
+        product_id: i32,
+        content_type: &str,
+        bytes: &[u8],
+        role_id: i32,
+    ) -> Result<ProductImageResponse, ImageServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ImageServiceError::PermissionDenied);
+        }
+
+        let repo = ProductRepo::new();
+        let product = repo
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| ImageServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or(ImageServiceError::ProductNotFound)?;
+
+        let image = self.store_image(product_id, content_type, bytes).await?;
+
+        let update = UpdateProduct {
+            name: None,
+            product_image_uri: Some(&image.product_image_uri),
+            description: None,
+            price: None,
+        };
+        repo.update(product_id, update)
+            .await
+            .map_err(|_| ImageServiceError::DatabaseError)?;
+
+        if let Some(old_key) = product
+            .product_image_uri
+            .as_deref()
+            .and_then(|uri| uri.strip_prefix(IMAGE_ROUTE))
+            .filter(|old_key| image_uri(old_key) != image.product_image_uri)
+        {
+            self.remove_image(old_key).await;
+        }
+
+        Ok(image)
+    }
+
+    /// Checks an uploaded image and stores it with its thumbnails, under keys made from its
+    /// content so a changed image never reuses a cached URI
+    pub async fn store_image(
+        &self,
+        product_id: i32,
+        content_type: &str,
+        bytes: &[u8],
+    ) -> Result<ProductImageResponse, ImageServiceError> {
+        let (format, extension) =
+            image_format(content_type).ok_or(ImageServiceError::UnsupportedImageType)?;
+        if bytes.len() > self.max_image_bytes {
+            return Err(ImageServiceError::ImageTooLarge);
+        }
+
+        // Decoding and resizing are CPU-bound, so they run off the async workers
+        let (bytes, encoded) = tokio::task::spawn_blocking({
+            let bytes = bytes.to_vec();
+            move || {
+                let image = decode(&bytes, format)?;
+                let encoded = THUMBNAIL_SIZES
+                    .iter()
+                    .map(|size| Ok((*size, encode(&thumbnail(&image, *size), format)?)))
+                    .collect::<Result<Vec<_>, ImageServiceError>>()?;
+                Ok::<_, ImageServiceError>((bytes, encoded))
+            }
+        })
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to process image: {}", e);
+            ImageServiceError::InvalidImage
+        })??;
+
+        let digest = hex::encode(Sha256::digest(&bytes));
+        let key = format!("products/{}/{}.{}", product_id, &digest[..16], extension);
+        let content_type = content_type_for_key(&key);
+
+        let mut thumbnails = Vec::new();
+        for (size, thumbnail) in encoded {
+            let thumbnail_key = thumbnail_key(&key, size);
+            self.put(&thumbnail_key, content_type, thumbnail).await?;
+            thumbnails.push(ThumbnailResponse {
+                size,
+                image_uri: image_uri(&thumbnail_key),
+            });
+        }
+        self.put(&key, content_type, bytes).await?;
+
+        Ok(ProductImageResponse {
+            product_image_uri: image_uri(&key),
+            thumbnails,
+        })
+    }
+
+    /// Gets a stored image or thumbnail. Images are public, so no permission is checked.
+    pub async fn get_image(&self, key: &str) -> Result<Blob, ImageServiceError> {
+        self.store.get(key).await.map_err(|e| match e {
+            BlobStoreError::NotFound => ImageServiceError::ImageNotFound,
+            BlobStoreError::InvalidKey => ImageServiceError::InvalidKey,
+            BlobStoreError::Unavailable => ImageServiceError::StorageFailed,
+        })
+    }
+
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), ImageServiceError> {
+        self.store
+            .put(key, content_type, bytes)
+            .await
+            .map_err(|_| ImageServiceError::StorageFailed)
+    }
+
+    /// Removes a replaced image and its thumbnails. The new image is already shown, so a
+    /// failure only leaves files behind and is logged rather than returned.
+    async fn remove_image(&self, key: &str) {
+        let keys = std::iter::once(key.to_string())
+            .chain(THUMBNAIL_SIZES.iter().map(|size| thumbnail_key(key, *size)));
+        for key in keys {
+            if let Err(e) = self.store.delete(&key).await {
+                tracing::warn!("Failed to remove replaced image {}: {:?}", key, e);
+            }
+        }
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, ImageServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| ImageServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+/// Decodes an uploaded image, refusing images over `MAX_IMAGE_SIDE` pixels on a side or that
+/// would take more than `MAX_DECODE_BYTES` to decode
+fn decode(bytes: &[u8], format: ImageFormat) -> Result<DynamicImage, ImageServiceError> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_SIDE);
+    limits.max_image_height = Some(MAX_IMAGE_SIDE);
+    limits.max_alloc = Some(MAX_DECODE_BYTES);
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+    reader.decode().map_err(|e| match e {
+        ImageError::Limits(_) => ImageServiceError::ImageTooLarge,
+        _ => ImageServiceError::InvalidImage,
+    })
+}
+
+fn thumbnail(image: &DynamicImage, size: u32) -> DynamicImage {
+    let (width, height) = thumbnail_dimensions(image.width(), image.height(), size);
+    if (width, height) == (image.width(), image.height()) {
+        return image.clone();
+    }
+    image.thumbnail_exact(width, height)
+}
+
+fn encode(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ImageServiceError> {
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), format)
+        .map_err(|e| {
+            tracing::error!("Failed to encode thumbnail: {}", e);
+            ImageServiceError::InvalidImage
+        })?;
+    Ok(bytes)
+}
+
+impl Default for ImageService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,521 @@
+use crate::api::config::Config;
+use crate::api::request::{
+    CreateIngredientRequest, RecipeRequest, StockAdjustmentRequest, UpdateIngredientRequest,
+};
+use crate::api::response::{IngredientResponse, RecipeItemResponse, RecipeResponse};
+use crate::data::models::ingredient::{
+    Ingredient, NewIngredient, NewStockMovement, Recipe, UpdateIngredient,
+};
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::bundle_repo::BundleRepo;
+use crate::data::repos::implementors::ingredient_repo::IngredientRepo;
+use crate::data::repos::implementors::order_product_repo::OrderProductRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::implementors::supplier_repo::SupplierRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::bundle_service::expand_lines;
+use crate::services::errors::InventoryServiceError;
+use crate::services::order_service::OrderStatus;
+use bigdecimal::{BigDecimal, Zero};
+use std::collections::{BTreeMap, HashSet};
+
+/// Why the stock of an ingredient changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StockMovementReason {
+    /// Stock used by an accepted order
+    Order,
+    /// Stock of a cancelled order, or of voided items, put back
+    Cancellation,
+    /// Manual change, such as a stock count correction
+    Adjustment,
+    /// Stock received against a purchase order
+    Purchase,
+    /// Stock thrown away, logged as waste
+    Waste,
+}
+
+impl StockMovementReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockMovementReason::Order => "order",
+            StockMovementReason::Cancellation => "cancellation",
+            StockMovementReason::Adjustment => "adjustment",
+            StockMovementReason::Purchase => "purchase",
+            StockMovementReason::Waste => "waste",
+        }
+    }
+}
+
+impl std::str::FromStr for StockMovementReason {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "order" => Ok(StockMovementReason::Order),
+            "cancellation" => Ok(StockMovementReason::Cancellation),
+            "adjustment" => Ok(StockMovementReason::Adjustment),
+            "purchase" => Ok(StockMovementReason::Purchase),
+            "waste" => Ok(StockMovementReason::Waste),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Adds up how much of each ingredient order lines (product_id, quantity) need, ordered by
+/// ingredient id. Products without a recipe need nothing.
+pub fn stock_needs(lines: &[(i32, i32)], recipes: &[Recipe]) -> Vec<(i32, BigDecimal)> {
+    let mut needs: BTreeMap<i32, BigDecimal> = BTreeMap::new();
+
+    for (product_id, quantity) in lines {
+        for recipe in recipes.iter().filter(|r| r.product_id == *product_id) {
+            *needs.entry(recipe.ingredient_id).or_default() +=
+                &recipe.quantity * BigDecimal::from(*quantity);
+        }
+    }
+
+    needs.into_iter().collect()
+}
+
+/// The ingredients whose stock on hand does not cover what is needed of them
+pub fn short_ingredients(needs: &[(i32, BigDecimal)], stock: &[Ingredient]) -> Vec<i32> {
+    needs
+        .iter()
+        .filter(|(id, need)| {
+            stock
+                .iter()
+                .find(|ingredient| ingredient.ingredient_id == *id)
+                .is_none_or(|ingredient| &ingredient.on_hand < need)
+        })
+        .map(|(id, _)| *id)
+        .collect()
+}
+
+fn is_negative(level: &Option<BigDecimal>) -> bool {
+    level.as_ref().is_some_and(|l| l < &BigDecimal::zero())
+}
+
+pub struct InventoryService;
+
+impl InventoryService {
+    pub fn new() -> Self {
+        InventoryService
+    }
+
+    /// Gets all ingredients with their stock (requires READ permission or Admin)
+    pub async fn get_ingredients(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<IngredientResponse>, InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        let mut ingredients = IngredientRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .unwrap_or_default();
+        ingredients.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(ingredients
+            .into_iter()
+            .map(IngredientResponse::from)
+            .collect())
+    }
+
+    /// Gets the ingredients at or below their reorder level (requires READ permission or
+    /// Admin)
+    pub async fn get_low_stock(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<IngredientResponse>, InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        Ok(IngredientRepo::new()
+            .get_low_stock()
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .into_iter()
+            .map(IngredientResponse::from)
+            .collect())
+    }
+
+    /// Creates an ingredient without stock (requires Admin)
+    pub async fn create_ingredient(
+        &self,
+        role_id: i32,
+        request: &CreateIngredientRequest,
+    ) -> Result<(), InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        if request.name.trim().is_empty()
+            || request.unit.trim().is_empty()
+            || is_negative(&request.reorder_level)
+            || is_negative(&request.par_level)
+        {
+            return Err(InventoryServiceError::InvalidIngredient);
+        }
+        self.ensure_name_free(&request.name, None).await?;
+        self.ensure_supplier_exists(request.supplier_id).await?;
+
+        IngredientRepo::new()
+            .add(NewIngredient::from(request))
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Updates the name, unit, supplier or stock levels of an ingredient (requires Admin)
+    pub async fn update_ingredient(
+        &self,
+        role_id: i32,
+        ingredient_id: i32,
+        request: &UpdateIngredientRequest,
+    ) -> Result<(), InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        if request.unit.as_ref().is_some_and(|u| u.trim().is_empty())
+            || is_negative(&request.reorder_level)
+            || is_negative(&request.par_level)
+        {
+            return Err(InventoryServiceError::InvalidIngredient);
+        }
+
+        let repo = IngredientRepo::new();
+        repo.get_by_id(ingredient_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .ok_or(InventoryServiceError::IngredientNotFound)?;
+
+        if let Some(name) = &request.name {
+            if name.trim().is_empty() {
+                return Err(InventoryServiceError::InvalidIngredient);
+            }
+            self.ensure_name_free(name, Some(ingredient_id)).await?;
+        }
+        self.ensure_supplier_exists(request.supplier_id).await?;
+
+        repo.update(ingredient_id, UpdateIngredient::from(request))
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Deletes an ingredient no recipe or purchase order uses, with its stock movements
+    /// (requires Admin)
+    pub async fn delete_ingredient(
+        &self,
+        role_id: i32,
+        ingredient_id: i32,
+    ) -> Result<(), InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        let repo = IngredientRepo::new();
+        repo.get_by_id(ingredient_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .ok_or(InventoryServiceError::IngredientNotFound)?;
+
+        if repo
+            .is_used(ingredient_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+        {
+            return Err(InventoryServiceError::IngredientInUse);
+        }
+
+        repo.delete(ingredient_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Adds to or takes from the stock on hand of an ingredient, recording who changed it
+    /// (requires WRITE permission or Admin)
+    pub async fn adjust_stock(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        ingredient_id: i32,
+        request: &StockAdjustmentRequest,
+    ) -> Result<(), InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        if request.quantity.is_zero() {
+            return Err(InventoryServiceError::InvalidQuantity);
+        }
+
+        let repo = IngredientRepo::new();
+        repo.get_by_id(ingredient_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .ok_or(InventoryServiceError::IngredientNotFound)?;
+
+        repo.record_movement(NewStockMovement {
+            ingredient_id,
+            order_id: None,
+            quantity: request.quantity.clone(),
+            reason: StockMovementReason::Adjustment.as_str(),
+            note: request.note.as_deref(),
+            user_id: Some(user_id),
+            purchase_order_id: None,
+            unit_cost: None,
+            waste_id: None,
+        })
+        .await
+        .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Gets the ingredients that go into one of a product (requires READ permission or
+    /// Admin)
+    pub async fn get_recipe(
+        &self,
+        role_id: i32,
+        product_id: i32,
+    ) -> Result<RecipeResponse, InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .ok_or(InventoryServiceError::ProductNotFound)?;
+
+        let items = IngredientRepo::new()
+            .get_recipe_items(product_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?;
+
+        Ok(RecipeResponse {
+            product_id,
+            items: items.into_iter().map(RecipeItemResponse::from).collect(),
+        })
+    }
+
+    /// Replaces the recipe of a product (requires Admin)
+    pub async fn set_recipe(
+        &self,
+        role_id: i32,
+        product_id: i32,
+        request: &RecipeRequest,
+    ) -> Result<(), InventoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(InventoryServiceError::PermissionDenied);
+        }
+
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .ok_or(InventoryServiceError::ProductNotFound)?;
+
+        let ingredient_ids: HashSet<i32> = request
+            .items
+            .iter()
+            .map(|item| item.ingredient_id)
+            .collect();
+        if ingredient_ids.len() != request.items.len()
+            || request
+                .items
+                .iter()
+                .any(|item| item.quantity <= BigDecimal::zero())
+        {
+            return Err(InventoryServiceError::InvalidRecipe);
+        }
+
+        let repo = IngredientRepo::new();
+        let known = repo
+            .get_by_ids(ingredient_ids.iter().copied().collect())
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?;
+        if known.len() != ingredient_ids.len() {
+            return Err(InventoryServiceError::IngredientNotFound);
+        }
+
+        repo.replace_recipe(
+            product_id,
+            request
+                .items
+                .iter()
+                .map(|item| Recipe {
+                    product_id,
+                    ingredient_id: item.ingredient_id,
+                    quantity: item.quantity.clone(),
+                })
+                .collect(),
+        )
+        .await
+        .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Finds the ingredients order lines (product_id, quantity) need more of than is in
+    /// stock. Called while creating an order, so no permission is checked.
+    pub async fn check_stock(
+        &self,
+        lines: &[(i32, i32)],
+    ) -> Result<Vec<i32>, InventoryServiceError> {
+        let needs = self.needs(lines).await?;
+        if needs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let stock = IngredientRepo::new()
+            .get_by_ids(needs.iter().map(|(id, _)| *id).collect())
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?;
+
+        Ok(short_ingredients(&needs, &stock))
+    }
+
+    /// Accepts an order, taking the ingredients of its items out of stock in the same
+    /// transaction. Bundles take out the products picked for them. With
+    /// `BLOCK_NEGATIVE_STOCK` set, an order that needs more than is in stock is left as it
+    /// is. Returns the ingredients that were short in that case.
+    /// Called by the order workflow, so no permission is checked.
+    pub async fn accept_order(&self, order_id: i32) -> Result<Vec<i32>, InventoryServiceError> {
+        let lines: Vec<(i32, i32)> = OrderProductRepo::new()
+            .get_by_order_id(order_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|line| (line.product_id, line.quantity))
+            .collect();
+        let bundle_items = BundleRepo::new()
+            .get_order_items(order_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?;
+        let needs = self.needs(&expand_lines(&lines, &bundle_items)).await?;
+
+        IngredientRepo::new()
+            .deduct_for_order(
+                order_id,
+                OrderStatus::Accepted.as_str(),
+                needs,
+                StockMovementReason::Order.as_str(),
+                !Config::new().block_negative_stock,
+            )
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Puts back the stock taken out for a cancelled order. Called by the order workflow, so
+    /// no permission is checked.
+    pub async fn restore_order(&self, order_id: i32) -> Result<(), InventoryServiceError> {
+        IngredientRepo::new()
+            .restore_for_order(order_id, StockMovementReason::Cancellation.as_str())
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)
+    }
+
+    /// Works out what voiding order lines (product_id, quantity) takes off the kitchen
+    /// tickets, with bundles replaced by the products picked for them, and the ingredients
+    /// those need back in stock. Called by the order workflow, so no permission is checked.
+    pub async fn voided_items(
+        &self,
+        order_id: i32,
+        lines: &[(i32, i32)],
+    ) -> Result<(Vec<(i32, i32)>, Vec<(i32, BigDecimal)>), InventoryServiceError> {
+        let bundle_items = BundleRepo::new()
+            .get_order_items(order_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?;
+        let items = expand_lines(lines, &bundle_items);
+        let needs = self.needs(&items).await?;
+        Ok((items, needs))
+    }
+
+    async fn needs(
+        &self,
+        lines: &[(i32, i32)],
+    ) -> Result<Vec<(i32, BigDecimal)>, InventoryServiceError> {
+        if lines.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let recipes = IngredientRepo::new()
+            .get_recipes(lines.iter().map(|(product_id, _)| *product_id).collect())
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?;
+
+        Ok(stock_needs(lines, &recipes))
+    }
+
+    async fn ensure_name_free(
+        &self,
+        name: &str,
+        except: Option<i32>,
+    ) -> Result<(), InventoryServiceError> {
+        let ingredients = IngredientRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        if ingredients
+            .iter()
+            .any(|i| i.name.eq_ignore_ascii_case(name.trim()) && Some(i.ingredient_id) != except)
+        {
+            return Err(InventoryServiceError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    async fn ensure_supplier_exists(
+        &self,
+        supplier_id: Option<i32>,
+    ) -> Result<(), InventoryServiceError> {
+        let Some(supplier_id) = supplier_id else {
+            return Ok(());
+        };
+
+        SupplierRepo::new()
+            .get_by_id(supplier_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+            .ok_or(InventoryServiceError::SupplierNotFound)?;
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, InventoryServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| InventoryServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for InventoryService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,656 @@
+use crate::api::request::{
+    CreateStationRequest, StationRoutingRequest, TicketQuery, UpdateStationRequest,
+};
+use crate::api::response::{KitchenTicketItemResponse, KitchenTicketResponse, StationResponse};
+use crate::data::models::kitchen_ticket::{KitchenTicket, RoutedTicket};
+use crate::data::models::order::Order;
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::station::{NewStation, StationCategory, StationProduct, UpdateStation};
+use crate::data::repos::implementors::category_repo::CategoryRepo;
+use crate::data::repos::implementors::kitchen_ticket_repo::KitchenTicketRepo;
+use crate::data::repos::implementors::order_repo::OrderRepo;
+use crate::data::repos::implementors::product_category_repo::ProductCategoryRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::implementors::station_repo::StationRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::KitchenServiceError;
+use crate::services::order_service::OrderStatus;
+use crate::utils::time::local_now;
+use chrono::{Duration, NaiveDateTime};
+use std::collections::{BTreeMap, HashMap};
+
+/// State of a kitchen ticket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TicketStatus {
+    Open,
+    Bumped,
+}
+
+impl TicketStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TicketStatus::Open => "open",
+            TicketStatus::Bumped => "bumped",
+        }
+    }
+}
+
+impl std::str::FromStr for TicketStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "open" => Ok(TicketStatus::Open),
+            "bumped" => Ok(TicketStatus::Bumped),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Order statuses whose tickets the kitchen works on
+const ACTIVE_ORDER_STATUSES: [OrderStatus; 2] = [OrderStatus::Pending, OrderStatus::Accepted];
+
+/// Splits order lines (product_id, quantity) into one ticket per station.
+///
+/// A product goes to the station it is routed to, or else to the station of its category
+/// with the lowest id that is routed. Products without a station end up on a ticket of
+/// their own, without a station, so nothing is left off. Tickets are ordered by station,
+/// the one without a station first.
+pub fn route_lines(
+    lines: &[(i32, i32)],
+    product_routes: &[StationProduct],
+    category_routes: &[StationCategory],
+    product_categories: &[(i32, i32)], // product_id, category_id
+) -> Vec<RoutedTicket> {
+    let by_product: HashMap<i32, i32> = product_routes
+        .iter()
+        .map(|r| (r.product_id, r.station_id))
+        .collect();
+    let by_category: HashMap<i32, i32> = category_routes
+        .iter()
+        .map(|r| (r.category_id, r.station_id))
+        .collect();
+
+    let mut tickets: BTreeMap<Option<i32>, Vec<(i32, i32)>> = BTreeMap::new();
+    for &(product_id, quantity) in lines {
+        let station = by_product.get(&product_id).copied().or_else(|| {
+            product_categories
+                .iter()
+                .filter(|(pid, _)| *pid == product_id)
+                .filter_map(|(_, cid)| by_category.get(cid).map(|station| (*cid, *station)))
+                .min()
+                .map(|(_, station)| station)
+        });
+
+        let items = tickets.entry(station).or_default();
+        match items.iter_mut().find(|(pid, _)| *pid == product_id) {
+            Some(item) => item.1 += quantity,
+            None => items.push((product_id, quantity)),
+        }
+    }
+
+    tickets
+        .into_iter()
+        .map(|(station_id, items)| RoutedTicket { station_id, items })
+        .collect()
+}
+
+/// A ticket waiting in the kitchen queue
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedTicket {
+    pub order_id: i32,
+    pub station_id: Option<i32>,
+    /// Prep time of the slowest item on the ticket, as its items are prepared side by side
+    pub prep_minutes: i32,
+}
+
+/// Works out when each order in the queue will be ready.
+///
+/// Every station works through its tickets in queue order, preparing as many at the same
+/// time as its capacity (one for tickets without a station or capacity). An order is ready
+/// once its last ticket is done. Returns the estimated ready time of each order id.
+pub fn estimate_ready_times(
+    now: NaiveDateTime,
+    queue: &[QueuedTicket],
+    capacities: &HashMap<i32, i32>,
+) -> HashMap<i32, NaiveDateTime> {
+    let mut lanes: HashMap<Option<i32>, Vec<NaiveDateTime>> = HashMap::new();
+    let mut ready: HashMap<i32, NaiveDateTime> = HashMap::new();
+
+    for ticket in queue {
+        let station_lanes = lanes.entry(ticket.station_id).or_insert_with(|| {
+            let capacity = ticket
+                .station_id
+                .and_then(|station| capacities.get(&station).copied())
+                .unwrap_or(1)
+                .max(1);
+            vec![now; capacity as usize]
+        });
+
+        if let Some(lane) = station_lanes.iter_mut().min() {
+            *lane += Duration::minutes(ticket.prep_minutes.max(0) as i64);
+            let done = *lane;
+            ready
+                .entry(ticket.order_id)
+                .and_modify(|at| *at = (*at).max(done))
+                .or_insert(done);
+        }
+    }
+
+    ready
+}
+
+pub struct KitchenService;
+
+impl KitchenService {
+    pub fn new() -> Self {
+        KitchenService
+    }
+
+    /// Gets all stations with the products and categories routed to them
+    /// (requires READ permission or Admin)
+    pub async fn get_stations(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<StationResponse>, KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        let repo = StationRepo::new();
+        let stations = repo
+            .get_all()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .unwrap_or_default();
+        let products = repo
+            .get_product_routes()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+        let categories = repo
+            .get_category_routes()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+
+        Ok(stations
+            .into_iter()
+            .map(|station| {
+                let station_id = station.station_id;
+                let mut response = StationResponse::from(station);
+                response.product_ids = products
+                    .iter()
+                    .filter(|r| r.station_id == station_id)
+                    .map(|r| r.product_id)
+                    .collect();
+                response.category_ids = categories
+                    .iter()
+                    .filter(|r| r.station_id == station_id)
+                    .map(|r| r.category_id)
+                    .collect();
+                response
+            })
+            .collect())
+    }
+
+    /// Creates a station (requires Admin)
+    pub async fn create_station(
+        &self,
+        role_id: i32,
+        request: &CreateStationRequest,
+    ) -> Result<(), KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        if request.name.trim().is_empty() || request.capacity.is_some_and(|c| c <= 0) {
+            return Err(KitchenServiceError::InvalidStation);
+        }
+        self.ensure_name_free(&request.name, None).await?;
+
+        StationRepo::new()
+            .add(NewStation::from(request))
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)
+    }
+
+    /// Renames a station or changes its capacity (requires Admin)
+    pub async fn update_station(
+        &self,
+        role_id: i32,
+        station_id: i32,
+        request: &UpdateStationRequest,
+    ) -> Result<(), KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        if request.capacity.is_some_and(|c| c <= 0) {
+            return Err(KitchenServiceError::InvalidStation);
+        }
+
+        let repo = StationRepo::new();
+        repo.get_by_id(station_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .ok_or(KitchenServiceError::StationNotFound)?;
+
+        if let Some(name) = &request.name {
+            if name.trim().is_empty() {
+                return Err(KitchenServiceError::InvalidStation);
+            }
+            self.ensure_name_free(name, Some(station_id)).await?;
+        }
+
+        repo.update(station_id, UpdateStation::from(request))
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)
+    }
+
+    /// Deletes a station. Its open tickets are kept without a station (requires Admin)
+    pub async fn delete_station(
+        &self,
+        role_id: i32,
+        station_id: i32,
+    ) -> Result<(), KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        let repo = StationRepo::new();
+        repo.get_by_id(station_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .ok_or(KitchenServiceError::StationNotFound)?;
+
+        repo.delete(station_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)
+    }
+
+    /// Replaces the products and categories routed to a station. Products and categories
+    /// routed to another station are moved to this one. Tickets already sent are not
+    /// re-routed (requires Admin)
+    pub async fn set_routing(
+        &self,
+        role_id: i32,
+        station_id: i32,
+        request: &StationRoutingRequest,
+    ) -> Result<(), KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        let repo = StationRepo::new();
+        repo.get_by_id(station_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .ok_or(KitchenServiceError::StationNotFound)?;
+
+        let mut product_ids = request.product_ids.clone().unwrap_or_default();
+        product_ids.sort_unstable();
+        product_ids.dedup();
+        let mut category_ids = request.category_ids.clone().unwrap_or_default();
+        category_ids.sort_unstable();
+        category_ids.dedup();
+
+        let product_repo = ProductRepo::new();
+        for product_id in &product_ids {
+            product_repo
+                .get_by_id(*product_id)
+                .await
+                .map_err(|_| KitchenServiceError::DatabaseError)?
+                .ok_or(KitchenServiceError::InvalidRouting)?;
+        }
+        let category_repo = CategoryRepo::new();
+        for category_id in &category_ids {
+            category_repo
+                .get_by_id(*category_id)
+                .await
+                .map_err(|_| KitchenServiceError::DatabaseError)?
+                .ok_or(KitchenServiceError::InvalidRouting)?;
+        }
+
+        repo.replace_routing(station_id, product_ids, category_ids)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)
+    }
+
+    /// Splits the lines of a new order (product_id, quantity) into station tickets with
+    /// the current routing. Called while creating an order, so no permission is checked.
+    pub async fn route_order(
+        &self,
+        lines: &[(i32, i32)],
+    ) -> Result<Vec<RoutedTicket>, KitchenServiceError> {
+        let repo = StationRepo::new();
+        let products = repo
+            .get_product_routes()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+        let categories = repo
+            .get_category_routes()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+        let product_categories = ProductCategoryRepo::new()
+            .get_category_ids_by_product_ids(lines.iter().map(|(pid, _)| *pid).collect())
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+
+        Ok(route_lines(
+            lines,
+            &products,
+            &categories,
+            &product_categories,
+        ))
+    }
+
+    /// Gets the open or bumped tickets of the orders the kitchen is working on, oldest
+    /// first, optionally only those of one station (requires READ permission or Admin)
+    pub async fn get_tickets(
+        &self,
+        role_id: i32,
+        query: &TicketQuery,
+    ) -> Result<Vec<KitchenTicketResponse>, KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        let status = match &query.status {
+            Some(status) => status
+                .parse::<TicketStatus>()
+                .map_err(|_| KitchenServiceError::InvalidStatus)?,
+            None => TicketStatus::Open,
+        };
+
+        if let Some(station_id) = query.station_id {
+            StationRepo::new()
+                .get_by_id(station_id)
+                .await
+                .map_err(|_| KitchenServiceError::DatabaseError)?
+                .ok_or(KitchenServiceError::StationNotFound)?;
+        }
+
+        let repo = KitchenTicketRepo::new();
+        let tickets = repo
+            .get_by_status(
+                query.station_id,
+                status.as_str(),
+                ACTIVE_ORDER_STATUSES
+                    .iter()
+                    .map(OrderStatus::as_str)
+                    .collect(),
+            )
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+        if tickets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let items = repo
+            .get_items(tickets.iter().map(|(t, _)| t.ticket_id).collect())
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+        let mut by_ticket: HashMap<i32, Vec<KitchenTicketItemResponse>> = HashMap::new();
+        for item in items {
+            by_ticket
+                .entry(item.0.ticket_id)
+                .or_default()
+                .push(KitchenTicketItemResponse::from(item));
+        }
+
+        Ok(tickets
+            .into_iter()
+            .map(|ticket| {
+                let mut response = KitchenTicketResponse::from(ticket);
+                response.items = by_ticket.remove(&response.ticket_id).unwrap_or_default();
+                response
+            })
+            .collect())
+    }
+
+    /// Bumps an open ticket once its station is done with it. The order is marked ready
+    /// when it was the last open ticket of the order (requires WRITE permission or Admin)
+    /// Returns whether the order became ready.
+    pub async fn bump_ticket(
+        &self,
+        role_id: i32,
+        ticket_id: i32,
+    ) -> Result<bool, KitchenServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(KitchenServiceError::PermissionDenied);
+        }
+
+        let repo = KitchenTicketRepo::new();
+        let ticket = repo
+            .get_by_id(ticket_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .ok_or(KitchenServiceError::TicketNotFound)?;
+        if ticket.status == TicketStatus::Bumped.as_str() {
+            return Err(KitchenServiceError::TicketAlreadyBumped);
+        }
+
+        let order = OrderRepo::new()
+            .get_by_id(ticket.order_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .ok_or(KitchenServiceError::TicketNotFound)?;
+        let active = order
+            .status
+            .as_deref()
+            .and_then(|s| s.parse::<OrderStatus>().ok())
+            .is_some_and(|s| ACTIVE_ORDER_STATUSES.contains(&s));
+        if !active {
+            return Err(KitchenServiceError::OrderNotActive);
+        }
+
+        let ready = repo
+            .bump(
+                ticket_id,
+                TicketStatus::Bumped.as_str(),
+                OrderStatus::Ready.as_str(),
+                ACTIVE_ORDER_STATUSES
+                    .iter()
+                    .map(OrderStatus::as_str)
+                    .collect(),
+            )
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+
+        // The ticket is bumped either way, so a failed estimate does not fail the request
+        if let Err(e) = self.refresh_estimates(local_now(), None).await {
+            tracing::error!("Failed to refresh estimated ready times: {}", e);
+        }
+
+        Ok(ready)
+    }
+
+    /// Estimates when a new order will be ready, queued behind the accepted orders, and
+    /// stores it on the order. Called while creating an order, so no permission is checked.
+    pub async fn estimate_order(
+        &self,
+        order_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<(), KitchenServiceError> {
+        let mut tickets: Vec<KitchenTicket> = self
+            .accepted_queue(None)
+            .await?
+            .into_iter()
+            .map(|(ticket, _)| ticket)
+            .collect();
+        tickets.extend(
+            KitchenTicketRepo::new()
+                .get_by_order_id(order_id)
+                .await
+                .map_err(|_| KitchenServiceError::DatabaseError)?,
+        );
+
+        let queue = self.to_queue(&tickets).await?;
+        let estimates = estimate_ready_times(now, &queue, &self.capacities().await?);
+        let ready_at = estimates.get(&order_id).copied().unwrap_or(now);
+
+        OrderRepo::new()
+            .set_estimated_ready_at(vec![(order_id, ready_at)])
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)
+    }
+
+    /// Recalculates the estimated ready times of the accepted orders as the kitchen queue
+    /// changes, with `accepted` being the order that just joined the back of the queue.
+    ///
+    /// The other orders keep an earlier estimate that has not passed yet, as the queue
+    /// ahead of them only gets shorter while their tickets are prepared. Called by the order
+    /// and ticket workflows, so no permission is checked.
+    pub async fn refresh_estimates(
+        &self,
+        now: NaiveDateTime,
+        accepted: Option<i32>,
+    ) -> Result<(), KitchenServiceError> {
+        let queued = self.accepted_queue(accepted).await?;
+        let stored: HashMap<i32, Option<NaiveDateTime>> = queued
+            .iter()
+            .map(|(_, order)| (order.order_id, order.estimated_ready_at))
+            .collect();
+        let tickets: Vec<KitchenTicket> = queued.into_iter().map(|(ticket, _)| ticket).collect();
+
+        let queue = self.to_queue(&tickets).await?;
+        let estimates = estimate_ready_times(now, &queue, &self.capacities().await?);
+
+        let mut updates: Vec<(i32, NaiveDateTime)> = estimates
+            .into_iter()
+            .filter_map(|(order_id, estimate)| {
+                let previous = stored.get(&order_id).copied().flatten();
+                let ready_at = match previous {
+                    Some(at) if Some(order_id) != accepted && at > now && at < estimate => at,
+                    _ => estimate,
+                };
+                (previous != Some(ready_at)).then_some((order_id, ready_at))
+            })
+            .collect();
+        if updates.is_empty() {
+            return Ok(());
+        }
+        updates.sort_unstable();
+
+        OrderRepo::new()
+            .set_estimated_ready_at(updates)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)
+    }
+
+    /// The open tickets of the accepted orders in queue order: by the current estimate of
+    /// their order, with the `last` order at the back
+    async fn accepted_queue(
+        &self,
+        last: Option<i32>,
+    ) -> Result<Vec<(KitchenTicket, Order)>, KitchenServiceError> {
+        let mut tickets = KitchenTicketRepo::new()
+            .get_by_status(
+                None,
+                TicketStatus::Open.as_str(),
+                vec![OrderStatus::Accepted.as_str()],
+            )
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+
+        tickets.sort_by_key(|(ticket, order)| {
+            (
+                Some(order.order_id) == last,
+                order.estimated_ready_at.is_none(),
+                order.estimated_ready_at,
+                order.order_id,
+                ticket.ticket_id,
+            )
+        });
+        Ok(tickets)
+    }
+
+    /// Queues tickets with the prep time of their slowest item
+    async fn to_queue(
+        &self,
+        tickets: &[KitchenTicket],
+    ) -> Result<Vec<QueuedTicket>, KitchenServiceError> {
+        if tickets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let items = KitchenTicketRepo::new()
+            .get_items(tickets.iter().map(|t| t.ticket_id).collect())
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?;
+        let mut prep: HashMap<i32, i32> = HashMap::new();
+        for (item, product) in items {
+            let minutes = prep.entry(item.ticket_id).or_default();
+            *minutes = (*minutes).max(product.prep_minutes);
+        }
+
+        Ok(tickets
+            .iter()
+            .map(|ticket| QueuedTicket {
+                order_id: ticket.order_id,
+                station_id: ticket.station_id,
+                prep_minutes: prep.get(&ticket.ticket_id).copied().unwrap_or(0),
+            })
+            .collect())
+    }
+
+    async fn capacities(&self) -> Result<HashMap<i32, i32>, KitchenServiceError> {
+        Ok(StationRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|station| (station.station_id, station.capacity))
+            .collect())
+    }
+
+    async fn ensure_name_free(
+        &self,
+        name: &str,
+        except: Option<i32>,
+    ) -> Result<(), KitchenServiceError> {
+        let stations = StationRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        if stations
+            .iter()
+            .any(|s| s.name.eq_ignore_ascii_case(name.trim()) && Some(s.station_id) != except)
+        {
+            return Err(KitchenServiceError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, KitchenServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| KitchenServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for KitchenService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
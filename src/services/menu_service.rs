@@ -0,0 +1,536 @@
+use crate::api::request::{
+    CreateMenuRequest, MenuItemsRequest, MenuScheduleRequest, UpdateMenuRequest,
+};
+use crate::api::response::{
+    CurrentMenuResponse, MenuResponse, MenuWindowResponse, NutritionResponse, ProductResponse,
+};
+use crate::data::models::menu::{
+    Menu, MenuCategory, MenuProduct, MenuSchedule, NewMenu, NewMenuSchedule, UpdateMenu,
+};
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::category_repo::CategoryRepo;
+use crate::data::repos::implementors::menu_repo::MenuRepo;
+use crate::data::repos::implementors::product_category_repo::ProductCategoryRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::MenuServiceError;
+use crate::services::nutrition_service::NutritionService;
+use crate::services::translation_service::TranslationService;
+use crate::utils::time::{business_offset, local_now, parse_time, to_offset};
+use chrono::{FixedOffset, NaiveDateTime, Weekday};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+const DAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Name of a day of the week, 0 being Monday
+pub fn day_name(day: i32) -> &'static str {
+    DAY_NAMES.get(day as usize).copied().unwrap_or("unknown")
+}
+
+/// Parses a day of the week such as `mon` or `Monday`, 0 being Monday
+pub fn parse_day(value: &str) -> Option<i32> {
+    Weekday::from_str(value.trim())
+        .ok()
+        .map(|day| day.num_days_from_monday() as i32)
+}
+
+/// Whether a menu is served at the business time `at`, the business being at the offset
+/// `business`. Inactive menus are never served and menus without windows are served all day.
+pub fn is_menu_open(
+    menu: &Menu,
+    windows: &[MenuSchedule],
+    at: NaiveDateTime,
+    business: FixedOffset,
+) -> bool {
+    if !menu.is_active {
+        return false;
+    }
+
+    let at = match menu.offset() {
+        Some(offset) => to_offset(at, business, offset),
+        None => at,
+    };
+    let mut windows = windows
+        .iter()
+        .filter(|w| w.menu_id == menu.menu_id)
+        .peekable();
+    windows.peek().is_none() || windows.any(|w| w.is_open_at(at))
+}
+
+/// The products, in the order given, that are on a menu, directly or through one of their
+/// categories, but not on any of the `open` menus. Products on no menu are never left out.
+pub fn products_off_menu(
+    product_ids: &[i32],
+    open: &[i32],
+    menu_products: &[MenuProduct],
+    menu_categories: &[MenuCategory],
+    product_categories: &[(i32, i32)], // product_id, category_id
+) -> Vec<i32> {
+    let mut off_menu = Vec::new();
+    for &product_id in product_ids {
+        let categories: HashSet<i32> = product_categories
+            .iter()
+            .filter(|(pid, _)| *pid == product_id)
+            .map(|(_, cid)| *cid)
+            .collect();
+        let menus: HashSet<i32> = menu_products
+            .iter()
+            .filter(|m| m.product_id == product_id)
+            .map(|m| m.menu_id)
+            .chain(
+                menu_categories
+                    .iter()
+                    .filter(|m| categories.contains(&m.category_id))
+                    .map(|m| m.menu_id),
+            )
+            .collect();
+
+        if !menus.is_empty()
+            && !menus.iter().any(|menu| open.contains(menu))
+            && !off_menu.contains(&product_id)
+        {
+            off_menu.push(product_id);
+        }
+    }
+    off_menu
+}
+
+/// Menus with their windows and the products and categories on them
+struct Menus {
+    menus: Vec<Menu>,
+    windows: Vec<MenuSchedule>,
+    products: Vec<MenuProduct>,
+    categories: Vec<MenuCategory>,
+}
+
+impl Menus {
+    /// Ids of the menus served at the business time `at`
+    fn open_at(&self, at: NaiveDateTime) -> Vec<i32> {
+        let business = business_offset();
+        self.menus
+            .iter()
+            .filter(|menu| is_menu_open(menu, &self.windows, at, business))
+            .map(|menu| menu.menu_id)
+            .collect()
+    }
+
+    fn response(&self, menu: Menu, open: &[i32]) -> MenuResponse {
+        let menu_id = menu.menu_id;
+        let mut response = MenuResponse::from(menu);
+        response.is_open = open.contains(&menu_id);
+        response.windows = self
+            .windows
+            .iter()
+            .filter(|w| w.menu_id == menu_id)
+            .map(MenuWindowResponse::from)
+            .collect();
+        response.product_ids = self
+            .products
+            .iter()
+            .filter(|m| m.menu_id == menu_id)
+            .map(|m| m.product_id)
+            .collect();
+        response.category_ids = self
+            .categories
+            .iter()
+            .filter(|m| m.menu_id == menu_id)
+            .map(|m| m.category_id)
+            .collect();
+        response
+    }
+}
+
+pub struct MenuService;
+
+impl MenuService {
+    pub fn new() -> Self {
+        MenuService
+    }
+
+    /// Gets all menus with their windows, products and categories
+    /// (requires READ permission or Admin)
+    pub async fn get_menus(&self, role_id: i32) -> Result<Vec<MenuResponse>, MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let mut menus = self.load().await?;
+        let open = menus.open_at(local_now());
+        let all = std::mem::take(&mut menus.menus);
+
+        Ok(all
+            .into_iter()
+            .map(|menu| menus.response(menu, &open))
+            .collect())
+    }
+
+    /// Gets a menu with its windows, products and categories
+    /// (requires READ permission or Admin)
+    pub async fn get_menu(
+        &self,
+        role_id: i32,
+        menu_id: i32,
+    ) -> Result<MenuResponse, MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let mut menus = self.load().await?;
+        let open = menus.open_at(local_now());
+        let menu = std::mem::take(&mut menus.menus)
+            .into_iter()
+            .find(|m| m.menu_id == menu_id)
+            .ok_or(MenuServiceError::MenuNotFound)?;
+
+        Ok(menus.response(menu, &open))
+    }
+
+    /// Resolves the menus being served now and the products that can be ordered from them,
+    /// along with the products on no menu, in a locale (requires READ permission or Admin)
+    pub async fn get_current(
+        &self,
+        role_id: i32,
+        locale: &str,
+    ) -> Result<CurrentMenuResponse, MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let now = local_now();
+        let mut menus = self.load().await?;
+        let open = menus.open_at(now);
+
+        let products: Vec<_> = ProductRepo::new()
+            .get_active()
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| p.is_available_at(now))
+            .collect();
+        let product_ids: Vec<i32> = products.iter().map(|p| p.product_id).collect();
+        let product_categories = ProductCategoryRepo::new()
+            .get_category_ids_by_product_ids(product_ids.clone())
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?;
+        let off_menu = products_off_menu(
+            &product_ids,
+            &open,
+            &menus.products,
+            &menus.categories,
+            &product_categories,
+        );
+
+        let mut nutrition = NutritionService::new()
+            .get_nutrition(product_ids)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?;
+
+        let served = std::mem::take(&mut menus.menus)
+            .into_iter()
+            .filter(|m| open.contains(&m.menu_id))
+            .map(|menu| menus.response(menu, &open))
+            .collect();
+
+        let mut products: Vec<ProductResponse> = products
+            .into_iter()
+            .filter(|p| !off_menu.contains(&p.product_id))
+            .map(|p| {
+                let mut response = ProductResponse::from(p);
+                response.nutrition = nutrition
+                    .remove(&response.product_id)
+                    .map(NutritionResponse::from);
+                response
+            })
+            .collect();
+        TranslationService::new()
+            .localize_products(&mut products, locale)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?;
+
+        Ok(CurrentMenuResponse {
+            at: now.to_string(),
+            menus: served,
+            products,
+        })
+    }
+
+    /// Creates a menu (requires Admin)
+    pub async fn create_menu(
+        &self,
+        role_id: i32,
+        request: &CreateMenuRequest,
+    ) -> Result<(), MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let menu = NewMenu::from(request);
+        if menu.name.is_empty() || !is_valid_offset(menu.utc_offset) {
+            return Err(MenuServiceError::InvalidMenu);
+        }
+        self.ensure_name_free(menu.name, None).await?;
+
+        MenuRepo::new()
+            .add(menu)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)
+    }
+
+    /// Renames a menu, moves its schedule to another offset or switches it on or off
+    /// (requires Admin)
+    pub async fn update_menu(
+        &self,
+        role_id: i32,
+        menu_id: i32,
+        request: &UpdateMenuRequest,
+    ) -> Result<(), MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let update = UpdateMenu::from(request);
+        if !is_valid_offset(update.utc_offset.flatten()) {
+            return Err(MenuServiceError::InvalidMenu);
+        }
+
+        let repo = MenuRepo::new();
+        repo.get_by_id(menu_id)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+            .ok_or(MenuServiceError::MenuNotFound)?;
+
+        if let Some(name) = update.name {
+            if name.is_empty() {
+                return Err(MenuServiceError::InvalidMenu);
+            }
+            self.ensure_name_free(name, Some(menu_id)).await?;
+        }
+
+        repo.update(menu_id, update)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)
+    }
+
+    /// Deletes a menu. Its products can be ordered at any time unless they are on another
+    /// menu (requires Admin)
+    pub async fn delete_menu(&self, role_id: i32, menu_id: i32) -> Result<(), MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let repo = MenuRepo::new();
+        repo.get_by_id(menu_id)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+            .ok_or(MenuServiceError::MenuNotFound)?;
+
+        repo.delete(menu_id)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)
+    }
+
+    /// Replaces the weekly windows a menu is served in. Without windows it is served all day
+    /// (requires Admin)
+    pub async fn set_schedule(
+        &self,
+        role_id: i32,
+        menu_id: i32,
+        request: &MenuScheduleRequest,
+    ) -> Result<(), MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let mut windows = Vec::new();
+        for window in &request.windows {
+            let (Some(day), Some(start), Some(end)) = (
+                parse_day(&window.day),
+                parse_time(&window.start),
+                parse_time(&window.end),
+            ) else {
+                return Err(MenuServiceError::InvalidSchedule);
+            };
+            if start == end {
+                return Err(MenuServiceError::InvalidSchedule);
+            }
+            windows.push(NewMenuSchedule {
+                menu_id,
+                day_of_week: day,
+                start_time: start,
+                end_time: end,
+            });
+        }
+
+        let repo = MenuRepo::new();
+        repo.get_by_id(menu_id)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+            .ok_or(MenuServiceError::MenuNotFound)?;
+
+        repo.replace_schedule(menu_id, windows)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)
+    }
+
+    /// Replaces the products and categories on a menu (requires Admin)
+    pub async fn set_items(
+        &self,
+        role_id: i32,
+        menu_id: i32,
+        request: &MenuItemsRequest,
+    ) -> Result<(), MenuServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(MenuServiceError::PermissionDenied);
+        }
+
+        let repo = MenuRepo::new();
+        repo.get_by_id(menu_id)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+            .ok_or(MenuServiceError::MenuNotFound)?;
+
+        let mut product_ids = request.product_ids.clone().unwrap_or_default();
+        product_ids.sort_unstable();
+        product_ids.dedup();
+        let mut category_ids = request.category_ids.clone().unwrap_or_default();
+        category_ids.sort_unstable();
+        category_ids.dedup();
+
+        let product_repo = ProductRepo::new();
+        for product_id in &product_ids {
+            product_repo
+                .get_by_id(*product_id)
+                .await
+                .map_err(|_| MenuServiceError::DatabaseError)?
+                .ok_or(MenuServiceError::InvalidItems)?;
+        }
+        let category_repo = CategoryRepo::new();
+        for category_id in &category_ids {
+            category_repo
+                .get_by_id(*category_id)
+                .await
+                .map_err(|_| MenuServiceError::DatabaseError)?
+                .ok_or(MenuServiceError::InvalidItems)?;
+        }
+
+        repo.replace_items(menu_id, product_ids, category_ids)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)
+    }
+
+    /// The products that cannot be ordered for the business time `at` because none of their
+    /// menus is served then. Called while creating an order, so no permission is checked.
+    pub async fn products_off_menu_at(
+        &self,
+        product_ids: &[i32],
+        at: NaiveDateTime,
+    ) -> Result<Vec<i32>, MenuServiceError> {
+        let menus = self.load().await?;
+        if menus.products.is_empty() && menus.categories.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let product_categories = ProductCategoryRepo::new()
+            .get_category_ids_by_product_ids(product_ids.to_vec())
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?;
+
+        Ok(products_off_menu(
+            product_ids,
+            &menus.open_at(at),
+            &menus.products,
+            &menus.categories,
+            &product_categories,
+        ))
+    }
+
+    async fn load(&self) -> Result<Menus, MenuServiceError> {
+        let repo = MenuRepo::new();
+        Ok(Menus {
+            menus: repo
+                .get_all()
+                .await
+                .map_err(|_| MenuServiceError::DatabaseError)?
+                .unwrap_or_default(),
+            windows: repo
+                .get_schedules()
+                .await
+                .map_err(|_| MenuServiceError::DatabaseError)?,
+            products: repo
+                .get_products()
+                .await
+                .map_err(|_| MenuServiceError::DatabaseError)?,
+            categories: repo
+                .get_categories()
+                .await
+                .map_err(|_| MenuServiceError::DatabaseError)?,
+        })
+    }
+
+    async fn ensure_name_free(
+        &self,
+        name: &str,
+        except: Option<i32>,
+    ) -> Result<(), MenuServiceError> {
+        let menus = MenuRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        if menus
+            .iter()
+            .any(|m| m.name.eq_ignore_ascii_case(name.trim()) && Some(m.menu_id) != except)
+        {
+            return Err(MenuServiceError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, MenuServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| MenuServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+/// Whether an offset is missing or is one such as `+09:00`
+fn is_valid_offset(offset: Option<&str>) -> bool {
+    offset.is_none_or(|offset| offset.parse::<FixedOffset>().is_ok())
+}
+
+impl Default for MenuService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
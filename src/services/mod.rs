@@ -1,5 +1,26 @@
+pub mod allergen_service;
+pub mod blob_store;
+pub mod bundle_service;
+pub mod check_service;
 pub mod errors;
+pub mod image_service;
+pub mod inventory_service;
+pub mod kitchen_service;
+pub mod menu_service;
+pub mod nutrition_service;
+pub mod order_scheduler;
 pub mod order_service;
+pub mod payment_gateway;
+pub mod payment_service;
+pub mod pricing_service;
 pub mod product_category_service;
+pub mod product_price_service;
 pub mod product_service;
+pub mod promotion_service;
+pub mod purchasing_service;
+pub mod reservation_service;
 pub mod role_service;
+pub mod table_service;
+pub mod tip_service;
+pub mod translation_service;
+pub mod waste_service;
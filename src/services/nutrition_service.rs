@@ -0,0 +1,378 @@
+use crate::api::request::{IngredientNutritionRequest, NutritionImportRequest, NutritionRequest};
+use crate::api::response::NutritionImportResponse;
+use crate::data::models::ingredient::Recipe;
+use crate::data::models::nutrition::{IngredientNutrition, ProductNutrition};
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::ingredient_repo::IngredientRepo;
+use crate::data::repos::implementors::nutrition_repo::NutritionRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::NutritionServiceError;
+use bigdecimal::{BigDecimal, RoundingMode, Signed, Zero};
+use std::collections::{HashMap, HashSet};
+
+/// Whether nutrition facts can be set on a product: no value is negative, the serving size
+/// is not blank and fits, and every value is given unless the facts come from the recipe
+pub fn is_valid_nutrition(request: &NutritionRequest) -> bool {
+    let values = [
+        &request.kcal,
+        &request.protein,
+        &request.carbohydrates,
+        &request.sugars,
+        &request.fat,
+        &request.salt,
+    ];
+
+    request
+        .serving_size
+        .as_ref()
+        .is_none_or(|s| !s.trim().is_empty() && s.trim().len() <= 50)
+        && values
+            .iter()
+            .all(|v| v.as_ref().is_none_or(|v| !v.is_negative()))
+        && (request.from_recipe.unwrap_or(false) || values.iter().all(|v| v.is_some()))
+}
+
+/// Whether the nutrition of an ingredient has no negative value
+pub fn is_valid_ingredient_nutrition(request: &IngredientNutritionRequest) -> bool {
+    [
+        &request.kcal,
+        &request.protein,
+        &request.carbohydrates,
+        &request.sugars,
+        &request.fat,
+        &request.salt,
+    ]
+    .iter()
+    .all(|v| !v.is_negative())
+}
+
+/// The nutrition facts shown for a product. Facts from the recipe are the nutrition of
+/// each ingredient times the quantity of it in one of the product, rounded to 2 decimals;
+/// None when the product has no recipe or an ingredient of it has no nutrition.
+pub fn resolve_nutrition(
+    nutrition: &ProductNutrition,
+    recipes: &[Recipe],
+    ingredients: &[IngredientNutrition],
+) -> Option<ProductNutrition> {
+    if !nutrition.from_recipe {
+        return Some(nutrition.clone());
+    }
+
+    let lines: Vec<&Recipe> = recipes
+        .iter()
+        .filter(|r| r.product_id == nutrition.product_id)
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+
+    let mut total = ProductNutrition {
+        product_id: nutrition.product_id,
+        serving_size: nutrition.serving_size.clone(),
+        kcal: BigDecimal::zero(),
+        protein: BigDecimal::zero(),
+        carbohydrates: BigDecimal::zero(),
+        sugars: BigDecimal::zero(),
+        fat: BigDecimal::zero(),
+        salt: BigDecimal::zero(),
+        from_recipe: true,
+    };
+    for line in lines {
+        let unit = ingredients
+            .iter()
+            .find(|i| i.ingredient_id == line.ingredient_id)?;
+        total.kcal += &line.quantity * &unit.kcal;
+        total.protein += &line.quantity * &unit.protein;
+        total.carbohydrates += &line.quantity * &unit.carbohydrates;
+        total.sugars += &line.quantity * &unit.sugars;
+        total.fat += &line.quantity * &unit.fat;
+        total.salt += &line.quantity * &unit.salt;
+    }
+
+    let round = |value: &BigDecimal| value.with_scale_round(2, RoundingMode::HalfUp);
+    Some(ProductNutrition {
+        kcal: round(&total.kcal),
+        protein: round(&total.protein),
+        carbohydrates: round(&total.carbohydrates),
+        sugars: round(&total.sugars),
+        fat: round(&total.fat),
+        salt: round(&total.salt),
+        ..total
+    })
+}
+
+/// Nutrition facts as stored, with no values when they come from the recipe
+fn product_nutrition(product_id: i32, request: &NutritionRequest) -> ProductNutrition {
+    let from_recipe = request.from_recipe.unwrap_or(false);
+    let value = |v: &Option<BigDecimal>| {
+        v.clone()
+            .filter(|_| !from_recipe)
+            .unwrap_or_else(BigDecimal::zero)
+    };
+
+    ProductNutrition {
+        product_id,
+        serving_size: request.serving_size.as_ref().map(|s| s.trim().to_string()),
+        kcal: value(&request.kcal),
+        protein: value(&request.protein),
+        carbohydrates: value(&request.carbohydrates),
+        sugars: value(&request.sugars),
+        fat: value(&request.fat),
+        salt: value(&request.salt),
+        from_recipe,
+    }
+}
+
+fn ingredient_nutrition(
+    ingredient_id: i32,
+    request: &IngredientNutritionRequest,
+) -> IngredientNutrition {
+    IngredientNutrition {
+        ingredient_id,
+        kcal: request.kcal.clone(),
+        protein: request.protein.clone(),
+        carbohydrates: request.carbohydrates.clone(),
+        sugars: request.sugars.clone(),
+        fat: request.fat.clone(),
+        salt: request.salt.clone(),
+    }
+}
+
+pub struct NutritionService;
+
+impl NutritionService {
+    pub fn new() -> Self {
+        NutritionService
+    }
+
+    /// Gets the nutrition facts shown for products, by product id. Products without facts,
+    /// or whose recipe cannot be summed, are left out. Used while listing products, so no
+    /// permission is checked.
+    pub async fn get_nutrition(
+        &self,
+        product_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, ProductNutrition>, NutritionServiceError> {
+        if product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let repo = NutritionRepo::new();
+        let stored = repo
+            .get_products(product_ids)
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?;
+
+        let from_recipe: Vec<i32> = stored
+            .iter()
+            .filter(|n| n.from_recipe)
+            .map(|n| n.product_id)
+            .collect();
+        let (recipes, ingredients) = if from_recipe.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            let recipes = IngredientRepo::new()
+                .get_recipes(from_recipe)
+                .await
+                .map_err(|_| NutritionServiceError::DatabaseError)?;
+            let ingredients = repo
+                .get_ingredients(recipes.iter().map(|r| r.ingredient_id).collect())
+                .await
+                .map_err(|_| NutritionServiceError::DatabaseError)?;
+            (recipes, ingredients)
+        };
+
+        Ok(stored
+            .iter()
+            .filter_map(|n| resolve_nutrition(n, &recipes, &ingredients))
+            .map(|n| (n.product_id, n))
+            .collect())
+    }
+
+    /// Replaces the nutrition facts of a product (requires WRITE permission or Admin)
+    pub async fn set_product_nutrition(
+        &self,
+        product_id: i32,
+        request: &NutritionRequest,
+        role_id: i32,
+    ) -> Result<(), NutritionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(NutritionServiceError::PermissionDenied);
+        }
+
+        self.ensure_product(product_id).await?;
+        if !is_valid_nutrition(request) {
+            return Err(NutritionServiceError::InvalidNutrition);
+        }
+
+        NutritionRepo::new()
+            .save(vec![product_nutrition(product_id, request)], Vec::new())
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)
+    }
+
+    /// Removes the nutrition facts of a product (requires WRITE permission or Admin)
+    pub async fn remove_product_nutrition(
+        &self,
+        product_id: i32,
+        role_id: i32,
+    ) -> Result<(), NutritionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(NutritionServiceError::PermissionDenied);
+        }
+
+        self.ensure_product(product_id).await?;
+
+        NutritionRepo::new()
+            .delete_product(product_id)
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)
+    }
+
+    /// Replaces the nutrition of one unit of an ingredient (requires Admin)
+    pub async fn set_ingredient_nutrition(
+        &self,
+        ingredient_id: i32,
+        request: &IngredientNutritionRequest,
+        role_id: i32,
+    ) -> Result<(), NutritionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(NutritionServiceError::PermissionDenied);
+        }
+
+        IngredientRepo::new()
+            .get_by_id(ingredient_id)
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?
+            .ok_or(NutritionServiceError::IngredientNotFound)?;
+        if !is_valid_ingredient_nutrition(request) {
+            return Err(NutritionServiceError::InvalidNutrition);
+        }
+
+        NutritionRepo::new()
+            .save(
+                Vec::new(),
+                vec![ingredient_nutrition(ingredient_id, request)],
+            )
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)
+    }
+
+    /// Replaces the nutrition of many products and ingredients in a single transaction
+    /// (requires Admin). Nothing is imported when any entry is invalid, lists a product or
+    /// ingredient twice, or is for an unknown or archived product or an unknown ingredient.
+    pub async fn import_nutrition(
+        &self,
+        request: &NutritionImportRequest,
+        role_id: i32,
+    ) -> Result<NutritionImportResponse, NutritionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(NutritionServiceError::PermissionDenied);
+        }
+
+        let product_ids: HashSet<i32> = request.products.iter().map(|p| p.product_id).collect();
+        let ingredient_ids: HashSet<i32> = request
+            .ingredients
+            .iter()
+            .map(|i| i.ingredient_id)
+            .collect();
+        if product_ids.len() != request.products.len()
+            || ingredient_ids.len() != request.ingredients.len()
+            || !request
+                .products
+                .iter()
+                .all(|p| is_valid_nutrition(&p.nutrition))
+            || !request
+                .ingredients
+                .iter()
+                .all(|i| is_valid_ingredient_nutrition(&i.nutrition))
+        {
+            return Err(NutritionServiceError::InvalidNutrition);
+        }
+
+        let products: HashSet<i32> = ProductRepo::new()
+            .get_active()
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| p.product_id)
+            .collect();
+        let mut unknown: Vec<i32> = product_ids.difference(&products).copied().collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            return Err(NutritionServiceError::UnknownProducts(unknown));
+        }
+
+        let ingredients: HashSet<i32> = IngredientRepo::new()
+            .get_by_ids(ingredient_ids.iter().copied().collect())
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?
+            .into_iter()
+            .map(|i| i.ingredient_id)
+            .collect();
+        let mut unknown: Vec<i32> = ingredient_ids.difference(&ingredients).copied().collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            return Err(NutritionServiceError::UnknownIngredients(unknown));
+        }
+
+        NutritionRepo::new()
+            .save(
+                request
+                    .products
+                    .iter()
+                    .map(|p| product_nutrition(p.product_id, &p.nutrition))
+                    .collect(),
+                request
+                    .ingredients
+                    .iter()
+                    .map(|i| ingredient_nutrition(i.ingredient_id, &i.nutrition))
+                    .collect(),
+            )
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?;
+
+        Ok(NutritionImportResponse {
+            products: request.products.len(),
+            ingredients: request.ingredients.len(),
+        })
+    }
+
+    async fn ensure_product(&self, product_id: i32) -> Result<(), NutritionServiceError> {
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or(NutritionServiceError::ProductNotFound)?;
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, NutritionServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| NutritionServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for NutritionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,59 @@
+use crate::api::config::Config;
+use crate::services::order_service::OrderService;
+use crate::services::product_price_service::ProductPriceService;
+use crate::utils::time::local_now;
+use chrono::Duration;
+
+/// Background task releasing scheduled orders to the kitchen once they are due within
+/// `SCHEDULE_LEAD_MINUTES` and applying scheduled price changes, checking every
+/// `SCHEDULER_INTERVAL_SECONDS`
+pub struct OrderScheduler {
+    interval: std::time::Duration,
+    lead: Duration,
+}
+
+impl OrderScheduler {
+    pub fn new() -> Self {
+        let config = Config::new();
+        OrderScheduler {
+            interval: std::time::Duration::from_secs(config.scheduler_interval_seconds.max(1)),
+            lead: Duration::minutes(config.schedule_lead_minutes),
+        }
+    }
+
+    /// Starts the scheduler on the runtime, it runs for as long as the server does
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(self) {
+        let service = OrderService::new();
+        let prices = ProductPriceService::new();
+        let mut ticker = tokio::time::interval(self.interval);
+
+        loop {
+            ticker.tick().await;
+
+            match service
+                .release_scheduled_orders(local_now(), self.lead)
+                .await
+            {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Released {} scheduled orders", count),
+                Err(e) => tracing::error!("Failed to release scheduled orders: {}", e),
+            }
+
+            match prices.apply_scheduled_prices(local_now()).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Applied scheduled prices to {} products", count),
+                Err(e) => tracing::error!("Failed to apply scheduled prices: {}", e),
+            }
+        }
+    }
+}
+
+impl Default for OrderScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
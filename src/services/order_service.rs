@@ -1,15 +1,51 @@
-use crate::data::models::order::{NewOrder, Order, UpdateOrder};
+use crate::api::config::Config;
+use crate::api::request::{OrderQuery, RefundPaymentRequest, VoidItemsRequest};
+use crate::api::response::{
+    AllergenAlertResponse, OrderAdjustmentResponse, OrderDiscountResponse, OrderResponse,
+};
+use crate::data::models::allergen::OrderAllergenAlert;
+use crate::data::models::order::{
+    NewOrder, Order, OrderPlacement, OrderPricing, OrderSeating, OrderTipping, UpdateOrder,
+};
+use crate::data::models::order_adjustment::NewOrderAdjustment;
+use crate::data::models::order_discount::NewOrderDiscount;
 use crate::data::models::order_product::OrderProduct;
 use crate::data::models::product::Product;
+use crate::data::models::refund::NewRefund;
 use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::allergen_repo::AllergenRepo;
+use crate::data::repos::implementors::order_adjustment_repo::OrderAdjustmentRepo;
+use crate::data::repos::implementors::order_check_repo::OrderCheckRepo;
+use crate::data::repos::implementors::order_discount_repo::OrderDiscountRepo;
 use crate::data::repos::implementors::order_repo::OrderRepo;
+use crate::data::repos::implementors::payment_repo::PaymentRepo;
+use crate::data::repos::implementors::product_price_repo::ProductPriceRepo;
+use crate::data::repos::implementors::refund_repo::RefundRepo;
+use crate::data::repos::implementors::table_session_repo::TableSessionRepo;
 use crate::data::repos::traits::repository::Repository;
-use crate::services::errors::OrderServiceError;
-use bigdecimal::{BigDecimal, FromPrimitive};
+use crate::services::allergen_service::{Allergen, AllergenService, allergen_alerts};
+use crate::services::bundle_service::{BundleService, expand_lines};
+use crate::services::check_service::split_components;
+use crate::services::errors::{
+    BundleServiceError, OrderServiceError, PaymentServiceError, PromotionServiceError,
+};
+use crate::services::inventory_service::{InventoryService, StockMovementReason};
+use crate::services::kitchen_service::KitchenService;
+use crate::services::menu_service::MenuService;
+use crate::services::payment_service::{PaymentService, PaymentStatus};
+use crate::services::pricing_service::{PriceComponents, PricingEngine, PricingLine};
+use crate::services::promotion_service::PromotionService;
+use crate::services::tip_service::auto_gratuity;
+use crate::utils::time::{business_date, is_within_daily_window, local_now, parse_date};
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{Duration, NaiveDateTime, NaiveTime};
+use std::collections::{HashMap, HashSet};
 
 /// Order statuses for workflow management
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OrderStatus {
+    /// Future-dated order that has not been released to the kitchen yet
+    Scheduled,
     Pending,
     Accepted,
     Ready,
@@ -20,6 +56,7 @@ pub enum OrderStatus {
 impl OrderStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
+            OrderStatus::Scheduled => "Scheduled",
             OrderStatus::Pending => "Pending",
             OrderStatus::Accepted => "Accepted",
             OrderStatus::Ready => "Ready",
@@ -33,6 +70,7 @@ impl std::str::FromStr for OrderStatus {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
+            "scheduled" => Ok(OrderStatus::Scheduled),
             "pending" => Ok(OrderStatus::Pending),
             "accepted" => Ok(OrderStatus::Accepted),
             "ready" => Ok(OrderStatus::Ready),
@@ -43,6 +81,210 @@ impl std::str::FromStr for OrderStatus {
     }
 }
 
+/// Kinds of entries in the order adjustment ledger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjustmentType {
+    Cancellation,
+    Void,
+    Refund,
+}
+
+impl AdjustmentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdjustmentType::Cancellation => "cancellation",
+            AdjustmentType::Void => "void",
+            AdjustmentType::Refund => "refund",
+        }
+    }
+}
+
+/// Why order lines were voided or money was refunded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReasonCode {
+    CustomerRequest,
+    WrongItem,
+    QualityIssue,
+    Overcharge,
+    Complimentary,
+    Other,
+}
+
+impl ReasonCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReasonCode::CustomerRequest => "customer_request",
+            ReasonCode::WrongItem => "wrong_item",
+            ReasonCode::QualityIssue => "quality_issue",
+            ReasonCode::Overcharge => "overcharge",
+            ReasonCode::Complimentary => "complimentary",
+            ReasonCode::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for ReasonCode {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "customer_request" => Ok(ReasonCode::CustomerRequest),
+            "wrong_item" => Ok(ReasonCode::WrongItem),
+            "quality_issue" => Ok(ReasonCode::QualityIssue),
+            "overcharge" => Ok(ReasonCode::Overcharge),
+            "complimentary" => Ok(ReasonCode::Complimentary),
+            "other" => Ok(ReasonCode::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Where an order is served
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrderType {
+    #[default]
+    DineIn,
+    Takeout,
+    Delivery,
+}
+
+impl OrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::DineIn => "dine_in",
+            OrderType::Takeout => "takeout",
+            OrderType::Delivery => "delivery",
+        }
+    }
+}
+
+impl std::str::FromStr for OrderType {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dine_in" => Ok(OrderType::DineIn),
+            "takeout" => Ok(OrderType::Takeout),
+            "delivery" => Ok(OrderType::Delivery),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Separates the voided quantities from priced order lines.
+/// Voids are (product_id, quantity) and must each name a different order line, voiding at
+/// least one and at most all of its quantity. Line discounts are divided in proportion to the
+/// quantity. Returns the remaining lines, without those voided entirely, and the voided part
+/// of each line in the order of the voids.
+pub fn void_lines(
+    lines: &[PricingLine],
+    voids: &[(i32, i32)],
+) -> Result<(Vec<PricingLine>, Vec<PricingLine>), OrderServiceError> {
+    let voided_ids: HashSet<i32> = voids.iter().map(|(product_id, _)| *product_id).collect();
+    if voids.is_empty() || voided_ids.len() != voids.len() {
+        return Err(OrderServiceError::InvalidVoid);
+    }
+
+    let mut voided = Vec::new();
+    for (product_id, quantity) in voids {
+        let line = lines
+            .iter()
+            .find(|l| l.product_id == *product_id)
+            .ok_or(OrderServiceError::InvalidVoid)?;
+
+        if *quantity <= 0 || *quantity > line.quantity {
+            return Err(OrderServiceError::InvalidVoid);
+        }
+
+        voided.push(PricingLine {
+            quantity: *quantity,
+            discount: &line.discount * BigDecimal::from(*quantity)
+                / BigDecimal::from(line.quantity),
+            ..line.clone()
+        });
+    }
+
+    let remaining = lines
+        .iter()
+        .filter_map(|line| {
+            let voided_quantity = voids
+                .iter()
+                .find(|(product_id, _)| *product_id == line.product_id)
+                .map(|(_, quantity)| *quantity)
+                .unwrap_or(0);
+            let quantity = line.quantity - voided_quantity;
+
+            (quantity > 0).then(|| PricingLine {
+                quantity,
+                discount: &line.discount * BigDecimal::from(quantity)
+                    / BigDecimal::from(line.quantity),
+                ..line.clone()
+            })
+        })
+        .collect();
+
+    Ok((remaining, voided))
+}
+
+/// Optional settings for a new order
+#[derive(Debug, Clone, Default)]
+pub struct OrderOptions {
+    /// Code of a promotion to redeem on the order
+    pub promo_code: Option<String>,
+    /// Number of guests, large parties are charged the auto gratuity
+    pub party_size: Option<i32>,
+    /// Staff member serving the order, who receives its tips
+    pub served_by: Option<i32>,
+    pub order_type: OrderType,
+    /// Table the order is served at, which must have an open session
+    pub table_id: Option<i32>,
+    /// When a future-dated order is due
+    pub scheduled_for: Option<NaiveDateTime>,
+    /// Products chosen for the slots (slot_id, product_id) of each bundle ordered, by the
+    /// product id of the bundle
+    pub bundle_selections: HashMap<i32, Vec<(i32, i32)>>,
+    /// Allergens the guest flagged, which items containing them are alerted about
+    pub allergies: Vec<Allergen>,
+}
+
+/// Whether an order can be scheduled for a time: it must be in the future and, when opening
+/// hours are given, fall within them
+pub fn is_valid_schedule(
+    scheduled_for: NaiveDateTime,
+    now: NaiveDateTime,
+    opening_hours: Option<(NaiveTime, NaiveTime)>,
+) -> bool {
+    scheduled_for > now
+        && opening_hours
+            .is_none_or(|(open, close)| is_within_daily_window(scheduled_for.time(), open, close))
+}
+
+/// Status a new order starts in. A scheduled order waits as `Scheduled` until `lead` before
+/// it is due, after which it goes straight to the kitchen as `Pending`.
+pub fn initial_status(
+    scheduled_for: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+    lead: Duration,
+) -> OrderStatus {
+    match scheduled_for {
+        Some(due) if due - lead > now => OrderStatus::Scheduled,
+        _ => OrderStatus::Pending,
+    }
+}
+
+/// Formats a daily order number for display, such as `#007`
+pub fn format_order_number(number: i32) -> String {
+    format!("#{number:03}")
+}
+
+/// Parses a daily order number as staff would type it, such as `7`, `007` or `#007`
+pub fn parse_order_number(value: &str) -> Option<i32> {
+    value
+        .trim()
+        .trim_start_matches('#')
+        .parse()
+        .ok()
+        .filter(|number| *number > 0)
+}
+
 pub struct OrderService;
 
 impl OrderService {
@@ -57,39 +299,306 @@ impl OrderService {
         role_id: i32,
         items: Vec<(i32, i32)>, // product_id, quantity
     ) -> Result<(), OrderServiceError> {
+        self.create_order_with_options(user_id, role_id, items, OrderOptions::default())
+            .await
+            .map(|_| ())
+    }
+
+    /// Creates a new order for a user, applying automatic promotions and the promo code
+    /// if one is given (requires WRITE permission or Admin)
+    /// An order for a table joins the table's open session and defaults to its party size.
+    /// Items that are 86'd are rejected with their product ids, as are orders needing more
+    /// of an ingredient than is in stock when `BLOCK_NEGATIVE_STOCK` is set.
+    /// Bundles are charged their price plus the upcharges of the products picked for them,
+    /// and the picks take their place on the kitchen tickets and in the stock check.
+    /// Its items are split into a kitchen ticket per station, and a pending order gets an
+    /// estimated ready time behind the accepted orders.
+    /// Items, or products picked for bundles, containing an allergen the guest flagged do not
+    /// stop the order; they are recorded on it and returned as (product_id, allergen).
+    pub async fn create_order_with_options(
+        &self,
+        user_id: i32,
+        role_id: i32,
+        items: Vec<(i32, i32)>, // product_id, quantity
+        options: OrderOptions,
+    ) -> Result<Vec<(i32, Allergen)>, OrderServiceError> {
         if !self.has_permission(role_id, RolePermissions::Write).await?
             && !self.has_permission(role_id, RolePermissions::Admin).await?
         {
             return Err(OrderServiceError::PermissionDenied);
         }
 
+        let config = Config::new();
+        let now = local_now();
+        if options
+            .scheduled_for
+            .is_some_and(|due| !is_valid_schedule(due, now, config.opening_hours))
+        {
+            return Err(OrderServiceError::InvalidSchedule);
+        }
+
+        let (session_id, party_size) = match options.table_id {
+            Some(table_id) => {
+                if options.order_type != OrderType::DineIn {
+                    return Err(OrderServiceError::InvalidOrderType);
+                }
+
+                let session = TableSessionRepo::new()
+                    .get_open_by_table_id(table_id)
+                    .await
+                    .map_err(|_| OrderServiceError::DatabaseError)?
+                    .ok_or(OrderServiceError::TableNotSeated)?;
+
+                (
+                    Some(session.session_id),
+                    options.party_size.or(session.party_size),
+                )
+            }
+            None => (None, options.party_size),
+        };
+
+        let bundle_items = BundleService::new()
+            .choose_for_order(&items, &options.bundle_selections)
+            .await
+            .map_err(|e| match e {
+                BundleServiceError::InvalidSelection(ids) => {
+                    OrderServiceError::InvalidBundleSelection(ids)
+                }
+                _ => OrderServiceError::DatabaseError,
+            })?;
+
         let product_repo = crate::data::repos::implementors::product_repo::ProductRepo::new();
         let mut order_items = Vec::new();
-        let mut total_amount = BigDecimal::from(0);
+        let mut archived = Vec::new();
+        let mut unavailable = Vec::new();
+        // A scheduled order needs its products when it is due rather than now
+        let due = options.scheduled_for.unwrap_or(now);
+        // Lines are charged at the prices in effect when the order is placed, even when a
+        // scheduled price change is yet to reach the products
+        let product_ids: Vec<i32> = items.iter().map(|(pid, _)| *pid).collect();
+        let effective_prices: HashMap<i32, BigDecimal> = ProductPriceRepo::new()
+            .get_effective(&product_ids, now)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .into_iter()
+            .map(|p| (p.product_id, p.price))
+            .collect();
 
         for (pid, qty) in items {
             let product = product_repo.get_by_id(pid).await
                 .map_err(|_| OrderServiceError::DatabaseError)?
                 .ok_or(OrderServiceError::OrderCreationFailed)?; 
 
-            let price = product.price;
-            let qty_bd = BigDecimal::from_i32(qty).unwrap_or_default();
-            let line_total = &price * &qty_bd;
-            total_amount += line_total;
+            if product.deleted_at.is_some() {
+                if !archived.contains(&pid) {
+                    archived.push(pid);
+                }
+            } else if !product.is_available_at(due) && !unavailable.contains(&pid) {
+                unavailable.push(pid);
+            }
+            let upcharges: BigDecimal = bundle_items
+                .iter()
+                .filter(|item| item.bundle_id == pid)
+                .map(|item| &item.upcharge)
+                .sum();
+            let price = effective_prices.get(&pid).cloned().unwrap_or(product.price);
+            order_items.push((pid, qty, price + upcharges));
+        }
 
-            order_items.push((pid, qty, price));
+        for item in &bundle_items {
+            let product = product_repo.get_by_id(item.product_id).await
+                .map_err(|_| OrderServiceError::DatabaseError)?
+                .ok_or(OrderServiceError::OrderCreationFailed)?;
+
+            if product.deleted_at.is_some() {
+                if !archived.contains(&item.product_id) {
+                    archived.push(item.product_id);
+                }
+            } else if !product.is_available_at(due) && !unavailable.contains(&item.product_id) {
+                unavailable.push(item.product_id);
+            }
+        }
+
+        // Archived products are gone for good, unlike 86'd ones, so they are reported apart
+        if !archived.is_empty() {
+            return Err(OrderServiceError::ProductsArchived(archived));
+        }
+        if !unavailable.is_empty() {
+            return Err(OrderServiceError::ProductsUnavailable(unavailable));
+        }
+
+        let alerts = if options.allergies.is_empty() {
+            Vec::new()
+        } else {
+            let ordered: Vec<i32> = product_ids
+                .iter()
+                .copied()
+                .chain(bundle_items.iter().map(|item| item.product_id))
+                .collect();
+            let profiles = AllergenService::new()
+                .get_profiles(ordered.clone())
+                .await
+                .map_err(|_| OrderServiceError::DatabaseError)?;
+            allergen_alerts(&ordered, &profiles, &options.allergies)
+        };
+
+        let engine = PricingEngine::from_config();
+        let mut lines = engine
+            .build_lines(&order_items)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        let discounts = PromotionService::new()
+            .apply_promotions(&mut lines, options.promo_code.as_deref(), &engine.rounding)
+            .await
+            .map_err(|e| match e {
+                PromotionServiceError::InvalidPromoCode => OrderServiceError::InvalidPromoCode,
+                _ => OrderServiceError::DatabaseError,
+            })?;
+
+        let breakdown = engine.price(&lines);
+
+        let party_size = party_size.filter(|size| *size > 0);
+        let tipping = OrderTipping {
+            party_size,
+            served_by: options.served_by,
+            gratuity_amount: Some(auto_gratuity(
+                party_size,
+                config.auto_gratuity_party_size,
+                &config.auto_gratuity_percent,
+                &breakdown,
+                &engine.rounding,
+            )),
+        };
+        let seating = OrderSeating {
+            order_type: options.order_type.as_str().to_string(),
+            table_id: options.table_id,
+            session_id,
+            scheduled_for: options.scheduled_for,
+        };
+
+        let lines: Vec<(i32, i32)> = order_items.iter().map(|(pid, qty, _)| (*pid, *qty)).collect();
+        let lines = expand_lines(&lines, &bundle_items);
+
+        // Products on a menu can only be ordered while one of their menus is served, which
+        // holds for the bundles ordered and for the products picked for them alike
+        let menu_ids: Vec<i32> = product_ids
+            .iter()
+            .copied()
+            .chain(lines.iter().map(|(pid, _)| *pid))
+            .collect();
+        let off_menu = MenuService::new()
+            .products_off_menu_at(&menu_ids, due)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+        if !off_menu.is_empty() {
+            return Err(OrderServiceError::ProductsOffMenu(off_menu));
+        }
+
+        if config.block_negative_stock {
+            let short = InventoryService::new()
+                .check_stock(&lines)
+                .await
+                .map_err(|_| OrderServiceError::DatabaseError)?;
+            if !short.is_empty() {
+                return Err(OrderServiceError::InsufficientStock(short));
+            }
         }
+        let tickets = KitchenService::new()
+            .route_order(&lines)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
 
         let repo = OrderRepo::new();
+        let status = initial_status(
+            options.scheduled_for,
+            now,
+            Duration::minutes(config.schedule_lead_minutes),
+        );
         let new_order = NewOrder {
             user_id,
-            total_amount,
-            status: Some(OrderStatus::Pending.as_str().to_string()),
+            total_amount: breakdown.total_amount.clone(),
+            status: Some(status.as_str().to_string()),
         };
 
-        repo.create_with_items(new_order, order_items)
+        let order_id = repo
+            .create_with_pricing(OrderPlacement {
+                order: new_order,
+                pricing: OrderPricing::from(breakdown),
+                tipping,
+                seating,
+                business_day: business_date(now, config.business_day_start),
+                items: order_items,
+                bundle_items,
+                allergen_alerts: alerts
+                    .iter()
+                    .map(|(product_id, allergen)| OrderAllergenAlert {
+                        order_id: 0,
+                        product_id: *product_id,
+                        allergen: allergen.as_str().to_string(),
+                    })
+                    .collect(),
+                discounts: discounts.into_iter().map(NewOrderDiscount::from).collect(),
+                tickets,
+            })
             .await
-            .map_err(|_| OrderServiceError::OrderCreationFailed)
+            .map_err(|_| OrderServiceError::OrderCreationFailed)?;
+
+        // The order is placed either way, so a failed estimate does not fail the request
+        if status == OrderStatus::Pending
+            && let Err(e) = KitchenService::new().estimate_order(order_id, now).await
+        {
+            tracing::error!("Failed to estimate ready time of order {}: {}", order_id, e);
+        }
+
+        Ok(alerts)
+    }
+
+    /// Converts orders into responses, including the discounts applied to each order, its
+    /// allergen alerts and how much of it has been paid
+    pub async fn to_responses(
+        &self,
+        orders: Vec<(Order, Vec<(OrderProduct, Product)>)>,
+    ) -> Result<Vec<OrderResponse>, OrderServiceError> {
+        let order_ids: Vec<i32> = orders.iter().map(|(order, _)| order.order_id).collect();
+        let discounts = OrderDiscountRepo::new()
+            .get_by_order_ids(order_ids.clone())
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+        let alerts = AllergenRepo::new()
+            .get_order_alerts(order_ids)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        let plain_orders: Vec<Order> = orders.iter().map(|(order, _)| order.clone()).collect();
+        let mut summaries = PaymentService::new()
+            .get_summaries(&plain_orders)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        Ok(orders
+            .into_iter()
+            .map(|order| {
+                let mut response = OrderResponse::from(order);
+                response.discounts = discounts
+                    .iter()
+                    .filter(|d| d.order_id == response.order_id)
+                    .cloned()
+                    .map(OrderDiscountResponse::from)
+                    .collect();
+                response.allergen_alerts = alerts
+                    .iter()
+                    .filter(|a| a.order_id == response.order_id)
+                    .cloned()
+                    .map(AllergenAlertResponse::from)
+                    .collect();
+                if let Some(summary) = summaries.remove(&response.order_id) {
+                    response.amount_paid = summary.amount_paid;
+                    response.balance_due = summary.balance_due;
+                }
+                response
+            })
+            .collect())
     }
 
     /// Gets all orders for a specific user (requires READ permission or Admin)
@@ -146,6 +655,84 @@ impl OrderService {
         }
     }
 
+    /// Gets the orders the kitchen is working on, pending and accepted, oldest first.
+    /// Scheduled orders show up once they are released. (READ or ADMIN permission required)
+    pub async fn get_kitchen_orders(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<(Order, Vec<(OrderProduct, Product)>)>, OrderServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await?
+            && !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+        {
+            return Err(OrderServiceError::PermissionDenied);
+        }
+
+        let repo = OrderRepo::new();
+        let orders = repo
+            .get_by_statuses(vec![
+                OrderStatus::Pending.as_str(),
+                OrderStatus::Accepted.as_str(),
+            ])
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        repo.attach_products(orders).await
+            .map_err(|_| OrderServiceError::DatabaseError)
+    }
+
+    /// Releases the scheduled orders due within the lead time to the kitchen, moving them
+    /// to `Pending`. Run by the order scheduler, so no permission is checked.
+    /// Returns the number of released orders.
+    pub async fn release_scheduled_orders(
+        &self,
+        now: NaiveDateTime,
+        lead: Duration,
+    ) -> Result<usize, OrderServiceError> {
+        OrderRepo::new()
+            .release_scheduled(
+                now + lead,
+                OrderStatus::Scheduled.as_str(),
+                OrderStatus::Pending.as_str(),
+            )
+            .await
+            .map_err(|_| OrderServiceError::OrderUpdateFailed)
+    }
+
+    /// Finds orders by business day and daily number (READ or ADMIN permission required)
+    /// The current business day is searched unless a date is given.
+    pub async fn search_orders(
+        &self,
+        query: &OrderQuery,
+        role_id: i32,
+    ) -> Result<Vec<(Order, Vec<(OrderProduct, Product)>)>, OrderServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await?
+            && !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+        {
+            return Err(OrderServiceError::PermissionDenied);
+        }
+
+        let number = match &query.number {
+            Some(value) => {
+                Some(parse_order_number(value).ok_or(OrderServiceError::InvalidOrderQuery)?)
+            }
+            None => None,
+        };
+        let day = match &query.date {
+            Some(value) => parse_date(value).ok_or(OrderServiceError::InvalidOrderQuery)?,
+            None => business_date(local_now(), Config::new().business_day_start),
+        };
+
+        let repo = OrderRepo::new();
+        let orders = repo.get_by_business_date(day, number)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        repo.attach_products(orders).await
+            .map_err(|_| OrderServiceError::DatabaseError)
+    }
+
     /// Gets an order by ID (must have READ permission or be Admin)
     pub async fn get_order_by_id(
         &self,
@@ -175,7 +762,8 @@ impl OrderService {
         }
     }
 
-    /// Cancels an order (must have WRITE permission or be Admin)
+    /// Cancels an order and records the cancellation in the adjustment ledger
+    /// (must have WRITE permission or be Admin)
     pub async fn cancel_order(&self, order_id: i32, role_id: i32) -> Result<(), OrderServiceError> {
         let has_permission = self.has_permission(role_id, RolePermissions::Admin).await?
             || self.has_permission(role_id, RolePermissions::Write).await?;
@@ -184,17 +772,13 @@ impl OrderService {
             return Err(OrderServiceError::PermissionDenied);
         }
 
-        let repo = OrderRepo::new();
-
-        let update = UpdateOrder {
-            user_id: None,
-            total_amount: None,
-            status: Some(OrderStatus::Cancelled.as_str()),
-        };
-
-        repo.update(order_id, update)
+        let order = OrderRepo::new()
+            .get_by_id(order_id)
             .await
-            .map_err(|_| OrderServiceError::OrderUpdateFailed)
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .ok_or(OrderServiceError::OrderNotFound)?;
+
+        self.record_cancellation(&order).await
     }
 
     /// Updates order status
@@ -213,20 +797,52 @@ impl OrderService {
         let repo = OrderRepo::new();
 
         // Verify order exists
-        repo.get_by_id(order_id)
+        let order = repo
+            .get_by_id(order_id)
             .await
             .map_err(|_| OrderServiceError::DatabaseError)?
             .ok_or(OrderServiceError::OrderNotFound)?;
 
-        let update = UpdateOrder {
-            user_id: None,
-            total_amount: None,
-            status: Some(new_status.as_str()),
-        };
+        if new_status == OrderStatus::Cancelled {
+            return self.record_cancellation(&order).await;
+        }
 
-        repo.update(order_id, update)
-            .await
-            .map_err(|_| OrderServiceError::OrderUpdateFailed)
+        let was_accepted = order.status.as_deref() == Some(OrderStatus::Accepted.as_str());
+
+        // Only future-dated orders can wait for the scheduler
+        if new_status == OrderStatus::Scheduled && order.scheduled_for.is_none() {
+            return Err(OrderServiceError::InvalidStatusTransition);
+        }
+
+        if new_status == OrderStatus::Accepted && !was_accepted {
+            // Accepting an order takes its ingredients out of stock
+            let short = InventoryService::new()
+                .accept_order(order_id)
+                .await
+                .map_err(|_| OrderServiceError::OrderUpdateFailed)?;
+            if !short.is_empty() {
+                return Err(OrderServiceError::InsufficientStock(short));
+            }
+        } else {
+            let update = UpdateOrder {
+                user_id: None,
+                total_amount: None,
+                status: Some(new_status.as_str()),
+            };
+
+            repo.update(order_id, update)
+                .await
+                .map_err(|_| OrderServiceError::OrderUpdateFailed)?;
+        }
+
+        // Accepting an order, or taking one out of the accepted queue, changes the kitchen load
+        if new_status == OrderStatus::Accepted && !was_accepted {
+            self.refresh_estimates(Some(order_id)).await;
+        } else if was_accepted && new_status != OrderStatus::Accepted {
+            self.refresh_estimates(None).await;
+        }
+
+        Ok(())
     }
 
     /// Gets orders by status
@@ -305,6 +921,405 @@ impl OrderService {
             .map_err(|_| OrderServiceError::OrderDeletionFailed)
     }
 
+    /// Gets the adjustment ledger of an order (requires READ permission or Admin)
+    pub async fn get_adjustments(
+        &self,
+        order_id: i32,
+        role_id: i32,
+    ) -> Result<Vec<OrderAdjustmentResponse>, OrderServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(OrderServiceError::PermissionDenied);
+        }
+
+        OrderRepo::new()
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .ok_or(OrderServiceError::OrderNotFound)?;
+
+        let adjustments = OrderAdjustmentRepo::new()
+            .get_by_order_ids(vec![order_id])
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        Ok(adjustments
+            .into_iter()
+            .map(OrderAdjustmentResponse::from)
+            .collect())
+    }
+
+    /// Voids quantities of order lines (requires REFUND permission or Admin).
+    /// The order totals are reduced by the share of the voided items and every voided line
+    /// is recorded with its negative amount. An auto gratuity shrinks with the subtotal. If the
+    /// order was already paid, the difference can then be returned with `refund_payment`.
+    pub async fn void_items(
+        &self,
+        order_id: i32,
+        user_id: i32,
+        role_id: i32,
+        request: &VoidItemsRequest,
+    ) -> Result<OrderResponse, OrderServiceError> {
+        if !self
+            .has_permission(role_id, RolePermissions::Refund)
+            .await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(OrderServiceError::PermissionDenied);
+        }
+
+        let reason: ReasonCode = request
+            .reason_code
+            .parse()
+            .map_err(|_| OrderServiceError::InvalidReasonCode)?;
+
+        let repo = OrderRepo::new();
+        let order = repo
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .ok_or(OrderServiceError::OrderNotFound)?;
+
+        if order.status.as_deref() == Some(OrderStatus::Cancelled.as_str()) {
+            return Err(OrderServiceError::OrderNotAdjustable);
+        }
+
+        // Checks hold totals of their own, the split has to be removed first
+        let checks = OrderCheckRepo::new()
+            .get_by_order_id(order_id)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+        if !checks.is_empty() {
+            return Err(OrderServiceError::OrderNotAdjustable);
+        }
+
+        let products = repo
+            .attach_products(vec![order.clone()])
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .into_iter()
+            .next()
+            .map(|(_, products)| products)
+            .unwrap_or_default();
+
+        let items: Vec<(i32, i32, BigDecimal)> = products
+            .iter()
+            .map(|(line, _)| (line.product_id, line.quantity, line.unit_price.clone()))
+            .collect();
+
+        let engine = PricingEngine::from_config();
+        let mut lines = engine
+            .build_lines(&items)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        // The order discount is spread over the lines in proportion to their price
+        let gross_total: BigDecimal = lines.iter().map(|line| line.gross()).sum();
+        if gross_total > BigDecimal::zero() {
+            for line in lines.iter_mut() {
+                line.discount = &order.discount_amount * line.gross() / &gross_total;
+            }
+        }
+
+        let voids: Vec<(i32, i32)> = request
+            .items
+            .iter()
+            .map(|item| (item.product_id, item.quantity))
+            .collect();
+        let (remaining_lines, voided_lines) = void_lines(&lines, &voids)?;
+
+        // Divide the stored totals rather than repricing, so tax rate changes since the
+        // order was placed do not leak into the adjustment
+        let mut parts = split_components(
+            &PriceComponents::from(&order),
+            &[
+                engine.components(&remaining_lines),
+                engine.components(&voided_lines),
+            ],
+            &engine.rounding,
+        );
+        let voided = parts.pop().ok_or(OrderServiceError::InvalidVoid)?;
+        let remaining = parts.pop().ok_or(OrderServiceError::InvalidVoid)?;
+
+        let line_weights: Vec<BigDecimal> = voided_lines
+            .iter()
+            .map(|line| engine.components(std::slice::from_ref(line)).total())
+            .collect();
+        let line_amounts = engine
+            .rounding
+            .allocate(&voided.total_amount, &line_weights);
+
+        let entries: Vec<NewOrderAdjustment> = voided_lines
+            .iter()
+            .zip(line_amounts)
+            .map(|(line, amount)| NewOrderAdjustment {
+                order_id,
+                adjustment_type: AdjustmentType::Void.as_str(),
+                product_id: Some(line.product_id),
+                quantity: Some(line.quantity),
+                refund_id: None,
+                amount: -amount,
+                reason_code: Some(reason.as_str()),
+                note: request.note.as_deref(),
+                user_id: Some(user_id),
+            })
+            .collect();
+
+        let quantities: Vec<(i32, i32)> = lines
+            .iter()
+            .filter(|line| {
+                voids
+                    .iter()
+                    .any(|(product_id, _)| *product_id == line.product_id)
+            })
+            .map(|line| {
+                let left = remaining_lines
+                    .iter()
+                    .find(|r| r.product_id == line.product_id)
+                    .map(|r| r.quantity)
+                    .unwrap_or(0);
+                (line.product_id, left)
+            })
+            .collect();
+
+        let charged = &order.subtotal - &order.discount_amount;
+        let gratuity_amount = if charged > BigDecimal::zero() {
+            engine.rounding.round(
+                &(&order.gratuity_amount * (&remaining.subtotal - &remaining.discount_amount)
+                    / charged),
+            )
+        } else {
+            order.gratuity_amount.clone()
+        };
+        let tipping = OrderTipping {
+            gratuity_amount: Some(gratuity_amount),
+            ..OrderTipping::default()
+        };
+
+        let voided_quantities: Vec<(i32, i32)> = voided_lines
+            .iter()
+            .map(|line| (line.product_id, line.quantity))
+            .collect();
+        let (voided_items, needed) = InventoryService::new()
+            .voided_items(order_id, &voided_quantities)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        OrderAdjustmentRepo::new()
+            .record_voids(
+                order_id,
+                quantities,
+                OrderPricing::from(remaining),
+                tipping,
+                entries,
+                voided_items,
+                needed,
+                StockMovementReason::Cancellation.as_str(),
+            )
+            .await
+            .map_err(|_| OrderServiceError::OrderUpdateFailed)?;
+
+        self.order_response(order_id).await
+    }
+
+    /// Refunds money from a payment of an order (requires REFUND permission or Admin).
+    /// Only what the customer is owed can be refunded: what was paid above the order total,
+    /// e.g. after voiding items, or everything paid for a cancelled order. The amount
+    /// defaults to that, up to what is left of the payment.
+    pub async fn refund_payment(
+        &self,
+        order_id: i32,
+        user_id: i32,
+        role_id: i32,
+        request: &RefundPaymentRequest,
+    ) -> Result<OrderResponse, OrderServiceError> {
+        if !self
+            .has_permission(role_id, RolePermissions::Refund)
+            .await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(OrderServiceError::PermissionDenied);
+        }
+
+        let reason: ReasonCode = request
+            .reason_code
+            .parse()
+            .map_err(|_| OrderServiceError::InvalidReasonCode)?;
+
+        let order = OrderRepo::new()
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .ok_or(OrderServiceError::OrderNotFound)?;
+
+        let payment = PaymentRepo::new()
+            .get_by_order_ids(vec![order_id])
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .into_iter()
+            .find(|p| p.payment_id == request.payment_id)
+            .ok_or(OrderServiceError::PaymentNotFound)?;
+
+        if payment.status == PaymentStatus::Pending.as_str()
+            || payment.status == PaymentStatus::Failed.as_str()
+            || payment.status == PaymentStatus::Refunded.as_str()
+        {
+            return Err(OrderServiceError::InvalidRefundAmount);
+        }
+
+        let refunded: BigDecimal = RefundRepo::new()
+            .get_by_payment_ids(vec![payment.payment_id])
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .iter()
+            .map(|r| r.amount.clone())
+            .sum();
+        let refundable = &payment.amount - refunded;
+
+        let payment_service = PaymentService::new();
+        let summary = payment_service
+            .get_summaries(std::slice::from_ref(&order))
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .remove(&order_id)
+            .ok_or(OrderServiceError::DatabaseError)?;
+
+        let owed = if order.status.as_deref() == Some(OrderStatus::Cancelled.as_str()) {
+            summary.amount_paid
+        } else {
+            -summary.balance_due
+        };
+
+        let amount = request
+            .amount
+            .clone()
+            .unwrap_or_else(|| owed.clone().min(refundable.clone()));
+
+        if amount <= BigDecimal::zero()
+            || amount > refundable
+            || amount > owed
+            || amount.with_scale(2) != amount
+        {
+            return Err(OrderServiceError::InvalidRefundAmount);
+        }
+
+        let receipt = payment_service
+            .refund_at_gateway(&payment, &amount)
+            .await
+            .map_err(|e| match e {
+                PaymentServiceError::PaymentDeclined => OrderServiceError::RefundDeclined,
+                PaymentServiceError::GatewayUnavailable => OrderServiceError::GatewayUnavailable,
+                _ => OrderServiceError::DatabaseError,
+            })?;
+
+        let payment_status = if amount == refundable {
+            PaymentStatus::Refunded
+        } else {
+            PaymentStatus::PartiallyRefunded
+        };
+
+        OrderAdjustmentRepo::new()
+            .record_refund(
+                NewRefund {
+                    payment_id: payment.payment_id,
+                    amount: amount.clone(),
+                    reason: Some(reason.as_str()),
+                    provider_reference: receipt.provider_reference.as_deref(),
+                },
+                payment_status.as_str(),
+                NewOrderAdjustment {
+                    order_id,
+                    adjustment_type: AdjustmentType::Refund.as_str(),
+                    product_id: None,
+                    quantity: None,
+                    refund_id: None,
+                    amount: -amount,
+                    reason_code: Some(reason.as_str()),
+                    note: request.note.as_deref(),
+                    user_id: Some(user_id),
+                },
+            )
+            .await
+            .map_err(|_| OrderServiceError::OrderUpdateFailed)?;
+
+        self.order_response(order_id).await
+    }
+
+    /// Sets an order to cancelled, records what was still charged for it and puts back the
+    /// stock it used. Cancelling an order twice records nothing.
+    async fn record_cancellation(&self, order: &Order) -> Result<(), OrderServiceError> {
+        if order.status.as_deref() == Some(OrderStatus::Cancelled.as_str()) {
+            // Restoring stock twice changes nothing, so cancelling again is harmless and puts
+            // back anything still deducted
+            return self.restore_stock(order.order_id).await;
+        }
+
+        OrderAdjustmentRepo::new()
+            .record_cancellation(
+                order.order_id,
+                OrderStatus::Cancelled.as_str(),
+                NewOrderAdjustment {
+                    order_id: order.order_id,
+                    adjustment_type: AdjustmentType::Cancellation.as_str(),
+                    product_id: None,
+                    quantity: None,
+                    refund_id: None,
+                    amount: -order.amount_due(),
+                    reason_code: None,
+                    note: None,
+                    user_id: None,
+                },
+                StockMovementReason::Cancellation.as_str(),
+            )
+            .await
+            .map_err(|_| OrderServiceError::OrderUpdateFailed)?;
+
+        if order.status.as_deref() == Some(OrderStatus::Accepted.as_str()) {
+            self.refresh_estimates(None).await;
+        }
+
+        Ok(())
+    }
+
+    async fn restore_stock(&self, order_id: i32) -> Result<(), OrderServiceError> {
+        InventoryService::new()
+            .restore_order(order_id)
+            .await
+            .map_err(|_| OrderServiceError::OrderUpdateFailed)
+    }
+
+    /// Recalculates the estimated ready times of the accepted orders. The status change is
+    /// already saved, so a failed estimate is logged rather than failing the request.
+    async fn refresh_estimates(&self, accepted: Option<i32>) {
+        if let Err(e) = KitchenService::new()
+            .refresh_estimates(local_now(), accepted)
+            .await
+        {
+            tracing::error!("Failed to refresh estimated ready times: {}", e);
+        }
+    }
+
+    async fn order_response(&self, order_id: i32) -> Result<OrderResponse, OrderServiceError> {
+        let repo = OrderRepo::new();
+        let order = repo
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?
+            .ok_or(OrderServiceError::OrderNotFound)?;
+        let detailed = repo
+            .attach_products(vec![order])
+            .await
+            .map_err(|_| OrderServiceError::DatabaseError)?;
+
+        self.to_responses(detailed)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(OrderServiceError::OrderNotFound)
+    }
+
     async fn has_permission(
         &self,
         role_id: i32,
@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+
+/// A charge sent to a payment gateway
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChargeRequest {
+    pub order_id: i32,
+    pub amount: BigDecimal,
+    /// Reference supplied by the client, e.g. the approval code printed by a card terminal
+    pub reference: Option<String>,
+}
+
+/// Outcome of a successful gateway operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatewayReceipt {
+    pub provider_reference: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GatewayError {
+    Declined,
+    Unavailable,
+}
+
+/// A payment provider able to capture and refund money for an order
+#[async_trait]
+pub trait PaymentGateway: Send + Sync {
+    /// Name stored on payments processed by this gateway
+    fn provider(&self) -> &'static str;
+
+    async fn capture(&self, request: &ChargeRequest) -> Result<GatewayReceipt, GatewayError>;
+
+    async fn refund(
+        &self,
+        provider_reference: Option<&str>,
+        amount: &BigDecimal,
+    ) -> Result<GatewayReceipt, GatewayError>;
+}
+
+/// Cash taken at the counter. Always succeeds and has no provider reference.
+pub struct CashGateway;
+
+#[async_trait]
+impl PaymentGateway for CashGateway {
+    fn provider(&self) -> &'static str {
+        "cash"
+    }
+
+    async fn capture(&self, _request: &ChargeRequest) -> Result<GatewayReceipt, GatewayError> {
+        Ok(GatewayReceipt {
+            provider_reference: None,
+        })
+    }
+
+    async fn refund(
+        &self,
+        _provider_reference: Option<&str>,
+        _amount: &BigDecimal,
+    ) -> Result<GatewayReceipt, GatewayError> {
+        Ok(GatewayReceipt {
+            provider_reference: None,
+        })
+    }
+}
+
+/// Standalone card terminal. The card is charged on the terminal and staff enter the
+/// approval code it prints, which becomes the provider reference.
+pub struct CardTerminalGateway;
+
+#[async_trait]
+impl PaymentGateway for CardTerminalGateway {
+    fn provider(&self) -> &'static str {
+        "card_terminal"
+    }
+
+    async fn capture(&self, request: &ChargeRequest) -> Result<GatewayReceipt, GatewayError> {
+        match request.reference.as_deref().map(str::trim) {
+            Some(code) if !code.is_empty() => Ok(GatewayReceipt {
+                provider_reference: Some(code.to_string()),
+            }),
+            _ => Err(GatewayError::Declined),
+        }
+    }
+
+    async fn refund(
+        &self,
+        provider_reference: Option<&str>,
+        _amount: &BigDecimal,
+    ) -> Result<GatewayReceipt, GatewayError> {
+        match provider_reference {
+            Some(code) => Ok(GatewayReceipt {
+                provider_reference: Some(format!("refund-{}", code)),
+            }),
+            None => Err(GatewayError::Declined),
+        }
+    }
+}
+
+/// Deterministic in-process gateway for tests and local development
+pub struct MockGateway {
+    decline: bool,
+}
+
+impl MockGateway {
+    /// A mock gateway that approves every charge and refund
+    pub fn new() -> Self {
+        MockGateway { decline: false }
+    }
+
+    /// A mock gateway that declines every charge and refund
+    pub fn declining() -> Self {
+        MockGateway { decline: true }
+    }
+}
+
+impl Default for MockGateway {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PaymentGateway for MockGateway {
+    fn provider(&self) -> &'static str {
+        "mock"
+    }
+
+    async fn capture(&self, request: &ChargeRequest) -> Result<GatewayReceipt, GatewayError> {
+        if self.decline {
+            return Err(GatewayError::Declined);
+        }
+
+        Ok(GatewayReceipt {
+            provider_reference: Some(format!("mock-{}-{}", request.order_id, request.amount)),
+        })
+    }
+
+    async fn refund(
+        &self,
+        provider_reference: Option<&str>,
+        amount: &BigDecimal,
+    ) -> Result<GatewayReceipt, GatewayError> {
+        if self.decline {
+            return Err(GatewayError::Declined);
+        }
+
+        Ok(GatewayReceipt {
+            provider_reference: Some(format!(
+                "mock-refund-{}-{}",
+                provider_reference.unwrap_or("none"),
+                amount
+            )),
+        })
+    }
+}
+
+/// Creates the card gateway named in the config (`terminal` or `mock`)
+pub fn card_gateway(name: &str) -> Option<Box<dyn PaymentGateway>> {
+    match name.to_lowercase().as_str() {
+        "terminal" | "card_terminal" => Some(Box::new(CardTerminalGateway)),
+        "mock" => Some(Box::new(MockGateway::new())),
+        _ => None,
+    }
+}
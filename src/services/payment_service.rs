@@ -0,0 +1,434 @@
+use crate::api::config::Config;
+use crate::api::request::CapturePaymentRequest;
+use crate::api::response::PaymentResponse;
+use crate::data::models::order::Order;
+use crate::data::models::order_check::OrderCheck;
+use crate::data::models::payment::{NewPayment, Payment};
+use crate::data::models::refund::Refund;
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::order_check_repo::OrderCheckRepo;
+use crate::data::repos::implementors::order_repo::OrderRepo;
+use crate::data::repos::implementors::payment_repo::PaymentRepo;
+use crate::data::repos::implementors::refund_repo::RefundRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::PaymentServiceError;
+use crate::services::order_service::OrderStatus;
+use crate::services::payment_gateway::{
+    CardTerminalGateway, CashGateway, ChargeRequest, GatewayError, GatewayReceipt, PaymentGateway,
+    card_gateway,
+};
+use bigdecimal::{BigDecimal, Zero};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// How the customer pays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentMethod {
+    Cash,
+    Card,
+}
+
+impl PaymentMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentMethod::Cash => "cash",
+            PaymentMethod::Card => "card",
+        }
+    }
+}
+
+impl FromStr for PaymentMethod {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cash" => Ok(PaymentMethod::Cash),
+            "card" => Ok(PaymentMethod::Card),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Payment statuses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentStatus {
+    /// Recorded before it is taken through the gateway, holding its share of the balance
+    Pending,
+    Captured,
+    Failed,
+    PartiallyRefunded,
+    Refunded,
+}
+
+impl PaymentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentStatus::Pending => "pending",
+            PaymentStatus::Captured => "captured",
+            PaymentStatus::Failed => "failed",
+            PaymentStatus::PartiallyRefunded => "partially_refunded",
+            PaymentStatus::Refunded => "refunded",
+        }
+    }
+}
+
+impl FromStr for PaymentStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(PaymentStatus::Pending),
+            "captured" => Ok(PaymentStatus::Captured),
+            "failed" => Ok(PaymentStatus::Failed),
+            "partially_refunded" => Ok(PaymentStatus::PartiallyRefunded),
+            "refunded" => Ok(PaymentStatus::Refunded),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How much of an order has been settled
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentSummary {
+    pub amount_paid: BigDecimal,
+    pub balance_due: BigDecimal,
+}
+
+impl PaymentSummary {
+    /// Amount paid is the sum of non-failed payments less their refunds
+    pub fn new(total_amount: &BigDecimal, payments: &[Payment], refunds: &[Refund]) -> Self {
+        let settled: Vec<&Payment> = payments
+            .iter()
+            .filter(|p| p.status != PaymentStatus::Failed.as_str())
+            .collect();
+
+        let captured: BigDecimal = settled.iter().map(|p| p.amount.clone()).sum();
+        let refunded: BigDecimal = refunds
+            .iter()
+            .filter(|r| settled.iter().any(|p| p.payment_id == r.payment_id))
+            .map(|r| r.amount.clone())
+            .sum();
+
+        let amount_paid = captured - refunded;
+        let balance_due = total_amount - &amount_paid;
+
+        PaymentSummary {
+            amount_paid,
+            balance_due,
+        }
+    }
+}
+
+pub struct PaymentService {
+    cash_gateway: Box<dyn PaymentGateway>,
+    card_gateway: Box<dyn PaymentGateway>,
+}
+
+impl PaymentService {
+    /// Creates a payment service using the card gateway from the config
+    pub fn new() -> Self {
+        let card = card_gateway(&Config::new().card_gateway)
+            .unwrap_or_else(|| Box::new(CardTerminalGateway));
+        PaymentService::with_gateways(Box::new(CashGateway), card)
+    }
+
+    pub fn with_gateways(
+        cash_gateway: Box<dyn PaymentGateway>,
+        card_gateway: Box<dyn PaymentGateway>,
+    ) -> Self {
+        PaymentService {
+            cash_gateway,
+            card_gateway,
+        }
+    }
+
+    /// Gets the payments of an order (requires READ permission or Admin)
+    pub async fn get_payments(
+        &self,
+        order_id: i32,
+        role_id: i32,
+    ) -> Result<Vec<PaymentResponse>, PaymentServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PaymentServiceError::PermissionDenied);
+        }
+
+        OrderRepo::new()
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?
+            .ok_or(PaymentServiceError::OrderNotFound)?;
+
+        let payments = PaymentRepo::new()
+            .get_by_order_ids(vec![order_id])
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?;
+
+        Ok(payments.into_iter().map(PaymentResponse::from).collect())
+    }
+
+    /// Captures a full or partial payment against an order (requires WRITE permission or Admin)
+    /// The amount defaults to the balance due, of the check when one is given, and may not
+    /// exceed it. A tip is charged on top of the amount and credited to the order.
+    /// The payment is recorded as pending, within the balance, before it is taken through the
+    /// gateway, so concurrent payments cannot together exceed the balance.
+    /// Declined attempts are recorded as failed payments without their tip.
+    pub async fn capture_payment(
+        &self,
+        order_id: i32,
+        role_id: i32,
+        request: &CapturePaymentRequest,
+    ) -> Result<PaymentResponse, PaymentServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PaymentServiceError::PermissionDenied);
+        }
+
+        let method = PaymentMethod::from_str(&request.method)
+            .map_err(|_| PaymentServiceError::InvalidPaymentMethod)?;
+
+        let order = OrderRepo::new()
+            .get_by_id(order_id)
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?
+            .ok_or(PaymentServiceError::OrderNotFound)?;
+
+        if order.status.as_deref() == Some(OrderStatus::Cancelled.as_str()) {
+            return Err(PaymentServiceError::OrderNotPayable);
+        }
+
+        let summary = self
+            .get_summaries(std::slice::from_ref(&order))
+            .await?
+            .remove(&order_id)
+            .unwrap_or_else(|| PaymentSummary::new(&order.amount_due(), &[], &[]));
+
+        let mut balance_due = summary.balance_due;
+        let mut check_due = None;
+
+        if let Some(check_id) = request.check_id {
+            let checks = OrderCheckRepo::new()
+                .get_by_order_id(order_id)
+                .await
+                .map_err(|_| PaymentServiceError::DatabaseError)?;
+            let check_summary = self
+                .get_check_summaries(order_id, &checks)
+                .await?
+                .remove(&check_id)
+                .ok_or(PaymentServiceError::CheckNotFound)?;
+            check_due = checks
+                .iter()
+                .find(|check| check.check_id == check_id)
+                .map(|check| check.amount_due());
+
+            if check_summary.balance_due < balance_due {
+                balance_due = check_summary.balance_due;
+            }
+        }
+
+        let amount = request
+            .amount
+            .clone()
+            .unwrap_or_else(|| balance_due.clone());
+
+        if amount <= BigDecimal::zero() || amount > balance_due || amount.with_scale(2) != amount {
+            return Err(PaymentServiceError::InvalidAmount);
+        }
+
+        let tip = request.tip.clone().unwrap_or_else(BigDecimal::zero);
+        if tip < BigDecimal::zero() || tip.with_scale(2) != tip {
+            return Err(PaymentServiceError::InvalidAmount);
+        }
+
+        let gateway = match method {
+            PaymentMethod::Cash => &self.cash_gateway,
+            PaymentMethod::Card => &self.card_gateway,
+        };
+
+        let charge = ChargeRequest {
+            order_id,
+            amount: &amount + &tip,
+            reference: request.reference.clone(),
+        };
+
+        let repo = PaymentRepo::new();
+
+        // The balances are checked again against the locked order, in case another payment
+        // was taken since they were read
+        let payment = repo
+            .reserve(
+                NewPayment {
+                    order_id,
+                    amount,
+                    method: method.as_str(),
+                    provider: gateway.provider(),
+                    status: PaymentStatus::Pending.as_str(),
+                    provider_reference: None,
+                    check_id: request.check_id,
+                    tip_amount: BigDecimal::zero(),
+                },
+                order.amount_due(),
+                check_due,
+                PaymentStatus::Failed.as_str(),
+            )
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?
+            .ok_or(PaymentServiceError::InvalidAmount)?;
+
+        match gateway.capture(&charge).await {
+            Ok(receipt) => {
+                let payment = repo
+                    .settle(
+                        payment.payment_id,
+                        PaymentStatus::Captured.as_str(),
+                        receipt.provider_reference.as_deref(),
+                        tip,
+                    )
+                    .await
+                    .map_err(|_| PaymentServiceError::DatabaseError)?;
+
+                Ok(PaymentResponse::from(payment))
+            }
+            Err(error) => {
+                repo.settle(
+                    payment.payment_id,
+                    PaymentStatus::Failed.as_str(),
+                    None,
+                    BigDecimal::zero(),
+                )
+                .await
+                .map_err(|_| PaymentServiceError::DatabaseError)?;
+
+                Err(match error {
+                    GatewayError::Declined => PaymentServiceError::PaymentDeclined,
+                    GatewayError::Unavailable => PaymentServiceError::GatewayUnavailable,
+                })
+            }
+        }
+    }
+
+    /// Returns money from a payment through the gateway that captured it. Payments taken
+    /// through a card gateway other than the configured one are refunded through that
+    /// gateway as long as it is still known.
+    pub async fn refund_at_gateway(
+        &self,
+        payment: &Payment,
+        amount: &BigDecimal,
+    ) -> Result<GatewayReceipt, PaymentServiceError> {
+        let reference = payment.provider_reference.as_deref();
+
+        let result = if self.cash_gateway.provider() == payment.provider {
+            self.cash_gateway.refund(reference, amount).await
+        } else if self.card_gateway.provider() == payment.provider {
+            self.card_gateway.refund(reference, amount).await
+        } else {
+            match card_gateway(&payment.provider) {
+                Some(gateway) => gateway.refund(reference, amount).await,
+                None => Err(GatewayError::Unavailable),
+            }
+        };
+
+        result.map_err(|error| match error {
+            GatewayError::Declined => PaymentServiceError::PaymentDeclined,
+            GatewayError::Unavailable => PaymentServiceError::GatewayUnavailable,
+        })
+    }
+
+    /// Computes the payment summary of each order, keyed by order id
+    pub async fn get_summaries(
+        &self,
+        orders: &[Order],
+    ) -> Result<HashMap<i32, PaymentSummary>, PaymentServiceError> {
+        let order_ids = orders.iter().map(|o| o.order_id).collect();
+        let payments = PaymentRepo::new()
+            .get_by_order_ids(order_ids)
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?;
+
+        let payment_ids: Vec<i32> = payments.iter().map(|p| p.payment_id).collect();
+        let refunds = if payment_ids.is_empty() {
+            Vec::new()
+        } else {
+            RefundRepo::new()
+                .get_by_payment_ids(payment_ids)
+                .await
+                .map_err(|_| PaymentServiceError::DatabaseError)?
+        };
+
+        Ok(orders
+            .iter()
+            .map(|order| {
+                let order_payments: Vec<Payment> = payments
+                    .iter()
+                    .filter(|p| p.order_id == order.order_id)
+                    .cloned()
+                    .collect();
+                (
+                    order.order_id,
+                    PaymentSummary::new(&order.amount_due(), &order_payments, &refunds),
+                )
+            })
+            .collect())
+    }
+
+    /// Computes the payment summary of each check of an order, keyed by check id
+    pub async fn get_check_summaries(
+        &self,
+        order_id: i32,
+        checks: &[OrderCheck],
+    ) -> Result<HashMap<i32, PaymentSummary>, PaymentServiceError> {
+        let payments = PaymentRepo::new()
+            .get_by_order_ids(vec![order_id])
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?;
+
+        let payment_ids: Vec<i32> = payments.iter().map(|p| p.payment_id).collect();
+        let refunds = if payment_ids.is_empty() {
+            Vec::new()
+        } else {
+            RefundRepo::new()
+                .get_by_payment_ids(payment_ids)
+                .await
+                .map_err(|_| PaymentServiceError::DatabaseError)?
+        };
+
+        Ok(checks
+            .iter()
+            .map(|check| {
+                let check_payments: Vec<Payment> = payments
+                    .iter()
+                    .filter(|p| p.check_id == Some(check.check_id))
+                    .cloned()
+                    .collect();
+                (
+                    check.check_id,
+                    PaymentSummary::new(&check.amount_due(), &check_payments, &refunds),
+                )
+            })
+            .collect())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, PaymentServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| PaymentServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for PaymentService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,454 @@
+use crate::api::config::Config;
+use crate::api::request::{CreateTaxRateRequest, UpdateTaxRateRequest};
+use crate::api::response::TaxRateResponse;
+use crate::data::models::order::{Order, OrderPricing};
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::tax_rate::{NewTaxRate, TaxRate, UpdateTaxRate};
+use crate::data::repos::implementors::tax_rate_repo::TaxRateRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::PricingServiceError;
+use bigdecimal::{BigDecimal, RoundingMode, ToPrimitive, Zero};
+
+/// Rounding rule applied to order money amounts, e.g. half-even to 0.01
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundingRule {
+    pub mode: RoundingMode,
+    pub increment: BigDecimal,
+}
+
+impl RoundingRule {
+    pub fn new(mode: RoundingMode, increment: BigDecimal) -> Self {
+        RoundingRule { mode, increment }
+    }
+
+    /// Rounds an amount to a multiple of the increment using the rounding mode
+    pub fn round(&self, amount: &BigDecimal) -> BigDecimal {
+        if self.increment <= BigDecimal::zero() {
+            return amount.clone();
+        }
+
+        let (_, scale) = self.increment.as_bigint_and_exponent();
+        let units = (amount / &self.increment).with_scale_round(0, self.mode);
+
+        (units * &self.increment).with_scale(scale.max(0))
+    }
+
+    /// Splits an amount into parts proportional to the weights, each a multiple of the
+    /// increment, that add up exactly to the rounded amount.
+    /// Leftover increments go to the parts with the largest remainders, earliest first.
+    /// Parts are equal when every weight is zero.
+    pub fn allocate(&self, amount: &BigDecimal, weights: &[BigDecimal]) -> Vec<BigDecimal> {
+        if weights.is_empty() {
+            return Vec::new();
+        }
+
+        let total_weight: BigDecimal = weights.iter().sum();
+        let weights: Vec<BigDecimal> = if total_weight > BigDecimal::zero() {
+            weights.to_vec()
+        } else {
+            vec![BigDecimal::from(1); weights.len()]
+        };
+        let total_weight: BigDecimal = weights.iter().sum();
+
+        if self.increment <= BigDecimal::zero() {
+            return weights
+                .iter()
+                .map(|weight| amount * weight / &total_weight)
+                .collect();
+        }
+
+        let (_, scale) = self.increment.as_bigint_and_exponent();
+        let units = (self.round(amount) / &self.increment).with_scale_round(0, self.mode);
+
+        let exact: Vec<BigDecimal> = weights
+            .iter()
+            .map(|weight| &units * weight / &total_weight)
+            .collect();
+        let mut parts: Vec<BigDecimal> = exact
+            .iter()
+            .map(|share| share.with_scale_round(0, RoundingMode::Floor))
+            .collect();
+
+        let allocated: BigDecimal = parts.iter().sum();
+        let leftover = (&units - allocated).to_u64().unwrap_or(0) as usize;
+
+        let mut by_remainder: Vec<usize> = (0..parts.len()).collect();
+        by_remainder.sort_by(|&a, &b| (&exact[b] - &parts[b]).cmp(&(&exact[a] - &parts[a])));
+        for &i in by_remainder.iter().take(leftover) {
+            parts[i] += BigDecimal::from(1);
+        }
+
+        parts
+            .into_iter()
+            .map(|part| (part * &self.increment).with_scale(scale.max(0)))
+            .collect()
+    }
+}
+
+/// A tax applied to an order line, as a percentage
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineTax {
+    pub percent: BigDecimal,
+    pub inclusive: bool,
+}
+
+impl From<&TaxRate> for LineTax {
+    fn from(rate: &TaxRate) -> Self {
+        LineTax {
+            percent: rate.rate.clone(),
+            inclusive: rate.is_inclusive,
+        }
+    }
+}
+
+/// An order line as seen by the pricing engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricingLine {
+    pub product_id: i32,
+    pub quantity: i32,
+    pub unit_price: BigDecimal,
+    pub taxes: Vec<LineTax>,
+    /// Amount taken off the line total by promotions
+    pub discount: BigDecimal,
+}
+
+impl PricingLine {
+    /// Line total before discounts
+    pub fn gross(&self) -> BigDecimal {
+        &self.unit_price * BigDecimal::from(self.quantity)
+    }
+}
+
+/// Subtotal/tax/service/total breakdown of an order
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceBreakdown {
+    pub subtotal: BigDecimal,
+    pub discount_amount: BigDecimal,
+    pub tax_amount: BigDecimal,
+    pub service_charge: BigDecimal,
+    pub total_amount: BigDecimal,
+}
+
+/// The amounts an order total is made of, before rounding
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceComponents {
+    pub subtotal: BigDecimal,
+    pub discount: BigDecimal,
+    pub inclusive_tax: BigDecimal,
+    pub exclusive_tax: BigDecimal,
+    pub service_charge: BigDecimal,
+}
+
+impl PriceComponents {
+    /// Components with the same value everywhere, used as weights for even splits
+    pub fn uniform(value: BigDecimal) -> Self {
+        PriceComponents {
+            subtotal: value.clone(),
+            discount: value.clone(),
+            inclusive_tax: value.clone(),
+            exclusive_tax: value.clone(),
+            service_charge: value,
+        }
+    }
+
+    pub fn total(&self) -> BigDecimal {
+        &self.subtotal - &self.discount + &self.exclusive_tax + &self.service_charge
+    }
+}
+
+impl From<PriceComponents> for PriceBreakdown {
+    fn from(components: PriceComponents) -> Self {
+        PriceBreakdown {
+            total_amount: components.total(),
+            tax_amount: &components.inclusive_tax + &components.exclusive_tax,
+            subtotal: components.subtotal,
+            discount_amount: components.discount,
+            service_charge: components.service_charge,
+        }
+    }
+}
+
+/// Splits the stored totals of an order back into components. Only the combined tax is
+/// stored, the exclusive part is whatever the total adds on top of the other amounts.
+impl From<&Order> for PriceComponents {
+    fn from(order: &Order) -> Self {
+        let exclusive_tax = &order.total_amount - &order.subtotal + &order.discount_amount
+            - &order.service_charge;
+
+        PriceComponents {
+            subtotal: order.subtotal.clone(),
+            discount: order.discount_amount.clone(),
+            inclusive_tax: &order.tax_amount - &exclusive_tax,
+            exclusive_tax,
+            service_charge: order.service_charge.clone(),
+        }
+    }
+}
+
+impl From<PriceBreakdown> for OrderPricing {
+    fn from(breakdown: PriceBreakdown) -> Self {
+        OrderPricing {
+            subtotal: breakdown.subtotal,
+            tax_amount: breakdown.tax_amount,
+            service_charge: breakdown.service_charge,
+            discount_amount: breakdown.discount_amount,
+            total_amount: breakdown.total_amount,
+        }
+    }
+}
+
+/// Computes order totals from line prices, tax rates and the service charge.
+///
+/// * The subtotal is the sum of `unit_price * quantity` as listed on the menu.
+/// * Discounts are taken off the line totals before taxes are computed.
+/// * Inclusive taxes are extracted from the discounted price, exclusive taxes are added on top
+///   of the discounted price net of inclusive taxes.
+/// * The service charge is a percentage of the discounted pre-tax subtotal and is not taxed.
+/// * Amounts are accumulated unrounded and rounded once per order, so the total is always
+///   `subtotal - discount + exclusive tax + service charge`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricingEngine {
+    pub service_charge_percent: BigDecimal,
+    pub rounding: RoundingRule,
+}
+
+impl PricingEngine {
+    pub fn new(service_charge_percent: BigDecimal, rounding: RoundingRule) -> Self {
+        PricingEngine {
+            service_charge_percent,
+            rounding,
+        }
+    }
+
+    /// Creates a pricing engine using the service charge and rounding rule from the config
+    pub fn from_config() -> Self {
+        let config = Config::new();
+        PricingEngine::new(
+            config.service_charge_percent,
+            RoundingRule::new(config.rounding_mode, config.rounding_increment),
+        )
+    }
+
+    pub fn price(&self, lines: &[PricingLine]) -> PriceBreakdown {
+        let components = self.components(lines);
+
+        PriceBreakdown::from(PriceComponents {
+            subtotal: self.rounding.round(&components.subtotal),
+            discount: self.rounding.round(&components.discount),
+            inclusive_tax: self.rounding.round(&components.inclusive_tax),
+            exclusive_tax: self.rounding.round(&components.exclusive_tax),
+            service_charge: self.rounding.round(&components.service_charge),
+        })
+    }
+
+    /// Computes the unrounded components of the order total
+    pub fn components(&self, lines: &[PricingLine]) -> PriceComponents {
+        let hundred = BigDecimal::from(100);
+        let mut subtotal = BigDecimal::zero();
+        let mut discount = BigDecimal::zero();
+        let mut net_subtotal = BigDecimal::zero();
+        let mut inclusive_tax = BigDecimal::zero();
+        let mut exclusive_tax = BigDecimal::zero();
+
+        for line in lines {
+            let line_gross = line.gross();
+            let line_total = &line_gross - &line.discount;
+
+            let inclusive_rate: BigDecimal = line
+                .taxes
+                .iter()
+                .filter(|t| t.inclusive)
+                .map(|t| &t.percent / &hundred)
+                .sum();
+            let exclusive_rate: BigDecimal = line
+                .taxes
+                .iter()
+                .filter(|t| !t.inclusive)
+                .map(|t| &t.percent / &hundred)
+                .sum();
+
+            let divisor = BigDecimal::from(1) + inclusive_rate;
+            let line_net = &line_total / &divisor;
+
+            inclusive_tax += &line_total - &line_net;
+            exclusive_tax += &line_net * &exclusive_rate;
+            net_subtotal += line_net;
+            discount += line.discount.clone();
+            subtotal += line_gross;
+        }
+
+        let service_charge = &net_subtotal * &self.service_charge_percent / &hundred;
+
+        PriceComponents {
+            subtotal,
+            discount,
+            inclusive_tax,
+            exclusive_tax,
+            service_charge,
+        }
+    }
+
+    /// Resolves the tax rates of every item into undiscounted pricing lines
+    /// Items are (product_id, quantity, unit_price)
+    pub async fn build_lines(
+        &self,
+        items: &[(i32, i32, BigDecimal)],
+    ) -> Result<Vec<PricingLine>, PricingServiceError> {
+        let repo = TaxRateRepo::new();
+        let mut lines = Vec::new();
+
+        for (product_id, quantity, unit_price) in items {
+            let taxes = repo
+                .get_for_product(*product_id)
+                .await
+                .map_err(|_| PricingServiceError::DatabaseError)?;
+
+            lines.push(PricingLine {
+                product_id: *product_id,
+                quantity: *quantity,
+                unit_price: unit_price.clone(),
+                taxes: taxes.iter().map(LineTax::from).collect(),
+                discount: BigDecimal::zero(),
+            });
+        }
+
+        Ok(lines)
+    }
+}
+
+impl Default for PricingEngine {
+    fn default() -> Self {
+        Self::from_config()
+    }
+}
+
+pub struct PricingService;
+
+impl PricingService {
+    pub fn new() -> Self {
+        PricingService
+    }
+
+    /// Gets all tax rates (requires READ permission or Admin)
+    pub async fn get_tax_rates(
+        &self,
+        role_id: i32,
+    ) -> Result<Option<Vec<TaxRateResponse>>, PricingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PricingServiceError::PermissionDenied);
+        }
+
+        let repo = TaxRateRepo::new();
+        let rates = repo
+            .get_all()
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)?;
+
+        Ok(rates.map(|rates| rates.into_iter().map(TaxRateResponse::from).collect()))
+    }
+
+    /// Creates a tax rate (requires Admin)
+    pub async fn create_tax_rate(
+        &self,
+        role_id: i32,
+        request: &CreateTaxRateRequest,
+    ) -> Result<(), PricingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PricingServiceError::PermissionDenied);
+        }
+
+        if !Self::is_valid_rate(&request.rate)
+            || (request.product_id.is_some() && request.category_id.is_some())
+        {
+            return Err(PricingServiceError::InvalidTaxRate);
+        }
+
+        let repo = TaxRateRepo::new();
+        repo.add(NewTaxRate::from(request))
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)
+    }
+
+    /// Updates a tax rate (requires Admin)
+    pub async fn update_tax_rate(
+        &self,
+        role_id: i32,
+        tax_rate_id: i32,
+        request: &UpdateTaxRateRequest,
+    ) -> Result<(), PricingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PricingServiceError::PermissionDenied);
+        }
+
+        if request
+            .rate
+            .as_ref()
+            .is_some_and(|rate| !Self::is_valid_rate(rate))
+        {
+            return Err(PricingServiceError::InvalidTaxRate);
+        }
+
+        let repo = TaxRateRepo::new();
+
+        repo.get_by_id(tax_rate_id)
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)?
+            .ok_or(PricingServiceError::TaxRateNotFound)?;
+
+        repo.update(tax_rate_id, UpdateTaxRate::from(request))
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)
+    }
+
+    /// Deletes a tax rate (requires Admin)
+    pub async fn delete_tax_rate(
+        &self,
+        role_id: i32,
+        tax_rate_id: i32,
+    ) -> Result<(), PricingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PricingServiceError::PermissionDenied);
+        }
+
+        let repo = TaxRateRepo::new();
+
+        repo.get_by_id(tax_rate_id)
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)?
+            .ok_or(PricingServiceError::TaxRateNotFound)?;
+
+        repo.delete(tax_rate_id)
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)
+    }
+
+    fn is_valid_rate(rate: &BigDecimal) -> bool {
+        *rate >= BigDecimal::zero() && *rate < BigDecimal::from(100)
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, PricingServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| PricingServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for PricingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
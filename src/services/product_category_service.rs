@@ -1,6 +1,6 @@
 use crate::api::request::{AssignCategoryRequest, CreateCategoryRequest, UpdateCategoryRequest};
 use crate::api::response::{CategoryResponse, ProductResponse};
-use crate::data::models::categories::{NewCategory, UpdateCategory};
+use crate::data::models::categories::{Category, NewCategory, UpdateCategory};
 use crate::data::models::product_category::NewProductCategory;
 use crate::data::models::roles::RolePermissions;
 use crate::data::repos::implementors::category_repo::CategoryRepo;
@@ -8,7 +8,89 @@ use crate::data::repos::implementors::product_category_repo::ProductCategoryRepo
 use crate::data::repos::implementors::product_repo::ProductRepo;
 use crate::data::repos::traits::repository::Repository;
 use crate::services::errors::ProductCategoryServiceError;
+use crate::services::translation_service::TranslationService;
 use diesel::result::{DatabaseErrorKind, Error};
+use std::collections::HashSet;
+
+/// Whether nesting the category `id` under `parent` would make it its own ancestor
+pub fn creates_cycle(categories: &[Category], id: i32, parent: i32) -> bool {
+    let mut seen = HashSet::new();
+    let mut current = Some(parent);
+    while let Some(category_id) = current {
+        if category_id == id || !seen.insert(category_id) {
+            return true;
+        }
+        current = categories
+            .iter()
+            .find(|c| c.category_id == category_id)
+            .and_then(|c| c.parent_id);
+    }
+    false
+}
+
+/// Whether a category, or a new one when `id` is none, can be nested under `parent`: the
+/// parent is among the active `categories` and is not the category itself or nested under it
+pub fn is_valid_parent(categories: &[Category], parent: i32, id: Option<i32>) -> bool {
+    categories.iter().any(|c| c.category_id == parent)
+        && !id.is_some_and(|id| creates_cycle(categories, id, parent))
+}
+
+/// The category `id` followed by every category nested under it, level by level
+pub fn descendant_ids(categories: &[Category], id: i32) -> Vec<i32> {
+    let mut ids = vec![id];
+    let mut next = 0;
+    while next < ids.len() {
+        let parent = ids[next];
+        for category in categories {
+            if category.parent_id == Some(parent) && !ids.contains(&category.category_id) {
+                ids.push(category.category_id);
+            }
+        }
+        next += 1;
+    }
+    ids
+}
+
+/// Nests categories under their parents, each level by sort order and then name. Categories
+/// whose parent is not among them, such as an archived one, are put at the top level.
+pub fn category_tree(categories: Vec<Category>) -> Vec<CategoryResponse> {
+    let ids: HashSet<i32> = categories.iter().map(|c| c.category_id).collect();
+    let (roots, nested): (Vec<Category>, Vec<Category>) = categories
+        .into_iter()
+        .partition(|c| c.parent_id.is_none_or(|parent| !ids.contains(&parent)));
+
+    let mut nested: Vec<Option<Category>> = nested.into_iter().map(Some).collect();
+    let mut tree: Vec<CategoryResponse> = roots.into_iter().map(CategoryResponse::from).collect();
+    attach_children(&mut tree, &mut nested);
+    tree
+}
+
+fn attach_children(level: &mut [CategoryResponse], nested: &mut [Option<Category>]) {
+    level.sort_by(|a, b| (a.sort_order, &a.name).cmp(&(b.sort_order, &b.name)));
+    for node in level.iter_mut() {
+        let mut children: Vec<CategoryResponse> = nested
+            .iter_mut()
+            .filter(|c| c.as_ref().is_some_and(|c| c.parent_id == node.category_id))
+            .filter_map(Option::take)
+            .map(CategoryResponse::from)
+            .collect();
+        attach_children(&mut children, nested);
+        node.children = Some(children);
+    }
+}
+
+/// Hides the ids and timestamps of categories from roles without Admin
+fn hide_details(categories: &mut [CategoryResponse]) {
+    for category in categories {
+        category.category_id = None;
+        category.parent_id = None;
+        category.created_at = None;
+        category.updated_at = None;
+        if let Some(children) = category.children.as_mut() {
+            hide_details(children);
+        }
+    }
+}
 
 pub struct ProductCategoryService {}
 
@@ -17,9 +99,12 @@ impl ProductCategoryService {
         ProductCategoryService {}
     }
 
+    /// Gets the categories that are not archived in a locale (requires READ permission or
+    /// Admin)
     pub async fn get_categories(
         &self,
         role_id: i32,
+        locale: &str,
     ) -> Result<Option<Vec<CategoryResponse>>, ProductCategoryServiceError> {
         if !self.has_permission(role_id, RolePermissions::Read).await?
             && !self.has_permission(role_id, RolePermissions::Admin).await?
@@ -32,21 +117,24 @@ impl ProductCategoryService {
         let repo = CategoryRepo::new();
 
         let categories = repo
-            .get_all()
+            .get_active()
             .await
             .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
 
-        let mut res = categories.map(|cats| cats.into_iter().map(|c| c.into()).collect());
+        let mut res: Option<Vec<CategoryResponse>> =
+            categories.map(|cats| cats.into_iter().map(|c| c.into()).collect());
+        if let Some(categories) = res.as_mut() {
+            TranslationService::new()
+                .localize_categories(categories, locale)
+                .await
+                .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+        }
 
         if is_admin {
             Ok(res)
         } else {
             if let Some(categories) = res.as_mut() {
-                categories.iter_mut().for_each(|cat| {
-                    cat.category_id = None;
-                    cat.created_at = None;
-                    cat.updated_at = None;
-                });
+                hide_details(categories);
             } else {
                 return Ok(Some(vec![]));
             }
@@ -55,6 +143,38 @@ impl ProductCategoryService {
         }
     }
 
+    /// Gets the categories that are not archived in a locale, nested under their parents
+    /// (requires READ permission or Admin)
+    pub async fn get_category_tree(
+        &self,
+        role_id: i32,
+        locale: &str,
+    ) -> Result<Vec<CategoryResponse>, ProductCategoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            Err(ProductCategoryServiceError::PermissionDenied)?
+        }
+
+        let is_admin = self.has_permission(role_id, RolePermissions::Admin).await?;
+
+        let categories = CategoryRepo::new()
+            .get_active()
+            .await
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        let mut tree = category_tree(categories);
+        TranslationService::new()
+            .localize_categories(&mut tree, locale)
+            .await
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+        if !is_admin {
+            hide_details(&mut tree);
+        }
+        Ok(tree)
+    }
+
     pub async fn add_category(
         &self,
         role_id: i32,
@@ -69,12 +189,20 @@ impl ProductCategoryService {
         let repo = CategoryRepo::new();
 
         let new_category = NewCategory::from(&request);
+        let parent_id = new_category.parent_id;
 
-        repo.add(new_category)
+        let added = repo
+            .add_nested(new_category, move |categories| {
+                parent_id.is_some_and(|parent| is_valid_parent(categories, parent, None))
+            })
             .await
             .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+        if !added {
+            Err(ProductCategoryServiceError::InvalidParent)?
+        }
 
-        repo.get_by_name(&request.name)
+        let category_id = repo
+            .get_by_name(&request.name)
             .await
             .map(|c| match c {
                 Some(c) => c.category_id,
@@ -90,7 +218,9 @@ impl ProductCategoryService {
                     Ok(v)
                 }
             })
-            .map_err(|_| ProductCategoryServiceError::CategoryNotFound)
+            .map_err(|_| ProductCategoryServiceError::CategoryNotFound)?;
+
+        Ok(category_id)
     }
 
     pub async fn add_product_to_category(
@@ -128,7 +258,16 @@ impl ProductCategoryService {
         product_category_repo
             .add(new_product_category)
             .await
-            .map_err(|_| ProductCategoryServiceError::DatabaseError)
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+
+        if let Some(sort_order) = request.sort_order {
+            product_category_repo
+                .set_sort_order(product.product_id, category.category_id, sort_order)
+                .await
+                .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+        }
+
+        Ok(())
     }
 
     pub async fn add_product_to_categories(
@@ -233,10 +372,20 @@ impl ProductCategoryService {
         let repo = CategoryRepo::new();
 
         let updated_category = UpdateCategory::from(&request);
+        let parent_id = updated_category.parent_id.flatten();
 
-        repo.update(category_id, updated_category)
+        let updated = repo
+            .update_nested(category_id, updated_category, move |categories| {
+                parent_id
+                    .is_some_and(|parent| is_valid_parent(categories, parent, Some(category_id)))
+            })
             .await
-            .map_err(|_| ProductCategoryServiceError::DatabaseError)
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+        if !updated {
+            Err(ProductCategoryServiceError::InvalidParent)?
+        }
+
+        Ok(())
     }
 
     pub async fn delete_category(
@@ -252,15 +401,64 @@ impl ProductCategoryService {
 
         let repo = CategoryRepo::new();
 
-        repo.delete(category_id)
+        // Archived rather than deleted, so the category can be restored
+        if !repo
+            .archive(category_id)
             .await
-            .map_err(|_| ProductCategoryServiceError::DatabaseError)
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?
+        {
+            Err(ProductCategoryServiceError::CategoryNotFound)?
+        }
+
+        Ok(())
+    }
+
+    /// Gets the archived categories, most recently archived first (requires Admin)
+    pub async fn get_archived_categories(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<CategoryResponse>, ProductCategoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            Err(ProductCategoryServiceError::PermissionDenied)?
+        }
+
+        let categories = CategoryRepo::new()
+            .get_archived()
+            .await
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+
+        Ok(categories.into_iter().map(|c| c.into()).collect())
+    }
+
+    /// Brings an archived category back (requires Admin)
+    pub async fn restore_category(
+        &self,
+        role_id: i32,
+        category_id: i32,
+    ) -> Result<(), ProductCategoryServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            Err(ProductCategoryServiceError::PermissionDenied)?
+        }
+
+        if !CategoryRepo::new()
+            .restore(category_id)
+            .await
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?
+        {
+            Err(ProductCategoryServiceError::CategoryNotFound)?
+        }
+
+        Ok(())
     }
 
+    /// Gets the products of a category in a locale by their sort order, optionally followed by
+    /// those of the categories nested under it, listing each product once
     pub async fn get_products_by_category(
         &self,
         role_id: i32,
         category_id: i32,
+        include_descendants: bool,
+        locale: &str,
     ) -> Result<Option<Vec<ProductResponse>>, ProductCategoryServiceError> {
         if !self.has_permission(role_id, RolePermissions::Read).await?
             && !self.has_permission(role_id, RolePermissions::Admin).await?
@@ -270,12 +468,43 @@ impl ProductCategoryService {
 
         let repo = ProductCategoryRepo::new();
 
-        let products = repo
-            .get_products_by_category_id(category_id)
+        if !include_descendants {
+            let products = repo
+                .get_products_by_category_id(category_id)
+                .await
+                .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+
+            let mut products: Option<Vec<ProductResponse>> =
+                products.map(|prods| prods.into_iter().map(|p| p.into()).collect());
+            if let Some(products) = products.as_mut() {
+                self.localize_products(products, locale).await?;
+            }
+            return Ok(products);
+        }
+
+        let categories = CategoryRepo::new()
+            .get_active()
             .await
-            .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?
+            .unwrap_or_default();
+        let ids = descendant_ids(&categories, category_id);
 
-        Ok(products.map(|prods| prods.into_iter().map(|p| p.into()).collect()))
+        let mut listed = repo
+            .get_products_by_category_ids(ids.clone())
+            .await
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)?;
+        // Stable, so products keep their sort order within each category
+        listed.sort_by_key(|(cid, _)| ids.iter().position(|id| id == cid));
+
+        let mut seen = HashSet::new();
+        let mut products: Vec<ProductResponse> = listed
+            .into_iter()
+            .filter(|(_, p)| seen.insert(p.product_id))
+            .map(|(_, p)| p.into())
+            .collect();
+        self.localize_products(&mut products, locale).await?;
+
+        Ok(Some(products).filter(|p| !p.is_empty()))
     }
 
     pub async fn remove_product_from_category(
@@ -312,6 +541,17 @@ impl ProductCategoryService {
             .map_err(|_| ProductCategoryServiceError::DatabaseError)
     }
 
+    async fn localize_products(
+        &self,
+        products: &mut [ProductResponse],
+        locale: &str,
+    ) -> Result<(), ProductCategoryServiceError> {
+        TranslationService::new()
+            .localize_products(products, locale)
+            .await
+            .map_err(|_| ProductCategoryServiceError::DatabaseError)
+    }
+
     async fn has_permission(
         &self,
         role_id: i32,
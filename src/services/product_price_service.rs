@@ -0,0 +1,198 @@
+use crate::api::request::SchedulePriceRequest;
+use crate::api::response::ProductPriceResponse;
+use crate::data::models::product_price::NewProductPrice;
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::product_price_repo::ProductPriceRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::ProductPriceServiceError;
+use crate::utils::time::{local_now, parse_datetime};
+use bigdecimal::{BigDecimal, Signed};
+use chrono::NaiveDateTime;
+
+/// When a price change asked for at `now` takes effect: straight away without a time, and
+/// otherwise at the given time, which cannot be in the past as that would rewrite prices
+/// orders were already charged at
+pub fn effective_from(
+    value: Option<&str>,
+    now: NaiveDateTime,
+) -> Result<NaiveDateTime, ProductPriceServiceError> {
+    match value {
+        None => Ok(now),
+        Some(value) => parse_datetime(value)
+            .filter(|at| *at >= now)
+            .ok_or(ProductPriceServiceError::InvalidEffectiveFrom),
+    }
+}
+
+pub struct ProductPriceService;
+
+impl ProductPriceService {
+    pub fn new() -> Self {
+        ProductPriceService
+    }
+
+    /// Gets every price a product has had or is scheduled to have, oldest first (requires
+    /// READ permission or Admin)
+    pub async fn get_price_timeline(
+        &self,
+        product_id: i32,
+        role_id: i32,
+    ) -> Result<Vec<ProductPriceResponse>, ProductPriceServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ProductPriceServiceError::PermissionDenied);
+        }
+
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?
+            .ok_or(ProductPriceServiceError::ProductNotFound)?;
+
+        let prices = ProductPriceRepo::new()
+            .get_by_product(product_id)
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?;
+
+        Ok(prices.into_iter().map(ProductPriceResponse::from).collect())
+    }
+
+    /// Changes the price of a product now or from a time in the future, replacing a change
+    /// already scheduled for the same time (requires WRITE permission or Admin)
+    pub async fn schedule_price_change(
+        &self,
+        product_id: i32,
+        request: &SchedulePriceRequest,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<ProductPriceResponse, ProductPriceServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ProductPriceServiceError::PermissionDenied);
+        }
+
+        if request.price.is_negative() {
+            return Err(ProductPriceServiceError::InvalidPrice);
+        }
+        let now = local_now();
+        let from = effective_from(request.effective_from.as_deref(), now)?;
+
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or(ProductPriceServiceError::ProductNotFound)?;
+
+        let repo = ProductPriceRepo::new();
+        let price_id = repo
+            .schedule(
+                NewProductPrice {
+                    product_id,
+                    price: request.price.clone(),
+                    effective_from: from,
+                    effective_to: None,
+                    created_by: Some(user_id),
+                },
+                now,
+            )
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?;
+
+        repo.get_by_product(product_id)
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?
+            .into_iter()
+            .find(|p| p.price_id == price_id)
+            .map(ProductPriceResponse::from)
+            .ok_or(ProductPriceServiceError::DatabaseError)
+    }
+
+    /// Cancels a price change that has not taken effect yet (requires WRITE permission or
+    /// Admin)
+    pub async fn cancel_price_change(
+        &self,
+        product_id: i32,
+        price_id: i32,
+        role_id: i32,
+    ) -> Result<(), ProductPriceServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ProductPriceServiceError::PermissionDenied);
+        }
+
+        if !ProductPriceRepo::new()
+            .cancel(product_id, price_id, local_now())
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?
+        {
+            return Err(ProductPriceServiceError::PriceChangeNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Records the price a product is sold at from `now` without checking permissions, for
+    /// products created or updated through the product service
+    pub async fn record_price(
+        &self,
+        product_id: i32,
+        price: BigDecimal,
+        now: NaiveDateTime,
+    ) -> Result<(), ProductPriceServiceError> {
+        ProductPriceRepo::new()
+            .schedule(
+                NewProductPrice {
+                    product_id,
+                    price,
+                    effective_from: now,
+                    effective_to: None,
+                    created_by: None,
+                },
+                now,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| ProductPriceServiceError::DatabaseError)
+    }
+
+    /// Puts the price changes that have come into effect by `now` on the products, returning
+    /// how many products changed price. Called by the scheduler.
+    pub async fn apply_scheduled_prices(
+        &self,
+        now: NaiveDateTime,
+    ) -> Result<usize, ProductPriceServiceError> {
+        ProductPriceRepo::new()
+            .apply_due(now)
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, ProductPriceServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| ProductPriceServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for ProductPriceService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,11 +1,21 @@
-use crate::api::response::{CategoryResponse, ProductResponse};
+use crate::api::config::Config;
+use crate::api::request::ProductQuery;
+use crate::api::response::{
+    CategoryResponse, DietaryTagsResponse, NutritionResponse, ProductResponse,
+};
 use crate::data::models::product::{NewProduct, UpdateProduct};
 use crate::data::models::roles::RolePermissions;
 use crate::data::repos::implementors::product_category_repo::ProductCategoryRepo;
 use crate::data::repos::implementors::product_repo::ProductRepo;
 use crate::data::repos::traits::repository::Repository;
+use crate::services::allergen_service::{AllergenService, DietaryFilter};
 use crate::services::errors::ProductServiceError;
+use crate::services::nutrition_service::NutritionService;
+use crate::services::product_price_service::ProductPriceService;
+use crate::services::translation_service::TranslationService;
+use crate::utils::time::local_now;
 use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
 
 pub struct ProductService;
 
@@ -14,10 +24,23 @@ impl ProductService {
         ProductService
     }
 
-    /// Gets all products (requires READ permission or Admin)
+    /// Gets all products that are not archived (requires READ permission or Admin)
     pub async fn get_all_products(
         &self,
         role_id: i32,
+    ) -> Result<Option<Vec<ProductResponse>>, ProductServiceError> {
+        let locale = Config::new().default_locale;
+        self.get_filtered_products(&ProductQuery::default(), &locale, role_id)
+            .await
+    }
+
+    /// Gets the products that are not archived in a locale, leaving out those with an excluded
+    /// allergen and those not suiting every diet asked for (requires READ permission or Admin)
+    pub async fn get_filtered_products(
+        &self,
+        query: &ProductQuery,
+        locale: &str,
+        role_id: i32,
     ) -> Result<Option<Vec<ProductResponse>>, ProductServiceError> {
         if !self.has_permission(role_id, RolePermissions::Read).await?
             && !self.has_permission(role_id, RolePermissions::Write).await?
@@ -26,32 +49,72 @@ impl ProductService {
             return Err(ProductServiceError::PermissionDenied);
         }
 
+        let filter =
+            DietaryFilter::parse(query.exclude_allergens.as_deref(), query.diet.as_deref())
+                .map_err(ProductServiceError::InvalidFilter)?;
+
         let repo = ProductRepo::new();
         let products = repo
-            .get_all()
+            .get_active()
             .await
             .map_err(|_| ProductServiceError::DatabaseError)?;
 
         match products {
             Some(prods) => {
+                let product_ids: Vec<i32> = prods.iter().map(|p| p.product_id).collect();
+                let profiles = AllergenService::new()
+                    .get_profiles(product_ids.clone())
+                    .await
+                    .map_err(|_| ProductServiceError::DatabaseError)?;
+                let mut nutrition = NutritionService::new()
+                    .get_nutrition(product_ids)
+                    .await
+                    .map_err(|_| ProductServiceError::DatabaseError)?;
+
                 let mut responses = Vec::new();
-                for p in prods {
+                for (p, profile) in prods.into_iter().zip(profiles) {
+                    if !filter.matches(&profile) {
+                        continue;
+                    }
                     let mut response = ProductResponse::from(p);
                     response.categories =
                         self.get_categories_for_product(response.product_id).await?;
+                    let tags = DietaryTagsResponse::from(profile);
+                    response.allergens = tags.allergens;
+                    response.dietary_tags = tags.dietary_tags;
+                    response.nutrition = nutrition
+                        .remove(&response.product_id)
+                        .map(NutritionResponse::from);
                     responses.push(response);
                 }
+                TranslationService::new()
+                    .localize_products(&mut responses, locale)
+                    .await
+                    .map_err(|_| ProductServiceError::DatabaseError)?;
                 Ok(Some(responses))
             }
             None => Ok(None),
         }
     }
 
-    /// Gets a product by ID (requires READ permission or Admin)
+    /// Gets a product by ID unless it is archived (requires READ permission or Admin)
     pub async fn get_product_by_id(
         &self,
         product_id: i32,
         role_id: i32,
+    ) -> Result<Option<ProductResponse>, ProductServiceError> {
+        let locale = Config::new().default_locale;
+        self.get_localized_product(product_id, &locale, role_id)
+            .await
+    }
+
+    /// Gets a product by ID in a locale unless it is archived (requires READ permission or
+    /// Admin)
+    pub async fn get_localized_product(
+        &self,
+        product_id: i32,
+        locale: &str,
+        role_id: i32,
     ) -> Result<Option<ProductResponse>, ProductServiceError> {
         if !self.has_permission(role_id, RolePermissions::Read).await?
             && !self.has_permission(role_id, RolePermissions::Write).await?
@@ -64,19 +127,25 @@ impl ProductService {
         let product = repo
             .get_by_id(product_id)
             .await
-            .map_err(|_| ProductServiceError::DatabaseError)?;
+            .map_err(|_| ProductServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none());
 
         match product {
             Some(p) => {
                 let mut response = ProductResponse::from(p);
                 response.categories = self.get_categories_for_product(response.product_id).await?;
+                self.add_details(&mut response).await?;
+                TranslationService::new()
+                    .localize_products(std::slice::from_mut(&mut response), locale)
+                    .await
+                    .map_err(|_| ProductServiceError::DatabaseError)?;
                 Ok(Some(response))
             }
             None => Ok(None),
         }
     }
 
-    /// Gets a product by name (requires READ permission or Admin)
+    /// Gets a product by name unless it is archived (requires READ permission or Admin)
     pub async fn get_product_by_name(
         &self,
         name: &str,
@@ -93,18 +162,41 @@ impl ProductService {
         let product = repo
             .get_by_name(name)
             .await
-            .map_err(|_| ProductServiceError::DatabaseError)?;
+            .map_err(|_| ProductServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none());
 
         match product {
             Some(p) => {
                 let mut response = ProductResponse::from(p);
                 response.categories = self.get_categories_for_product(response.product_id).await?;
+                self.add_details(&mut response).await?;
                 Ok(Some(response))
             }
             None => Ok(None),
         }
     }
 
+    /// Fills in the allergens, dietary tags and nutrition facts of a product
+    async fn add_details(&self, response: &mut ProductResponse) -> Result<(), ProductServiceError> {
+        let profiles = AllergenService::new()
+            .get_profiles(vec![response.product_id])
+            .await
+            .map_err(|_| ProductServiceError::DatabaseError)?;
+        if let Some(profile) = profiles.into_iter().next() {
+            let tags = DietaryTagsResponse::from(profile);
+            response.allergens = tags.allergens;
+            response.dietary_tags = tags.dietary_tags;
+        }
+
+        response.nutrition = NutritionService::new()
+            .get_nutrition(vec![response.product_id])
+            .await
+            .map_err(|_| ProductServiceError::DatabaseError)?
+            .remove(&response.product_id)
+            .map(NutritionResponse::from);
+        Ok(())
+    }
+
     async fn get_categories_for_product(
         &self,
         product_id: i32,
@@ -149,10 +241,21 @@ impl ProductService {
             name,
             product_image_uri: image_uri,
             description,
-            price,
+            price: price.clone(),
         };
 
         repo.add(new_product)
+            .await
+            .map_err(|_| ProductServiceError::ProductCreationFailed)?;
+
+        // The first price starts the price history of the product
+        let product = repo
+            .get_by_name(name)
+            .await
+            .map_err(|_| ProductServiceError::DatabaseError)?
+            .ok_or(ProductServiceError::ProductCreationFailed)?;
+        ProductPriceService::new()
+            .record_price(product.product_id, price, local_now())
             .await
             .map_err(|_| ProductServiceError::ProductCreationFailed)
     }
@@ -176,7 +279,8 @@ impl ProductService {
         let repo = ProductRepo::new();
 
         // Verify product exists
-        repo.get_by_id(product_id)
+        let product = repo
+            .get_by_id(product_id)
             .await
             .map_err(|_| ProductServiceError::DatabaseError)?
             .ok_or(ProductServiceError::ProductNotFound)?;
@@ -185,15 +289,25 @@ impl ProductService {
             name,
             product_image_uri: image_uri,
             description,
-            price,
+            price: price.clone(),
         };
 
         repo.update(product_id, update)
             .await
-            .map_err(|_| ProductServiceError::ProductUpdateFailed)
+            .map_err(|_| ProductServiceError::ProductUpdateFailed)?;
+
+        // A new price applies from now on and keeps the old one in the price history
+        match price {
+            Some(price) if price != product.price => ProductPriceService::new()
+                .record_price(product_id, price, local_now())
+                .await
+                .map_err(|_| ProductServiceError::ProductUpdateFailed),
+            _ => Ok(()),
+        }
     }
 
-    /// Deletes a product (requires DELETE permission or Admin)
+    /// Archives a product (requires DELETE permission or Admin). It leaves the menu and can
+    /// no longer be ordered, but stays on the orders it was sold on and can be restored.
     pub async fn delete_product(
         &self,
         product_id: i32,
@@ -209,19 +323,58 @@ impl ProductService {
 
         let repo = ProductRepo::new();
 
-        // Verify product exists
-        repo.get_by_id(product_id)
+        if !repo
+            .archive(product_id)
             .await
-            .map_err(|_| ProductServiceError::DatabaseError)?
-            .ok_or(ProductServiceError::ProductNotFound)?;
+            .map_err(|_| ProductServiceError::ProductDeletionFailed)?
+        {
+            return Err(ProductServiceError::ProductNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the archived products, most recently archived first (requires Admin)
+    pub async fn get_archived_products(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<ProductResponse>, ProductServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(ProductServiceError::PermissionDenied);
+        }
+
+        let products = ProductRepo::new()
+            .get_archived()
+            .await
+            .map_err(|_| ProductServiceError::DatabaseError)?;
+
+        Ok(products.into_iter().map(ProductResponse::from).collect())
+    }
+
+    /// Brings an archived product back onto the menu (requires Admin)
+    pub async fn restore_product(
+        &self,
+        product_id: i32,
+        role_id: i32,
+    ) -> Result<(), ProductServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(ProductServiceError::PermissionDenied);
+        }
 
-        repo.delete(product_id)
+        if !ProductRepo::new()
+            .restore(product_id)
             .await
-            .map_err(|_| ProductServiceError::ProductDeletionFailed)
+            .map_err(|_| ProductServiceError::ProductUpdateFailed)?
+        {
+            return Err(ProductServiceError::ProductNotFound);
+        }
+
+        Ok(())
     }
 
     /// Updates product image URI (requires WRITE permission or Admin)
-    /// This method is intended for use with Azure Blob Storage integration
+    /// Uploaded images are stored through `ImageService::upload_product_image`, which sets the
+    /// URI itself; this only points a product at an image hosted elsewhere
     pub async fn update_product_image(
         &self,
         product_id: i32,
@@ -254,6 +407,70 @@ impl ProductService {
             .map_err(|_| ProductServiceError::ProductUpdateFailed)
     }
 
+    /// Sets how many minutes the kitchen needs to prepare a product (requires WRITE
+    /// permission or Admin)
+    pub async fn update_prep_time(
+        &self,
+        product_id: i32,
+        prep_minutes: i32,
+        role_id: i32,
+    ) -> Result<(), ProductServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ProductServiceError::PermissionDenied);
+        }
+
+        if prep_minutes < 0 {
+            return Err(ProductServiceError::InvalidPrepTime);
+        }
+
+        let repo = ProductRepo::new();
+
+        // Verify product exists
+        repo.get_by_id(product_id)
+            .await
+            .map_err(|_| ProductServiceError::DatabaseError)?
+            .ok_or(ProductServiceError::ProductNotFound)?;
+
+        repo.update_prep_time(product_id, prep_minutes)
+            .await
+            .map_err(|_| ProductServiceError::ProductUpdateFailed)
+    }
+
+    /// 86s a product or makes it available again (requires WRITE permission or Admin, so
+    /// the kitchen can toggle it). An unavailable product can be given a future time at
+    /// which it becomes available again on its own.
+    pub async fn set_availability(
+        &self,
+        product_id: i32,
+        is_available: bool,
+        available_again_at: Option<NaiveDateTime>,
+        role_id: i32,
+    ) -> Result<(), ProductServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ProductServiceError::PermissionDenied);
+        }
+
+        if available_again_at.is_some_and(|at| is_available || at <= local_now()) {
+            return Err(ProductServiceError::InvalidAvailability);
+        }
+
+        let repo = ProductRepo::new();
+
+        // Verify product exists
+        repo.get_by_id(product_id)
+            .await
+            .map_err(|_| ProductServiceError::DatabaseError)?
+            .ok_or(ProductServiceError::ProductNotFound)?;
+
+        repo.set_availability(product_id, is_available, available_again_at)
+            .await
+            .map_err(|_| ProductServiceError::ProductUpdateFailed)
+    }
+
     async fn has_permission(
         &self,
         role_id: i32,
@@ -0,0 +1,501 @@
+use crate::api::request::{CreatePromotionRequest, UpdatePromotionRequest};
+use crate::api::response::PromotionResponse;
+use crate::data::models::order_discount::NewOrderDiscount;
+use crate::data::models::promotion::{NewPromotion, Promotion, UpdatePromotion};
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::product_category_repo::ProductCategoryRepo;
+use crate::data::repos::implementors::promotion_repo::PromotionRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::PromotionServiceError;
+use crate::services::pricing_service::{PricingLine, RoundingRule};
+use crate::utils::time::{is_within_daily_window, local_now, parse_datetime, parse_time};
+use bigdecimal::{BigDecimal, Zero};
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// How a promotion's discount value is interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscountType {
+    Percentage,
+    Fixed,
+}
+
+impl DiscountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscountType::Percentage => "percentage",
+            DiscountType::Fixed => "fixed",
+        }
+    }
+}
+
+impl FromStr for DiscountType {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "percentage" => Ok(DiscountType::Percentage),
+            "fixed" => Ok(DiscountType::Fixed),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A promotion together with the products and categories it is restricted to.
+/// A promotion without products or categories applies to every order line.
+#[derive(Debug, Clone)]
+pub struct PromotionRule {
+    pub promotion: Promotion,
+    pub product_ids: Vec<i32>,
+    pub category_ids: Vec<i32>,
+}
+
+impl PromotionRule {
+    /// Whether the promotion is active, within its date and daily time window and under its
+    /// usage limit
+    pub fn is_available_at(&self, now: NaiveDateTime) -> bool {
+        let promotion = &self.promotion;
+
+        if !promotion.is_active
+            || promotion.starts_at.is_some_and(|starts_at| now < starts_at)
+            || promotion.ends_at.is_some_and(|ends_at| now > ends_at)
+            || promotion
+                .usage_limit
+                .is_some_and(|limit| promotion.usage_count >= limit)
+        {
+            return false;
+        }
+
+        match (promotion.daily_start_time, promotion.daily_end_time) {
+            (Some(start), Some(end)) => is_within_daily_window(now.time(), start, end),
+            _ => true,
+        }
+    }
+
+    fn applies_to(&self, product_id: i32, product_categories: &[i32]) -> bool {
+        if self.product_ids.is_empty() && self.category_ids.is_empty() {
+            return true;
+        }
+
+        self.product_ids.contains(&product_id)
+            || product_categories
+                .iter()
+                .any(|category_id| self.category_ids.contains(category_id))
+    }
+}
+
+/// A promotion applied to an order, with the share of the discount taken off each line
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedDiscount {
+    pub promotion_id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    pub amount: BigDecimal,
+    pub line_amounts: Vec<BigDecimal>,
+}
+
+impl From<AppliedDiscount> for NewOrderDiscount {
+    fn from(discount: AppliedDiscount) -> Self {
+        NewOrderDiscount {
+            // Set when the order is inserted
+            order_id: 0,
+            promotion_id: Some(discount.promotion_id),
+            name: discount.name,
+            code: discount.code,
+            amount: discount.amount,
+        }
+    }
+}
+
+/// Evaluates promotions against the lines of an order.
+///
+/// Automatic promotions apply whenever their conditions are met, code promotions only when
+/// their code is supplied. An unknown or ineligible code is an error so the customer is told
+/// why no discount was given. Promotions are applied one after the other to what is left of
+/// each line, so stacked discounts can never exceed the order subtotal.
+pub fn evaluate_promotions(
+    rules: &[PromotionRule],
+    lines: &[PricingLine],
+    product_categories: &HashMap<i32, Vec<i32>>,
+    code: Option<&str>,
+    now: NaiveDateTime,
+    rounding: &RoundingRule,
+) -> Result<Vec<AppliedDiscount>, PromotionServiceError> {
+    let code = code.map(|c| c.trim().to_lowercase());
+    let subtotal: BigDecimal = lines.iter().map(|line| line.gross()).sum();
+    let mut remaining: Vec<BigDecimal> = lines
+        .iter()
+        .map(|line| line.gross() - &line.discount)
+        .collect();
+
+    let matches_code = |promotion: &Promotion| match (&code, &promotion.code) {
+        (Some(code), Some(promotion_code)) => *code == promotion_code.to_lowercase(),
+        _ => false,
+    };
+
+    let mut candidates: Vec<&PromotionRule> = rules
+        .iter()
+        .filter(|rule| rule.promotion.is_automatic || matches_code(&rule.promotion))
+        .collect();
+    candidates.sort_by_key(|rule| (!rule.promotion.is_automatic, rule.promotion.promotion_id));
+
+    let mut applied = Vec::new();
+    let mut code_applied = false;
+
+    for rule in candidates {
+        let promotion = &rule.promotion;
+        let is_code = !promotion.is_automatic;
+
+        let meets_min_spend = promotion
+            .min_spend
+            .as_ref()
+            .is_none_or(|min_spend| &subtotal >= min_spend);
+
+        let eligible: Vec<usize> = (0..lines.len())
+            .filter(|&i| remaining[i] > BigDecimal::zero())
+            .filter(|&i| {
+                let categories = product_categories
+                    .get(&lines[i].product_id)
+                    .map(|c| c.as_slice())
+                    .unwrap_or_default();
+                rule.applies_to(lines[i].product_id, categories)
+            })
+            .collect();
+        let eligible_total: BigDecimal = eligible.iter().map(|&i| remaining[i].clone()).sum();
+
+        let amount = match DiscountType::from_str(&promotion.discount_type) {
+            Ok(DiscountType::Percentage) => rounding
+                .round(&(&eligible_total * &promotion.discount_value / BigDecimal::from(100))),
+            Ok(DiscountType::Fixed) => promotion.discount_value.clone(),
+            Err(_) => BigDecimal::zero(),
+        };
+        let amount = if amount > eligible_total {
+            eligible_total.clone()
+        } else {
+            amount
+        };
+
+        if !rule.is_available_at(now) || !meets_min_spend || amount <= BigDecimal::zero() {
+            if is_code {
+                return Err(PromotionServiceError::InvalidPromoCode);
+            }
+            continue;
+        }
+
+        // Spread the discount over the eligible lines in proportion to what is left of them,
+        // giving the last line the remainder so the shares add up exactly
+        let mut line_amounts = vec![BigDecimal::zero(); lines.len()];
+        let mut allocated = BigDecimal::zero();
+        for (n, &i) in eligible.iter().enumerate() {
+            let share = if n + 1 == eligible.len() {
+                &amount - &allocated
+            } else {
+                &amount * &remaining[i] / &eligible_total
+            };
+            remaining[i] = &remaining[i] - &share;
+            allocated += share.clone();
+            line_amounts[i] = share;
+        }
+
+        code_applied |= is_code;
+        applied.push(AppliedDiscount {
+            promotion_id: promotion.promotion_id,
+            name: promotion.name.clone(),
+            code: promotion.code.clone(),
+            amount,
+            line_amounts,
+        });
+    }
+
+    if code.is_some() && !code_applied {
+        return Err(PromotionServiceError::InvalidPromoCode);
+    }
+
+    Ok(applied)
+}
+
+pub struct PromotionService;
+
+impl PromotionService {
+    pub fn new() -> Self {
+        PromotionService
+    }
+
+    /// Evaluates the active promotions against the order lines and takes the applied
+    /// discounts off the lines
+    pub async fn apply_promotions(
+        &self,
+        lines: &mut [PricingLine],
+        code: Option<&str>,
+        rounding: &RoundingRule,
+    ) -> Result<Vec<AppliedDiscount>, PromotionServiceError> {
+        let rules = self.get_rules().await?;
+
+        let product_ids = lines.iter().map(|line| line.product_id).collect();
+        let mut product_categories: HashMap<i32, Vec<i32>> = HashMap::new();
+        for (product_id, category_id) in ProductCategoryRepo::new()
+            .get_category_ids_by_product_ids(product_ids)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?
+        {
+            product_categories
+                .entry(product_id)
+                .or_default()
+                .push(category_id);
+        }
+
+        let applied = evaluate_promotions(
+            &rules,
+            lines,
+            &product_categories,
+            code,
+            local_now(),
+            rounding,
+        )?;
+
+        for discount in &applied {
+            for (line, amount) in lines.iter_mut().zip(&discount.line_amounts) {
+                line.discount += amount.clone();
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Gets all promotions (requires READ permission or Admin)
+    pub async fn get_promotions(
+        &self,
+        role_id: i32,
+    ) -> Result<Option<Vec<PromotionResponse>>, PromotionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PromotionServiceError::PermissionDenied);
+        }
+
+        let repo = PromotionRepo::new();
+        let promotions = repo
+            .get_all()
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?;
+
+        match promotions {
+            Some(promotions) => {
+                let rules = self.with_conditions(promotions).await?;
+                Ok(Some(
+                    rules.into_iter().map(PromotionResponse::from).collect(),
+                ))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Creates a promotion (requires Admin)
+    pub async fn create_promotion(
+        &self,
+        role_id: i32,
+        request: &CreatePromotionRequest,
+    ) -> Result<i32, PromotionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PromotionServiceError::PermissionDenied);
+        }
+
+        let is_automatic = request.is_automatic.unwrap_or(false);
+        let discount_type = DiscountType::from_str(&request.discount_type)
+            .map_err(|_| PromotionServiceError::InvalidPromotion)?;
+
+        if !Self::is_valid_discount(discount_type, &request.discount_value)
+            || (!is_automatic && request.code.is_none())
+        {
+            return Err(PromotionServiceError::InvalidPromotion);
+        }
+
+        let starts_at = Self::parse_optional(request.starts_at.as_deref(), parse_datetime)?;
+        let ends_at = Self::parse_optional(request.ends_at.as_deref(), parse_datetime)?;
+        let daily_start_time =
+            Self::parse_optional(request.daily_start_time.as_deref(), parse_time)?;
+        let daily_end_time = Self::parse_optional(request.daily_end_time.as_deref(), parse_time)?;
+
+        if let (Some(starts_at), Some(ends_at)) = (starts_at, ends_at)
+            && starts_at >= ends_at
+        {
+            return Err(PromotionServiceError::InvalidPromotion);
+        }
+
+        let new_promotion = NewPromotion {
+            discount_type: discount_type.as_str(),
+            is_automatic,
+            starts_at,
+            ends_at,
+            daily_start_time,
+            daily_end_time,
+            ..NewPromotion::from(request)
+        };
+
+        let repo = PromotionRepo::new();
+        repo.create_with_conditions(
+            new_promotion,
+            request.product_ids.clone().unwrap_or_default(),
+            request.category_ids.clone().unwrap_or_default(),
+        )
+        .await
+        .map_err(|_| PromotionServiceError::DatabaseError)
+    }
+
+    /// Updates a promotion (requires Admin)
+    /// Product and category conditions are replaced when given
+    pub async fn update_promotion(
+        &self,
+        role_id: i32,
+        promotion_id: i32,
+        request: &UpdatePromotionRequest,
+    ) -> Result<(), PromotionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PromotionServiceError::PermissionDenied);
+        }
+
+        let repo = PromotionRepo::new();
+        let promotion = repo
+            .get_by_id(promotion_id)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?
+            .ok_or(PromotionServiceError::PromotionNotFound)?;
+
+        if let Some(value) = &request.discount_value {
+            let discount_type = DiscountType::from_str(&promotion.discount_type)
+                .map_err(|_| PromotionServiceError::InvalidPromotion)?;
+            if !Self::is_valid_discount(discount_type, value) {
+                return Err(PromotionServiceError::InvalidPromotion);
+            }
+        }
+
+        let update = UpdatePromotion {
+            starts_at: Self::parse_optional(request.starts_at.as_deref(), parse_datetime)?,
+            ends_at: Self::parse_optional(request.ends_at.as_deref(), parse_datetime)?,
+            daily_start_time: Self::parse_optional(
+                request.daily_start_time.as_deref(),
+                parse_time,
+            )?,
+            daily_end_time: Self::parse_optional(request.daily_end_time.as_deref(), parse_time)?,
+            ..UpdatePromotion::from(request)
+        };
+
+        repo.update(promotion_id, update)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?;
+
+        if request.product_ids.is_some() || request.category_ids.is_some() {
+            repo.set_conditions(
+                promotion_id,
+                request.product_ids.clone().unwrap_or_default(),
+                request.category_ids.clone().unwrap_or_default(),
+            )
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes a promotion (requires Admin)
+    /// Discount lines already recorded on orders are kept
+    pub async fn delete_promotion(
+        &self,
+        role_id: i32,
+        promotion_id: i32,
+    ) -> Result<(), PromotionServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PromotionServiceError::PermissionDenied);
+        }
+
+        let repo = PromotionRepo::new();
+
+        repo.get_by_id(promotion_id)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?
+            .ok_or(PromotionServiceError::PromotionNotFound)?;
+
+        repo.delete(promotion_id)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)
+    }
+
+    async fn get_rules(&self) -> Result<Vec<PromotionRule>, PromotionServiceError> {
+        let promotions = PromotionRepo::new()
+            .get_active()
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?;
+
+        self.with_conditions(promotions).await
+    }
+
+    async fn with_conditions(
+        &self,
+        promotions: Vec<Promotion>,
+    ) -> Result<Vec<PromotionRule>, PromotionServiceError> {
+        if promotions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids = promotions.iter().map(|p| p.promotion_id).collect();
+        let (products, categories) = PromotionRepo::new()
+            .get_conditions(ids)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?;
+
+        Ok(promotions
+            .into_iter()
+            .map(|promotion| PromotionRule {
+                product_ids: products
+                    .iter()
+                    .filter(|p| p.promotion_id == promotion.promotion_id)
+                    .map(|p| p.product_id)
+                    .collect(),
+                category_ids: categories
+                    .iter()
+                    .filter(|c| c.promotion_id == promotion.promotion_id)
+                    .map(|c| c.category_id)
+                    .collect(),
+                promotion,
+            })
+            .collect())
+    }
+
+    fn is_valid_discount(discount_type: DiscountType, value: &BigDecimal) -> bool {
+        *value > BigDecimal::zero()
+            && (discount_type == DiscountType::Fixed || *value <= BigDecimal::from(100))
+    }
+
+    fn parse_optional<T>(
+        value: Option<&str>,
+        parse: fn(&str) -> Option<T>,
+    ) -> Result<Option<T>, PromotionServiceError> {
+        value
+            .map(|v| parse(v).ok_or(PromotionServiceError::InvalidPromotion))
+            .transpose()
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, PromotionServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| PromotionServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for PromotionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,788 @@
+use crate::api::request::{
+    CreatePurchaseOrderRequest, CreateSupplierRequest, PurchaseOrderLineRequest,
+    PurchaseOrderQuery, ReceivePurchaseOrderRequest, UpdatePurchaseOrderRequest,
+    UpdateSupplierRequest,
+};
+use crate::api::response::{
+    PurchaseOrderLineResponse, PurchaseOrderResponse, PurchaseSuggestionResponse,
+    SuggestedLineResponse, SupplierResponse,
+};
+use crate::data::models::ingredient::Ingredient;
+use crate::data::models::purchase_order::{
+    NewPurchaseOrder, PurchaseOrder, PurchaseOrderLine, UpdatePurchaseOrder,
+};
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::supplier::{NewSupplier, Supplier, UpdateSupplier};
+use crate::data::repos::implementors::ingredient_repo::IngredientRepo;
+use crate::data::repos::implementors::purchase_order_repo::PurchaseOrderRepo;
+use crate::data::repos::implementors::supplier_repo::SupplierRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::PurchasingServiceError;
+use crate::services::inventory_service::StockMovementReason;
+use bigdecimal::{BigDecimal, RoundingMode, Signed, Zero};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// State of a purchase order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseOrderStatus {
+    /// Still being put together, lines can be changed
+    Draft,
+    /// Placed with the supplier, waiting for the delivery
+    Sent,
+    /// Some of the ordered stock has arrived
+    PartiallyReceived,
+    /// Every line has arrived in full
+    Received,
+}
+
+impl PurchaseOrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PurchaseOrderStatus::Draft => "draft",
+            PurchaseOrderStatus::Sent => "sent",
+            PurchaseOrderStatus::PartiallyReceived => "partially_received",
+            PurchaseOrderStatus::Received => "received",
+        }
+    }
+}
+
+impl std::str::FromStr for PurchaseOrderStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "draft" => Ok(PurchaseOrderStatus::Draft),
+            "sent" => Ok(PurchaseOrderStatus::Sent),
+            "partially_received" => Ok(PurchaseOrderStatus::PartiallyReceived),
+            "received" => Ok(PurchaseOrderStatus::Received),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Purchase order statuses whose outstanding quantities count as on order
+const OPEN_STATUSES: [PurchaseOrderStatus; 3] = [
+    PurchaseOrderStatus::Draft,
+    PurchaseOrderStatus::Sent,
+    PurchaseOrderStatus::PartiallyReceived,
+];
+
+/// Purchase order statuses stock can be received against
+const RECEIVABLE_STATUSES: [PurchaseOrderStatus; 2] = [
+    PurchaseOrderStatus::Sent,
+    PurchaseOrderStatus::PartiallyReceived,
+];
+
+/// Quantity of an ingredient suggested to order
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestockLine {
+    pub ingredient_id: i32,
+    /// Still to be received on open purchase orders
+    pub on_order: BigDecimal,
+    pub quantity: BigDecimal,
+}
+
+/// Suggests restocking the ingredients whose stock on hand, together with what is still to
+/// be received on the `open_lines` of purchase orders, is at or below their reorder level.
+/// Each is topped up to its par level, or to its reorder level when that is higher.
+/// Ingredients that would need nothing are left out.
+pub fn suggest_restock(
+    ingredients: &[Ingredient],
+    open_lines: &[PurchaseOrderLine],
+) -> Vec<RestockLine> {
+    let mut on_order: HashMap<i32, BigDecimal> = HashMap::new();
+    for line in open_lines {
+        let outstanding = line.outstanding();
+        if outstanding.is_positive() {
+            *on_order.entry(line.ingredient_id).or_default() += outstanding;
+        }
+    }
+
+    ingredients
+        .iter()
+        .filter_map(|ingredient| {
+            let on_order = on_order
+                .get(&ingredient.ingredient_id)
+                .cloned()
+                .unwrap_or_default();
+            let expected = &ingredient.on_hand + &on_order;
+            if expected > ingredient.reorder_level {
+                return None;
+            }
+
+            let target = if ingredient.par_level > ingredient.reorder_level {
+                &ingredient.par_level
+            } else {
+                &ingredient.reorder_level
+            };
+            let quantity = target - &expected;
+            quantity.is_positive().then_some(RestockLine {
+                ingredient_id: ingredient.ingredient_id,
+                on_order,
+                quantity,
+            })
+        })
+        .collect()
+}
+
+/// Cost of the ordered quantities at their unit costs, rounded to cents
+pub fn order_total(lines: &[PurchaseOrderLine]) -> BigDecimal {
+    lines
+        .iter()
+        .map(|line| &line.quantity * &line.unit_cost)
+        .fold(BigDecimal::zero(), |total, cost| total + cost)
+        .with_scale_round(2, RoundingMode::HalfEven)
+}
+
+/// Turns a delivery into receipts (ingredient_id, quantity, unit_cost) against the `lines`
+/// of a purchase order. Without lines in the request everything still outstanding is
+/// received. Receipts are at the ordered unit cost unless another one is given.
+/// Each ingredient must be on the purchase order, appear once, and arrive in a positive
+/// quantity no larger than is still outstanding.
+pub fn receipts(
+    lines: &[PurchaseOrderLine],
+    request: &ReceivePurchaseOrderRequest,
+) -> Result<Vec<(i32, BigDecimal, BigDecimal)>, PurchasingServiceError> {
+    let receipts: Vec<(i32, BigDecimal, BigDecimal)> = match &request.lines {
+        None => lines
+            .iter()
+            .filter(|line| line.outstanding().is_positive())
+            .map(|line| {
+                (
+                    line.ingredient_id,
+                    line.outstanding(),
+                    line.unit_cost.clone(),
+                )
+            })
+            .collect(),
+        Some(received) => {
+            let mut seen = HashSet::new();
+            let mut receipts = Vec::new();
+            for item in received {
+                let line = lines
+                    .iter()
+                    .find(|line| line.ingredient_id == item.ingredient_id)
+                    .ok_or(PurchasingServiceError::InvalidReceipt)?;
+                let unit_cost = item.unit_cost.as_ref().unwrap_or(&line.unit_cost);
+                if !seen.insert(item.ingredient_id)
+                    || !item.quantity.is_positive()
+                    || item.quantity > line.outstanding()
+                    || unit_cost.is_negative()
+                {
+                    return Err(PurchasingServiceError::InvalidReceipt);
+                }
+                receipts.push((item.ingredient_id, item.quantity.clone(), unit_cost.clone()));
+            }
+            receipts
+        }
+    };
+
+    if receipts.is_empty() {
+        return Err(PurchasingServiceError::InvalidReceipt);
+    }
+    Ok(receipts)
+}
+
+pub struct PurchasingService;
+
+impl PurchasingService {
+    pub fn new() -> Self {
+        PurchasingService
+    }
+
+    /// Gets all suppliers (requires READ permission or Admin)
+    pub async fn get_suppliers(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<SupplierResponse>, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let mut suppliers = SupplierRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .unwrap_or_default();
+        suppliers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(suppliers.into_iter().map(SupplierResponse::from).collect())
+    }
+
+    /// Creates a supplier (requires Admin)
+    pub async fn create_supplier(
+        &self,
+        role_id: i32,
+        request: &CreateSupplierRequest,
+    ) -> Result<(), PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        if request.name.trim().is_empty() {
+            return Err(PurchasingServiceError::InvalidSupplier);
+        }
+        self.ensure_name_free(&request.name, None).await?;
+
+        SupplierRepo::new()
+            .add(NewSupplier::from(request))
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)
+    }
+
+    /// Updates the name or contact details of a supplier (requires Admin)
+    pub async fn update_supplier(
+        &self,
+        role_id: i32,
+        supplier_id: i32,
+        request: &UpdateSupplierRequest,
+    ) -> Result<(), PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let repo = SupplierRepo::new();
+        repo.get_by_id(supplier_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::SupplierNotFound)?;
+
+        if let Some(name) = &request.name {
+            if name.trim().is_empty() {
+                return Err(PurchasingServiceError::InvalidSupplier);
+            }
+            self.ensure_name_free(name, Some(supplier_id)).await?;
+        }
+
+        repo.update(supplier_id, UpdateSupplier::from(request))
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)
+    }
+
+    /// Deletes a supplier no purchase order was placed with (requires Admin). Its
+    /// ingredients are kept without a supplier.
+    pub async fn delete_supplier(
+        &self,
+        role_id: i32,
+        supplier_id: i32,
+    ) -> Result<(), PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let repo = SupplierRepo::new();
+        repo.get_by_id(supplier_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::SupplierNotFound)?;
+
+        if repo
+            .has_purchase_orders(supplier_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+        {
+            return Err(PurchasingServiceError::SupplierInUse);
+        }
+
+        repo.delete(supplier_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)
+    }
+
+    /// Gets the purchase orders, newest first, optionally only those in a status or of a
+    /// supplier (requires READ permission or Admin)
+    pub async fn get_purchase_orders(
+        &self,
+        role_id: i32,
+        query: &PurchaseOrderQuery,
+    ) -> Result<Vec<PurchaseOrderResponse>, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let status = match &query.status {
+            Some(status) => Some(
+                status
+                    .parse::<PurchaseOrderStatus>()
+                    .map_err(|_| PurchasingServiceError::InvalidStatus)?,
+            ),
+            None => None,
+        };
+
+        let orders = PurchaseOrderRepo::new()
+            .get_filtered(
+                status.as_ref().map(PurchaseOrderStatus::as_str),
+                query.supplier_id,
+            )
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+
+        self.responses(orders).await
+    }
+
+    /// Gets a purchase order with its lines (requires READ permission or Admin)
+    pub async fn get_purchase_order(
+        &self,
+        role_id: i32,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderResponse, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        self.purchase_order_response(purchase_order_id).await
+    }
+
+    /// Creates a draft purchase order (requires WRITE permission or Admin)
+    pub async fn create_purchase_order(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        request: &CreatePurchaseOrderRequest,
+    ) -> Result<PurchaseOrderResponse, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let lines = self.order_lines(&request.lines).await?;
+        self.ensure_supplier_exists(request.supplier_id).await?;
+
+        let purchase_order_id = PurchaseOrderRepo::new()
+            .create(
+                NewPurchaseOrder {
+                    supplier_id: request.supplier_id,
+                    note: request.note.as_deref(),
+                    created_by: Some(user_id),
+                },
+                lines,
+            )
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+
+        self.purchase_order_response(purchase_order_id).await
+    }
+
+    /// Changes the supplier, note or lines of a draft purchase order (requires WRITE
+    /// permission or Admin)
+    pub async fn update_purchase_order(
+        &self,
+        role_id: i32,
+        purchase_order_id: i32,
+        request: &UpdatePurchaseOrderRequest,
+    ) -> Result<PurchaseOrderResponse, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let repo = PurchaseOrderRepo::new();
+        repo.get_by_id(purchase_order_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::PurchaseOrderNotFound)?;
+
+        let lines = match &request.lines {
+            Some(lines) => Some(self.order_lines(lines).await?),
+            None => None,
+        };
+        if let Some(supplier_id) = request.supplier_id {
+            self.ensure_supplier_exists(supplier_id).await?;
+        }
+
+        let updated = repo
+            .update_draft(
+                purchase_order_id,
+                PurchaseOrderStatus::Draft.as_str(),
+                UpdatePurchaseOrder {
+                    supplier_id: request.supplier_id,
+                    note: request.note.as_deref(),
+                },
+                lines,
+            )
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        if !updated {
+            return Err(PurchasingServiceError::NotDraft);
+        }
+
+        self.purchase_order_response(purchase_order_id).await
+    }
+
+    /// Deletes a draft purchase order (requires WRITE permission or Admin)
+    pub async fn delete_purchase_order(
+        &self,
+        role_id: i32,
+        purchase_order_id: i32,
+    ) -> Result<(), PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let repo = PurchaseOrderRepo::new();
+        repo.get_by_id(purchase_order_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::PurchaseOrderNotFound)?;
+
+        let deleted = repo
+            .delete_draft(purchase_order_id, PurchaseOrderStatus::Draft.as_str())
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        if !deleted {
+            return Err(PurchasingServiceError::NotDraft);
+        }
+        Ok(())
+    }
+
+    /// Marks a draft purchase order as sent to its supplier (requires WRITE permission or
+    /// Admin)
+    pub async fn send_purchase_order(
+        &self,
+        role_id: i32,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderResponse, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let repo = PurchaseOrderRepo::new();
+        repo.get_by_id(purchase_order_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::PurchaseOrderNotFound)?;
+
+        let sent = repo
+            .send(
+                purchase_order_id,
+                PurchaseOrderStatus::Draft.as_str(),
+                PurchaseOrderStatus::Sent.as_str(),
+            )
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        if !sent {
+            return Err(PurchasingServiceError::NotDraft);
+        }
+
+        self.purchase_order_response(purchase_order_id).await
+    }
+
+    /// Receives a delivery against a sent purchase order, adding it to the stock on hand at
+    /// the cost paid for it (requires WRITE permission or Admin)
+    pub async fn receive_purchase_order(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        purchase_order_id: i32,
+        request: &ReceivePurchaseOrderRequest,
+    ) -> Result<PurchaseOrderResponse, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let repo = PurchaseOrderRepo::new();
+        let order = repo
+            .get_by_id(purchase_order_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::PurchaseOrderNotFound)?;
+        if !RECEIVABLE_STATUSES
+            .iter()
+            .any(|status| status.as_str() == order.status)
+        {
+            return Err(PurchasingServiceError::NotReceivable);
+        }
+
+        let lines = repo
+            .get_lines(vec![purchase_order_id])
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        let receipts = receipts(&lines, request)?;
+
+        // The delivery is checked again under lock, so a concurrent one cannot be received
+        // twice
+        let received = repo
+            .receive(
+                purchase_order_id,
+                receipts,
+                RECEIVABLE_STATUSES
+                    .iter()
+                    .map(PurchaseOrderStatus::as_str)
+                    .collect(),
+                PurchaseOrderStatus::PartiallyReceived.as_str(),
+                PurchaseOrderStatus::Received.as_str(),
+                StockMovementReason::Purchase.as_str(),
+                Some(user_id),
+                request.note.as_deref(),
+            )
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        if !received {
+            return Err(PurchasingServiceError::NotReceivable);
+        }
+
+        self.purchase_order_response(purchase_order_id).await
+    }
+
+    /// Suggests purchase orders for the ingredients running low, grouped by the supplier
+    /// they are usually bought from (requires READ permission or Admin)
+    pub async fn get_suggestions(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<PurchaseSuggestionResponse>, PurchasingServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(PurchasingServiceError::PermissionDenied);
+        }
+
+        let mut ingredients = IngredientRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .unwrap_or_default();
+        ingredients.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let open_lines = PurchaseOrderRepo::new()
+            .get_lines_by_status(
+                OPEN_STATUSES
+                    .iter()
+                    .map(PurchaseOrderStatus::as_str)
+                    .collect(),
+            )
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        let suggestions = suggest_restock(&ingredients, &open_lines);
+        if suggestions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let suppliers: HashMap<i32, Supplier> = SupplierRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|supplier| (supplier.supplier_id, supplier))
+            .collect();
+
+        // Ingredients without a supplier come first
+        let mut by_supplier: BTreeMap<Option<i32>, Vec<SuggestedLineResponse>> = BTreeMap::new();
+        for suggestion in suggestions {
+            let Some(ingredient) = ingredients
+                .iter()
+                .find(|i| i.ingredient_id == suggestion.ingredient_id)
+            else {
+                continue;
+            };
+            by_supplier
+                .entry(ingredient.supplier_id)
+                .or_default()
+                .push(SuggestedLineResponse {
+                    ingredient_id: ingredient.ingredient_id,
+                    name: ingredient.name.clone(),
+                    unit: ingredient.unit.clone(),
+                    on_hand: ingredient.on_hand.clone(),
+                    on_order: suggestion.on_order,
+                    reorder_level: ingredient.reorder_level.clone(),
+                    par_level: ingredient.par_level.clone(),
+                    quantity: suggestion.quantity,
+                    unit_cost: ingredient.average_cost.clone(),
+                });
+        }
+
+        Ok(by_supplier
+            .into_iter()
+            .map(|(supplier_id, lines)| PurchaseSuggestionResponse {
+                supplier_id,
+                supplier_name: supplier_id
+                    .and_then(|id| suppliers.get(&id))
+                    .map(|supplier| supplier.name.clone()),
+                lines,
+            })
+            .collect())
+    }
+
+    /// Checks the lines of a purchase order: at least one, each ingredient known and listed
+    /// once, with a positive quantity and a unit cost that is not negative
+    async fn order_lines(
+        &self,
+        lines: &[PurchaseOrderLineRequest],
+    ) -> Result<Vec<(i32, BigDecimal, BigDecimal)>, PurchasingServiceError> {
+        let mut seen = HashSet::new();
+        if lines.is_empty()
+            || lines.iter().any(|line| {
+                !seen.insert(line.ingredient_id)
+                    || !line.quantity.is_positive()
+                    || line.unit_cost.is_negative()
+            })
+        {
+            return Err(PurchasingServiceError::InvalidPurchaseOrder);
+        }
+
+        let known = IngredientRepo::new()
+            .get_by_ids(lines.iter().map(|line| line.ingredient_id).collect())
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        if known.len() != lines.len() {
+            return Err(PurchasingServiceError::IngredientNotFound);
+        }
+
+        Ok(lines
+            .iter()
+            .map(|line| {
+                (
+                    line.ingredient_id,
+                    line.quantity.clone(),
+                    line.unit_cost.clone(),
+                )
+            })
+            .collect())
+    }
+
+    async fn purchase_order_response(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderResponse, PurchasingServiceError> {
+        let order = PurchaseOrderRepo::new()
+            .get_by_id(purchase_order_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::PurchaseOrderNotFound)?;
+
+        self.responses(vec![order])
+            .await?
+            .pop()
+            .ok_or(PurchasingServiceError::PurchaseOrderNotFound)
+    }
+
+    async fn responses(
+        &self,
+        orders: Vec<PurchaseOrder>,
+    ) -> Result<Vec<PurchaseOrderResponse>, PurchasingServiceError> {
+        if orders.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let suppliers: HashMap<i32, Supplier> = SupplierRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|supplier| (supplier.supplier_id, supplier))
+            .collect();
+
+        let lines = PurchaseOrderRepo::new()
+            .get_lines(orders.iter().map(|o| o.purchase_order_id).collect())
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?;
+        let ingredients: HashMap<i32, Ingredient> = IngredientRepo::new()
+            .get_by_ids(lines.iter().map(|line| line.ingredient_id).collect())
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .into_iter()
+            .map(|ingredient| (ingredient.ingredient_id, ingredient))
+            .collect();
+
+        let mut by_order: HashMap<i32, Vec<PurchaseOrderLine>> = HashMap::new();
+        for line in lines {
+            by_order
+                .entry(line.purchase_order_id)
+                .or_default()
+                .push(line);
+        }
+
+        let mut responses = Vec::new();
+        for order in orders {
+            let supplier = suppliers
+                .get(&order.supplier_id)
+                .cloned()
+                .ok_or(PurchasingServiceError::SupplierNotFound)?;
+            let lines = by_order
+                .remove(&order.purchase_order_id)
+                .unwrap_or_default();
+
+            let mut response = PurchaseOrderResponse::from((order, supplier));
+            response.total_cost = order_total(&lines);
+            response.lines = lines
+                .into_iter()
+                .filter_map(|line| {
+                    let ingredient = ingredients.get(&line.ingredient_id)?.clone();
+                    Some(PurchaseOrderLineResponse::from((line, ingredient)))
+                })
+                .collect();
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+
+    async fn ensure_supplier_exists(&self, supplier_id: i32) -> Result<(), PurchasingServiceError> {
+        SupplierRepo::new()
+            .get_by_id(supplier_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .ok_or(PurchasingServiceError::SupplierNotFound)?;
+        Ok(())
+    }
+
+    async fn ensure_name_free(
+        &self,
+        name: &str,
+        except: Option<i32>,
+    ) -> Result<(), PurchasingServiceError> {
+        let suppliers = SupplierRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        if suppliers
+            .iter()
+            .any(|s| s.name.eq_ignore_ascii_case(name.trim()) && Some(s.supplier_id) != except)
+        {
+            return Err(PurchasingServiceError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, PurchasingServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| PurchasingServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for PurchasingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
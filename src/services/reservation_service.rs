@@ -0,0 +1,710 @@
+use crate::api::config::Config;
+use crate::api::request::{
+    AvailabilityQuery, CreateReservationRequest, CreateWaitlistEntryRequest,
+    OpenTableSessionRequest, ReservationQuery, SeatPartyRequest, UpdateReservationRequest,
+};
+use crate::api::response::{AvailabilityResponse, ReservationResponse, WaitlistEntryResponse};
+use crate::data::models::dining_table::DiningTable;
+use crate::data::models::reservation::{NewReservation, Reservation, UpdateReservation};
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::table_session::TableSession;
+use crate::data::models::waitlist_entry::{NewWaitlistEntry, UpdateWaitlistEntry, WaitlistEntry};
+use crate::data::repos::implementors::reservation_repo::ReservationRepo;
+use crate::data::repos::implementors::table_repo::TableRepo;
+use crate::data::repos::implementors::table_session_repo::TableSessionRepo;
+use crate::data::repos::implementors::waitlist_repo::WaitlistRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::{ReservationServiceError, TableServiceError};
+use crate::services::table_service::TableService;
+use crate::utils::time::{local_now, parse_date, parse_datetime};
+use chrono::{Days, Duration, NaiveDateTime, NaiveTime};
+
+/// State of a reservation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationStatus {
+    Booked,
+    Seated,
+    NoShow,
+    Cancelled,
+}
+
+impl ReservationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReservationStatus::Booked => "booked",
+            ReservationStatus::Seated => "seated",
+            ReservationStatus::NoShow => "no_show",
+            ReservationStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl std::str::FromStr for ReservationStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "booked" => Ok(ReservationStatus::Booked),
+            "seated" => Ok(ReservationStatus::Seated),
+            "no_show" => Ok(ReservationStatus::NoShow),
+            "cancelled" => Ok(ReservationStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+/// State of a walk-in party on the waitlist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitlistStatus {
+    Waiting,
+    Seated,
+    Left,
+}
+
+impl WaitlistStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaitlistStatus::Waiting => "waiting",
+            WaitlistStatus::Seated => "seated",
+            WaitlistStatus::Left => "left",
+        }
+    }
+}
+
+/// Whether two parties seated at the same table at `a` and `b` would overlap, given that a
+/// party keeps its table for `turn`
+pub fn slots_overlap(a: NaiveDateTime, b: NaiveDateTime, turn: Duration) -> bool {
+    a < b + turn && b < a + turn
+}
+
+/// Picks the table to hold for a party at a time slot: the smallest table seating the party
+/// that no booked reservation overlaps, the lowest id breaking ties. `preferred` limits the
+/// choice to one table. Returns `None` when no table is available.
+pub fn find_table(
+    tables: &[DiningTable],
+    bookings: &[Reservation],
+    party_size: i32,
+    slot: NaiveDateTime,
+    turn: Duration,
+    preferred: Option<i32>,
+) -> Option<i32> {
+    tables
+        .iter()
+        .filter(|table| preferred.is_none_or(|id| id == table.table_id))
+        .filter(|table| table.capacity >= party_size)
+        .filter(|table| {
+            !bookings.iter().any(|booking| {
+                booking.table_id == Some(table.table_id)
+                    && booking.status == ReservationStatus::Booked.as_str()
+                    && slots_overlap(booking.reserved_for, slot, turn)
+            })
+        })
+        .min_by_key(|table| (table.capacity, table.table_id))
+        .map(|table| table.table_id)
+}
+
+/// When a table is expected to be ready for a walk-in party: once the party at it has had
+/// its turn, and then once every booked reservation that would not leave it a full turn has
+/// been seated and had its own
+pub fn table_ready_at(
+    table_id: i32,
+    session: Option<&TableSession>,
+    bookings: &[Reservation],
+    now: NaiveDateTime,
+    turn: Duration,
+) -> NaiveDateTime {
+    let mut ready = session
+        .map(|s| s.opened_at.map_or(now, |opened| (opened + turn).max(now)))
+        .unwrap_or(now);
+
+    let mut upcoming: Vec<NaiveDateTime> = bookings
+        .iter()
+        .filter(|b| b.table_id == Some(table_id))
+        .filter(|b| b.status == ReservationStatus::Booked.as_str())
+        .map(|b| b.reserved_for)
+        .collect();
+    upcoming.sort();
+
+    for start in upcoming {
+        if slots_overlap(ready, start, turn) {
+            ready = ready.max(start + turn);
+        }
+    }
+
+    ready
+}
+
+/// Estimates the wait, in whole minutes, of a party joining the waitlist behind
+/// `parties_ahead` others, given when each table that seats it will be ready. Each party
+/// ahead takes the first table ready and keeps it for a turn. Returns `None` when no table
+/// seats the party.
+pub fn estimate_wait(
+    ready_at: &[NaiveDateTime],
+    parties_ahead: usize,
+    now: NaiveDateTime,
+    turn: Duration,
+) -> Option<i64> {
+    let mut ready = ready_at.to_vec();
+
+    for _ in 0..parties_ahead {
+        let first = ready.iter_mut().min()?;
+        *first += turn;
+    }
+
+    ready
+        .into_iter()
+        .min()
+        .map(|next| (next - now).num_minutes().max(0))
+}
+
+pub struct ReservationService;
+
+impl ReservationService {
+    pub fn new() -> Self {
+        ReservationService
+    }
+
+    /// Gets the reservations of a day, today by default, earliest first
+    /// (requires READ permission or Admin)
+    pub async fn get_reservations(
+        &self,
+        role_id: i32,
+        query: &ReservationQuery,
+    ) -> Result<Vec<ReservationResponse>, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let date = match &query.date {
+            Some(date) => parse_date(date).ok_or(ReservationServiceError::InvalidReservation)?,
+            None => local_now().date(),
+        };
+
+        let reservations = ReservationRepo::new()
+            .get_between(
+                date.and_time(NaiveTime::MIN),
+                (date + Days::new(1)).and_time(NaiveTime::MIN),
+            )
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(reservations
+            .into_iter()
+            .map(ReservationResponse::from)
+            .collect())
+    }
+
+    /// Checks whether a table can be held for a party at a time slot
+    /// (requires READ permission or Admin)
+    pub async fn check_availability(
+        &self,
+        role_id: i32,
+        query: &AvailabilityQuery,
+    ) -> Result<AvailabilityResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let slot = parse_datetime(&query.reserved_for)
+            .ok_or(ReservationServiceError::InvalidReservation)?;
+        if query.party_size <= 0 {
+            return Err(ReservationServiceError::InvalidReservation);
+        }
+
+        let table_id = self
+            .available_table(query.party_size, slot, None, None)
+            .await?;
+
+        Ok(AvailabilityResponse {
+            available: table_id.is_some(),
+            table_id,
+        })
+    }
+
+    /// Books a table for a party (requires WRITE permission or Admin)
+    pub async fn create_reservation(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        request: &CreateReservationRequest,
+    ) -> Result<ReservationResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let slot = parse_datetime(&request.reserved_for)
+            .ok_or(ReservationServiceError::InvalidReservation)?;
+        if request.contact_name.trim().is_empty()
+            || request.contact_phone.trim().is_empty()
+            || request.party_size <= 0
+            || slot < local_now()
+        {
+            return Err(ReservationServiceError::InvalidReservation);
+        }
+
+        let table_id = self
+            .available_table(request.party_size, slot, request.table_id, None)
+            .await?
+            .ok_or(ReservationServiceError::NoTableAvailable)?;
+
+        let reservation = ReservationRepo::new()
+            .create(NewReservation {
+                contact_name: request.contact_name.trim(),
+                contact_phone: request.contact_phone.trim(),
+                party_size: request.party_size,
+                reserved_for: slot,
+                table_id: Some(table_id),
+                notes: request.notes.as_deref(),
+                created_by: Some(user_id),
+            })
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(ReservationResponse::from(reservation))
+    }
+
+    /// Changes a booked reservation, holding another table when the party, time slot or
+    /// table changes (requires WRITE permission or Admin)
+    pub async fn update_reservation(
+        &self,
+        role_id: i32,
+        reservation_id: i32,
+        request: &UpdateReservationRequest,
+    ) -> Result<ReservationResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let reservation = self.get_reservation(reservation_id).await?;
+        if reservation.status != ReservationStatus::Booked.as_str() {
+            return Err(ReservationServiceError::InvalidStatus);
+        }
+
+        let slot = match &request.reserved_for {
+            Some(value) => {
+                parse_datetime(value).ok_or(ReservationServiceError::InvalidReservation)?
+            }
+            None => reservation.reserved_for,
+        };
+        let party_size = request.party_size.unwrap_or(reservation.party_size);
+        if request
+            .contact_name
+            .as_deref()
+            .is_some_and(|name| name.trim().is_empty())
+            || request
+                .contact_phone
+                .as_deref()
+                .is_some_and(|phone| phone.trim().is_empty())
+            || party_size <= 0
+            || (request.reserved_for.is_some() && slot < local_now())
+        {
+            return Err(ReservationServiceError::InvalidReservation);
+        }
+
+        let table_id = if request.party_size.is_some()
+            || request.reserved_for.is_some()
+            || request.table_id.is_some()
+        {
+            Some(
+                self.available_table(party_size, slot, request.table_id, Some(reservation_id))
+                    .await?
+                    .ok_or(ReservationServiceError::NoTableAvailable)?,
+            )
+        } else {
+            None
+        };
+
+        let repo = ReservationRepo::new();
+        repo.update(
+            reservation_id,
+            UpdateReservation {
+                contact_name: request.contact_name.as_deref().map(str::trim),
+                contact_phone: request.contact_phone.as_deref().map(str::trim),
+                party_size: request.party_size,
+                reserved_for: request.reserved_for.as_ref().map(|_| slot),
+                table_id,
+                notes: request.notes.as_deref(),
+                ..UpdateReservation::default()
+            },
+        )
+        .await
+        .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(ReservationResponse::from(
+            self.get_reservation(reservation_id).await?,
+        ))
+    }
+
+    /// Marks a booked reservation as a no-show or cancelled, releasing its table
+    /// (requires WRITE permission or Admin)
+    pub async fn update_status(
+        &self,
+        role_id: i32,
+        reservation_id: i32,
+        status: ReservationStatus,
+    ) -> Result<ReservationResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        if !matches!(
+            status,
+            ReservationStatus::NoShow | ReservationStatus::Cancelled
+        ) {
+            return Err(ReservationServiceError::InvalidStatus);
+        }
+
+        let reservation = self.get_reservation(reservation_id).await?;
+        if reservation.status != ReservationStatus::Booked.as_str() {
+            return Err(ReservationServiceError::InvalidStatus);
+        }
+
+        ReservationRepo::new()
+            .update(
+                reservation_id,
+                UpdateReservation {
+                    status: Some(status.as_str()),
+                    ..UpdateReservation::default()
+                },
+            )
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(ReservationResponse::from(
+            self.get_reservation(reservation_id).await?,
+        ))
+    }
+
+    /// Seats a booked party at its table, or at another one, by opening a table session
+    /// (requires WRITE permission or Admin)
+    pub async fn seat_reservation(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        reservation_id: i32,
+        request: &SeatPartyRequest,
+    ) -> Result<ReservationResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let reservation = self.get_reservation(reservation_id).await?;
+        if reservation.status != ReservationStatus::Booked.as_str() {
+            return Err(ReservationServiceError::InvalidStatus);
+        }
+
+        let table_id = request
+            .table_id
+            .or(reservation.table_id)
+            .ok_or(ReservationServiceError::TableRequired)?;
+        let session_id = self
+            .open_session(role_id, user_id, table_id, reservation.party_size)
+            .await?;
+
+        ReservationRepo::new()
+            .update(
+                reservation_id,
+                UpdateReservation {
+                    table_id: Some(table_id),
+                    status: Some(ReservationStatus::Seated.as_str()),
+                    session_id: Some(session_id),
+                    ..UpdateReservation::default()
+                },
+            )
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(ReservationResponse::from(
+            self.get_reservation(reservation_id).await?,
+        ))
+    }
+
+    /// Gets the parties waiting for a table in the order they joined
+    /// (requires READ permission or Admin)
+    pub async fn get_waitlist(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<WaitlistEntryResponse>, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let entries = WaitlistRepo::new()
+            .get_by_status(WaitlistStatus::Waiting.as_str())
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(entries
+            .into_iter()
+            .map(WaitlistEntryResponse::from)
+            .collect())
+    }
+
+    /// Adds a walk-in party to the waitlist with a quoted wait based on when the tables
+    /// that seat it will be ready and how many parties are waiting ahead of it
+    /// (requires WRITE permission or Admin)
+    pub async fn join_waitlist(
+        &self,
+        role_id: i32,
+        request: &CreateWaitlistEntryRequest,
+    ) -> Result<WaitlistEntryResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        if request.contact_name.trim().is_empty() || request.party_size <= 0 {
+            return Err(ReservationServiceError::InvalidReservation);
+        }
+
+        let now = local_now();
+        let turn = Duration::minutes(Config::new().table_turn_minutes);
+
+        let tables = self.get_tables().await?;
+        let sessions = TableSessionRepo::new()
+            .get_open()
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+        let bookings = ReservationRepo::new()
+            .get_between(now - turn, now + Days::new(1))
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        let ready_at: Vec<NaiveDateTime> = tables
+            .iter()
+            .filter(|table| table.capacity >= request.party_size)
+            .map(|table| {
+                let session = sessions.iter().find(|s| s.table_id == table.table_id);
+                table_ready_at(table.table_id, session, &bookings, now, turn)
+            })
+            .collect();
+
+        let repo = WaitlistRepo::new();
+        let parties_ahead = repo
+            .get_by_status(WaitlistStatus::Waiting.as_str())
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?
+            .len();
+
+        let quoted_wait = estimate_wait(&ready_at, parties_ahead, now, turn)
+            .ok_or(ReservationServiceError::NoTableAvailable)?;
+
+        let entry = repo
+            .create(NewWaitlistEntry {
+                contact_name: request.contact_name.trim(),
+                contact_phone: request.contact_phone.as_deref().map(str::trim),
+                party_size: request.party_size,
+                quoted_wait_minutes: quoted_wait as i32,
+                notes: request.notes.as_deref(),
+            })
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        Ok(WaitlistEntryResponse::from(entry))
+    }
+
+    /// Seats a waiting party at a table by opening a table session
+    /// (requires WRITE permission or Admin)
+    pub async fn seat_waitlist_entry(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        entry_id: i32,
+        request: &SeatPartyRequest,
+    ) -> Result<WaitlistEntryResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        let entry = self.get_waiting_entry(entry_id).await?;
+        let table_id = request
+            .table_id
+            .ok_or(ReservationServiceError::TableRequired)?;
+        let session_id = self
+            .open_session(role_id, user_id, table_id, entry.party_size)
+            .await?;
+
+        self.finish_waiting(
+            entry_id,
+            UpdateWaitlistEntry {
+                status: Some(WaitlistStatus::Seated.as_str()),
+                session_id: Some(session_id),
+            },
+        )
+        .await
+    }
+
+    /// Removes a party that left from the waitlist (requires WRITE permission or Admin)
+    pub async fn leave_waitlist(
+        &self,
+        role_id: i32,
+        entry_id: i32,
+    ) -> Result<WaitlistEntryResponse, ReservationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(ReservationServiceError::PermissionDenied);
+        }
+
+        self.get_waiting_entry(entry_id).await?;
+
+        self.finish_waiting(
+            entry_id,
+            UpdateWaitlistEntry {
+                status: Some(WaitlistStatus::Left.as_str()),
+                ..UpdateWaitlistEntry::default()
+            },
+        )
+        .await
+    }
+
+    /// Finds the table to hold for a party at a time slot, leaving out the reservation
+    /// being changed
+    async fn available_table(
+        &self,
+        party_size: i32,
+        slot: NaiveDateTime,
+        preferred: Option<i32>,
+        exclude: Option<i32>,
+    ) -> Result<Option<i32>, ReservationServiceError> {
+        let tables = self.get_tables().await?;
+        if preferred.is_some_and(|id| !tables.iter().any(|table| table.table_id == id)) {
+            return Err(ReservationServiceError::TableNotFound);
+        }
+
+        let turn = Duration::minutes(Config::new().table_turn_minutes);
+        let bookings: Vec<Reservation> = ReservationRepo::new()
+            .get_between(slot - turn, slot + turn)
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?
+            .into_iter()
+            .filter(|booking| Some(booking.reservation_id) != exclude)
+            .collect();
+
+        Ok(find_table(
+            &tables, &bookings, party_size, slot, turn, preferred,
+        ))
+    }
+
+    /// Opens a table session for a party and returns its id
+    async fn open_session(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        table_id: i32,
+        party_size: i32,
+    ) -> Result<i32, ReservationServiceError> {
+        TableService::new()
+            .open_session(
+                role_id,
+                user_id,
+                table_id,
+                &OpenTableSessionRequest {
+                    party_size: Some(party_size),
+                },
+            )
+            .await
+            .map(|session| session.session_id)
+            .map_err(|e| match e {
+                TableServiceError::TableNotFound => ReservationServiceError::TableNotFound,
+                TableServiceError::TableNotAvailable => ReservationServiceError::TableNotAvailable,
+                TableServiceError::PermissionDenied => ReservationServiceError::PermissionDenied,
+                _ => ReservationServiceError::DatabaseError,
+            })
+    }
+
+    async fn finish_waiting(
+        &self,
+        entry_id: i32,
+        update: UpdateWaitlistEntry<'_>,
+    ) -> Result<WaitlistEntryResponse, ReservationServiceError> {
+        let repo = WaitlistRepo::new();
+        repo.update(entry_id, update)
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?;
+
+        repo.get_by_id(entry_id)
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?
+            .map(WaitlistEntryResponse::from)
+            .ok_or(ReservationServiceError::EntryNotFound)
+    }
+
+    async fn get_reservation(
+        &self,
+        reservation_id: i32,
+    ) -> Result<Reservation, ReservationServiceError> {
+        ReservationRepo::new()
+            .get_by_id(reservation_id)
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?
+            .ok_or(ReservationServiceError::ReservationNotFound)
+    }
+
+    async fn get_waiting_entry(
+        &self,
+        entry_id: i32,
+    ) -> Result<WaitlistEntry, ReservationServiceError> {
+        let entry = WaitlistRepo::new()
+            .get_by_id(entry_id)
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?
+            .ok_or(ReservationServiceError::EntryNotFound)?;
+
+        if entry.status != WaitlistStatus::Waiting.as_str() {
+            return Err(ReservationServiceError::InvalidStatus);
+        }
+
+        Ok(entry)
+    }
+
+    async fn get_tables(&self) -> Result<Vec<DiningTable>, ReservationServiceError> {
+        TableRepo::new()
+            .get_all()
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(|_| ReservationServiceError::DatabaseError)
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, ReservationServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| ReservationServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for ReservationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
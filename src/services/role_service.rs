@@ -3,7 +3,7 @@ use crate::data::repos::traits::repository::Repository;
 use crate::services::errors::RoleError;
 
 // ROLE MANAGEMENT LOGIC GOES HERE
-// Possible permissions: Read, Write, Delete, Admin
+// Possible permissions: Read, Write, Delete, Admin, Refund
 // Admin could add other roles and assign permissions
 // Write could only modify and add content within their own tables
 // Read could only view content
@@ -0,0 +1,538 @@
+use crate::api::request::{
+    CreateDiningAreaRequest, CreateTableRequest, OpenTableSessionRequest, TableQuery,
+    UpdateDiningAreaRequest, UpdateTableRequest,
+};
+use crate::api::response::{DiningAreaResponse, TableResponse, TableSessionResponse};
+use crate::data::models::dining_area::{NewDiningArea, UpdateDiningArea};
+use crate::data::models::dining_table::{DiningTable, NewDiningTable, UpdateDiningTable};
+use crate::data::models::order::Order;
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::table_session::{NewTableSession, SessionTotals, TableSession};
+use crate::data::repos::implementors::dining_area_repo::DiningAreaRepo;
+use crate::data::repos::implementors::order_repo::OrderRepo;
+use crate::data::repos::implementors::table_repo::TableRepo;
+use crate::data::repos::implementors::table_session_repo::TableSessionRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::TableServiceError;
+use crate::services::order_service::OrderStatus;
+use crate::services::payment_service::PaymentService;
+use bigdecimal::{BigDecimal, Zero};
+
+/// State of a dining table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStatus {
+    Free,
+    Seated,
+    Dirty,
+    Reserved,
+}
+
+impl TableStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TableStatus::Free => "free",
+            TableStatus::Seated => "seated",
+            TableStatus::Dirty => "dirty",
+            TableStatus::Reserved => "reserved",
+        }
+    }
+
+    /// Whether a party can be seated at a table in this state
+    pub fn is_seatable(&self) -> bool {
+        matches!(self, TableStatus::Free | TableStatus::Reserved)
+    }
+}
+
+impl std::str::FromStr for TableStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "free" => Ok(TableStatus::Free),
+            "seated" => Ok(TableStatus::Seated),
+            "dirty" => Ok(TableStatus::Dirty),
+            "reserved" => Ok(TableStatus::Reserved),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Adds up the orders of a table session into one check. Cancelled orders are left out.
+pub fn combine_orders(orders: &[Order]) -> SessionTotals {
+    let mut totals = SessionTotals {
+        subtotal: BigDecimal::zero(),
+        discount_amount: BigDecimal::zero(),
+        tax_amount: BigDecimal::zero(),
+        service_charge: BigDecimal::zero(),
+        gratuity_amount: BigDecimal::zero(),
+        total_amount: BigDecimal::zero(),
+    };
+
+    for order in orders.iter().filter(|o| is_billable(o)) {
+        totals.subtotal += &order.subtotal;
+        totals.discount_amount += &order.discount_amount;
+        totals.tax_amount += &order.tax_amount;
+        totals.service_charge += &order.service_charge;
+        totals.gratuity_amount += &order.gratuity_amount;
+        totals.total_amount += &order.total_amount;
+    }
+
+    totals
+}
+
+fn is_billable(order: &Order) -> bool {
+    order.status.as_deref() != Some(OrderStatus::Cancelled.as_str())
+}
+
+pub struct TableService;
+
+impl TableService {
+    pub fn new() -> Self {
+        TableService
+    }
+
+    /// Gets all dining areas (requires READ permission or Admin)
+    pub async fn get_areas(
+        &self,
+        role_id: i32,
+    ) -> Result<Vec<DiningAreaResponse>, TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        let areas = DiningAreaRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        Ok(areas.into_iter().map(DiningAreaResponse::from).collect())
+    }
+
+    /// Creates a dining area (requires Admin)
+    pub async fn create_area(
+        &self,
+        role_id: i32,
+        request: &CreateDiningAreaRequest,
+    ) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        if request.name.trim().is_empty() {
+            return Err(TableServiceError::InvalidTable);
+        }
+        self.ensure_area_name_free(&request.name, None).await?;
+
+        DiningAreaRepo::new()
+            .add(NewDiningArea::from(request))
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Updates a dining area (requires Admin)
+    pub async fn update_area(
+        &self,
+        role_id: i32,
+        area_id: i32,
+        request: &UpdateDiningAreaRequest,
+    ) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        let repo = DiningAreaRepo::new();
+        repo.get_by_id(area_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+            .ok_or(TableServiceError::AreaNotFound)?;
+
+        if let Some(name) = &request.name {
+            if name.trim().is_empty() {
+                return Err(TableServiceError::InvalidTable);
+            }
+            self.ensure_area_name_free(name, Some(area_id)).await?;
+        }
+
+        repo.update(area_id, UpdateDiningArea::from(request))
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Deletes a dining area without tables (requires Admin)
+    pub async fn delete_area(&self, role_id: i32, area_id: i32) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        let repo = DiningAreaRepo::new();
+        repo.get_by_id(area_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+            .ok_or(TableServiceError::AreaNotFound)?;
+
+        let tables = TableRepo::new()
+            .get_by_area_id(area_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?;
+        if !tables.is_empty() {
+            return Err(TableServiceError::AreaNotEmpty);
+        }
+
+        repo.delete(area_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Gets all tables, or those of one dining area (requires READ permission or Admin)
+    pub async fn get_tables(
+        &self,
+        role_id: i32,
+        query: &TableQuery,
+    ) -> Result<Vec<TableResponse>, TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        let repo = TableRepo::new();
+        let tables = match query.area_id {
+            Some(area_id) => repo.get_by_area_id(area_id).await,
+            None => repo.get_all().await.map(Option::unwrap_or_default),
+        }
+        .map_err(|_| TableServiceError::DatabaseError)?;
+
+        Ok(tables.into_iter().map(TableResponse::from).collect())
+    }
+
+    /// Creates a table in a dining area (requires Admin)
+    pub async fn create_table(
+        &self,
+        role_id: i32,
+        request: &CreateTableRequest,
+    ) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        if request.name.trim().is_empty() || request.capacity <= 0 {
+            return Err(TableServiceError::InvalidTable);
+        }
+        self.ensure_table_name_free(request.area_id, &request.name, None)
+            .await?;
+
+        TableRepo::new()
+            .add(NewDiningTable::from(request))
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Updates the name, capacity or dining area of a table (requires Admin)
+    pub async fn update_table(
+        &self,
+        role_id: i32,
+        table_id: i32,
+        request: &UpdateTableRequest,
+    ) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        if request.name.as_ref().is_some_and(|n| n.trim().is_empty())
+            || request.capacity.is_some_and(|c| c <= 0)
+        {
+            return Err(TableServiceError::InvalidTable);
+        }
+
+        let table = self.get_table(table_id).await?;
+        if request.name.is_some() || request.area_id.is_some() {
+            let area_id = request.area_id.unwrap_or(table.area_id);
+            let name = request.name.as_deref().unwrap_or(&table.name);
+            self.ensure_table_name_free(area_id, name, Some(table_id))
+                .await?;
+        }
+
+        TableRepo::new()
+            .update(table_id, UpdateDiningTable::from(request))
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Deletes a table that is not seated (requires Admin)
+    pub async fn delete_table(&self, role_id: i32, table_id: i32) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        self.get_table(table_id).await?;
+        if self.find_open_session(table_id).await?.is_some() {
+            return Err(TableServiceError::TableNotAvailable);
+        }
+
+        TableRepo::new()
+            .delete(table_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Marks a table free, dirty or reserved (requires WRITE permission or Admin).
+    /// Tables are only seated by opening a session, and stay seated until it is closed.
+    pub async fn update_status(
+        &self,
+        role_id: i32,
+        table_id: i32,
+        status: TableStatus,
+    ) -> Result<(), TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        if status == TableStatus::Seated {
+            return Err(TableServiceError::InvalidStatus);
+        }
+
+        self.get_table(table_id).await?;
+        if self.find_open_session(table_id).await?.is_some() {
+            return Err(TableServiceError::TableNotAvailable);
+        }
+
+        TableRepo::new()
+            .update(
+                table_id,
+                UpdateDiningTable {
+                    area_id: None,
+                    name: None,
+                    capacity: None,
+                    status: Some(status.as_str()),
+                },
+            )
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    /// Seats a party at a free or reserved table (requires WRITE permission or Admin)
+    pub async fn open_session(
+        &self,
+        role_id: i32,
+        user_id: i32,
+        table_id: i32,
+        request: &OpenTableSessionRequest,
+    ) -> Result<TableSessionResponse, TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        if request.party_size.is_some_and(|size| size <= 0) {
+            return Err(TableServiceError::InvalidTable);
+        }
+
+        let table = self.get_table(table_id).await?;
+        let seatable = table
+            .status
+            .parse::<TableStatus>()
+            .is_ok_and(|status| status.is_seatable());
+        if !seatable || self.find_open_session(table_id).await?.is_some() {
+            return Err(TableServiceError::TableNotAvailable);
+        }
+
+        let session = TableSessionRepo::new()
+            .open(
+                NewTableSession {
+                    table_id,
+                    party_size: request.party_size,
+                    opened_by: Some(user_id),
+                },
+                TableStatus::Seated.as_str(),
+            )
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?;
+
+        self.session_response(session, Vec::new()).await
+    }
+
+    /// Gets the open session of a table with its orders and combined check so far
+    /// (requires READ permission or Admin)
+    pub async fn get_session(
+        &self,
+        role_id: i32,
+        table_id: i32,
+    ) -> Result<TableSessionResponse, TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        self.get_table(table_id).await?;
+        let session = self
+            .find_open_session(table_id)
+            .await?
+            .ok_or(TableServiceError::NoOpenSession)?;
+        let orders = self.session_orders(session.session_id).await?;
+
+        self.session_response(session, orders).await
+    }
+
+    /// Closes the open session of a table, combining its orders into one check, and marks
+    /// the table dirty (requires WRITE permission or Admin)
+    pub async fn close_session(
+        &self,
+        role_id: i32,
+        table_id: i32,
+    ) -> Result<TableSessionResponse, TableServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TableServiceError::PermissionDenied);
+        }
+
+        self.get_table(table_id).await?;
+        let session = self
+            .find_open_session(table_id)
+            .await?
+            .ok_or(TableServiceError::NoOpenSession)?;
+        let orders = self.session_orders(session.session_id).await?;
+
+        let closed = TableSessionRepo::new()
+            .close(
+                &session,
+                combine_orders(&orders),
+                TableStatus::Dirty.as_str(),
+            )
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?;
+
+        self.session_response(closed, orders).await
+    }
+
+    /// Builds the response of a session, with the combined check of its orders and how
+    /// much of it has been paid
+    async fn session_response(
+        &self,
+        session: TableSession,
+        orders: Vec<Order>,
+    ) -> Result<TableSessionResponse, TableServiceError> {
+        let billable: Vec<Order> = orders.into_iter().filter(is_billable).collect();
+        let totals = combine_orders(&billable);
+        let summaries = PaymentService::new()
+            .get_summaries(&billable)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?;
+
+        let mut response = TableSessionResponse::from(session);
+        response.order_ids = billable.iter().map(|o| o.order_id).collect();
+        response.amount_paid = summaries.values().map(|s| s.amount_paid.clone()).sum();
+        response.balance_due = summaries.values().map(|s| s.balance_due.clone()).sum();
+        response.subtotal = totals.subtotal;
+        response.discount_amount = totals.discount_amount;
+        response.tax_amount = totals.tax_amount;
+        response.service_charge = totals.service_charge;
+        response.gratuity_amount = totals.gratuity_amount;
+        response.total_amount = totals.total_amount;
+
+        Ok(response)
+    }
+
+    async fn get_table(&self, table_id: i32) -> Result<DiningTable, TableServiceError> {
+        TableRepo::new()
+            .get_by_id(table_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+            .ok_or(TableServiceError::TableNotFound)
+    }
+
+    async fn find_open_session(
+        &self,
+        table_id: i32,
+    ) -> Result<Option<TableSession>, TableServiceError> {
+        TableSessionRepo::new()
+            .get_open_by_table_id(table_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    async fn session_orders(&self, session_id: i32) -> Result<Vec<Order>, TableServiceError> {
+        OrderRepo::new()
+            .get_by_session_id(session_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)
+    }
+
+    async fn ensure_area_name_free(
+        &self,
+        name: &str,
+        except: Option<i32>,
+    ) -> Result<(), TableServiceError> {
+        let areas = DiningAreaRepo::new()
+            .get_all()
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+            .unwrap_or_default();
+
+        if areas
+            .iter()
+            .any(|a| a.name.eq_ignore_ascii_case(name.trim()) && Some(a.area_id) != except)
+        {
+            return Err(TableServiceError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    async fn ensure_table_name_free(
+        &self,
+        area_id: i32,
+        name: &str,
+        except: Option<i32>,
+    ) -> Result<(), TableServiceError> {
+        DiningAreaRepo::new()
+            .get_by_id(area_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+            .ok_or(TableServiceError::AreaNotFound)?;
+
+        let tables = TableRepo::new()
+            .get_by_area_id(area_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?;
+
+        if tables
+            .iter()
+            .any(|t| t.name.eq_ignore_ascii_case(name.trim()) && Some(t.table_id) != except)
+        {
+            return Err(TableServiceError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, TableServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| TableServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for TableService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
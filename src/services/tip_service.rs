@@ -0,0 +1,211 @@
+use crate::api::request::DateRangeQuery;
+use crate::api::response::{StaffTipsResponse, TipPoolResponse};
+use crate::data::models::order::{Order, OrderTipping};
+use crate::data::models::roles::RolePermissions;
+use crate::data::repos::implementors::order_repo::OrderRepo;
+use crate::data::repos::implementors::user_repo::UserRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::TipServiceError;
+use crate::services::order_service::OrderStatus;
+use crate::services::pricing_service::{PriceBreakdown, PricingEngine, RoundingRule};
+use crate::utils::time::parse_date;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{Days, NaiveTime};
+use std::collections::BTreeMap;
+
+/// Gratuity added to the order of a party of `min_party_size` or more guests, as a
+/// percentage of the discounted subtotal. Returns zero for smaller or unknown parties.
+pub fn auto_gratuity(
+    party_size: Option<i32>,
+    min_party_size: i32,
+    percent: &BigDecimal,
+    breakdown: &PriceBreakdown,
+    rounding: &RoundingRule,
+) -> BigDecimal {
+    match party_size {
+        Some(size) if size >= min_party_size && *percent > BigDecimal::zero() => {
+            let base = &breakdown.subtotal - &breakdown.discount_amount;
+            rounding.round(&(base * percent / BigDecimal::from(100)))
+        }
+        _ => rounding.round(&BigDecimal::zero()),
+    }
+}
+
+/// Tips attributed to one staff member over a period
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffTips {
+    pub user_id: i32,
+    pub order_count: i32,
+    pub gratuity_amount: BigDecimal,
+    pub tip_amount: BigDecimal,
+    /// Equal part of all tips of the period
+    pub pool_share: BigDecimal,
+}
+
+impl StaffTips {
+    pub fn total(&self) -> BigDecimal {
+        &self.gratuity_amount + &self.tip_amount
+    }
+}
+
+/// Totals the gratuities and tips of orders per staff member and pools them.
+///
+/// An order's tips go to the staff member assigned to serve it, or to the user who placed
+/// it when nobody was assigned. Cancelled orders are left out. The pool is divided equally
+/// between everyone attributed an order, in whole rounding increments that add up to the
+/// pool. Staff are ordered by user id.
+pub fn pool_tips(orders: &[Order], rounding: &RoundingRule) -> Vec<StaffTips> {
+    let mut by_user: BTreeMap<i32, StaffTips> = BTreeMap::new();
+
+    for order in orders
+        .iter()
+        .filter(|o| o.status.as_deref() != Some(OrderStatus::Cancelled.as_str()))
+    {
+        let user_id = order.served_by.unwrap_or(order.user_id);
+        let entry = by_user.entry(user_id).or_insert_with(|| StaffTips {
+            user_id,
+            order_count: 0,
+            gratuity_amount: BigDecimal::zero(),
+            tip_amount: BigDecimal::zero(),
+            pool_share: BigDecimal::zero(),
+        });
+
+        entry.order_count += 1;
+        entry.gratuity_amount += &order.gratuity_amount;
+        entry.tip_amount += &order.tip_amount;
+    }
+
+    let mut staff: Vec<StaffTips> = by_user.into_values().collect();
+    let pool: BigDecimal = staff.iter().map(StaffTips::total).sum();
+    let shares = rounding.allocate(&pool, &vec![BigDecimal::from(1); staff.len()]);
+
+    for (entry, share) in staff.iter_mut().zip(shares) {
+        entry.pool_share = share;
+    }
+
+    staff
+}
+
+pub struct TipService;
+
+impl TipService {
+    pub fn new() -> Self {
+        TipService
+    }
+
+    /// Assigns the staff member who serves an order and receives its tips
+    /// (requires WRITE permission or Admin)
+    pub async fn assign_server(
+        &self,
+        order_id: i32,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<(), TipServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(TipServiceError::PermissionDenied);
+        }
+
+        let repo = OrderRepo::new();
+        repo.get_by_id(order_id)
+            .await
+            .map_err(|_| TipServiceError::DatabaseError)?
+            .ok_or(TipServiceError::OrderNotFound)?;
+
+        UserRepo::new()
+            .get_by_id(user_id)
+            .await
+            .map_err(|_| TipServiceError::DatabaseError)?
+            .ok_or(TipServiceError::UserNotFound)?;
+
+        repo.update_tipping(
+            order_id,
+            OrderTipping {
+                served_by: Some(user_id),
+                ..OrderTipping::default()
+            },
+        )
+        .await
+        .map_err(|_| TipServiceError::DatabaseError)
+    }
+
+    /// Reports the tips of each staff member for orders placed between two dates, both
+    /// included, and their share of the pooled tips (requires Admin)
+    pub async fn get_tip_pool(
+        &self,
+        range: &DateRangeQuery,
+        role_id: i32,
+    ) -> Result<TipPoolResponse, TipServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TipServiceError::PermissionDenied);
+        }
+
+        let from = parse_date(&range.from).ok_or(TipServiceError::InvalidDateRange)?;
+        let to = parse_date(&range.to).ok_or(TipServiceError::InvalidDateRange)?;
+        if to < from {
+            return Err(TipServiceError::InvalidDateRange);
+        }
+
+        let orders = OrderRepo::new()
+            .get_created_between(
+                from.and_time(NaiveTime::MIN),
+                (to + Days::new(1)).and_time(NaiveTime::MIN),
+            )
+            .await
+            .map_err(|_| TipServiceError::DatabaseError)?;
+
+        let engine = PricingEngine::from_config();
+        let staff = pool_tips(&orders, &engine.rounding);
+
+        let user_repo = UserRepo::new();
+        let mut entries = Vec::new();
+        for tips in staff {
+            let username = user_repo
+                .get_by_id(tips.user_id)
+                .await
+                .map_err(|_| TipServiceError::DatabaseError)?
+                .map(|user| user.username);
+
+            entries.push(StaffTipsResponse {
+                user_id: tips.user_id,
+                username,
+                order_count: tips.order_count,
+                total_tips: tips.total(),
+                gratuity_amount: tips.gratuity_amount,
+                tip_amount: tips.tip_amount,
+                pool_share: tips.pool_share,
+            });
+        }
+
+        Ok(TipPoolResponse {
+            from: from.to_string(),
+            to: to.to_string(),
+            total_tips: entries.iter().map(|e| e.total_tips.clone()).sum(),
+            staff: entries,
+        })
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, TipServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| TipServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for TipService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,391 @@
+use crate::api::config::Config;
+use crate::api::request::TranslationRequest;
+use crate::api::response::{CategoryResponse, ProductResponse, TranslationResponse};
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::translation::{CategoryTranslation, ProductTranslation};
+use crate::data::repos::implementors::category_repo::CategoryRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::implementors::translation_repo::TranslationRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::TranslationServiceError;
+
+/// Longest translated name of a product, as long as product names can be
+pub const MAX_PRODUCT_NAME_LENGTH: usize = 100;
+/// Longest translated name of a category, as long as category names can be
+pub const MAX_CATEGORY_NAME_LENGTH: usize = 255;
+
+/// Reduces a language tag such as `es-MX` or `JA` to its lowercase language code
+pub fn normalize_locale(tag: &str) -> Option<String> {
+    let language = tag.trim().split(['-', '_']).next()?.to_lowercase();
+    if (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase()) {
+        Some(language)
+    } else {
+        None
+    }
+}
+
+/// Picks the locale content is shown in: `lang` when it is supported, otherwise the supported
+/// language an `Accept-Language` header prefers most, otherwise the default locale
+pub fn negotiate_locale(
+    lang: Option<&str>,
+    accept_language: Option<&str>,
+    supported: &[String],
+    default_locale: &str,
+) -> String {
+    let is_supported = |locale: &String| supported.contains(locale);
+    if let Some(locale) = lang.and_then(normalize_locale).filter(is_supported) {
+        return locale;
+    }
+
+    let mut ranges: Vec<(&str, f32)> = accept_language
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|range| {
+            let mut params = range.split(';');
+            let tag = params.next()?.trim();
+            let quality = match params.find_map(|p| p.trim().strip_prefix("q=")) {
+                Some(q) => q.trim().parse::<f32>().ok()?,
+                None => 1.0,
+            };
+            Some((tag, quality))
+        })
+        .filter(|(tag, quality)| !tag.is_empty() && *quality > 0.0)
+        .collect();
+    // Stable, so ranges of the same quality keep the order they were listed in
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    ranges
+        .into_iter()
+        .find_map(|(tag, _)| match tag {
+            "*" => Some(default_locale.to_string()),
+            _ => normalize_locale(tag).filter(is_supported),
+        })
+        .unwrap_or_else(|| default_locale.to_string())
+}
+
+/// Whether a translation can be saved: its name is not blank and fits
+pub fn is_valid_translation(request: &TranslationRequest, max_name_length: usize) -> bool {
+    let name = request.name.trim();
+    !name.is_empty() && name.chars().count() <= max_name_length
+}
+
+/// Shows products, and the categories listed on them, in their translated name and
+/// description. Anything not translated keeps the content in the default locale.
+pub fn apply_product_translations(
+    products: &mut [ProductResponse],
+    translations: &[ProductTranslation],
+    category_translations: &[CategoryTranslation],
+) {
+    for product in products {
+        if let Some(translation) = translations
+            .iter()
+            .find(|t| t.product_id == product.product_id)
+        {
+            product.name = translation.name.clone();
+            if translation.description.is_some() {
+                product.description = translation.description.clone();
+            }
+        }
+        if let Some(categories) = product.categories.as_mut() {
+            apply_category_translations(categories, category_translations);
+        }
+    }
+}
+
+/// Shows categories, and those nested under them, in their translated name and description
+pub fn apply_category_translations(
+    categories: &mut [CategoryResponse],
+    translations: &[CategoryTranslation],
+) {
+    for category in categories {
+        if let Some(translation) = translations
+            .iter()
+            .find(|t| Some(t.category_id) == category.category_id)
+        {
+            category.name = translation.name.clone();
+            if translation.description.is_some() {
+                category.description = translation.description.clone();
+            }
+        }
+        if let Some(children) = category.children.as_mut() {
+            apply_category_translations(children, translations);
+        }
+    }
+}
+
+/// Ids of the categories and of those nested under them
+fn category_ids(categories: &[CategoryResponse], ids: &mut Vec<i32>) {
+    for category in categories {
+        ids.extend(category.category_id);
+        if let Some(children) = category.children.as_ref() {
+            category_ids(children, ids);
+        }
+    }
+}
+
+pub struct TranslationService;
+
+impl TranslationService {
+    pub fn new() -> Self {
+        TranslationService
+    }
+
+    /// Translates products and their categories into a locale. Used while listing products,
+    /// so no permission is checked.
+    pub async fn localize_products(
+        &self,
+        products: &mut [ProductResponse],
+        locale: &str,
+    ) -> Result<(), TranslationServiceError> {
+        if products.is_empty() || locale == Config::new().default_locale {
+            return Ok(());
+        }
+
+        let repo = TranslationRepo::new();
+        let translations = repo
+            .get_products(products.iter().map(|p| p.product_id).collect(), locale)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?;
+
+        let mut ids = Vec::new();
+        for product in products.iter() {
+            category_ids(product.categories.as_deref().unwrap_or_default(), &mut ids);
+        }
+        let category_translations = if ids.is_empty() {
+            Vec::new()
+        } else {
+            repo.get_categories(ids, locale)
+                .await
+                .map_err(|_| TranslationServiceError::DatabaseError)?
+        };
+
+        apply_product_translations(products, &translations, &category_translations);
+        Ok(())
+    }
+
+    /// Translates categories, and those nested under them, into a locale. Used while listing
+    /// categories, so no permission is checked.
+    pub async fn localize_categories(
+        &self,
+        categories: &mut [CategoryResponse],
+        locale: &str,
+    ) -> Result<(), TranslationServiceError> {
+        let mut ids = Vec::new();
+        category_ids(categories, &mut ids);
+        if ids.is_empty() || locale == Config::new().default_locale {
+            return Ok(());
+        }
+
+        let translations = TranslationRepo::new()
+            .get_categories(ids, locale)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?;
+
+        apply_category_translations(categories, &translations);
+        Ok(())
+    }
+
+    /// Gets every translation of a product (requires Admin)
+    pub async fn get_product_translations(
+        &self,
+        product_id: i32,
+        role_id: i32,
+    ) -> Result<Vec<TranslationResponse>, TranslationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TranslationServiceError::PermissionDenied);
+        }
+
+        self.ensure_product(product_id).await?;
+
+        let translations = TranslationRepo::new()
+            .get_for_product(product_id)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?;
+
+        Ok(translations
+            .into_iter()
+            .map(TranslationResponse::from)
+            .collect())
+    }
+
+    /// Replaces the translation of a product into a locale (requires Admin)
+    pub async fn set_product_translation(
+        &self,
+        product_id: i32,
+        locale: &str,
+        request: &TranslationRequest,
+        role_id: i32,
+    ) -> Result<(), TranslationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TranslationServiceError::PermissionDenied);
+        }
+
+        self.ensure_product(product_id).await?;
+        let locale = translatable_locale(locale)?;
+        if !is_valid_translation(request, MAX_PRODUCT_NAME_LENGTH) {
+            return Err(TranslationServiceError::InvalidTranslation);
+        }
+
+        TranslationRepo::new()
+            .save_product(ProductTranslation {
+                product_id,
+                locale,
+                name: request.name.trim().to_string(),
+                description: request.description.clone(),
+            })
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)
+    }
+
+    /// Removes the translation of a product into a locale (requires Admin)
+    pub async fn remove_product_translation(
+        &self,
+        product_id: i32,
+        locale: &str,
+        role_id: i32,
+    ) -> Result<(), TranslationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TranslationServiceError::PermissionDenied);
+        }
+
+        self.ensure_product(product_id).await?;
+        let locale = translatable_locale(locale)?;
+
+        let removed = TranslationRepo::new()
+            .delete_product(product_id, &locale)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?;
+        if removed == 0 {
+            return Err(TranslationServiceError::TranslationNotFound);
+        }
+        Ok(())
+    }
+
+    /// Gets every translation of a category (requires Admin)
+    pub async fn get_category_translations(
+        &self,
+        category_id: i32,
+        role_id: i32,
+    ) -> Result<Vec<TranslationResponse>, TranslationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TranslationServiceError::PermissionDenied);
+        }
+
+        self.ensure_category(category_id).await?;
+
+        let translations = TranslationRepo::new()
+            .get_for_category(category_id)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?;
+
+        Ok(translations
+            .into_iter()
+            .map(TranslationResponse::from)
+            .collect())
+    }
+
+    /// Replaces the translation of a category into a locale (requires Admin)
+    pub async fn set_category_translation(
+        &self,
+        category_id: i32,
+        locale: &str,
+        request: &TranslationRequest,
+        role_id: i32,
+    ) -> Result<(), TranslationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TranslationServiceError::PermissionDenied);
+        }
+
+        self.ensure_category(category_id).await?;
+        let locale = translatable_locale(locale)?;
+        if !is_valid_translation(request, MAX_CATEGORY_NAME_LENGTH) {
+            return Err(TranslationServiceError::InvalidTranslation);
+        }
+
+        TranslationRepo::new()
+            .save_category(CategoryTranslation {
+                category_id,
+                locale,
+                name: request.name.trim().to_string(),
+                description: request.description.clone(),
+            })
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)
+    }
+
+    /// Removes the translation of a category into a locale (requires Admin)
+    pub async fn remove_category_translation(
+        &self,
+        category_id: i32,
+        locale: &str,
+        role_id: i32,
+    ) -> Result<(), TranslationServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Admin).await? {
+            return Err(TranslationServiceError::PermissionDenied);
+        }
+
+        self.ensure_category(category_id).await?;
+        let locale = translatable_locale(locale)?;
+
+        let removed = TranslationRepo::new()
+            .delete_category(category_id, &locale)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?;
+        if removed == 0 {
+            return Err(TranslationServiceError::TranslationNotFound);
+        }
+        Ok(())
+    }
+
+    async fn ensure_product(&self, product_id: i32) -> Result<(), TranslationServiceError> {
+        ProductRepo::new()
+            .get_by_id(product_id)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or(TranslationServiceError::ProductNotFound)?;
+        Ok(())
+    }
+
+    async fn ensure_category(&self, category_id: i32) -> Result<(), TranslationServiceError> {
+        CategoryRepo::new()
+            .get_by_id(category_id)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?
+            .filter(|c| c.deleted_at.is_none())
+            .ok_or(TranslationServiceError::CategoryNotFound)?;
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, TranslationServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| TranslationServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+/// A locale content can be translated into: a supported one other than the default, whose
+/// content is the product or category itself
+fn translatable_locale(locale: &str) -> Result<String, TranslationServiceError> {
+    let config = Config::new();
+    normalize_locale(locale)
+        .filter(|l| config.locales.contains(l) && *l != config.default_locale)
+        .ok_or_else(|| TranslationServiceError::UnsupportedLocale(locale.to_string()))
+}
+
+impl Default for TranslationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
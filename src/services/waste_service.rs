@@ -0,0 +1,444 @@
+use crate::api::request::{DateRangeQuery, LogWasteRequest};
+use crate::api::response::{
+    IngredientUsageResponse, WasteEntryResponse, WasteReportResponse, WasteTotalResponse,
+};
+use crate::data::models::ingredient::{Ingredient, Recipe, StockMovement};
+use crate::data::models::roles::RolePermissions;
+use crate::data::models::waste_entry::{NewWasteEntry, WasteEntry};
+use crate::data::repos::implementors::ingredient_repo::IngredientRepo;
+use crate::data::repos::implementors::product_repo::ProductRepo;
+use crate::data::repos::implementors::waste_repo::WasteRepo;
+use crate::data::repos::traits::repository::Repository;
+use crate::services::errors::WasteServiceError;
+use crate::services::inventory_service::StockMovementReason;
+use crate::utils::time::parse_date;
+use bigdecimal::{BigDecimal, RoundingMode, Signed, Zero};
+use chrono::{Days, NaiveDate, NaiveTime};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+/// Why stock was thrown away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasteReason {
+    Dropped,
+    Burnt,
+    Expired,
+    Spoiled,
+    Other,
+}
+
+impl WasteReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WasteReason::Dropped => "dropped",
+            WasteReason::Burnt => "burnt",
+            WasteReason::Expired => "expired",
+            WasteReason::Spoiled => "spoiled",
+            WasteReason::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for WasteReason {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dropped" => Ok(WasteReason::Dropped),
+            "burnt" => Ok(WasteReason::Burnt),
+            "expired" => Ok(WasteReason::Expired),
+            "spoiled" => Ok(WasteReason::Spoiled),
+            "other" => Ok(WasteReason::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Adds up how much of each ingredient wasting `quantity` of a product takes out of stock,
+/// ordered by ingredient id. A product without a recipe takes nothing.
+pub fn product_waste_uses(
+    product_id: i32,
+    quantity: &BigDecimal,
+    recipes: &[Recipe],
+) -> Vec<(i32, BigDecimal)> {
+    let mut uses: BTreeMap<i32, BigDecimal> = BTreeMap::new();
+
+    for recipe in recipes.iter().filter(|r| r.product_id == product_id) {
+        *uses.entry(recipe.ingredient_id).or_default() += &recipe.quantity * quantity;
+    }
+
+    uses.into_iter().collect()
+}
+
+/// Cost of the ingredients used at their average cost, to the cent. Ingredients missing
+/// from `stock` cost nothing.
+pub fn waste_cost(uses: &[(i32, BigDecimal)], stock: &[Ingredient]) -> BigDecimal {
+    uses.iter()
+        .filter_map(|(id, used)| {
+            stock
+                .iter()
+                .find(|ingredient| ingredient.ingredient_id == *id)
+                .map(|ingredient| used * &ingredient.average_cost)
+        })
+        .sum::<BigDecimal>()
+        .with_scale_round(2, RoundingMode::HalfEven)
+}
+
+/// Waste logged under one key, such as a reason or a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasteTotal {
+    pub key: String,
+    pub entry_count: i32,
+    pub cost: BigDecimal,
+}
+
+fn waste_totals<'a>(entries: impl Iterator<Item = (String, &'a WasteEntry)>) -> Vec<WasteTotal> {
+    let mut totals: BTreeMap<String, WasteTotal> = BTreeMap::new();
+
+    for (key, entry) in entries {
+        let total = totals.entry(key.clone()).or_insert_with(|| WasteTotal {
+            key,
+            entry_count: 0,
+            cost: BigDecimal::zero(),
+        });
+        total.entry_count += 1;
+        total.cost += &entry.cost;
+    }
+
+    totals.into_values().collect()
+}
+
+/// Totals the cost of waste entries per reason, ordered by reason
+pub fn waste_by_reason(entries: &[WasteEntry]) -> Vec<WasteTotal> {
+    waste_totals(entries.iter().map(|entry| (entry.reason.clone(), entry)))
+}
+
+/// Totals the cost of waste entries per day they were logged on, oldest first
+pub fn waste_by_day(entries: &[WasteEntry]) -> Vec<WasteTotal> {
+    waste_totals(entries.iter().filter_map(|entry| {
+        entry
+            .created_at
+            .map(|created_at| (created_at.date().to_string(), entry))
+    }))
+}
+
+/// How much of an ingredient was used over a period, by what moved its stock
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientUsage {
+    pub ingredient_id: i32,
+    /// What the recipes of accepted orders used, less what cancelled orders put back
+    pub theoretical: BigDecimal,
+    /// What was logged as waste
+    pub waste: BigDecimal,
+    /// Everything that left stock other than through purchases
+    pub actual: BigDecimal,
+}
+
+impl IngredientUsage {
+    /// Usage the recipes of the orders do not account for
+    pub fn variance(&self) -> BigDecimal {
+        &self.actual - &self.theoretical
+    }
+}
+
+/// Compares the theoretical usage of each ingredient, from orders and their recipes, with its
+/// actual usage: the theoretical usage plus waste and stock count corrections. Purchases are
+/// left out, as are ingredients only purchased. Ordered by ingredient id.
+pub fn ingredient_usage(movements: &[StockMovement]) -> Vec<IngredientUsage> {
+    let mut usage: BTreeMap<i32, IngredientUsage> = BTreeMap::new();
+
+    for movement in movements {
+        let reason = StockMovementReason::from_str(&movement.reason);
+        if reason == Ok(StockMovementReason::Purchase) {
+            continue;
+        }
+
+        let entry = usage
+            .entry(movement.ingredient_id)
+            .or_insert_with(|| IngredientUsage {
+                ingredient_id: movement.ingredient_id,
+                theoretical: BigDecimal::zero(),
+                waste: BigDecimal::zero(),
+                actual: BigDecimal::zero(),
+            });
+        match reason {
+            Ok(StockMovementReason::Order) | Ok(StockMovementReason::Cancellation) => {
+                entry.theoretical -= &movement.quantity;
+            }
+            Ok(StockMovementReason::Waste) => entry.waste -= &movement.quantity,
+            _ => {}
+        }
+        entry.actual -= &movement.quantity;
+    }
+
+    usage.into_values().collect()
+}
+
+fn parse_range(range: &DateRangeQuery) -> Result<(NaiveDate, NaiveDate), WasteServiceError> {
+    let from = parse_date(&range.from).ok_or(WasteServiceError::InvalidDateRange)?;
+    let to = parse_date(&range.to).ok_or(WasteServiceError::InvalidDateRange)?;
+    if to < from {
+        return Err(WasteServiceError::InvalidDateRange);
+    }
+    Ok((from, to))
+}
+
+pub struct WasteService;
+
+impl WasteService {
+    pub fn new() -> Self {
+        WasteService
+    }
+
+    /// Logs waste of an ingredient or product and takes it out of stock (requires WRITE
+    /// permission or Admin). Wasting a product takes the ingredients of its recipe. The
+    /// waste is costed at the average cost of the ingredients, and may take stock below
+    /// zero when the counts were off.
+    pub async fn log_waste(
+        &self,
+        request: &LogWasteRequest,
+        user_id: i32,
+        role_id: i32,
+    ) -> Result<WasteEntryResponse, WasteServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(WasteServiceError::PermissionDenied);
+        }
+
+        let reason =
+            WasteReason::from_str(&request.reason).map_err(|_| WasteServiceError::InvalidReason)?;
+        if !request.quantity.is_positive() {
+            return Err(WasteServiceError::InvalidWaste);
+        }
+
+        let ingredient_repo = IngredientRepo::new();
+        let (uses, name, unit) = match (request.ingredient_id, request.product_id) {
+            (Some(ingredient_id), None) => {
+                let ingredient = ingredient_repo
+                    .get_by_id(ingredient_id)
+                    .await
+                    .map_err(|_| WasteServiceError::DatabaseError)?
+                    .ok_or(WasteServiceError::IngredientNotFound)?;
+                (
+                    vec![(ingredient_id, request.quantity.clone())],
+                    ingredient.name,
+                    Some(ingredient.unit),
+                )
+            }
+            (None, Some(product_id)) => {
+                let product = ProductRepo::new()
+                    .get_by_id(product_id)
+                    .await
+                    .map_err(|_| WasteServiceError::DatabaseError)?
+                    .ok_or(WasteServiceError::ProductNotFound)?;
+                let recipes = ingredient_repo
+                    .get_recipes(vec![product_id])
+                    .await
+                    .map_err(|_| WasteServiceError::DatabaseError)?;
+                (
+                    product_waste_uses(product_id, &request.quantity, &recipes),
+                    product.name,
+                    None,
+                )
+            }
+            _ => return Err(WasteServiceError::InvalidWaste),
+        };
+
+        let stock = ingredient_repo
+            .get_by_ids(uses.iter().map(|(id, _)| *id).collect())
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+
+        let repo = WasteRepo::new();
+        let waste_id = repo
+            .record(
+                NewWasteEntry {
+                    ingredient_id: request.ingredient_id,
+                    product_id: request.product_id,
+                    quantity: request.quantity.clone(),
+                    reason: reason.as_str(),
+                    cost: waste_cost(&uses, &stock),
+                    note: request.note.as_deref(),
+                    user_id: Some(user_id),
+                },
+                uses,
+                StockMovementReason::Waste.as_str(),
+            )
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+
+        let entry = repo
+            .get_by_id(waste_id)
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?
+            .ok_or(WasteServiceError::DatabaseError)?;
+
+        let mut response = WasteEntryResponse::from(entry);
+        response.name = Some(name);
+        response.unit = unit;
+        Ok(response)
+    }
+
+    /// Gets the waste logged between two dates, both included, oldest first (requires READ
+    /// permission or Admin)
+    pub async fn get_waste(
+        &self,
+        range: &DateRangeQuery,
+        role_id: i32,
+    ) -> Result<Vec<WasteEntryResponse>, WasteServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Read).await?
+            && !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(WasteServiceError::PermissionDenied);
+        }
+
+        let (from, to) = parse_range(range)?;
+        let entries = WasteRepo::new()
+            .get_between(
+                from.and_time(NaiveTime::MIN),
+                (to + Days::new(1)).and_time(NaiveTime::MIN),
+            )
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+
+        let ingredients = IngredientRepo::new()
+            .get_by_ids(entries.iter().filter_map(|e| e.ingredient_id).collect())
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+
+        let product_repo = ProductRepo::new();
+        let mut product_names: HashMap<i32, Option<String>> = HashMap::new();
+        let mut responses = Vec::new();
+        for entry in entries {
+            let (name, unit) = match (entry.ingredient_id, entry.product_id) {
+                (Some(ingredient_id), _) => ingredients
+                    .iter()
+                    .find(|ingredient| ingredient.ingredient_id == ingredient_id)
+                    .map(|ingredient| {
+                        (Some(ingredient.name.clone()), Some(ingredient.unit.clone()))
+                    })
+                    .unwrap_or_default(),
+                (None, Some(product_id)) => match product_names.get(&product_id) {
+                    Some(name) => (name.clone(), None),
+                    None => {
+                        let name = product_repo
+                            .get_by_id(product_id)
+                            .await
+                            .map_err(|_| WasteServiceError::DatabaseError)?
+                            .map(|product| product.name);
+                        product_names.insert(product_id, name.clone());
+                        (name, None)
+                    }
+                },
+                (None, None) => (None, None),
+            };
+
+            let mut response = WasteEntryResponse::from(entry);
+            response.name = name;
+            response.unit = unit;
+            responses.push(response);
+        }
+
+        Ok(responses)
+    }
+
+    /// Reports the cost of the waste logged between two dates, both included, per reason
+    /// and per day, and compares the theoretical usage of each ingredient with its actual
+    /// usage over the period (requires WRITE permission or Admin)
+    pub async fn get_waste_report(
+        &self,
+        range: &DateRangeQuery,
+        role_id: i32,
+    ) -> Result<WasteReportResponse, WasteServiceError> {
+        if !self.has_permission(role_id, RolePermissions::Write).await?
+            && !self.has_permission(role_id, RolePermissions::Admin).await?
+        {
+            return Err(WasteServiceError::PermissionDenied);
+        }
+
+        let (from, to) = parse_range(range)?;
+        let start = from.and_time(NaiveTime::MIN);
+        let end = (to + Days::new(1)).and_time(NaiveTime::MIN);
+
+        let entries = WasteRepo::new()
+            .get_between(start, end)
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+
+        let ingredient_repo = IngredientRepo::new();
+        let movements = ingredient_repo
+            .get_movements_between(start, end)
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+        let usage = ingredient_usage(&movements);
+        let ingredients = ingredient_repo
+            .get_by_ids(usage.iter().map(|u| u.ingredient_id).collect())
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?;
+
+        let usage = usage
+            .into_iter()
+            .filter_map(|u| {
+                let ingredient = ingredients
+                    .iter()
+                    .find(|ingredient| ingredient.ingredient_id == u.ingredient_id)?;
+                let variance = u.variance();
+                Some(IngredientUsageResponse {
+                    ingredient_id: u.ingredient_id,
+                    name: ingredient.name.clone(),
+                    unit: ingredient.unit.clone(),
+                    variance_cost: (&variance * &ingredient.average_cost)
+                        .with_scale_round(2, RoundingMode::HalfEven),
+                    theoretical: u.theoretical,
+                    waste: u.waste,
+                    actual: u.actual,
+                    variance,
+                })
+            })
+            .collect();
+
+        let totals = |totals: Vec<WasteTotal>| -> Vec<WasteTotalResponse> {
+            totals
+                .into_iter()
+                .map(|total| WasteTotalResponse {
+                    key: total.key,
+                    entry_count: total.entry_count,
+                    cost: total.cost,
+                })
+                .collect()
+        };
+
+        Ok(WasteReportResponse {
+            from: from.to_string(),
+            to: to.to_string(),
+            total_cost: entries.iter().map(|e| e.cost.clone()).sum(),
+            by_reason: totals(waste_by_reason(&entries)),
+            by_day: totals(waste_by_day(&entries)),
+            usage,
+        })
+    }
+
+    async fn has_permission(
+        &self,
+        role_id: i32,
+        required_permission: RolePermissions,
+    ) -> Result<bool, WasteServiceError> {
+        use crate::data::repos::implementors::role_repo::RoleRepo;
+        let role_repo = RoleRepo::new();
+        if let Some(role) = role_repo
+            .get_by_id(role_id)
+            .await
+            .map_err(|_| WasteServiceError::DatabaseError)?
+        {
+            return Ok(role.has_permission(required_permission));
+        }
+        Ok(false)
+    }
+}
+
+impl Default for WasteService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,17 +2,61 @@ use crate::api::controllers::dto::{
     role_dto::{RoleDTO, UpdateRoleDTO},
     user_dto::{NewUserDTO, UpdateUserDTO},
 };
-use crate::api::request::{CreateCategoryRequest, UpdateCategoryRequest};
-use crate::api::response::{CategoryResponse, OrderResponse, ProductResponse};
+use crate::api::request::{
+    CreateCategoryRequest, CreateDiningAreaRequest, CreateIngredientRequest, CreateMenuRequest,
+    CreatePromotionRequest, CreateStationRequest, CreateSupplierRequest, CreateTableRequest,
+    CreateTaxRateRequest, UpdateCategoryRequest, UpdateDiningAreaRequest, UpdateIngredientRequest,
+    UpdateMenuRequest, UpdatePromotionRequest, UpdateStationRequest, UpdateSupplierRequest,
+    UpdateTableRequest, UpdateTaxRateRequest,
+};
+use crate::api::response::{
+    AllergenAlertResponse, BundleChoiceResponse, CategoryResponse, CheckResponse,
+    DietaryTagsResponse, DiningAreaResponse, IngredientResponse, KitchenTicketItemResponse,
+    KitchenTicketResponse, MenuResponse, MenuWindowResponse, NutritionResponse,
+    OrderAdjustmentResponse, OrderDiscountResponse, OrderResponse, PaymentResponse,
+    ProductPriceResponse, ProductResponse, PromotionResponse, PurchaseOrderLineResponse,
+    PurchaseOrderResponse, RecipeItemResponse, ReservationResponse, StationResponse,
+    SupplierResponse, TableResponse, TableSessionResponse, TaxRateResponse, TranslationResponse,
+    WaitlistEntryResponse, WasteEntryResponse,
+};
+use crate::data::models::allergen::OrderAllergenAlert;
+use crate::data::models::bundle::BundleSlotChoice;
 use crate::data::models::categories::{Category, NewCategory, UpdateCategory};
+use crate::data::models::dining_area::{DiningArea, NewDiningArea, UpdateDiningArea};
+use crate::data::models::dining_table::{DiningTable, NewDiningTable, UpdateDiningTable};
+use crate::data::models::ingredient::{Ingredient, NewIngredient, Recipe, UpdateIngredient};
+use crate::data::models::kitchen_ticket::{KitchenTicket, KitchenTicketItem};
+use crate::data::models::menu::{Menu, MenuSchedule, NewMenu, UpdateMenu};
+use crate::data::models::nutrition::ProductNutrition;
 use crate::data::models::order::Order;
+use crate::data::models::order_adjustment::OrderAdjustment;
+use crate::data::models::order_check::OrderCheck;
+use crate::data::models::order_discount::OrderDiscount;
 use crate::data::models::order_product::OrderProduct;
+use crate::data::models::payment::Payment;
 use crate::data::models::product::Product;
+use crate::data::models::product_price::ProductPrice;
+use crate::data::models::reservation::Reservation;
+use crate::data::models::promotion::{NewPromotion, UpdatePromotion};
+use crate::data::models::purchase_order::{PurchaseOrder, PurchaseOrderLine};
 use crate::data::models::schema::sql_types::RolesPermissionsSet;
+use crate::data::models::station::{NewStation, Station, UpdateStation};
+use crate::data::models::supplier::{NewSupplier, Supplier, UpdateSupplier};
+use crate::data::models::table_session::TableSession;
+use crate::data::models::tax_rate::{NewTaxRate, TaxRate, UpdateTaxRate};
+use crate::data::models::translation::{CategoryTranslation, ProductTranslation};
+use crate::services::allergen_service::DietaryProfile;
+use crate::services::menu_service::day_name;
+use crate::services::order_service::format_order_number;
+use crate::services::promotion_service::PromotionRule;
+use crate::utils::time::local_now;
 use crate::data::models::user::{NewUser, UpdateUser};
+use crate::data::models::waitlist_entry::WaitlistEntry;
+use crate::data::models::waste_entry::WasteEntry;
 use crate::data::models::roles::{
     PermissionString, RolePermissions, UpdateRole, Role,
 };
+use bigdecimal::{BigDecimal, Zero};
 use diesel::deserialize::FromSql;
 use diesel::mysql::{Mysql, MysqlValue};
 use diesel::serialize::{Output, ToSql};
@@ -100,6 +144,7 @@ impl TryFrom<&str> for RolePermissions {
             "WRITE" => Ok(RolePermissions::Write),
             "DELETE" => Ok(RolePermissions::Delete),
             "ADMIN" => Ok(RolePermissions::Admin),
+            "REFUND" => Ok(RolePermissions::Refund),
             _ => Err("Unknown permission"),
         }
     }
@@ -114,6 +159,7 @@ impl FromStr for RolePermissions {
             "WRITE" => Some(RolePermissions::Write),
             "DELETE" => Some(RolePermissions::Delete),
             "ADMIN" => Some(RolePermissions::Admin),
+            "REFUND" => Some(RolePermissions::Refund),
             _ => None,
         }
         .ok_or("Unknown permission")
@@ -125,6 +171,8 @@ impl<'a> From<&'a CreateCategoryRequest> for NewCategory<'a> {
         NewCategory {
             name: &request.name,
             description: request.description.as_deref(),
+            parent_id: request.parent_id,
+            sort_order: request.sort_order,
         }
     }
 }
@@ -134,6 +182,8 @@ impl<'a> From<&'a UpdateCategoryRequest> for UpdateCategory<'a> {
         UpdateCategory {
             name: request.name.as_deref(),
             description: request.description.as_deref(),
+            parent_id: request.parent_id,
+            sort_order: request.sort_order,
         }
     }
 }
@@ -147,12 +197,32 @@ impl From<(Order, Vec<(OrderProduct, Product)>)> for OrderResponse {
             total_qty += op.quantity;
             product_responses.push(ProductResponse::from(p));
         }
+        let balance_due = order.amount_due();
 
         Self {
             order_id: order.order_id,
             user_id: order.user_id,
             products: product_responses,
             quantity: total_qty,
+            subtotal: order.subtotal,
+            tax_amount: order.tax_amount,
+            service_charge: order.service_charge,
+            discount_amount: order.discount_amount,
+            discounts: Vec::new(),
+            party_size: order.party_size,
+            served_by: order.served_by,
+            amount_paid: BigDecimal::from(0),
+            balance_due,
+            gratuity_amount: order.gratuity_amount,
+            tip_amount: order.tip_amount,
+            order_type: order.order_type,
+            table_id: order.table_id,
+            session_id: order.session_id,
+            order_number: order.order_number.map(format_order_number),
+            business_date: order.business_date.map(|d| d.to_string()),
+            scheduled_for: order.scheduled_for.map(|d| d.to_string()),
+            allergen_alerts: Vec::new(),
+            estimated_ready_at: order.estimated_ready_at.map(|d| d.to_string()),
             total_amount: order.total_amount,
             status: order.status,
             created_at: order.created_at.map(|d| d.to_string()),
@@ -165,13 +235,24 @@ impl From<(Order, Vec<(OrderProduct, Product)>)> for OrderResponse {
 
 impl From<Product> for ProductResponse {
     fn from(product: Product) -> Self {
+        let is_available = product.is_available_at(local_now());
         Self {
             product_id: product.product_id,
             name: product.name,
             description: product.description,
             price: product.price,
             product_image_uri: product.product_image_uri,
+            prep_minutes: product.prep_minutes,
+            is_available,
+            available_again_at: product
+                .available_again_at
+                .filter(|_| !is_available)
+                .map(|d| d.to_string()),
             categories: None,
+            allergens: Vec::new(),
+            dietary_tags: Vec::new(),
+            nutrition: None,
+            deleted_at: product.deleted_at.map(|d| d.to_string()),
         }
     }
 }
@@ -184,6 +265,630 @@ impl From<Category> for CategoryResponse {
             description: category.description,
             created_at: category.created_at.map(|d| d.to_string()),
             updated_at: category.updated_at.map(|d| d.to_string()),
+            deleted_at: category.deleted_at.map(|d| d.to_string()),
+            parent_id: category.parent_id,
+            sort_order: category.sort_order,
+            children: None,
+        }
+    }
+}
+
+impl<'a> From<&'a CreateTaxRateRequest> for NewTaxRate<'a> {
+    fn from(request: &'a CreateTaxRateRequest) -> Self {
+        NewTaxRate {
+            name: &request.name,
+            rate: request.rate.clone(),
+            is_inclusive: request.is_inclusive.unwrap_or(false),
+            product_id: request.product_id,
+            category_id: request.category_id,
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateTaxRateRequest> for UpdateTaxRate<'a> {
+    fn from(request: &'a UpdateTaxRateRequest) -> Self {
+        UpdateTaxRate {
+            name: request.name.as_deref(),
+            rate: request.rate.clone(),
+            is_inclusive: request.is_inclusive,
+        }
+    }
+}
+
+impl From<TaxRate> for TaxRateResponse {
+    fn from(rate: TaxRate) -> Self {
+        Self {
+            tax_rate_id: rate.tax_rate_id,
+            name: rate.name,
+            rate: rate.rate,
+            is_inclusive: rate.is_inclusive,
+            product_id: rate.product_id,
+            category_id: rate.category_id,
+            created_at: rate.created_at.map(|d| d.to_string()),
+            updated_at: rate.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<OrderDiscount> for OrderDiscountResponse {
+    fn from(discount: OrderDiscount) -> Self {
+        Self {
+            promotion_id: discount.promotion_id,
+            name: discount.name,
+            code: discount.code,
+            amount: discount.amount,
+        }
+    }
+}
+
+/// Dates and daily times are parsed and validated by the promotion service
+impl<'a> From<&'a CreatePromotionRequest> for NewPromotion<'a> {
+    fn from(request: &'a CreatePromotionRequest) -> Self {
+        NewPromotion {
+            name: &request.name,
+            code: request.code.as_deref(),
+            discount_type: &request.discount_type,
+            discount_value: request.discount_value.clone(),
+            is_automatic: request.is_automatic.unwrap_or(false),
+            min_spend: request.min_spend.clone(),
+            starts_at: None,
+            ends_at: None,
+            daily_start_time: None,
+            daily_end_time: None,
+            usage_limit: request.usage_limit,
+        }
+    }
+}
+
+/// Dates and daily times are parsed and validated by the promotion service
+impl<'a> From<&'a UpdatePromotionRequest> for UpdatePromotion<'a> {
+    fn from(request: &'a UpdatePromotionRequest) -> Self {
+        UpdatePromotion {
+            name: request.name.as_deref(),
+            discount_value: request.discount_value.clone(),
+            is_active: request.is_active,
+            min_spend: request.min_spend.clone(),
+            starts_at: None,
+            ends_at: None,
+            daily_start_time: None,
+            daily_end_time: None,
+            usage_limit: request.usage_limit,
+        }
+    }
+}
+
+impl From<PromotionRule> for PromotionResponse {
+    fn from(rule: PromotionRule) -> Self {
+        let promotion = rule.promotion;
+        Self {
+            promotion_id: promotion.promotion_id,
+            name: promotion.name,
+            code: promotion.code,
+            discount_type: promotion.discount_type,
+            discount_value: promotion.discount_value,
+            is_automatic: promotion.is_automatic,
+            is_active: promotion.is_active,
+            min_spend: promotion.min_spend,
+            starts_at: promotion.starts_at.map(|d| d.to_string()),
+            ends_at: promotion.ends_at.map(|d| d.to_string()),
+            daily_start_time: promotion.daily_start_time.map(|t| t.to_string()),
+            daily_end_time: promotion.daily_end_time.map(|t| t.to_string()),
+            usage_limit: promotion.usage_limit,
+            usage_count: promotion.usage_count,
+            product_ids: rule.product_ids,
+            category_ids: rule.category_ids,
+            created_at: promotion.created_at.map(|d| d.to_string()),
+            updated_at: promotion.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<Payment> for PaymentResponse {
+    fn from(payment: Payment) -> Self {
+        Self {
+            payment_id: payment.payment_id,
+            order_id: payment.order_id,
+            amount: payment.amount,
+            tip_amount: payment.tip_amount,
+            method: payment.method,
+            provider: payment.provider,
+            status: payment.status,
+            provider_reference: payment.provider_reference,
+            check_id: payment.check_id,
+            created_at: payment.created_at.map(|d| d.to_string()),
+            updated_at: payment.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<OrderCheck> for CheckResponse {
+    fn from(check: OrderCheck) -> Self {
+        let balance_due = check.amount_due();
+        Self {
+            check_id: check.check_id,
+            check_number: check.check_number,
+            subtotal: check.subtotal,
+            discount_amount: check.discount_amount,
+            tax_amount: check.tax_amount,
+            service_charge: check.service_charge,
+            amount_paid: BigDecimal::from(0),
+            balance_due,
+            total_amount: check.total_amount,
+            gratuity_amount: check.gratuity_amount,
+            product_ids: Vec::new(),
+        }
+    }
+}
+
+impl From<OrderAdjustment> for OrderAdjustmentResponse {
+    fn from(adjustment: OrderAdjustment) -> Self {
+        Self {
+            adjustment_id: adjustment.adjustment_id,
+            order_id: adjustment.order_id,
+            adjustment_type: adjustment.adjustment_type,
+            product_id: adjustment.product_id,
+            quantity: adjustment.quantity,
+            refund_id: adjustment.refund_id,
+            amount: adjustment.amount,
+            reason_code: adjustment.reason_code,
+            note: adjustment.note,
+            user_id: adjustment.user_id,
+            created_at: adjustment.created_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl<'a> From<&'a CreateDiningAreaRequest> for NewDiningArea<'a> {
+    fn from(request: &'a CreateDiningAreaRequest) -> Self {
+        NewDiningArea {
+            name: request.name.trim(),
+            description: request.description.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateDiningAreaRequest> for UpdateDiningArea<'a> {
+    fn from(request: &'a UpdateDiningAreaRequest) -> Self {
+        UpdateDiningArea {
+            name: request.name.as_deref().map(str::trim),
+            description: request.description.as_deref(),
+        }
+    }
+}
+
+impl From<DiningArea> for DiningAreaResponse {
+    fn from(area: DiningArea) -> Self {
+        Self {
+            area_id: area.area_id,
+            name: area.name,
+            description: area.description,
+            created_at: area.created_at.map(|d| d.to_string()),
+            updated_at: area.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl<'a> From<&'a CreateTableRequest> for NewDiningTable<'a> {
+    fn from(request: &'a CreateTableRequest) -> Self {
+        NewDiningTable {
+            area_id: request.area_id,
+            name: request.name.trim(),
+            capacity: request.capacity,
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateTableRequest> for UpdateDiningTable<'a> {
+    fn from(request: &'a UpdateTableRequest) -> Self {
+        UpdateDiningTable {
+            area_id: request.area_id,
+            name: request.name.as_deref().map(str::trim),
+            capacity: request.capacity,
+            status: None,
+        }
+    }
+}
+
+impl From<DiningTable> for TableResponse {
+    fn from(table: DiningTable) -> Self {
+        Self {
+            table_id: table.table_id,
+            area_id: table.area_id,
+            name: table.name,
+            capacity: table.capacity,
+            status: table.status,
+            created_at: table.created_at.map(|d| d.to_string()),
+            updated_at: table.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<TableSession> for TableSessionResponse {
+    fn from(session: TableSession) -> Self {
+        Self {
+            session_id: session.session_id,
+            table_id: session.table_id,
+            party_size: session.party_size,
+            opened_by: session.opened_by,
+            opened_at: session.opened_at.map(|d| d.to_string()),
+            closed_at: session.closed_at.map(|d| d.to_string()),
+            order_ids: Vec::new(),
+            subtotal: session.subtotal,
+            discount_amount: session.discount_amount,
+            tax_amount: session.tax_amount,
+            service_charge: session.service_charge,
+            gratuity_amount: session.gratuity_amount,
+            amount_paid: BigDecimal::from(0),
+            balance_due: session.total_amount.clone(),
+            total_amount: session.total_amount,
+        }
+    }
+}
+
+impl From<Reservation> for ReservationResponse {
+    fn from(reservation: Reservation) -> Self {
+        Self {
+            reservation_id: reservation.reservation_id,
+            contact_name: reservation.contact_name,
+            contact_phone: reservation.contact_phone,
+            party_size: reservation.party_size,
+            reserved_for: reservation.reserved_for.to_string(),
+            table_id: reservation.table_id,
+            status: reservation.status,
+            notes: reservation.notes,
+            session_id: reservation.session_id,
+            created_at: reservation.created_at.map(|d| d.to_string()),
+            updated_at: reservation.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<WaitlistEntry> for WaitlistEntryResponse {
+    fn from(entry: WaitlistEntry) -> Self {
+        Self {
+            entry_id: entry.entry_id,
+            contact_name: entry.contact_name,
+            contact_phone: entry.contact_phone,
+            party_size: entry.party_size,
+            quoted_wait_minutes: entry.quoted_wait_minutes,
+            status: entry.status,
+            notes: entry.notes,
+            session_id: entry.session_id,
+            created_at: entry.created_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl<'a> From<&'a CreateStationRequest> for NewStation<'a> {
+    fn from(request: &'a CreateStationRequest) -> Self {
+        NewStation {
+            name: request.name.trim(),
+            capacity: request.capacity,
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateStationRequest> for UpdateStation<'a> {
+    fn from(request: &'a UpdateStationRequest) -> Self {
+        UpdateStation {
+            name: request.name.as_deref().map(str::trim),
+            capacity: request.capacity,
+        }
+    }
+}
+
+impl From<Station> for StationResponse {
+    fn from(station: Station) -> Self {
+        Self {
+            station_id: station.station_id,
+            name: station.name,
+            capacity: station.capacity,
+            product_ids: Vec::new(),
+            category_ids: Vec::new(),
+            created_at: station.created_at.map(|d| d.to_string()),
+            updated_at: station.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<(KitchenTicket, Order)> for KitchenTicketResponse {
+    fn from((ticket, order): (KitchenTicket, Order)) -> Self {
+        Self {
+            ticket_id: ticket.ticket_id,
+            order_id: ticket.order_id,
+            order_number: order.order_number.map(format_order_number),
+            station_id: ticket.station_id,
+            status: ticket.status,
+            items: Vec::new(),
+            created_at: ticket.created_at.map(|d| d.to_string()),
+            bumped_at: ticket.bumped_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<(KitchenTicketItem, Product)> for KitchenTicketItemResponse {
+    fn from((item, product): (KitchenTicketItem, Product)) -> Self {
+        Self {
+            product_id: item.product_id,
+            name: product.name,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl<'a> From<&'a CreateIngredientRequest> for NewIngredient<'a> {
+    fn from(request: &'a CreateIngredientRequest) -> Self {
+        NewIngredient {
+            name: request.name.trim(),
+            unit: request.unit.trim(),
+            reorder_level: request.reorder_level.clone(),
+            supplier_id: request.supplier_id,
+            par_level: request.par_level.clone(),
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateIngredientRequest> for UpdateIngredient<'a> {
+    fn from(request: &'a UpdateIngredientRequest) -> Self {
+        UpdateIngredient {
+            name: request.name.as_deref().map(str::trim),
+            unit: request.unit.as_deref().map(str::trim),
+            reorder_level: request.reorder_level.clone(),
+            supplier_id: request.supplier_id,
+            par_level: request.par_level.clone(),
+        }
+    }
+}
+
+impl From<Ingredient> for IngredientResponse {
+    fn from(ingredient: Ingredient) -> Self {
+        Self {
+            stock_value: ingredient.stock_value(),
+            ingredient_id: ingredient.ingredient_id,
+            name: ingredient.name,
+            unit: ingredient.unit,
+            is_low: ingredient.on_hand <= ingredient.reorder_level,
+            on_hand: ingredient.on_hand,
+            reorder_level: ingredient.reorder_level,
+            supplier_id: ingredient.supplier_id,
+            par_level: ingredient.par_level,
+            average_cost: ingredient.average_cost,
+            created_at: ingredient.created_at.map(|d| d.to_string()),
+            updated_at: ingredient.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<(Recipe, Ingredient)> for RecipeItemResponse {
+    fn from((recipe, ingredient): (Recipe, Ingredient)) -> Self {
+        Self {
+            ingredient_id: recipe.ingredient_id,
+            name: ingredient.name,
+            unit: ingredient.unit,
+            quantity: recipe.quantity,
+        }
+    }
+}
+
+impl<'a> From<&'a CreateSupplierRequest> for NewSupplier<'a> {
+    fn from(request: &'a CreateSupplierRequest) -> Self {
+        NewSupplier {
+            name: request.name.trim(),
+            contact_name: request.contact_name.as_deref().map(str::trim),
+            email: request.email.as_deref().map(str::trim),
+            phone: request.phone.as_deref().map(str::trim),
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateSupplierRequest> for UpdateSupplier<'a> {
+    fn from(request: &'a UpdateSupplierRequest) -> Self {
+        UpdateSupplier {
+            name: request.name.as_deref().map(str::trim),
+            contact_name: request.contact_name.as_deref().map(str::trim),
+            email: request.email.as_deref().map(str::trim),
+            phone: request.phone.as_deref().map(str::trim),
+        }
+    }
+}
+
+impl From<Supplier> for SupplierResponse {
+    fn from(supplier: Supplier) -> Self {
+        Self {
+            supplier_id: supplier.supplier_id,
+            name: supplier.name,
+            contact_name: supplier.contact_name,
+            email: supplier.email,
+            phone: supplier.phone,
+            created_at: supplier.created_at.map(|d| d.to_string()),
+            updated_at: supplier.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<(PurchaseOrder, Supplier)> for PurchaseOrderResponse {
+    fn from((order, supplier): (PurchaseOrder, Supplier)) -> Self {
+        Self {
+            purchase_order_id: order.purchase_order_id,
+            supplier_id: order.supplier_id,
+            supplier_name: supplier.name,
+            status: order.status,
+            note: order.note,
+            created_by: order.created_by,
+            lines: Vec::new(),
+            total_cost: BigDecimal::zero(),
+            sent_at: order.sent_at.map(|d| d.to_string()),
+            received_at: order.received_at.map(|d| d.to_string()),
+            created_at: order.created_at.map(|d| d.to_string()),
+            updated_at: order.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<(PurchaseOrderLine, Ingredient)> for PurchaseOrderLineResponse {
+    fn from((line, ingredient): (PurchaseOrderLine, Ingredient)) -> Self {
+        Self {
+            ingredient_id: line.ingredient_id,
+            name: ingredient.name,
+            unit: ingredient.unit,
+            quantity: line.quantity,
+            unit_cost: line.unit_cost,
+            received_quantity: line.received_quantity,
+        }
+    }
+}
+
+/// The name and unit are filled in from the ingredient or product by the waste service
+impl From<WasteEntry> for WasteEntryResponse {
+    fn from(entry: WasteEntry) -> Self {
+        Self {
+            waste_id: entry.waste_id,
+            ingredient_id: entry.ingredient_id,
+            product_id: entry.product_id,
+            name: None,
+            unit: None,
+            quantity: entry.quantity,
+            reason: entry.reason,
+            cost: entry.cost,
+            note: entry.note,
+            user_id: entry.user_id,
+            created_at: entry.created_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<ProductPrice> for ProductPriceResponse {
+    fn from(price: ProductPrice) -> Self {
+        Self {
+            is_current: price.is_effective_at(local_now()),
+            price_id: price.price_id,
+            product_id: price.product_id,
+            price: price.price,
+            effective_from: price.effective_from.to_string(),
+            effective_to: price.effective_to.map(|d| d.to_string()),
+            created_by: price.created_by,
+            created_at: price.created_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl<'a> From<&'a CreateMenuRequest> for NewMenu<'a> {
+    fn from(request: &'a CreateMenuRequest) -> Self {
+        NewMenu {
+            name: request.name.trim(),
+            description: request.description.as_deref(),
+            utc_offset: request
+                .utc_offset
+                .as_deref()
+                .map(str::trim)
+                .filter(|o| !o.is_empty()),
+            is_active: request.is_active,
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateMenuRequest> for UpdateMenu<'a> {
+    fn from(request: &'a UpdateMenuRequest) -> Self {
+        UpdateMenu {
+            name: request.name.as_deref().map(str::trim),
+            description: request.description.as_deref(),
+            utc_offset: request
+                .utc_offset
+                .as_deref()
+                .map(|offset| Some(offset.trim()).filter(|o| !o.is_empty())),
+            is_active: request.is_active,
+        }
+    }
+}
+
+impl From<Menu> for MenuResponse {
+    fn from(menu: Menu) -> Self {
+        Self {
+            menu_id: menu.menu_id,
+            name: menu.name,
+            description: menu.description,
+            utc_offset: menu.utc_offset,
+            is_active: menu.is_active,
+            is_open: false,
+            windows: Vec::new(),
+            product_ids: Vec::new(),
+            category_ids: Vec::new(),
+            created_at: menu.created_at.map(|d| d.to_string()),
+            updated_at: menu.updated_at.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl From<&MenuSchedule> for MenuWindowResponse {
+    fn from(window: &MenuSchedule) -> Self {
+        Self {
+            day: day_name(window.day_of_week).to_string(),
+            start: window.start_time.format("%H:%M").to_string(),
+            end: window.end_time.format("%H:%M").to_string(),
+        }
+    }
+}
+
+impl From<BundleSlotChoice> for BundleChoiceResponse {
+    fn from(choice: BundleSlotChoice) -> Self {
+        BundleChoiceResponse {
+            choice_id: choice.choice_id,
+            product_id: choice.product_id,
+            category_id: choice.category_id,
+            upcharge: choice.upcharge,
+        }
+    }
+}
+
+impl From<DietaryProfile> for DietaryTagsResponse {
+    fn from(profile: DietaryProfile) -> Self {
+        DietaryTagsResponse {
+            allergens: profile.allergens.iter().map(|a| a.as_str().to_string()).collect(),
+            dietary_tags: profile.dietary_tags.iter().map(|t| t.as_str().to_string()).collect(),
+        }
+    }
+}
+
+impl From<OrderAllergenAlert> for AllergenAlertResponse {
+    fn from(alert: OrderAllergenAlert) -> Self {
+        AllergenAlertResponse {
+            product_id: alert.product_id,
+            allergen: alert.allergen,
+        }
+    }
+}
+
+impl From<ProductNutrition> for NutritionResponse {
+    fn from(nutrition: ProductNutrition) -> Self {
+        NutritionResponse {
+            serving_size: nutrition.serving_size,
+            kcal: nutrition.kcal,
+            protein: nutrition.protein,
+            carbohydrates: nutrition.carbohydrates,
+            sugars: nutrition.sugars,
+            fat: nutrition.fat,
+            salt: nutrition.salt,
+            from_recipe: nutrition.from_recipe,
+        }
+    }
+}
+
+impl From<ProductTranslation> for TranslationResponse {
+    fn from(translation: ProductTranslation) -> Self {
+        TranslationResponse {
+            locale: translation.locale,
+            name: translation.name,
+            description: translation.description,
+        }
+    }
+}
+
+impl From<CategoryTranslation> for TranslationResponse {
+    fn from(translation: CategoryTranslation) -> Self {
+        TranslationResponse {
+            locale: translation.locale,
+            name: translation.name,
+            description: translation.description,
         }
     }
 }
@@ -0,0 +1,65 @@
+use crate::api::config::Config;
+use chrono::{Days, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Current local date and time of the business, used for business rules such as promotion
+/// windows. Uses `BUSINESS_UTC_OFFSET` when it is set.
+pub fn local_now() -> NaiveDateTime {
+    match Config::new().business_utc_offset {
+        Some(offset) => chrono::Utc::now().with_timezone(&offset).naive_local(),
+        None => chrono::Local::now().naive_local(),
+    }
+}
+
+/// Offset from UTC of the business time `local_now` returns
+pub fn business_offset() -> FixedOffset {
+    Config::new()
+        .business_utc_offset
+        .unwrap_or_else(|| *chrono::Local::now().offset())
+}
+
+/// Converts a local date and time at the offset `from` to the local time at `to`, such as
+/// business time to the time a menu is scheduled in
+pub fn to_offset(at: NaiveDateTime, from: FixedOffset, to: FixedOffset) -> NaiveDateTime {
+    at - Duration::seconds(from.local_minus_utc() as i64)
+        + Duration::seconds(to.local_minus_utc() as i64)
+}
+
+/// Business day a local date and time falls on. Times before `day_start` belong to the
+/// previous day.
+pub fn business_date(at: NaiveDateTime, day_start: NaiveTime) -> NaiveDate {
+    if at.time() < day_start {
+        at.date() - Days::new(1)
+    } else {
+        at.date()
+    }
+}
+
+/// Parses a date and time such as `2026-01-12T17:00:00` or `2026-01-12 17:00:00`
+pub fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S"))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+        .ok()
+}
+
+/// Parses a date such as `2026-02-09`
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// Parses a time of day such as `17:00` or `17:00:00`
+pub fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .ok()
+}
+
+/// Whether a time of day falls within a daily window.
+/// Windows that end before they start wrap around midnight (e.g. 22:00 to 02:00).
+pub fn is_within_daily_window(time: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start <= end {
+        time >= start && time < end
+    } else {
+        time >= start || time < end
+    }
+}
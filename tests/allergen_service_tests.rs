@@ -0,0 +1,184 @@
+use arrow_server_lib::data::models::allergen::{
+    IngredientAllergen, IngredientDietaryTag, ProductAllergen, ProductDietaryTag,
+};
+use arrow_server_lib::data::models::ingredient::Recipe;
+use arrow_server_lib::services::allergen_service::{
+    Allergen, DietaryFilter, DietaryProfile, DietaryTag, allergen_alerts, dietary_profiles,
+    parse_values,
+};
+use bigdecimal::BigDecimal;
+
+const BREAD: i32 = 1;
+const SALAD: i32 = 2;
+const PESTO_PASTA: i32 = 3;
+
+const FLOUR: i32 = 10;
+const LETTUCE: i32 = 11;
+const TOMATO: i32 = 12;
+const PESTO: i32 = 13;
+
+fn recipe(product_id: i32, ingredient_id: i32) -> Recipe {
+    Recipe {
+        product_id,
+        ingredient_id,
+        quantity: BigDecimal::from(1),
+    }
+}
+
+fn ingredient_tag(ingredient_id: i32, tag: &str) -> IngredientDietaryTag {
+    IngredientDietaryTag {
+        ingredient_id,
+        tag: tag.to_string(),
+    }
+}
+
+/// Bread is tagged vegan by hand and made of flour; the salad is lettuce and tomato, which
+/// are both vegan and gluten free; the pasta has pesto with nuts and milk
+fn profiles() -> Vec<DietaryProfile> {
+    let product_allergens = vec![ProductAllergen {
+        product_id: PESTO_PASTA,
+        allergen: "gluten".to_string(),
+    }];
+    let product_tags = vec![
+        ProductDietaryTag {
+            product_id: BREAD,
+            tag: "vegan".to_string(),
+        },
+        ProductDietaryTag {
+            product_id: PESTO_PASTA,
+            tag: "gluten_free".to_string(),
+        },
+    ];
+    let recipes = vec![
+        recipe(BREAD, FLOUR),
+        recipe(SALAD, LETTUCE),
+        recipe(SALAD, TOMATO),
+        recipe(PESTO_PASTA, PESTO),
+    ];
+    let ingredient_allergens = vec![
+        IngredientAllergen {
+            ingredient_id: FLOUR,
+            allergen: "gluten".to_string(),
+        },
+        IngredientAllergen {
+            ingredient_id: PESTO,
+            allergen: "nuts".to_string(),
+        },
+        IngredientAllergen {
+            ingredient_id: PESTO,
+            allergen: "milk".to_string(),
+        },
+    ];
+    let ingredient_tags = vec![
+        ingredient_tag(LETTUCE, "vegan"),
+        ingredient_tag(LETTUCE, "gluten_free"),
+        ingredient_tag(LETTUCE, "halal"),
+        ingredient_tag(TOMATO, "vegan"),
+        ingredient_tag(TOMATO, "gluten_free"),
+        ingredient_tag(PESTO, "vegetarian"),
+    ];
+
+    dietary_profiles(
+        &[BREAD, SALAD, PESTO_PASTA],
+        &product_allergens,
+        &product_tags,
+        &recipes,
+        &ingredient_allergens,
+        &ingredient_tags,
+    )
+}
+
+#[test]
+fn test_dietary_profiles_take_in_recipe_ingredients() {
+    let profiles = profiles();
+
+    assert_eq!(
+        profiles[0],
+        DietaryProfile {
+            product_id: BREAD,
+            allergens: vec![Allergen::Gluten],
+            dietary_tags: vec![DietaryTag::Vegan],
+        }
+    );
+    // Halal is only on the lettuce, so the salad is not
+    assert_eq!(
+        profiles[1],
+        DietaryProfile {
+            product_id: SALAD,
+            allergens: vec![],
+            dietary_tags: vec![DietaryTag::Vegan, DietaryTag::GlutenFree],
+        }
+    );
+    // Gluten free is dropped as the pasta contains gluten
+    assert_eq!(
+        profiles[2],
+        DietaryProfile {
+            product_id: PESTO_PASTA,
+            allergens: vec![Allergen::Gluten, Allergen::Milk, Allergen::Nuts],
+            dietary_tags: vec![DietaryTag::Vegetarian],
+        }
+    );
+}
+
+#[test]
+fn test_dietary_filter_excludes_allergens_and_requires_diets() {
+    let profiles = profiles();
+    let kept = |exclude: Option<&str>, diet: Option<&str>| -> Vec<i32> {
+        let filter = DietaryFilter::parse(exclude, diet).unwrap();
+        profiles
+            .iter()
+            .filter(|p| filter.matches(p))
+            .map(|p| p.product_id)
+            .collect()
+    };
+
+    assert_eq!(kept(None, None), vec![BREAD, SALAD, PESTO_PASTA]);
+    assert_eq!(kept(Some("nuts"), None), vec![BREAD, SALAD]);
+    assert_eq!(kept(None, Some("vegan")), vec![BREAD, SALAD]);
+    assert_eq!(kept(Some("Gluten, milk"), Some("vegan")), vec![SALAD]);
+    assert_eq!(kept(None, Some("vegan,gluten-free")), vec![SALAD]);
+}
+
+#[test]
+fn test_dietary_filter_rejects_unknown_values() {
+    assert!(
+        DietaryFilter::parse(Some(""), Some(","))
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        DietaryFilter::parse(Some("nuts,shellfish"), Some("keto")),
+        Err(vec!["shellfish".to_string(), "keto".to_string()])
+    );
+}
+
+#[test]
+fn test_parse_values_dedups_and_orders() {
+    let values = ["Soya", "celery", "soya"].map(String::from);
+
+    assert_eq!(
+        parse_values::<Allergen>(&values),
+        Ok(vec![Allergen::Celery, Allergen::Soya])
+    );
+    assert_eq!(parse_values::<DietaryTag>(&values), Err(values.to_vec()));
+}
+
+#[test]
+fn test_allergen_alerts_flag_ordered_items() {
+    let profiles = profiles();
+
+    assert_eq!(
+        allergen_alerts(
+            &[PESTO_PASTA, BREAD, SALAD],
+            &profiles,
+            &[Allergen::Nuts, Allergen::Gluten]
+        ),
+        vec![
+            (BREAD, Allergen::Gluten),
+            (PESTO_PASTA, Allergen::Gluten),
+            (PESTO_PASTA, Allergen::Nuts)
+        ]
+    );
+    assert!(allergen_alerts(&[SALAD], &profiles, &[Allergen::Nuts]).is_empty());
+    assert!(allergen_alerts(&[PESTO_PASTA], &profiles, &[]).is_empty());
+}
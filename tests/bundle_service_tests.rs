@@ -0,0 +1,148 @@
+use arrow_server_lib::api::request::{BundleChoiceRequest, BundleRequest, BundleSlotRequest};
+use arrow_server_lib::data::models::bundle::{BundleSlot, BundleSlotChoice, OrderBundleItem};
+use arrow_server_lib::services::bundle_service::{choose_items, expand_lines, is_valid_bundle};
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+const COMBO: i32 = 10;
+const BURGER: i32 = 1;
+const FRIES: i32 = 2;
+const COLA: i32 = 3;
+const SHAKE: i32 = 4;
+const DRINKS: i32 = 20;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn slot(slot_id: i32, quantity: i32) -> BundleSlot {
+    BundleSlot {
+        slot_id,
+        bundle_id: COMBO,
+        name: format!("Slot {}", slot_id),
+        quantity,
+        sort_order: slot_id,
+    }
+}
+
+fn choice(
+    slot_id: i32,
+    product: Option<i32>,
+    category: Option<i32>,
+    upcharge: &str,
+) -> BundleSlotChoice {
+    BundleSlotChoice {
+        choice_id: slot_id * 10 + product.or(category).unwrap(),
+        slot_id,
+        product_id: product,
+        category_id: category,
+        upcharge: dec(upcharge),
+    }
+}
+
+/// Burger + 2 sides of fries + a drink from the drinks category, with a shake at 1.50 extra
+fn combo() -> (Vec<BundleSlot>, Vec<BundleSlotChoice>, Vec<(i32, i32)>) {
+    let slots = vec![slot(1, 1), slot(2, 2), slot(3, 1)];
+    let choices = vec![
+        choice(1, Some(BURGER), None, "0"),
+        choice(2, Some(FRIES), None, "0"),
+        choice(3, None, Some(DRINKS), "0"),
+        choice(3, Some(SHAKE), None, "1.50"),
+    ];
+    let product_categories = vec![(COLA, DRINKS), (SHAKE, DRINKS)];
+    (slots, choices, product_categories)
+}
+
+fn item(product_id: i32, quantity: i32, upcharge: &str) -> OrderBundleItem {
+    OrderBundleItem {
+        order_id: 0,
+        bundle_id: COMBO,
+        product_id,
+        quantity,
+        upcharge: dec(upcharge),
+    }
+}
+
+#[test]
+fn test_choose_items_merges_picks_and_adds_upcharges() {
+    let (slots, choices, categories) = combo();
+    let selections = [(1, BURGER), (2, FRIES), (2, FRIES), (3, SHAKE)];
+
+    let items = choose_items(COMBO, &slots, &choices, &categories, &selections).unwrap();
+
+    assert_eq!(
+        items,
+        vec![
+            item(BURGER, 1, "0"),
+            item(FRIES, 2, "0"),
+            item(SHAKE, 1, "1.50")
+        ]
+    );
+}
+
+#[test]
+fn test_choose_items_accepts_products_of_chosen_categories() {
+    let (slots, choices, categories) = combo();
+    let selections = [(1, BURGER), (2, FRIES), (2, FRIES), (3, COLA)];
+
+    let items = choose_items(COMBO, &slots, &choices, &categories, &selections).unwrap();
+
+    assert_eq!(items[2], item(COLA, 1, "0"));
+}
+
+#[test]
+fn test_choose_items_rejects_selections_that_do_not_fit() {
+    let (slots, choices, categories) = combo();
+    let choose =
+        |selections: &[(i32, i32)]| choose_items(COMBO, &slots, &choices, &categories, selections);
+
+    // A side short, a product that is not a choice, a slot of another bundle, nothing
+    assert_eq!(choose(&[(1, BURGER), (2, FRIES), (3, COLA)]), None);
+    assert_eq!(
+        choose(&[(1, COLA), (2, FRIES), (2, FRIES), (3, COLA)]),
+        None
+    );
+    assert_eq!(
+        choose(&[(1, BURGER), (2, FRIES), (2, FRIES), (3, COLA), (9, COLA)]),
+        None
+    );
+    assert_eq!(choose(&[]), None);
+    assert_eq!(choose_items(99, &slots, &choices, &categories, &[]), None);
+}
+
+#[test]
+fn test_expand_lines_replaces_bundles_with_picks() {
+    let items = vec![item(BURGER, 1, "0"), item(FRIES, 2, "0")];
+
+    let lines = expand_lines(&[(COMBO, 3), (COLA, 1)], &items);
+
+    assert_eq!(lines, vec![(BURGER, 3), (FRIES, 6), (COLA, 1)]);
+}
+
+#[test]
+fn test_is_valid_bundle() {
+    let valid = |quantity: Option<i32>, product_id, category_id, upcharge: Option<&str>| {
+        let request = BundleRequest {
+            slots: vec![BundleSlotRequest {
+                name: "Drink".to_string(),
+                quantity,
+                choices: vec![BundleChoiceRequest {
+                    product_id,
+                    category_id,
+                    upcharge: upcharge.map(dec),
+                }],
+            }],
+        };
+        is_valid_bundle(COMBO, &request)
+    };
+
+    assert!(valid(None, Some(COLA), None, None));
+    assert!(valid(Some(2), None, Some(DRINKS), Some("0.50")));
+    assert!(is_valid_bundle(COMBO, &BundleRequest { slots: vec![] }));
+    // No pick, both a product and a category, neither, the bundle itself, a discount
+    assert!(!valid(Some(0), Some(COLA), None, None));
+    assert!(!valid(None, Some(COLA), Some(DRINKS), None));
+    assert!(!valid(None, None, None, None));
+    assert!(!valid(None, Some(COMBO), None, None));
+    assert!(!valid(None, Some(COLA), None, Some("-1")));
+}
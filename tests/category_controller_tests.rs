@@ -135,6 +135,8 @@ async fn create_test_category(name: &str) -> i32 {
     let category = NewCategory {
         name,
         description: Some("Test Category"),
+        parent_id: None,
+        sort_order: None,
     };
     repo.add(category).await.expect("Failed to add category");
     repo.get_by_name(name)
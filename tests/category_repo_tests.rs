@@ -18,6 +18,8 @@ async fn test_category_repo_crud() {
     let new_category = NewCategory {
         name: name,
         description: Some(description),
+        parent_id: None,
+        sort_order: None,
     };
     let add_result = repo.add(new_category).await;
     assert!(add_result.is_ok(), "Failed to add category");
@@ -36,6 +38,8 @@ async fn test_category_repo_crud() {
     let update_data = UpdateCategory {
         name: Some(updated_name),
         description: None,
+        parent_id: None,
+        sort_order: None,
     };
     let update_result = repo.update(category.category_id, update_data).await;
     assert!(update_result.is_ok(), "Failed to update category");
@@ -59,3 +63,83 @@ async fn test_category_repo_crud() {
         .expect("Failed to get category after delete");
     assert!(deleted_category.is_none(), "Category should be deleted");
 }
+
+#[tokio::test]
+#[serial_test::serial]
+async fn test_category_repo_nests_categories() {
+    let repo = CategoryRepo::new();
+    let parent_name = "Test Parent Category";
+    let child_name = "Test Child Category";
+
+    // Cleanup
+    for name in [child_name, parent_name] {
+        if let Ok(Some(cat)) = repo.get_by_name(name).await {
+            repo.delete(cat.category_id).await.ok();
+        }
+    }
+
+    let parent = NewCategory {
+        name: parent_name,
+        description: None,
+        parent_id: None,
+        sort_order: None,
+    };
+    assert!(
+        repo.add_nested(parent, |_| false)
+            .await
+            .expect("Failed to add parent")
+    );
+    let parent = repo
+        .get_by_name(parent_name)
+        .await
+        .expect("Failed to get parent")
+        .expect("Parent not found");
+
+    // The parent is checked and the child added with its place in one write
+    let child = NewCategory {
+        name: child_name,
+        description: None,
+        parent_id: Some(parent.category_id),
+        sort_order: Some(2),
+    };
+    let parent_id = parent.category_id;
+    assert!(
+        repo.add_nested(child, move |categories| {
+            categories.iter().any(|c| c.category_id == parent_id)
+        })
+        .await
+        .expect("Failed to add child")
+    );
+    let child = repo
+        .get_by_name(child_name)
+        .await
+        .expect("Failed to get child")
+        .expect("Child not found");
+    assert_eq!(child.parent_id, Some(parent.category_id));
+    assert_eq!(child.sort_order, 2);
+
+    // A rejected parent leaves the category as it was
+    let update = UpdateCategory {
+        name: Some("Renamed Child Category"),
+        description: None,
+        parent_id: Some(Some(child.category_id)),
+        sort_order: Some(5),
+    };
+    assert!(
+        !repo
+            .update_nested(parent.category_id, update, |_| false)
+            .await
+            .expect("Failed to update parent")
+    );
+    let unchanged = repo
+        .get_by_id(parent.category_id)
+        .await
+        .expect("Failed to get parent")
+        .expect("Parent not found");
+    assert_eq!(unchanged.name, parent_name);
+    assert_eq!(unchanged.parent_id, None);
+    assert_eq!(unchanged.sort_order, 0);
+
+    repo.delete(child.category_id).await.ok();
+    repo.delete(parent.category_id).await.ok();
+}
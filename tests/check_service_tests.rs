@@ -0,0 +1,156 @@
+use arrow_server_lib::data::models::order::Order;
+use arrow_server_lib::services::check_service::{split_components, split_order_checks};
+use arrow_server_lib::services::pricing_service::{PriceBreakdown, PriceComponents, RoundingRule};
+use bigdecimal::{BigDecimal, RoundingMode};
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn cents() -> RoundingRule {
+    RoundingRule::new(RoundingMode::HalfEven, dec("0.01"))
+}
+
+fn components(
+    subtotal: &str,
+    discount: &str,
+    inclusive_tax: &str,
+    exclusive_tax: &str,
+    service_charge: &str,
+) -> PriceComponents {
+    PriceComponents {
+        subtotal: dec(subtotal),
+        discount: dec(discount),
+        inclusive_tax: dec(inclusive_tax),
+        exclusive_tax: dec(exclusive_tax),
+        service_charge: dec(service_charge),
+    }
+}
+
+fn order(subtotal: &str, tax: &str, gratuity: &str) -> Order {
+    Order {
+        order_id: 1,
+        user_id: 1,
+        total_amount: dec(subtotal) + dec(tax),
+        status: Some("pending".to_string()),
+        created_at: None,
+        updated_at: None,
+        subtotal: dec(subtotal),
+        tax_amount: dec(tax),
+        service_charge: dec("0"),
+        discount_amount: dec("0"),
+        party_size: Some(8),
+        served_by: None,
+        gratuity_amount: dec(gratuity),
+        tip_amount: dec("0"),
+        order_type: "dine_in".to_string(),
+        table_id: None,
+        session_id: None,
+        business_date: None,
+        order_number: None,
+        scheduled_for: None,
+        estimated_ready_at: None,
+    }
+}
+
+fn sum(checks: &[PriceBreakdown], pick: fn(&PriceBreakdown) -> &BigDecimal) -> BigDecimal {
+    checks.iter().map(|c| pick(c).clone()).sum()
+}
+
+#[test]
+fn test_allocate_gives_leftover_cents_to_first_parts() {
+    let parts = cents().allocate(&dec("10.00"), &[dec("1"), dec("1"), dec("1")]);
+
+    assert_eq!(parts, vec![dec("3.34"), dec("3.33"), dec("3.33")]);
+}
+
+#[test]
+fn test_allocate_follows_weights() {
+    let parts = cents().allocate(&dec("10.00"), &[dec("3"), dec("1")]);
+
+    assert_eq!(parts, vec![dec("7.50"), dec("2.50")]);
+}
+
+#[test]
+fn test_allocate_with_zero_weights_splits_evenly() {
+    let parts = cents().allocate(&dec("1.00"), &[dec("0"), dec("0")]);
+
+    assert_eq!(parts, vec![dec("0.50"), dec("0.50")]);
+}
+
+#[test]
+fn test_even_split_adds_up_to_order() {
+    let order = components("31.00", "1.00", "0.00", "3.10", "2.79");
+    let weights = vec![PriceComponents::uniform(dec("1")); 3];
+
+    let checks = split_components(&order, &weights, &cents());
+
+    assert_eq!(checks.len(), 3);
+    assert_eq!(sum(&checks, |c| &c.subtotal), dec("31.00"));
+    assert_eq!(sum(&checks, |c| &c.discount_amount), dec("1.00"));
+    assert_eq!(sum(&checks, |c| &c.tax_amount), dec("3.10"));
+    assert_eq!(sum(&checks, |c| &c.service_charge), dec("2.79"));
+    assert_eq!(sum(&checks, |c| &c.total_amount), dec("35.89"));
+}
+
+#[test]
+fn test_item_split_keeps_tax_with_its_items() {
+    let order = components("30.00", "0.00", "0.00", "2.00", "0.00");
+    let weights = vec![
+        components("20.00", "0", "0", "2.00", "0"),
+        components("10.00", "0", "0", "0", "0"),
+    ];
+
+    let checks = split_components(&order, &weights, &cents());
+
+    assert_eq!(checks[0].tax_amount, dec("2.00"));
+    assert_eq!(checks[0].total_amount, dec("22.00"));
+    assert_eq!(checks[1].tax_amount, dec("0.00"));
+    assert_eq!(checks[1].total_amount, dec("10.00"));
+}
+
+#[test]
+fn test_share_split_divides_by_share() {
+    let order = components("40.00", "0.00", "0.00", "0.00", "0.00");
+    let weights = vec![
+        PriceComponents::uniform(dec("1")),
+        PriceComponents::uniform(dec("3")),
+    ];
+
+    let checks = split_components(&order, &weights, &cents());
+
+    assert_eq!(checks[0].total_amount, dec("10.00"));
+    assert_eq!(checks[1].total_amount, dec("30.00"));
+}
+
+#[test]
+fn test_split_checks_add_up_to_amount_due() {
+    let order = order("100.00", "10.00", "18.00");
+    let weights = vec![PriceComponents::uniform(dec("1")); 3];
+
+    let checks = split_order_checks(&order, &weights, &cents());
+
+    let due: BigDecimal = checks
+        .iter()
+        .map(|c| &c.total_amount + &c.gratuity_amount)
+        .sum();
+    assert_eq!(due, order.amount_due());
+    let gratuities: Vec<BigDecimal> = checks.iter().map(|c| c.gratuity_amount.clone()).collect();
+    assert_eq!(gratuities, vec![dec("6.00"), dec("6.00"), dec("6.00")]);
+}
+
+#[test]
+fn test_split_checks_divide_gratuity_by_subtotal() {
+    let order = order("40.00", "0.00", "8.00");
+    let weights = vec![
+        components("30.00", "0", "0", "0", "0"),
+        components("10.00", "0", "0", "0", "0"),
+    ];
+
+    let checks = split_order_checks(&order, &weights, &cents());
+
+    assert_eq!(checks[0].gratuity_amount, dec("6.00"));
+    assert_eq!(checks[1].gratuity_amount, dec("2.00"));
+    assert_eq!(checks[1].check_number, 2);
+}
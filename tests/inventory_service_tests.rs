@@ -0,0 +1,59 @@
+use arrow_server_lib::data::models::ingredient::{Ingredient, Recipe};
+use arrow_server_lib::services::inventory_service::{short_ingredients, stock_needs};
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn recipe(product_id: i32, ingredient_id: i32, quantity: &str) -> Recipe {
+    Recipe {
+        product_id,
+        ingredient_id,
+        quantity: dec(quantity),
+    }
+}
+
+fn ingredient(ingredient_id: i32, on_hand: &str) -> Ingredient {
+    Ingredient {
+        ingredient_id,
+        name: format!("Ingredient {}", ingredient_id),
+        unit: "kg".to_string(),
+        on_hand: dec(on_hand),
+        reorder_level: dec("0"),
+        created_at: None,
+        updated_at: None,
+        supplier_id: None,
+        par_level: dec("0"),
+        average_cost: dec("0"),
+    }
+}
+
+#[test]
+fn test_stock_needs_adds_up_recipes_per_ingredient() {
+    let recipes = vec![
+        recipe(1, 10, "0.150"),
+        recipe(1, 20, "1"),
+        recipe(2, 10, "0.200"),
+    ];
+
+    let needs = stock_needs(&[(1, 2), (2, 3), (3, 5)], &recipes);
+
+    assert_eq!(needs, vec![(10, dec("0.900")), (20, dec("2"))]);
+}
+
+#[test]
+fn test_stock_needs_without_recipes_is_empty() {
+    let needs = stock_needs(&[(1, 2)], &[]);
+
+    assert!(needs.is_empty(), "Products without a recipe need no stock");
+}
+
+#[test]
+fn test_short_ingredients_reports_uncovered_and_unknown_ingredients() {
+    let needs = vec![(10, dec("0.900")), (20, dec("2")), (30, dec("1"))];
+    let stock = vec![ingredient(10, "0.900"), ingredient(20, "1.5")];
+
+    assert_eq!(short_ingredients(&needs, &stock), vec![20, 30]);
+}
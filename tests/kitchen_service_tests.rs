@@ -0,0 +1,137 @@
+use arrow_server_lib::data::models::kitchen_ticket::RoutedTicket;
+use arrow_server_lib::data::models::station::{StationCategory, StationProduct};
+use arrow_server_lib::services::kitchen_service::{
+    QueuedTicket, estimate_ready_times, route_lines,
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::HashMap;
+
+fn product_route(product_id: i32, station_id: i32) -> StationProduct {
+    StationProduct {
+        product_id,
+        station_id,
+    }
+}
+
+fn category_route(category_id: i32, station_id: i32) -> StationCategory {
+    StationCategory {
+        category_id,
+        station_id,
+    }
+}
+
+fn queued(order_id: i32, station_id: Option<i32>, prep_minutes: i32) -> QueuedTicket {
+    QueuedTicket {
+        order_id,
+        station_id,
+        prep_minutes,
+    }
+}
+
+fn at(hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 3, 23)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn ticket(station_id: Option<i32>, items: Vec<(i32, i32)>) -> RoutedTicket {
+    RoutedTicket { station_id, items }
+}
+
+#[test]
+fn test_route_lines_splits_order_per_station() {
+    let products = vec![product_route(1, 10)];
+    let categories = vec![category_route(5, 20)];
+    let product_categories = vec![(2, 5), (3, 5)];
+
+    let tickets = route_lines(
+        &[(1, 2), (2, 1), (3, 4)],
+        &products,
+        &categories,
+        &product_categories,
+    );
+
+    assert_eq!(
+        tickets,
+        vec![
+            ticket(Some(10), vec![(1, 2)]),
+            ticket(Some(20), vec![(2, 1), (3, 4)]),
+        ]
+    );
+}
+
+#[test]
+fn test_route_lines_product_mapping_wins_over_category() {
+    let products = vec![product_route(1, 10)];
+    let categories = vec![category_route(5, 20), category_route(4, 30)];
+    let product_categories = vec![(1, 5), (2, 5), (2, 4)];
+
+    let tickets = route_lines(
+        &[(1, 1), (2, 1)],
+        &products,
+        &categories,
+        &product_categories,
+    );
+
+    assert_eq!(
+        tickets,
+        vec![
+            ticket(Some(10), vec![(1, 1)]),
+            ticket(Some(30), vec![(2, 1)])
+        ]
+    );
+}
+
+#[test]
+fn test_route_lines_keeps_unrouted_items() {
+    let products = vec![product_route(1, 10)];
+
+    let tickets = route_lines(&[(1, 1), (7, 2), (7, 1)], &products, &[], &[(7, 3)]);
+
+    assert_eq!(
+        tickets,
+        vec![ticket(None, vec![(7, 3)]), ticket(Some(10), vec![(1, 1)])]
+    );
+}
+
+#[test]
+fn test_estimate_ready_times_queues_behind_earlier_orders() {
+    let queue = vec![queued(1, Some(10), 10), queued(2, Some(10), 5)];
+
+    let ready = estimate_ready_times(at(12, 0), &queue, &HashMap::new());
+
+    assert_eq!(ready[&1], at(12, 10));
+    assert_eq!(ready[&2], at(12, 15));
+}
+
+#[test]
+fn test_estimate_ready_times_uses_station_capacity() {
+    let queue = vec![
+        queued(1, Some(10), 10),
+        queued(2, Some(10), 10),
+        queued(3, Some(10), 10),
+    ];
+    let capacities = HashMap::from([(10, 2)]);
+
+    let ready = estimate_ready_times(at(12, 0), &queue, &capacities);
+
+    assert_eq!(ready[&1], at(12, 10));
+    assert_eq!(ready[&2], at(12, 10));
+    assert_eq!(ready[&3], at(12, 20));
+}
+
+#[test]
+fn test_estimate_ready_times_waits_for_slowest_station() {
+    let queue = vec![
+        queued(1, Some(10), 20),
+        queued(2, Some(10), 5),
+        queued(2, Some(20), 8),
+        queued(2, None, 0),
+    ];
+
+    let ready = estimate_ready_times(at(12, 0), &queue, &HashMap::new());
+
+    assert_eq!(ready[&1], at(12, 20));
+    assert_eq!(ready[&2], at(12, 25));
+}
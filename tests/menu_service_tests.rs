@@ -0,0 +1,116 @@
+use arrow_server_lib::data::models::menu::{Menu, MenuCategory, MenuProduct, MenuSchedule};
+use arrow_server_lib::services::menu_service::{
+    day_name, is_menu_open, parse_day, products_off_menu,
+};
+use arrow_server_lib::utils::time::to_offset;
+use chrono::{FixedOffset, NaiveDateTime, NaiveTime};
+
+fn at(value: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+}
+
+fn time(value: &str) -> NaiveTime {
+    NaiveTime::parse_from_str(value, "%H:%M").unwrap()
+}
+
+fn offset(value: &str) -> FixedOffset {
+    value.parse().unwrap()
+}
+
+fn menu(menu_id: i32, utc_offset: Option<&str>, is_active: bool) -> Menu {
+    Menu {
+        menu_id,
+        name: format!("Menu {}", menu_id),
+        description: None,
+        utc_offset: utc_offset.map(str::to_string),
+        is_active,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn window(menu_id: i32, day_of_week: i32, start: &str, end: &str) -> MenuSchedule {
+    MenuSchedule {
+        schedule_id: 0,
+        menu_id,
+        day_of_week,
+        start_time: time(start),
+        end_time: time(end),
+    }
+}
+
+#[test]
+fn test_days_parse_and_name_from_monday() {
+    assert_eq!(parse_day("mon"), Some(0));
+    assert_eq!(parse_day("Sunday"), Some(6));
+    assert_eq!(parse_day("someday"), None);
+    assert_eq!(day_name(4), "friday");
+}
+
+#[test]
+fn test_window_is_open_on_its_day_and_past_midnight() {
+    // 2026-05-11 is a Monday
+    let breakfast = window(1, 0, "07:00", "11:00");
+    assert!(breakfast.is_open_at(at("2026-05-11 07:00")));
+    assert!(!breakfast.is_open_at(at("2026-05-11 11:00")));
+    assert!(!breakfast.is_open_at(at("2026-05-12 08:00")));
+
+    let late = window(2, 4, "22:00", "02:00");
+    assert!(late.is_open_at(at("2026-05-15 23:30")));
+    assert!(late.is_open_at(at("2026-05-16 01:59")));
+    assert!(!late.is_open_at(at("2026-05-15 01:00")));
+}
+
+#[test]
+fn test_menu_is_open_in_its_own_offset() {
+    let windows = vec![window(1, 0, "07:00", "11:00")];
+    let open = |menu: &Menu, when: &str| is_menu_open(menu, &windows, at(when), offset("+00:00"));
+
+    // 09:00 in Tokyo is 00:00 in business time
+    let tokyo = menu(1, Some("+09:00"), true);
+    assert!(open(&tokyo, "2026-05-11 00:00"));
+    assert!(!open(&tokyo, "2026-05-11 09:00"));
+
+    assert!(open(&menu(1, None, true), "2026-05-11 09:00"));
+    assert!(!open(&menu(1, None, false), "2026-05-11 09:00"));
+    // Menu 2 has no windows, so it is served all day
+    assert!(open(&menu(2, None, true), "2026-05-13 03:00"));
+}
+
+#[test]
+fn test_to_offset_converts_between_offsets() {
+    assert_eq!(
+        to_offset(at("2026-05-11 12:00"), offset("+02:00"), offset("-05:00")),
+        at("2026-05-11 05:00")
+    );
+}
+
+#[test]
+fn test_products_off_menu_only_lists_products_on_closed_menus() {
+    let menu_products = vec![
+        MenuProduct {
+            menu_id: 1,
+            product_id: 10,
+        },
+        MenuProduct {
+            menu_id: 2,
+            product_id: 11,
+        },
+    ];
+    let menu_categories = vec![MenuCategory {
+        menu_id: 1,
+        category_id: 5,
+    }];
+    let product_categories = vec![(12, 5), (13, 6)];
+
+    // Menu 2 is served, menu 1 is not; product 13 is on no menu
+    let off_menu = products_off_menu(
+        &[10, 11, 12, 13, 10],
+        &[2],
+        &menu_products,
+        &menu_categories,
+        &product_categories,
+    );
+
+    assert_eq!(off_menu, vec![10, 12]);
+}
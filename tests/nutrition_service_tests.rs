@@ -0,0 +1,152 @@
+use arrow_server_lib::api::request::{IngredientNutritionRequest, NutritionRequest};
+use arrow_server_lib::data::models::ingredient::Recipe;
+use arrow_server_lib::data::models::nutrition::{IngredientNutrition, ProductNutrition};
+use arrow_server_lib::services::nutrition_service::{
+    is_valid_ingredient_nutrition, is_valid_nutrition, resolve_nutrition,
+};
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+const BURGER: i32 = 1;
+const BUN: i32 = 10;
+const PATTY: i32 = 11;
+const CHEESE: i32 = 12;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn recipe(ingredient_id: i32, quantity: &str) -> Recipe {
+    Recipe {
+        product_id: BURGER,
+        ingredient_id,
+        quantity: dec(quantity),
+    }
+}
+
+/// kcal, protein, carbohydrates, sugars, fat and salt of one unit
+fn ingredient(ingredient_id: i32, values: [&str; 6]) -> IngredientNutrition {
+    IngredientNutrition {
+        ingredient_id,
+        kcal: dec(values[0]),
+        protein: dec(values[1]),
+        carbohydrates: dec(values[2]),
+        sugars: dec(values[3]),
+        fat: dec(values[4]),
+        salt: dec(values[5]),
+    }
+}
+
+fn facts(values: [&str; 6], from_recipe: bool) -> ProductNutrition {
+    ProductNutrition {
+        product_id: BURGER,
+        serving_size: Some("1 burger".to_string()),
+        kcal: dec(values[0]),
+        protein: dec(values[1]),
+        carbohydrates: dec(values[2]),
+        sugars: dec(values[3]),
+        fat: dec(values[4]),
+        salt: dec(values[5]),
+        from_recipe,
+    }
+}
+
+/// A bun counted in pieces, and the patty and cheese in kg
+fn burger() -> (Vec<Recipe>, Vec<IngredientNutrition>) {
+    let recipes = vec![
+        recipe(BUN, "1"),
+        recipe(PATTY, "0.15"),
+        recipe(CHEESE, "0.02"),
+    ];
+    let ingredients = vec![
+        ingredient(BUN, ["150", "5", "28", "4", "2", "0.45"]),
+        ingredient(PATTY, ["2500", "260", "0", "0", "160", "1.2"]),
+        ingredient(CHEESE, ["4030", "250", "13", "5", "330", "1.8"]),
+    ];
+    (recipes, ingredients)
+}
+
+#[test]
+fn test_resolve_nutrition_sums_recipe_ingredients() {
+    let (recipes, ingredients) = burger();
+    let stored = facts(["0", "0", "0", "0", "0", "0"], true);
+
+    let resolved = resolve_nutrition(&stored, &recipes, &ingredients).unwrap();
+
+    assert_eq!(
+        resolved,
+        facts(["605.60", "49.00", "28.26", "4.10", "32.60", "0.67"], true)
+    );
+}
+
+#[test]
+fn test_resolve_nutrition_keeps_values_set_by_hand() {
+    let (recipes, ingredients) = burger();
+    let stored = facts(["550", "30", "40", "6", "28", "1.1"], false);
+
+    assert_eq!(
+        resolve_nutrition(&stored, &recipes, &ingredients),
+        Some(stored)
+    );
+}
+
+#[test]
+fn test_resolve_nutrition_needs_a_complete_recipe() {
+    let (recipes, ingredients) = burger();
+    let stored = facts(["0", "0", "0", "0", "0", "0"], true);
+
+    assert_eq!(resolve_nutrition(&stored, &[], &ingredients), None);
+    assert_eq!(
+        resolve_nutrition(&stored, &recipes, &ingredients[..2]),
+        None
+    );
+}
+
+#[test]
+fn test_is_valid_nutrition() {
+    let full = NutritionRequest {
+        serving_size: Some("1 burger (250 g)".to_string()),
+        from_recipe: None,
+        kcal: Some(dec("550")),
+        protein: Some(dec("30")),
+        carbohydrates: Some(dec("40")),
+        sugars: Some(dec("6")),
+        fat: Some(dec("28")),
+        salt: Some(dec("1.1")),
+    };
+    let from_recipe = NutritionRequest {
+        from_recipe: Some(true),
+        ..NutritionRequest::default()
+    };
+
+    assert!(is_valid_nutrition(&full));
+    assert!(is_valid_nutrition(&from_recipe));
+    // A value missing, a negative value, a blank serving size
+    assert!(!is_valid_nutrition(&NutritionRequest {
+        salt: None,
+        ..full.clone()
+    }));
+    assert!(!is_valid_nutrition(&NutritionRequest {
+        kcal: Some(dec("-1")),
+        ..from_recipe.clone()
+    }));
+    assert!(!is_valid_nutrition(&NutritionRequest {
+        serving_size: Some(" ".to_string()),
+        ..full
+    }));
+}
+
+#[test]
+fn test_is_valid_ingredient_nutrition() {
+    let request = |salt: &str| IngredientNutritionRequest {
+        kcal: dec("150"),
+        protein: dec("5"),
+        carbohydrates: dec("28"),
+        sugars: dec("4"),
+        fat: dec("2"),
+        salt: dec(salt),
+    };
+
+    assert!(is_valid_ingredient_nutrition(&request("0")));
+    assert!(!is_valid_ingredient_nutrition(&request("-0.1")));
+}
@@ -1,3 +1,7 @@
+use arrow_server_lib::api::config::parse_opening_hours;
+use arrow_server_lib::api::request::{
+    BundleChoiceRequest, BundleRequest, BundleSlotRequest, OrderItemRequest, VoidItemsRequest,
+};
 use arrow_server_lib::data::database::*;
 use arrow_server_lib::data::models::product::NewProduct;
 use arrow_server_lib::data::models::user::NewUser;
@@ -7,11 +11,19 @@ use arrow_server_lib::data::repos::implementors::user_repo::UserRepo;
 use arrow_server_lib::data::repos::implementors::role_repo::RoleRepo;
 use arrow_server_lib::data::repos::traits::repository::Repository;
 use arrow_server_lib::security::auth::AuthService;
+use arrow_server_lib::services::bundle_service::BundleService;
 use arrow_server_lib::services::errors::OrderServiceError;
-use arrow_server_lib::services::order_service::{OrderService, OrderStatus};
+use arrow_server_lib::services::order_service::{
+    OrderOptions, OrderService, OrderStatus, ReasonCode, format_order_number, initial_status,
+    is_valid_schedule, parse_order_number, void_lines,
+};
+use arrow_server_lib::services::pricing_service::PricingLine;
+use arrow_server_lib::utils::time::business_date;
 use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use diesel::result;
 use diesel_async::RunQueryDsl;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 async fn setup() -> Result<(), result::Error> {
@@ -186,6 +198,112 @@ async fn test_create_order_without_permission() {
     );
 }
 
+#[tokio::test]
+#[serial_test::serial]
+async fn test_create_order_rejects_unavailable_products() {
+    setup().await.expect("Setup failed");
+
+    let user_id = create_test_user("write_user").await;
+    let role_id = create_role_with_permission("writer", RolePermissions::Write).await;
+    let product_id = create_test_product().await;
+
+    ProductRepo::new()
+        .set_availability(product_id, false, None)
+        .await
+        .expect("Failed to 86 product");
+
+    let service = OrderService::new();
+
+    let result = service
+        .create_order(
+            user_id,
+            role_id,
+            vec![(product_id, 1), (product_id, 2)],
+        )
+        .await;
+
+    assert_eq!(
+        result.err(),
+        Some(OrderServiceError::ProductsUnavailable(vec![product_id])),
+        "Should not be able to order an 86'd product"
+    );
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn test_create_order_rejects_archived_products() {
+    setup().await.expect("Setup failed");
+
+    let user_id = create_test_user("write_user").await;
+    let role_id = create_role_with_permission("writer", RolePermissions::Write).await;
+    let admin_role_id = create_role_with_permission("admin", RolePermissions::Admin).await;
+    let product_id = create_test_product().await;
+
+    let repo = ProductRepo::new();
+    repo.add(NewProduct {
+        name: "ServiceTestSide",
+        product_image_uri: None,
+        description: None,
+        price: BigDecimal::from_str("3.00").unwrap(),
+    })
+    .await
+    .expect("Failed to add product");
+    let side_id = repo
+        .get_by_name("ServiceTestSide")
+        .await
+        .expect("Failed to get product")
+        .expect("Product not found")
+        .product_id;
+
+    // The product becomes a bundle whose only slot is filled with the side
+    let bundle = BundleService::new()
+        .set_bundle(
+            product_id,
+            &BundleRequest {
+                slots: vec![BundleSlotRequest {
+                    name: "Side".to_string(),
+                    quantity: None,
+                    choices: vec![BundleChoiceRequest {
+                        product_id: Some(side_id),
+                        category_id: None,
+                        upcharge: None,
+                    }],
+                }],
+            },
+            admin_role_id,
+        )
+        .await
+        .expect("Failed to set bundle");
+    let selections = HashMap::from([(product_id, vec![(bundle.slots[0].slot_id, side_id)])]);
+
+    let service = OrderService::new();
+    let order = |items: Vec<(i32, i32)>| {
+        service.create_order_with_options(
+            user_id,
+            role_id,
+            items,
+            OrderOptions {
+                bundle_selections: selections.clone(),
+                ..OrderOptions::default()
+            },
+        )
+    };
+
+    assert!(repo.archive(side_id).await.expect("Failed to archive side"));
+    assert_eq!(
+        order(vec![(product_id, 1)]).await.err(),
+        Some(OrderServiceError::ProductsArchived(vec![side_id])),
+        "Should not be able to pick an archived product for a bundle"
+    );
+
+    assert!(repo.archive(product_id).await.expect("Failed to archive product"));
+    assert_eq!(
+        order(vec![(product_id, 1), (product_id, 2)]).await.err(),
+        Some(OrderServiceError::ProductsArchived(vec![product_id, side_id])),
+        "Should not be able to order an archived product"
+    );
+}
+
 #[tokio::test]
 #[serial_test::serial]
 async fn test_get_user_own_orders() {
@@ -685,4 +803,174 @@ async fn test_order_status_enum() {
         Ok(OrderStatus::Cancelled)
     );
     assert_eq!(OrderStatus::from_str("invalid"), Err(()));
-}
\ No newline at end of file
+}
+
+fn pricing_line(product_id: i32, quantity: i32, discount: &str) -> PricingLine {
+    PricingLine {
+        product_id,
+        quantity,
+        unit_price: BigDecimal::from_str("10.00").unwrap(),
+        taxes: vec![],
+        discount: BigDecimal::from_str(discount).unwrap(),
+    }
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn test_void_items_requires_refund_permission() {
+    setup().await.expect("Setup failed");
+
+    let user_id = create_test_user("voider").await;
+    let write_role_id = create_role_with_permission("writer", RolePermissions::Write).await;
+    let admin_role_id = create_role_with_permission("admin", RolePermissions::Admin).await;
+    let product_id = create_test_product().await;
+
+    let service = OrderService::new();
+
+    service
+        .create_order(user_id, write_role_id, vec![(product_id, 2)])
+        .await
+        .expect("Failed to create order");
+
+    let orders = service
+        .get_user_orders(user_id, admin_role_id)
+        .await
+        .expect("Failed to get orders")
+        .expect("No orders");
+    let order_id = orders[0].0.order_id;
+
+    let request = VoidItemsRequest {
+        items: vec![OrderItemRequest {
+            product_id,
+            quantity: 1,
+        }],
+        reason_code: "wrong_item".to_string(),
+        note: None,
+    };
+
+    let result = service
+        .void_items(order_id, user_id, write_role_id, &request)
+        .await;
+
+    assert_eq!(
+        result.err(),
+        Some(OrderServiceError::PermissionDenied),
+        "Write role should not void items (requires REFUND or ADMIN)"
+    );
+}
+
+#[test]
+fn test_void_lines_splits_quantity_and_discount() {
+    let lines = vec![pricing_line(1, 4, "2.00"), pricing_line(2, 1, "0")];
+
+    let (remaining, voided) = void_lines(&lines, &[(1, 1)]).unwrap();
+
+    assert_eq!(remaining.len(), 2);
+    assert_eq!(remaining[0].quantity, 3);
+    assert_eq!(remaining[0].discount, BigDecimal::from_str("1.50").unwrap());
+    assert_eq!(voided.len(), 1);
+    assert_eq!(voided[0].quantity, 1);
+    assert_eq!(voided[0].discount, BigDecimal::from_str("0.50").unwrap());
+}
+
+#[test]
+fn test_void_lines_drops_fully_voided_lines() {
+    let lines = vec![pricing_line(1, 2, "0"), pricing_line(2, 1, "0")];
+
+    let (remaining, voided) = void_lines(&lines, &[(2, 1)]).unwrap();
+
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].product_id, 1);
+    assert_eq!(voided[0].product_id, 2);
+}
+
+#[test]
+fn test_void_lines_rejects_invalid_voids() {
+    let lines = vec![pricing_line(1, 2, "0")];
+
+    assert_eq!(
+        void_lines(&lines, &[(1, 3)]).err(),
+        Some(OrderServiceError::InvalidVoid)
+    );
+    assert_eq!(
+        void_lines(&lines, &[(1, 0)]).err(),
+        Some(OrderServiceError::InvalidVoid)
+    );
+    assert_eq!(
+        void_lines(&lines, &[(9, 1)]).err(),
+        Some(OrderServiceError::InvalidVoid)
+    );
+    assert_eq!(
+        void_lines(&lines, &[(1, 1), (1, 1)]).err(),
+        Some(OrderServiceError::InvalidVoid)
+    );
+    assert_eq!(void_lines(&lines, &[]).err(), Some(OrderServiceError::InvalidVoid));
+}
+
+#[test]
+fn test_reason_code_parsing() {
+    assert_eq!(
+        ReasonCode::from_str("WRONG_ITEM"),
+        Ok(ReasonCode::WrongItem)
+    );
+    assert_eq!(ReasonCode::QualityIssue.as_str(), "quality_issue");
+    assert_eq!(ReasonCode::from_str("because"), Err(()));
+}
+
+#[test]
+fn test_order_number_formatting_and_parsing() {
+    assert_eq!(format_order_number(7), "#007");
+    assert_eq!(format_order_number(1234), "#1234");
+    assert_eq!(parse_order_number(" #007 "), Some(7));
+    assert_eq!(parse_order_number("12"), Some(12));
+    assert_eq!(parse_order_number("#0"), None);
+    assert_eq!(parse_order_number("next"), None);
+}
+
+#[test]
+fn test_business_date_rolls_over_at_day_start() {
+    let day_start = NaiveTime::from_hms_opt(4, 0, 0).unwrap();
+    let at = |value: &str| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap();
+
+    assert_eq!(
+        business_date(at("2026-03-03 01:30"), day_start),
+        NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+    );
+    assert_eq!(
+        business_date(at("2026-03-03 04:00"), day_start),
+        NaiveDate::from_ymd_opt(2026, 3, 3).unwrap()
+    );
+}
+
+fn datetime(value: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+}
+
+#[test]
+fn test_schedule_must_be_future_and_within_opening_hours() {
+    let now = datetime("2026-03-09 12:00");
+    let hours = parse_opening_hours("11:00-22:00");
+
+    assert!(is_valid_schedule(datetime("2026-03-10 18:00"), now, hours));
+    assert!(!is_valid_schedule(datetime("2026-03-09 11:30"), now, hours));
+    assert!(!is_valid_schedule(datetime("2026-03-10 23:00"), now, hours));
+    assert!(is_valid_schedule(datetime("2026-03-10 23:00"), now, None));
+    assert_eq!(parse_opening_hours("late"), None);
+}
+
+#[test]
+fn test_scheduled_orders_wait_until_lead_time() {
+    let now = datetime("2026-03-09 17:00");
+    let lead = Duration::minutes(30);
+
+    assert_eq!(initial_status(None, now, lead), OrderStatus::Pending);
+    assert_eq!(
+        initial_status(Some(datetime("2026-03-09 18:00")), now, lead),
+        OrderStatus::Scheduled
+    );
+    assert_eq!(
+        initial_status(Some(datetime("2026-03-09 17:20")), now, lead),
+        OrderStatus::Pending
+    );
+    assert_eq!(OrderStatus::from_str("scheduled"), Ok(OrderStatus::Scheduled));
+}
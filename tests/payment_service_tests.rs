@@ -0,0 +1,130 @@
+use arrow_server_lib::data::models::payment::Payment;
+use arrow_server_lib::data::models::refund::Refund;
+use arrow_server_lib::services::payment_gateway::{
+    CardTerminalGateway, CashGateway, ChargeRequest, GatewayError, MockGateway, PaymentGateway,
+};
+use arrow_server_lib::services::payment_service::PaymentSummary;
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn charge(amount: &str, reference: Option<&str>) -> ChargeRequest {
+    ChargeRequest {
+        order_id: 7,
+        amount: dec(amount),
+        reference: reference.map(str::to_string),
+    }
+}
+
+fn payment(payment_id: i32, amount: &str, status: &str) -> Payment {
+    Payment {
+        payment_id,
+        order_id: 1,
+        amount: dec(amount),
+        method: "cash".to_string(),
+        provider: "cash".to_string(),
+        status: status.to_string(),
+        provider_reference: None,
+        created_at: None,
+        updated_at: None,
+        check_id: None,
+        tip_amount: dec("0"),
+    }
+}
+
+fn refund(payment_id: i32, amount: &str) -> Refund {
+    Refund {
+        refund_id: 1,
+        payment_id,
+        amount: dec(amount),
+        reason: None,
+        provider_reference: None,
+        created_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_mock_gateway_is_deterministic() {
+    let gateway = MockGateway::new();
+
+    let first = gateway.capture(&charge("12.50", None)).await.unwrap();
+    let second = gateway.capture(&charge("12.50", None)).await.unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(first.provider_reference.as_deref(), Some("mock-7-12.50"));
+}
+
+#[tokio::test]
+async fn test_declining_mock_gateway_declines() {
+    let gateway = MockGateway::declining();
+
+    let result = gateway.capture(&charge("12.50", None)).await;
+
+    assert_eq!(result, Err(GatewayError::Declined));
+}
+
+#[tokio::test]
+async fn test_cash_gateway_always_captures() {
+    let receipt = CashGateway.capture(&charge("5.00", None)).await.unwrap();
+
+    assert_eq!(receipt.provider_reference, None);
+}
+
+#[tokio::test]
+async fn test_card_terminal_requires_approval_code() {
+    let missing = CardTerminalGateway.capture(&charge("5.00", None)).await;
+    let approved = CardTerminalGateway
+        .capture(&charge("5.00", Some(" A1B2C3 ")))
+        .await
+        .unwrap();
+
+    assert_eq!(missing, Err(GatewayError::Declined));
+    assert_eq!(approved.provider_reference.as_deref(), Some("A1B2C3"));
+}
+
+#[test]
+fn test_summary_without_payments_is_fully_due() {
+    let summary = PaymentSummary::new(&dec("25.00"), &[], &[]);
+
+    assert_eq!(summary.amount_paid, dec("0"));
+    assert_eq!(summary.balance_due, dec("25.00"));
+}
+
+#[test]
+fn test_summary_ignores_failed_payments() {
+    let payments = vec![
+        payment(1, "10.00", "captured"),
+        payment(2, "15.00", "failed"),
+    ];
+
+    let summary = PaymentSummary::new(&dec("25.00"), &payments, &[]);
+
+    assert_eq!(summary.amount_paid, dec("10.00"));
+    assert_eq!(summary.balance_due, dec("15.00"));
+}
+
+#[test]
+fn test_summary_subtracts_refunds() {
+    let payments = vec![payment(1, "25.00", "partially_refunded")];
+    let refunds = vec![refund(1, "5.00"), refund(99, "3.00")];
+
+    let summary = PaymentSummary::new(&dec("25.00"), &payments, &refunds);
+
+    assert_eq!(summary.amount_paid, dec("20.00"));
+    assert_eq!(summary.balance_due, dec("5.00"));
+}
+
+#[test]
+fn test_summary_holds_the_balance_of_pending_payments() {
+    let payments = vec![
+        payment(1, "10.00", "captured"),
+        payment(2, "15.00", "pending"),
+    ];
+
+    let summary = PaymentSummary::new(&dec("25.00"), &payments, &[]);
+
+    assert_eq!(summary.balance_due, dec("0.00"));
+}
@@ -0,0 +1,152 @@
+use arrow_server_lib::data::models::tax_rate::TaxRate;
+use arrow_server_lib::services::pricing_service::{
+    LineTax, PricingEngine, PricingLine, RoundingRule,
+};
+use bigdecimal::{BigDecimal, RoundingMode};
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn engine(service_charge_percent: &str) -> PricingEngine {
+    PricingEngine::new(
+        dec(service_charge_percent),
+        RoundingRule::new(RoundingMode::HalfEven, dec("0.01")),
+    )
+}
+
+fn line(unit_price: &str, quantity: i32, taxes: Vec<LineTax>) -> PricingLine {
+    PricingLine {
+        product_id: 1,
+        quantity,
+        unit_price: dec(unit_price),
+        taxes,
+        discount: dec("0"),
+    }
+}
+
+fn tax(percent: &str, inclusive: bool) -> LineTax {
+    LineTax {
+        percent: dec(percent),
+        inclusive,
+    }
+}
+
+fn category_rate(tax_rate_id: i32, category_id: i32, percent: &str) -> TaxRate {
+    TaxRate {
+        tax_rate_id,
+        name: format!("Tax {}", tax_rate_id),
+        rate: dec(percent),
+        is_inclusive: false,
+        product_id: None,
+        category_id: Some(category_id),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[test]
+fn test_price_without_taxes_is_plain_sum() {
+    let breakdown = engine("0").price(&[line("15.00", 2, vec![]), line("2.50", 1, vec![])]);
+
+    assert_eq!(breakdown.subtotal, dec("32.50"));
+    assert_eq!(breakdown.tax_amount, dec("0.00"));
+    assert_eq!(breakdown.service_charge, dec("0.00"));
+    assert_eq!(breakdown.total_amount, dec("32.50"));
+}
+
+#[test]
+fn test_price_with_exclusive_tax() {
+    let breakdown = engine("0").price(&[line("10.00", 3, vec![tax("12", false)])]);
+
+    assert_eq!(breakdown.subtotal, dec("30.00"));
+    assert_eq!(breakdown.tax_amount, dec("3.60"));
+    assert_eq!(breakdown.total_amount, dec("33.60"));
+}
+
+#[test]
+fn test_price_with_inclusive_tax_does_not_change_total() {
+    let breakdown = engine("0").price(&[line("11.20", 1, vec![tax("12", true)])]);
+
+    assert_eq!(breakdown.subtotal, dec("11.20"));
+    assert_eq!(breakdown.tax_amount, dec("1.20"));
+    assert_eq!(breakdown.total_amount, dec("11.20"));
+}
+
+#[test]
+fn test_service_charge_is_computed_on_pre_tax_subtotal() {
+    let breakdown = engine("10").price(&[line("11.20", 1, vec![tax("12", true)])]);
+
+    assert_eq!(breakdown.service_charge, dec("1.00"));
+    assert_eq!(breakdown.total_amount, dec("12.20"));
+}
+
+#[test]
+fn test_stacked_exclusive_taxes() {
+    let breakdown =
+        engine("0").price(&[line("20.00", 1, vec![tax("5", false), tax("2.5", false)])]);
+
+    assert_eq!(breakdown.tax_amount, dec("1.50"));
+    assert_eq!(breakdown.total_amount, dec("21.50"));
+}
+
+#[test]
+fn test_half_even_rounding() {
+    let rule = RoundingRule::new(RoundingMode::HalfEven, dec("0.01"));
+
+    assert_eq!(rule.round(&dec("0.125")), dec("0.12"));
+    assert_eq!(rule.round(&dec("0.135")), dec("0.14"));
+}
+
+#[test]
+fn test_rounding_to_increment() {
+    let rule = RoundingRule::new(RoundingMode::HalfUp, dec("0.05"));
+
+    assert_eq!(rule.round(&dec("1.12")), dec("1.10"));
+    assert_eq!(rule.round(&dec("1.13")), dec("1.15"));
+}
+
+#[test]
+fn test_tax_is_rounded_once_per_order() {
+    // 3 x 0.125 tax would round to 0.36 per line, but 0.375 rounds to 0.38 overall
+    let lines = vec![
+        line("1.25", 1, vec![tax("10", false)]),
+        line("1.25", 1, vec![tax("10", false)]),
+        line("1.25", 1, vec![tax("10", false)]),
+    ];
+    let breakdown = engine("0").price(&lines);
+
+    assert_eq!(breakdown.tax_amount, dec("0.38"));
+    assert_eq!(breakdown.total_amount, dec("4.13"));
+}
+
+#[test]
+fn test_discount_is_taken_off_before_tax_and_service_charge() {
+    let mut discounted = line("100.00", 1, vec![tax("12", false)]);
+    discounted.discount = dec("10.00");
+    let breakdown = engine("10").price(&[discounted]);
+
+    assert_eq!(breakdown.subtotal, dec("100.00"));
+    assert_eq!(breakdown.discount_amount, dec("10.00"));
+    assert_eq!(breakdown.tax_amount, dec("10.80"));
+    assert_eq!(breakdown.service_charge, dec("9.00"));
+    assert_eq!(breakdown.total_amount, dec("109.80"));
+}
+
+#[test]
+fn test_product_in_two_taxed_categories_takes_the_lowest_category_rates() {
+    // State and city tax on category 3, and a single rate on category 7
+    let rates = vec![
+        category_rate(1, 7, "10"),
+        category_rate(2, 3, "6"),
+        category_rate(3, 3, "2"),
+    ];
+
+    let ids: Vec<i32> = TaxRate::of_one_category(rates)
+        .iter()
+        .map(|r| r.tax_rate_id)
+        .collect();
+
+    assert_eq!(ids, vec![2, 3]);
+}
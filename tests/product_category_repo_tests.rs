@@ -37,6 +37,8 @@ async fn test_product_category_repo_crud() {
     let new_category = NewCategory {
         name: category_name,
         description: Some("Test Description"),
+        parent_id: None,
+        sort_order: None,
     };
     category_repo
         .add(new_category)
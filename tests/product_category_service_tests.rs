@@ -0,0 +1,87 @@
+use arrow_server_lib::api::response::CategoryResponse;
+use arrow_server_lib::data::models::categories::Category;
+use arrow_server_lib::services::product_category_service::{
+    category_tree, creates_cycle, descendant_ids, is_valid_parent,
+};
+
+fn category(category_id: i32, name: &str, parent_id: Option<i32>, sort_order: i32) -> Category {
+    Category {
+        category_id,
+        name: name.to_string(),
+        description: None,
+        created_at: None,
+        updated_at: None,
+        deleted_at: None,
+        parent_id,
+        sort_order,
+    }
+}
+
+fn names(level: &[CategoryResponse]) -> Vec<&str> {
+    level.iter().map(|c| c.name.as_str()).collect()
+}
+
+/// Drinks(1) > Hot(2) > Coffee(3), Drinks(1) > Cold(4), Food(5)
+fn categories() -> Vec<Category> {
+    vec![
+        category(1, "Drinks", None, 0),
+        category(2, "Hot", Some(1), 1),
+        category(3, "Coffee", Some(2), 0),
+        category(4, "Cold", Some(1), 0),
+        category(5, "Food", None, 0),
+    ]
+}
+
+#[test]
+fn test_creates_cycle() {
+    let categories = categories();
+
+    assert!(creates_cycle(&categories, 1, 1));
+    assert!(creates_cycle(&categories, 1, 3));
+    assert!(creates_cycle(&categories, 2, 3));
+    assert!(!creates_cycle(&categories, 3, 4));
+    assert!(!creates_cycle(&categories, 1, 5));
+    assert!(!creates_cycle(&categories, 5, 99));
+}
+
+#[test]
+fn test_is_valid_parent() {
+    let categories = categories();
+
+    assert!(is_valid_parent(&categories, 2, None));
+    assert!(is_valid_parent(&categories, 5, Some(2)));
+    assert!(!is_valid_parent(&categories, 99, None));
+    assert!(!is_valid_parent(&categories, 3, Some(1)));
+    assert!(!is_valid_parent(&categories, 2, Some(2)));
+}
+
+#[test]
+fn test_descendant_ids() {
+    let categories = categories();
+
+    assert_eq!(descendant_ids(&categories, 1), vec![1, 2, 4, 3]);
+    assert_eq!(descendant_ids(&categories, 2), vec![2, 3]);
+    assert_eq!(descendant_ids(&categories, 5), vec![5]);
+}
+
+#[test]
+fn test_category_tree_orders_levels() {
+    let tree = category_tree(categories());
+
+    assert_eq!(names(&tree), vec!["Drinks", "Food"]);
+    let drinks = tree[0].children.as_ref().unwrap();
+    assert_eq!(names(drinks), vec!["Cold", "Hot"]);
+    assert_eq!(names(drinks[1].children.as_ref().unwrap()), vec!["Coffee"]);
+    assert!(tree[1].children.as_ref().unwrap().is_empty());
+}
+
+#[test]
+fn test_category_tree_lifts_orphans_to_top_level() {
+    // The parent of "Tea" is archived, so it is not among the categories
+    let mut categories = categories();
+    categories.push(category(6, "Tea", Some(99), -1));
+
+    let tree = category_tree(categories);
+
+    assert_eq!(names(&tree), vec!["Tea", "Drinks", "Food"]);
+}
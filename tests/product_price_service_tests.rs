@@ -0,0 +1,54 @@
+use arrow_server_lib::data::models::product_price::ProductPrice;
+use arrow_server_lib::services::errors::ProductPriceServiceError;
+use arrow_server_lib::services::product_price_service::effective_from;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use std::str::FromStr;
+
+fn at(value: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn price(from: &str, to: Option<&str>) -> ProductPrice {
+    ProductPrice {
+        price_id: 1,
+        product_id: 1,
+        price: BigDecimal::from_str("9.50").unwrap(),
+        effective_from: at(from),
+        effective_to: to.map(at),
+        created_by: None,
+        created_at: None,
+    }
+}
+
+#[test]
+fn test_price_is_effective_within_its_period() {
+    let closed = price("2026-05-01 00:00:00", Some("2026-05-11 00:00:00"));
+
+    assert!(!closed.is_effective_at(at("2026-04-30 23:59:59")));
+    assert!(closed.is_effective_at(at("2026-05-01 00:00:00")));
+    assert!(closed.is_effective_at(at("2026-05-10 23:59:59")));
+    assert!(!closed.is_effective_at(at("2026-05-11 00:00:00")));
+
+    let latest = price("2026-05-11 00:00:00", None);
+    assert!(latest.is_effective_at(at("2030-01-01 00:00:00")));
+}
+
+#[test]
+fn test_effective_from_defaults_to_now_and_rejects_the_past() {
+    let now = at("2026-05-04 12:00:00");
+
+    assert_eq!(effective_from(None, now), Ok(now));
+    assert_eq!(
+        effective_from(Some("2026-05-11T00:00"), now),
+        Ok(at("2026-05-11 00:00:00"))
+    );
+    assert_eq!(
+        effective_from(Some("2026-05-04 11:59:00"), now),
+        Err(ProductPriceServiceError::InvalidEffectiveFrom)
+    );
+    assert_eq!(
+        effective_from(Some("next monday"), now),
+        Err(ProductPriceServiceError::InvalidEffectiveFrom)
+    );
+}
@@ -1,5 +1,6 @@
 use arrow_server_lib::data::database::*;
 use arrow_server_lib::data::models::categories::NewCategory;
+use arrow_server_lib::data::models::product::Product;
 use arrow_server_lib::data::models::product_category::NewProductCategory;
 use arrow_server_lib::data::models::user::NewUser;
 use arrow_server_lib::data::models::roles::{NewRole, RolePermissions};
@@ -11,7 +12,9 @@ use arrow_server_lib::data::repos::traits::repository::Repository;
 use arrow_server_lib::security::auth::AuthService;
 use arrow_server_lib::services::errors::ProductServiceError;
 use arrow_server_lib::services::product_service::ProductService;
+use arrow_server_lib::utils::time::local_now;
 use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDate};
 use diesel::result;
 use diesel_async::RunQueryDsl;
 use std::str::FromStr;
@@ -860,6 +863,8 @@ async fn test_get_product_with_categories() {
     let category = NewCategory {
         name: "TestCategory",
         description: None,
+        parent_id: None,
+        sort_order: None,
     };
     category_repo
         .add(category)
@@ -890,4 +895,202 @@ async fn test_get_product_with_categories() {
     let cats = fetched.categories.unwrap();
     assert_eq!(cats.len(), 1);
     assert_eq!(cats[0].name, "TestCategory");
-}
\ No newline at end of file
+}
+#[tokio::test]
+#[serial_test::serial]
+async fn test_set_product_availability() {
+    setup().await.expect("Setup failed");
+
+    let user_id = create_test_user("kitchen_user").await;
+    let write_role = create_role_with_permission(user_id, "kitchen", RolePermissions::Write).await;
+    let read_role = create_role_with_permission(user_id, "reader", RolePermissions::Read).await;
+
+    let service = ProductService::new();
+
+    service
+        .create_product(
+            "Salmon",
+            None,
+            BigDecimal::from_str("18.00").unwrap(),
+            None,
+            write_role,
+        )
+        .await
+        .expect("Failed to create product");
+
+    let product = service
+        .get_product_by_name("Salmon", read_role)
+        .await
+        .expect("Failed to get product")
+        .expect("Product not found");
+
+    assert!(product.is_available);
+
+    let restore_at = local_now() + Duration::hours(2);
+    service
+        .set_availability(product.product_id, false, Some(restore_at), write_role)
+        .await
+        .expect("Failed to 86 product");
+
+    let updated = service
+        .get_product_by_id(product.product_id, read_role)
+        .await
+        .expect("Failed to get product")
+        .expect("Product not found");
+
+    assert!(!updated.is_available);
+    assert!(updated.available_again_at.is_some());
+
+    let result = service
+        .set_availability(product.product_id, true, None, read_role)
+        .await;
+
+    assert_eq!(
+        result.err(),
+        Some(ProductServiceError::PermissionDenied),
+        "Should not toggle availability with READ permission"
+    );
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn test_set_product_availability_rejects_past_restore_time() {
+    setup().await.expect("Setup failed");
+
+    let user_id = create_test_user("kitchen_user").await;
+    let write_role = create_role_with_permission(user_id, "kitchen", RolePermissions::Write).await;
+
+    let service = ProductService::new();
+
+    let result = service
+        .set_availability(1, false, Some(local_now() - Duration::minutes(5)), write_role)
+        .await;
+
+    assert_eq!(
+        result.err(),
+        Some(ProductServiceError::InvalidAvailability),
+        "Should not accept a restore time in the past"
+    );
+}
+
+#[test]
+fn test_product_available_again_after_restore_time() {
+    let restore_at = NaiveDate::from_ymd_opt(2026, 3, 30)
+        .unwrap()
+        .and_hms_opt(17, 0, 0)
+        .unwrap();
+    let product = Product {
+        product_id: 1,
+        name: "Salmon".to_string(),
+        product_image_uri: None,
+        description: None,
+        price: BigDecimal::from_str("18.00").unwrap(),
+        created_at: None,
+        updated_at: None,
+        prep_minutes: 12,
+        is_available: false,
+        available_again_at: Some(restore_at),
+        deleted_at: None,
+    };
+
+    assert!(!product.is_available_at(restore_at - Duration::minutes(1)));
+    assert!(product.is_available_at(restore_at));
+}
+
+#[tokio::test]
+#[serial_test::serial]
+async fn test_restore_archived_product() {
+    setup().await.expect("Setup failed");
+
+    let user_id = create_test_user("product_restorer").await;
+    let write_role = create_role_with_permission(user_id, "writer", RolePermissions::Write).await;
+    let admin_role = create_role_with_permission(user_id, "admin", RolePermissions::Admin).await;
+
+    let service = ProductService::new();
+
+    service
+        .create_product(
+            "SeasonalProduct",
+            None,
+            BigDecimal::from_str("7.50").unwrap(),
+            None,
+            write_role,
+        )
+        .await
+        .expect("Failed to create product");
+
+    let product = service
+        .get_product_by_name("SeasonalProduct", admin_role)
+        .await
+        .expect("Failed to get product")
+        .expect("Product not found");
+
+    service
+        .delete_product(product.product_id, admin_role)
+        .await
+        .expect("Failed to archive product");
+
+    let archived = service
+        .get_archived_products(admin_role)
+        .await
+        .expect("Failed to get archived products");
+    assert_eq!(archived.len(), 1);
+    assert!(archived[0].deleted_at.is_some());
+    assert!(
+        !archived[0].is_available,
+        "Archived product should not be orderable"
+    );
+
+    assert_eq!(
+        service
+            .restore_product(product.product_id, write_role)
+            .await
+            .err(),
+        Some(ProductServiceError::PermissionDenied),
+        "Only admins should restore products"
+    );
+
+    service
+        .restore_product(product.product_id, admin_role)
+        .await
+        .expect("Failed to restore product");
+
+    let restored = service
+        .get_product_by_id(product.product_id, admin_role)
+        .await
+        .expect("Failed to query")
+        .expect("Restored product should be listed again");
+    assert!(restored.deleted_at.is_none());
+
+    assert_eq!(
+        service
+            .restore_product(product.product_id, admin_role)
+            .await
+            .err(),
+        Some(ProductServiceError::ProductNotFound),
+        "A product that is not archived cannot be restored"
+    );
+}
+
+#[test]
+fn test_archived_product_is_not_available() {
+    let now = NaiveDate::from_ymd_opt(2026, 4, 27)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let product = Product {
+        product_id: 1,
+        name: "Pumpkin soup".to_string(),
+        product_image_uri: None,
+        description: None,
+        price: BigDecimal::from_str("6.50").unwrap(),
+        created_at: None,
+        updated_at: None,
+        prep_minutes: 5,
+        is_available: true,
+        available_again_at: None,
+        deleted_at: Some(now - Duration::days(1)),
+    };
+
+    assert!(!product.is_available_at(now));
+}
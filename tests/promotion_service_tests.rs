@@ -0,0 +1,294 @@
+use arrow_server_lib::data::models::promotion::Promotion;
+use arrow_server_lib::services::errors::PromotionServiceError;
+use arrow_server_lib::services::pricing_service::{PricingLine, RoundingRule};
+use arrow_server_lib::services::promotion_service::{PromotionRule, evaluate_promotions};
+use bigdecimal::{BigDecimal, RoundingMode};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn rounding() -> RoundingRule {
+    RoundingRule::new(RoundingMode::HalfEven, dec("0.01"))
+}
+
+fn at(hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 1, 12)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn line(product_id: i32, unit_price: &str, quantity: i32) -> PricingLine {
+    PricingLine {
+        product_id,
+        quantity,
+        unit_price: dec(unit_price),
+        taxes: vec![],
+        discount: dec("0"),
+    }
+}
+
+fn promotion(promotion_id: i32, discount_type: &str, value: &str) -> Promotion {
+    Promotion {
+        promotion_id,
+        name: format!("Promotion {}", promotion_id),
+        code: None,
+        discount_type: discount_type.to_string(),
+        discount_value: dec(value),
+        is_automatic: true,
+        is_active: true,
+        min_spend: None,
+        starts_at: None,
+        ends_at: None,
+        daily_start_time: None,
+        daily_end_time: None,
+        usage_limit: None,
+        usage_count: 0,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn rule(promotion: Promotion) -> PromotionRule {
+    PromotionRule {
+        promotion,
+        product_ids: vec![],
+        category_ids: vec![],
+    }
+}
+
+fn code_promotion(promotion_id: i32, code: &str, discount_type: &str, value: &str) -> Promotion {
+    Promotion {
+        code: Some(code.to_string()),
+        is_automatic: false,
+        ..promotion(promotion_id, discount_type, value)
+    }
+}
+
+#[test]
+fn test_automatic_percentage_applies_to_whole_order() {
+    let rules = vec![rule(promotion(1, "percentage", "10"))];
+    let lines = vec![line(1, "15.00", 2), line(2, "20.00", 1)];
+
+    let applied = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].amount, dec("5.00"));
+    assert_eq!(applied[0].line_amounts, vec![dec("3.00"), dec("2.00")]);
+}
+
+#[test]
+fn test_product_condition_limits_discount_to_matching_lines() {
+    let mut product_rule = rule(promotion(1, "percentage", "50"));
+    product_rule.product_ids = vec![2];
+    let lines = vec![line(1, "10.00", 1), line(2, "8.00", 1)];
+
+    let applied = evaluate_promotions(
+        &[product_rule],
+        &lines,
+        &HashMap::new(),
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert_eq!(applied[0].amount, dec("4.00"));
+    assert_eq!(applied[0].line_amounts, vec![dec("0"), dec("4.00")]);
+}
+
+#[test]
+fn test_category_condition_matches_product_categories() {
+    let mut category_rule = rule(promotion(1, "fixed", "3"));
+    category_rule.category_ids = vec![7];
+    let lines = vec![line(1, "10.00", 1), line(2, "8.00", 1)];
+    let categories = HashMap::from([(1, vec![7]), (2, vec![8])]);
+
+    let applied = evaluate_promotions(
+        &[category_rule],
+        &lines,
+        &categories,
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert_eq!(applied[0].line_amounts, vec![dec("3"), dec("0")]);
+}
+
+#[test]
+fn test_fixed_discount_is_capped_at_order_subtotal() {
+    let rules = vec![rule(promotion(1, "fixed", "50"))];
+    let lines = vec![line(1, "12.00", 1)];
+
+    let applied = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert_eq!(applied[0].amount, dec("12.00"));
+}
+
+#[test]
+fn test_happy_hour_only_applies_within_daily_window() {
+    let happy_hour = Promotion {
+        daily_start_time: NaiveTime::from_hms_opt(17, 0, 0),
+        daily_end_time: NaiveTime::from_hms_opt(19, 0, 0),
+        ..promotion(1, "percentage", "20")
+    };
+    let rules = vec![rule(happy_hour)];
+    let lines = vec![line(1, "10.00", 1)];
+
+    let during = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        None,
+        at(18, 0),
+        &rounding(),
+    )
+    .unwrap();
+    let after = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        None,
+        at(19, 30),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert_eq!(during.len(), 1);
+    assert!(after.is_empty());
+}
+
+#[test]
+fn test_min_spend_is_checked_against_subtotal() {
+    let min_spend = Promotion {
+        min_spend: Some(dec("30.00")),
+        ..promotion(1, "fixed", "5")
+    };
+    let rules = vec![rule(min_spend)];
+
+    let below = evaluate_promotions(
+        &rules,
+        &[line(1, "10.00", 2)],
+        &HashMap::new(),
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+    let above = evaluate_promotions(
+        &rules,
+        &[line(1, "10.00", 3)],
+        &HashMap::new(),
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert!(below.is_empty());
+    assert_eq!(above.len(), 1);
+}
+
+#[test]
+fn test_code_promotion_requires_matching_code() {
+    let rules = vec![rule(code_promotion(1, "SAVE10", "percentage", "10"))];
+    let lines = vec![line(1, "20.00", 1)];
+
+    let without_code = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        None,
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+    let with_code = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        Some("save10"),
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert!(without_code.is_empty());
+    assert_eq!(with_code[0].amount, dec("2.00"));
+}
+
+#[test]
+fn test_unknown_or_exhausted_code_is_rejected() {
+    let exhausted = Promotion {
+        usage_limit: Some(5),
+        usage_count: 5,
+        ..code_promotion(1, "ONCE", "fixed", "5")
+    };
+    let rules = vec![rule(exhausted)];
+    let lines = vec![line(1, "20.00", 1)];
+
+    let unknown = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        Some("NOPE"),
+        at(12, 0),
+        &rounding(),
+    );
+    let used_up = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        Some("ONCE"),
+        at(12, 0),
+        &rounding(),
+    );
+
+    assert_eq!(unknown, Err(PromotionServiceError::InvalidPromoCode));
+    assert_eq!(used_up, Err(PromotionServiceError::InvalidPromoCode));
+}
+
+#[test]
+fn test_stacked_promotions_apply_to_remaining_amount() {
+    let rules = vec![
+        rule(promotion(1, "percentage", "10")),
+        rule(code_promotion(2, "EXTRA", "percentage", "10")),
+    ];
+    let lines = vec![line(1, "100.00", 1)];
+
+    let applied = evaluate_promotions(
+        &rules,
+        &lines,
+        &HashMap::new(),
+        Some("EXTRA"),
+        at(12, 0),
+        &rounding(),
+    )
+    .unwrap();
+
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied[0].amount, dec("10.00"));
+    assert_eq!(applied[1].amount, dec("9.00"));
+}
@@ -0,0 +1,201 @@
+use arrow_server_lib::api::request::{ReceiptLineRequest, ReceivePurchaseOrderRequest};
+use arrow_server_lib::data::models::ingredient::Ingredient;
+use arrow_server_lib::data::models::purchase_order::PurchaseOrderLine;
+use arrow_server_lib::services::errors::PurchasingServiceError;
+use arrow_server_lib::services::purchasing_service::{
+    PurchaseOrderStatus, RestockLine, order_total, receipts, suggest_restock,
+};
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn ingredient(
+    ingredient_id: i32,
+    on_hand: &str,
+    reorder_level: &str,
+    par_level: &str,
+) -> Ingredient {
+    Ingredient {
+        ingredient_id,
+        name: format!("Ingredient {}", ingredient_id),
+        unit: "kg".to_string(),
+        on_hand: dec(on_hand),
+        reorder_level: dec(reorder_level),
+        created_at: None,
+        updated_at: None,
+        supplier_id: None,
+        par_level: dec(par_level),
+        average_cost: dec("0"),
+    }
+}
+
+fn line(ingredient_id: i32, quantity: &str, unit_cost: &str, received: &str) -> PurchaseOrderLine {
+    PurchaseOrderLine {
+        purchase_order_id: 1,
+        ingredient_id,
+        quantity: dec(quantity),
+        unit_cost: dec(unit_cost),
+        received_quantity: dec(received),
+    }
+}
+
+fn receipt(ingredient_id: i32, quantity: &str, unit_cost: Option<&str>) -> ReceiptLineRequest {
+    ReceiptLineRequest {
+        ingredient_id,
+        quantity: dec(quantity),
+        unit_cost: unit_cost.map(dec),
+    }
+}
+
+#[test]
+fn test_suggest_restock_tops_low_stock_up_to_par_level() {
+    let ingredients = vec![
+        ingredient(1, "2", "5", "20"),
+        ingredient(2, "8", "5", "20"),
+        ingredient(3, "1", "4", "0"),
+    ];
+
+    let suggestions = suggest_restock(&ingredients, &[]);
+
+    assert_eq!(
+        suggestions,
+        vec![
+            RestockLine {
+                ingredient_id: 1,
+                on_order: dec("0"),
+                quantity: dec("18"),
+            },
+            RestockLine {
+                ingredient_id: 3,
+                on_order: dec("0"),
+                quantity: dec("3"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_suggest_restock_counts_stock_still_on_order() {
+    let ingredients = vec![ingredient(1, "2", "5", "20"), ingredient(2, "2", "5", "20")];
+    let open_lines = vec![line(1, "10", "1", "8"), line(2, "10", "1", "0")];
+
+    let suggestions = suggest_restock(&ingredients, &open_lines);
+
+    assert_eq!(
+        suggestions,
+        vec![RestockLine {
+            ingredient_id: 1,
+            on_order: dec("2"),
+            quantity: dec("16"),
+        }],
+        "Ingredient 2 is covered by what is on order"
+    );
+}
+
+#[test]
+fn test_order_total_rounds_to_cents() {
+    let lines = vec![line(1, "2.5", "1.2345", "0"), line(2, "3", "0.10", "0")];
+
+    assert_eq!(order_total(&lines), dec("3.39"));
+}
+
+#[test]
+fn test_receipts_default_to_everything_outstanding() {
+    let lines = vec![line(1, "10", "1.50", "4"), line(2, "5", "2", "5")];
+
+    let received = receipts(&lines, &ReceivePurchaseOrderRequest::default()).unwrap();
+
+    assert_eq!(received, vec![(1, dec("6"), dec("1.50"))]);
+}
+
+#[test]
+fn test_receipts_use_the_cost_paid() {
+    let lines = vec![line(1, "10", "1.50", "0"), line(2, "5", "2", "0")];
+    let request = ReceivePurchaseOrderRequest {
+        lines: Some(vec![receipt(2, "3", Some("2.25")), receipt(1, "10", None)]),
+        note: None,
+    };
+
+    let received = receipts(&lines, &request).unwrap();
+
+    assert_eq!(
+        received,
+        vec![(2, dec("3"), dec("2.25")), (1, dec("10"), dec("1.50"))]
+    );
+}
+
+#[test]
+fn test_receipts_reject_more_than_outstanding() {
+    let lines = vec![line(1, "10", "1.50", "8")];
+    let request = ReceivePurchaseOrderRequest {
+        lines: Some(vec![receipt(1, "3", None)]),
+        note: None,
+    };
+
+    assert_eq!(
+        receipts(&lines, &request),
+        Err(PurchasingServiceError::InvalidReceipt)
+    );
+}
+
+#[test]
+fn test_receipts_reject_ingredients_not_ordered() {
+    let lines = vec![line(1, "10", "1.50", "0")];
+    let request = ReceivePurchaseOrderRequest {
+        lines: Some(vec![receipt(2, "1", None)]),
+        note: None,
+    };
+
+    assert_eq!(
+        receipts(&lines, &request),
+        Err(PurchasingServiceError::InvalidReceipt)
+    );
+}
+
+#[test]
+fn test_receipts_of_fully_received_order_are_rejected() {
+    let lines = vec![line(1, "10", "1.50", "10")];
+
+    assert_eq!(
+        receipts(&lines, &ReceivePurchaseOrderRequest::default()),
+        Err(PurchasingServiceError::InvalidReceipt)
+    );
+}
+
+#[test]
+fn test_average_cost_after_weighs_stock_on_hand() {
+    let mut stock = ingredient(1, "10", "0", "0");
+    stock.average_cost = dec("2");
+
+    assert_eq!(
+        stock.average_cost_after(&dec("30"), &dec("3")),
+        dec("2.7500")
+    );
+}
+
+#[test]
+fn test_average_cost_after_without_stock_takes_new_cost() {
+    let mut stock = ingredient(1, "-2", "0", "0");
+    stock.average_cost = dec("2");
+
+    assert_eq!(
+        stock.average_cost_after(&dec("5"), &dec("3.10")),
+        dec("3.10")
+    );
+}
+
+#[test]
+fn test_purchase_order_status_round_trip() {
+    for status in [
+        PurchaseOrderStatus::Draft,
+        PurchaseOrderStatus::Sent,
+        PurchaseOrderStatus::PartiallyReceived,
+        PurchaseOrderStatus::Received,
+    ] {
+        assert_eq!(status.as_str().parse::<PurchaseOrderStatus>(), Ok(status));
+    }
+    assert!("cancelled".parse::<PurchaseOrderStatus>().is_err());
+}
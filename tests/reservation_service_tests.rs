@@ -0,0 +1,135 @@
+use arrow_server_lib::data::models::dining_table::DiningTable;
+use arrow_server_lib::data::models::reservation::Reservation;
+use arrow_server_lib::data::models::table_session::TableSession;
+use arrow_server_lib::services::reservation_service::{
+    estimate_wait, find_table, slots_overlap, table_ready_at,
+};
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDateTime};
+
+fn at(value: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+}
+
+fn turn() -> Duration {
+    Duration::minutes(90)
+}
+
+fn table(table_id: i32, capacity: i32) -> DiningTable {
+    DiningTable {
+        table_id,
+        area_id: 1,
+        name: format!("T{table_id}"),
+        capacity,
+        status: "free".to_string(),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn booking(table_id: i32, reserved_for: &str, status: &str) -> Reservation {
+    Reservation {
+        reservation_id: 1,
+        contact_name: "Ada".to_string(),
+        contact_phone: "555-0100".to_string(),
+        party_size: 2,
+        reserved_for: at(reserved_for),
+        table_id: Some(table_id),
+        status: status.to_string(),
+        notes: None,
+        session_id: None,
+        created_by: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn session(table_id: i32, opened_at: &str) -> TableSession {
+    TableSession {
+        session_id: 1,
+        table_id,
+        party_size: Some(2),
+        opened_by: None,
+        opened_at: Some(at(opened_at)),
+        closed_at: None,
+        subtotal: BigDecimal::from(0),
+        discount_amount: BigDecimal::from(0),
+        tax_amount: BigDecimal::from(0),
+        service_charge: BigDecimal::from(0),
+        gratuity_amount: BigDecimal::from(0),
+        total_amount: BigDecimal::from(0),
+    }
+}
+
+#[test]
+fn test_slots_overlap_within_turn() {
+    assert!(slots_overlap(
+        at("2026-02-23 19:00"),
+        at("2026-02-23 20:00"),
+        turn()
+    ));
+    assert!(!slots_overlap(
+        at("2026-02-23 19:00"),
+        at("2026-02-23 20:30"),
+        turn()
+    ));
+}
+
+#[test]
+fn test_find_table_picks_smallest_fitting_table() {
+    let tables = vec![table(1, 8), table(2, 4), table(3, 2), table(4, 4)];
+
+    let table_id = find_table(&tables, &[], 3, at("2026-02-23 19:00"), turn(), None);
+
+    assert_eq!(table_id, Some(2));
+}
+
+#[test]
+fn test_find_table_skips_overlapping_bookings() {
+    let tables = vec![table(1, 4), table(2, 4)];
+    let bookings = vec![
+        booking(1, "2026-02-23 18:00", "booked"),
+        booking(2, "2026-02-23 19:30", "cancelled"),
+    ];
+
+    let table_id = find_table(&tables, &bookings, 4, at("2026-02-23 19:00"), turn(), None);
+    let later = find_table(&tables, &bookings, 4, at("2026-02-23 19:30"), turn(), None);
+    let preferred = find_table(
+        &tables,
+        &bookings,
+        4,
+        at("2026-02-23 19:00"),
+        turn(),
+        Some(1),
+    );
+
+    assert_eq!(table_id, Some(2));
+    assert_eq!(later, Some(1));
+    assert_eq!(preferred, None);
+}
+
+#[test]
+fn test_table_ready_after_current_party_and_bookings() {
+    let now = at("2026-02-23 18:30");
+    let open = session(1, "2026-02-23 18:00");
+    let bookings = vec![booking(1, "2026-02-23 20:00", "booked")];
+
+    let free = table_ready_at(2, None, &bookings, now, turn());
+    let seated = table_ready_at(1, Some(&open), &[], now, turn());
+    let booked = table_ready_at(1, Some(&open), &bookings, now, turn());
+
+    assert_eq!(free, now);
+    assert_eq!(seated, at("2026-02-23 19:30"));
+    assert_eq!(booked, at("2026-02-23 21:30"));
+}
+
+#[test]
+fn test_estimate_wait_counts_parties_ahead() {
+    let now = at("2026-02-23 18:30");
+    let ready_at = vec![at("2026-02-23 18:45"), at("2026-02-23 19:00")];
+
+    assert_eq!(estimate_wait(&ready_at, 0, now, turn()), Some(15));
+    assert_eq!(estimate_wait(&ready_at, 1, now, turn()), Some(30));
+    assert_eq!(estimate_wait(&ready_at, 2, now, turn()), Some(105));
+    assert_eq!(estimate_wait(&[], 0, now, turn()), None);
+}
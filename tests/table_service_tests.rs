@@ -0,0 +1,93 @@
+use arrow_server_lib::data::models::order::Order;
+use arrow_server_lib::services::table_service::{TableStatus, combine_orders};
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn order(order_id: i32, subtotal: &str, tax: &str, gratuity: &str, status: &str) -> Order {
+    let total = dec(subtotal) + dec(tax);
+    Order {
+        order_id,
+        user_id: 1,
+        total_amount: total,
+        status: Some(status.to_string()),
+        created_at: None,
+        updated_at: None,
+        subtotal: dec(subtotal),
+        tax_amount: dec(tax),
+        service_charge: dec("0"),
+        discount_amount: dec("0"),
+        party_size: Some(4),
+        served_by: None,
+        gratuity_amount: dec(gratuity),
+        tip_amount: dec("0"),
+        order_type: "dine_in".to_string(),
+        table_id: Some(3),
+        session_id: Some(9),
+        business_date: None,
+        order_number: None,
+        scheduled_for: None,
+        estimated_ready_at: None,
+    }
+}
+
+#[test]
+fn test_table_status_round_trips() {
+    for status in [
+        TableStatus::Free,
+        TableStatus::Seated,
+        TableStatus::Dirty,
+        TableStatus::Reserved,
+    ] {
+        assert_eq!(TableStatus::from_str(status.as_str()), Ok(status));
+    }
+
+    assert_eq!(TableStatus::from_str("FREE"), Ok(TableStatus::Free));
+    assert!(TableStatus::from_str("occupied").is_err());
+}
+
+#[test]
+fn test_only_free_or_reserved_tables_can_be_seated() {
+    assert!(TableStatus::Free.is_seatable());
+    assert!(TableStatus::Reserved.is_seatable());
+    assert!(!TableStatus::Seated.is_seatable());
+    assert!(!TableStatus::Dirty.is_seatable());
+}
+
+#[test]
+fn test_combine_orders_adds_up_the_session() {
+    let orders = vec![
+        order(1, "20.00", "2.40", "0", "Completed"),
+        order(2, "15.50", "1.86", "3.00", "Pending"),
+    ];
+
+    let totals = combine_orders(&orders);
+
+    assert_eq!(totals.subtotal, dec("35.50"));
+    assert_eq!(totals.tax_amount, dec("4.26"));
+    assert_eq!(totals.gratuity_amount, dec("3.00"));
+    assert_eq!(totals.total_amount, dec("39.76"));
+}
+
+#[test]
+fn test_combine_orders_skips_cancelled_orders() {
+    let orders = vec![
+        order(1, "20.00", "2.40", "0", "Completed"),
+        order(2, "99.00", "9.90", "0", "Cancelled"),
+    ];
+
+    let totals = combine_orders(&orders);
+
+    assert_eq!(totals.subtotal, dec("20.00"));
+    assert_eq!(totals.total_amount, dec("22.40"));
+}
+
+#[test]
+fn test_combine_orders_of_empty_session_is_zero() {
+    let totals = combine_orders(&[]);
+
+    assert_eq!(totals.total_amount, dec("0"));
+}
@@ -0,0 +1,138 @@
+use arrow_server_lib::api::response::OrderResponse;
+use arrow_server_lib::data::models::order::Order;
+use arrow_server_lib::services::pricing_service::{PriceBreakdown, RoundingRule};
+use arrow_server_lib::services::tip_service::{auto_gratuity, pool_tips};
+use bigdecimal::{BigDecimal, RoundingMode};
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn cents() -> RoundingRule {
+    RoundingRule::new(RoundingMode::HalfEven, dec("0.01"))
+}
+
+fn breakdown(subtotal: &str, discount: &str) -> PriceBreakdown {
+    PriceBreakdown {
+        subtotal: dec(subtotal),
+        discount_amount: dec(discount),
+        tax_amount: dec("0"),
+        service_charge: dec("0"),
+        total_amount: dec(subtotal) - dec(discount),
+    }
+}
+
+fn order(
+    order_id: i32,
+    user_id: i32,
+    served_by: Option<i32>,
+    gratuity: &str,
+    tip: &str,
+    status: &str,
+) -> Order {
+    Order {
+        order_id,
+        user_id,
+        total_amount: dec("50.00"),
+        status: Some(status.to_string()),
+        created_at: None,
+        updated_at: None,
+        subtotal: dec("50.00"),
+        tax_amount: dec("0"),
+        service_charge: dec("0"),
+        discount_amount: dec("0"),
+        party_size: None,
+        served_by,
+        gratuity_amount: dec(gratuity),
+        tip_amount: dec(tip),
+        order_type: "dine_in".to_string(),
+        table_id: None,
+        session_id: None,
+        business_date: None,
+        order_number: None,
+        scheduled_for: None,
+        estimated_ready_at: None,
+    }
+}
+
+#[test]
+fn test_auto_gratuity_applies_to_large_parties() {
+    let gratuity = auto_gratuity(
+        Some(8),
+        8,
+        &dec("18"),
+        &breakdown("100.00", "10.00"),
+        &cents(),
+    );
+
+    assert_eq!(gratuity, dec("16.20"));
+}
+
+#[test]
+fn test_auto_gratuity_skips_small_or_unknown_parties() {
+    let small = auto_gratuity(Some(7), 8, &dec("18"), &breakdown("100.00", "0"), &cents());
+    let unknown = auto_gratuity(None, 8, &dec("18"), &breakdown("100.00", "0"), &cents());
+    let disabled = auto_gratuity(Some(12), 8, &dec("0"), &breakdown("100.00", "0"), &cents());
+
+    assert_eq!(small, dec("0"));
+    assert_eq!(unknown, dec("0"));
+    assert_eq!(disabled, dec("0"));
+}
+
+#[test]
+fn test_pool_tips_attributes_orders_to_their_server() {
+    let orders = vec![
+        order(1, 5, Some(2), "10.00", "0", "Pending"),
+        order(2, 5, None, "0", "4.00", "Completed"),
+        order(3, 2, None, "0", "1.50", "Completed"),
+    ];
+
+    let staff = pool_tips(&orders, &cents());
+
+    assert_eq!(staff.len(), 2);
+    assert_eq!(staff[0].user_id, 2);
+    assert_eq!(staff[0].order_count, 2);
+    assert_eq!(staff[0].total(), dec("11.50"));
+    assert_eq!(staff[1].user_id, 5);
+    assert_eq!(staff[1].total(), dec("4.00"));
+}
+
+#[test]
+fn test_pool_tips_shares_add_up_to_the_pool() {
+    let orders = vec![
+        order(1, 1, None, "0", "5.00", "Completed"),
+        order(2, 2, None, "0", "3.00", "Completed"),
+        order(3, 3, None, "0", "2.00", "Completed"),
+    ];
+
+    let staff = pool_tips(&orders, &cents());
+    let shares: BigDecimal = staff.iter().map(|s| s.pool_share.clone()).sum();
+
+    assert_eq!(shares, dec("10.00"));
+    assert_eq!(staff[0].pool_share, dec("3.34"));
+    assert_eq!(staff[1].pool_share, dec("3.33"));
+    assert_eq!(staff[2].pool_share, dec("3.33"));
+}
+
+#[test]
+fn test_pool_tips_ignores_cancelled_orders() {
+    let orders = vec![
+        order(1, 1, None, "0", "5.00", "Completed"),
+        order(2, 2, None, "8.00", "0", "Cancelled"),
+    ];
+
+    let staff = pool_tips(&orders, &cents());
+
+    assert_eq!(staff.len(), 1);
+    assert_eq!(staff[0].pool_share, dec("5.00"));
+}
+
+#[test]
+fn test_order_response_charges_the_auto_gratuity() {
+    let response = OrderResponse::from((order(1, 1, None, "8.00", "0", "Pending"), Vec::new()));
+
+    assert_eq!(response.total_amount, dec("50.00"));
+    assert_eq!(response.gratuity_amount, dec("8.00"));
+    assert_eq!(response.balance_due, dec("58.00"));
+}
@@ -0,0 +1,174 @@
+use arrow_server_lib::api::config::parse_locales;
+use arrow_server_lib::api::request::TranslationRequest;
+use arrow_server_lib::api::response::{CategoryResponse, ProductResponse};
+use arrow_server_lib::data::models::translation::{CategoryTranslation, ProductTranslation};
+use arrow_server_lib::services::translation_service::{
+    apply_category_translations, apply_product_translations, is_valid_translation,
+    negotiate_locale, normalize_locale,
+};
+use serde_json::json;
+
+const DRINKS: i32 = 1;
+const SOFT_DRINKS: i32 = 2;
+const LEMONADE: i32 = 10;
+const WATER: i32 = 11;
+
+fn supported() -> Vec<String> {
+    parse_locales("es, JA,en", "en")
+}
+
+fn category(category_id: i32, name: &str, children: Vec<CategoryResponse>) -> CategoryResponse {
+    CategoryResponse {
+        category_id: Some(category_id),
+        name: name.to_string(),
+        description: Some(format!("All {}", name.to_lowercase())),
+        created_at: None,
+        updated_at: None,
+        deleted_at: None,
+        parent_id: None,
+        sort_order: 0,
+        children: Some(children),
+    }
+}
+
+fn product(product_id: i32, name: &str, categories: Vec<CategoryResponse>) -> ProductResponse {
+    serde_json::from_value(json!({
+        "product_id": product_id,
+        "name": name,
+        "description": format!("A cold {}", name.to_lowercase()),
+        "price": "2.50",
+        "prep_minutes": 0,
+        "is_available": true,
+        "categories": serde_json::to_value(categories).unwrap(),
+    }))
+    .unwrap()
+}
+
+fn category_translation(category_id: i32, name: &str) -> CategoryTranslation {
+    CategoryTranslation {
+        category_id,
+        locale: "es".to_string(),
+        name: name.to_string(),
+        description: None,
+    }
+}
+
+#[test]
+fn test_parse_locales_keeps_the_default_first() {
+    assert_eq!(supported(), vec!["en", "es", "ja"]);
+    assert_eq!(parse_locales("", "en"), vec!["en"]);
+}
+
+#[test]
+fn test_normalize_locale() {
+    assert_eq!(normalize_locale("es-MX"), Some("es".to_string()));
+    assert_eq!(normalize_locale(" JA "), Some("ja".to_string()));
+    assert_eq!(normalize_locale("zh_Hant"), Some("zh".to_string()));
+    assert_eq!(normalize_locale("*"), None);
+    assert_eq!(normalize_locale("english"), None);
+}
+
+#[test]
+fn test_negotiate_locale_prefers_the_lang_parameter() {
+    let supported = supported();
+
+    assert_eq!(
+        negotiate_locale(Some("ja"), Some("es"), &supported, "en"),
+        "ja"
+    );
+    // An unsupported `lang` falls through to the header
+    assert_eq!(
+        negotiate_locale(Some("fr"), Some("es"), &supported, "en"),
+        "es"
+    );
+    assert_eq!(negotiate_locale(None, None, &supported, "en"), "en");
+}
+
+#[test]
+fn test_negotiate_locale_follows_accept_language_quality() {
+    let supported = supported();
+    let negotiate = |header: &str| negotiate_locale(None, Some(header), &supported, "en");
+
+    assert_eq!(negotiate("es-ES,es;q=0.9,en;q=0.8"), "es");
+    assert_eq!(negotiate("en;q=0.5, ja;q=0.9"), "ja");
+    assert_eq!(negotiate("fr-FR, de;q=0.8, ja;q=0.1"), "ja");
+    // Ranges listed with the same quality keep their order, and a quality of 0 rules out
+    assert_eq!(negotiate("ja, es"), "ja");
+    assert_eq!(negotiate("ja;q=0, es;q=0.2"), "es");
+    assert_eq!(negotiate("fr, *;q=0.5"), "en");
+    assert_eq!(negotiate("fr;q=abc, de"), "en");
+}
+
+#[test]
+fn test_is_valid_translation() {
+    let request = |name: &str| TranslationRequest {
+        name: name.to_string(),
+        description: None,
+    };
+
+    assert!(is_valid_translation(&request("Limonada"), 100));
+    assert!(is_valid_translation(&request(&"あ".repeat(100)), 100));
+    assert!(!is_valid_translation(&request(&"あ".repeat(101)), 100));
+    assert!(!is_valid_translation(&request("  "), 100));
+}
+
+#[test]
+fn test_apply_category_translations_to_nested_categories() {
+    let mut categories = vec![category(
+        DRINKS,
+        "Drinks",
+        vec![category(SOFT_DRINKS, "Soft drinks", vec![])],
+    )];
+    let translations = vec![
+        CategoryTranslation {
+            description: Some("Todas las bebidas".to_string()),
+            ..category_translation(DRINKS, "Bebidas")
+        },
+        category_translation(SOFT_DRINKS, "Refrescos"),
+    ];
+
+    apply_category_translations(&mut categories, &translations);
+
+    assert_eq!(categories[0].name, "Bebidas");
+    assert_eq!(
+        categories[0].description.as_deref(),
+        Some("Todas las bebidas")
+    );
+    // Without a translated description the untranslated one is kept
+    let children = categories[0].children.as_ref().unwrap();
+    assert_eq!(children[0].name, "Refrescos");
+    assert_eq!(children[0].description.as_deref(), Some("All soft drinks"));
+}
+
+#[test]
+fn test_apply_product_translations_falls_back_to_the_default_locale() {
+    let mut products = vec![
+        product(
+            LEMONADE,
+            "Lemonade",
+            vec![category(DRINKS, "Drinks", vec![])],
+        ),
+        product(WATER, "Water", vec![]),
+    ];
+    let translations = vec![ProductTranslation {
+        product_id: LEMONADE,
+        locale: "es".to_string(),
+        name: "Limonada".to_string(),
+        description: Some("Una limonada fría".to_string()),
+    }];
+
+    apply_product_translations(
+        &mut products,
+        &translations,
+        &[category_translation(DRINKS, "Bebidas")],
+    );
+
+    assert_eq!(products[0].name, "Limonada");
+    assert_eq!(
+        products[0].description.as_deref(),
+        Some("Una limonada fría")
+    );
+    assert_eq!(products[0].categories.as_ref().unwrap()[0].name, "Bebidas");
+    assert_eq!(products[1].name, "Water");
+    assert_eq!(products[1].description.as_deref(), Some("A cold water"));
+}
@@ -0,0 +1,168 @@
+use arrow_server_lib::data::models::ingredient::{Ingredient, Recipe, StockMovement};
+use arrow_server_lib::data::models::waste_entry::WasteEntry;
+use arrow_server_lib::services::waste_service::{
+    IngredientUsage, WasteReason, WasteTotal, ingredient_usage, product_waste_uses,
+    waste_by_day, waste_by_reason, waste_cost,
+};
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use std::str::FromStr;
+
+fn dec(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+fn at(value: &str) -> Option<NaiveDateTime> {
+    Some(NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap())
+}
+
+fn ingredient(ingredient_id: i32, average_cost: &str) -> Ingredient {
+    Ingredient {
+        ingredient_id,
+        name: format!("Ingredient {}", ingredient_id),
+        unit: "kg".to_string(),
+        on_hand: dec("10"),
+        reorder_level: dec("0"),
+        created_at: None,
+        updated_at: None,
+        supplier_id: None,
+        par_level: dec("0"),
+        average_cost: dec(average_cost),
+    }
+}
+
+fn recipe(product_id: i32, ingredient_id: i32, quantity: &str) -> Recipe {
+    Recipe {
+        product_id,
+        ingredient_id,
+        quantity: dec(quantity),
+    }
+}
+
+fn entry(waste_id: i32, reason: &str, cost: &str, created_at: &str) -> WasteEntry {
+    WasteEntry {
+        waste_id,
+        ingredient_id: Some(1),
+        product_id: None,
+        quantity: dec("1"),
+        reason: reason.to_string(),
+        cost: dec(cost),
+        note: None,
+        user_id: None,
+        created_at: at(created_at),
+    }
+}
+
+fn movement(ingredient_id: i32, quantity: &str, reason: &str) -> StockMovement {
+    StockMovement {
+        movement_id: 0,
+        ingredient_id,
+        order_id: None,
+        quantity: dec(quantity),
+        reason: reason.to_string(),
+        note: None,
+        user_id: None,
+        created_at: None,
+        purchase_order_id: None,
+        unit_cost: None,
+        waste_id: None,
+    }
+}
+
+#[test]
+fn test_waste_reason_parses_known_reasons_only() {
+    assert_eq!(WasteReason::from_str("Burnt"), Ok(WasteReason::Burnt));
+    assert_eq!(WasteReason::from_str("spoiled"), Ok(WasteReason::Spoiled));
+    assert_eq!(WasteReason::from_str("eaten"), Err(()));
+    assert_eq!(WasteReason::Expired.as_str(), "expired");
+}
+
+#[test]
+fn test_product_waste_uses_scales_the_recipe() {
+    let recipes = vec![
+        recipe(1, 10, "0.150"),
+        recipe(1, 11, "1"),
+        recipe(2, 10, "0.5"),
+    ];
+
+    let uses = product_waste_uses(1, &dec("2"), &recipes);
+
+    assert_eq!(uses, vec![(10, dec("0.3")), (11, dec("2"))]);
+    assert!(product_waste_uses(3, &dec("2"), &recipes).is_empty());
+}
+
+#[test]
+fn test_waste_cost_uses_average_costs() {
+    let stock = vec![ingredient(10, "12.5"), ingredient(11, "0.3333")];
+
+    let cost = waste_cost(&[(10, dec("0.3")), (11, dec("2")), (12, dec("5"))], &stock);
+
+    // 3.75 + 0.6666, ingredient 12 is unknown
+    assert_eq!(cost, dec("4.42"));
+}
+
+#[test]
+fn test_waste_totals_by_reason_and_day() {
+    let entries = vec![
+        entry(1, "burnt", "4.50", "2026-04-20 12:00:00"),
+        entry(2, "dropped", "2.00", "2026-04-20 19:30:00"),
+        entry(3, "burnt", "1.25", "2026-04-21 09:15:00"),
+    ];
+
+    assert_eq!(
+        waste_by_reason(&entries),
+        vec![
+            WasteTotal {
+                key: "burnt".to_string(),
+                entry_count: 2,
+                cost: dec("5.75"),
+            },
+            WasteTotal {
+                key: "dropped".to_string(),
+                entry_count: 1,
+                cost: dec("2.00"),
+            },
+        ]
+    );
+    assert_eq!(
+        waste_by_day(&entries),
+        vec![
+            WasteTotal {
+                key: "2026-04-20".to_string(),
+                entry_count: 2,
+                cost: dec("6.50"),
+            },
+            WasteTotal {
+                key: "2026-04-21".to_string(),
+                entry_count: 1,
+                cost: dec("1.25"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_ingredient_usage_compares_theoretical_with_actual() {
+    let movements = vec![
+        movement(1, "-3", "order"),
+        movement(1, "-1", "order"),
+        movement(1, "1", "cancellation"),
+        movement(1, "-0.5", "waste"),
+        movement(1, "-0.25", "adjustment"),
+        movement(1, "20", "purchase"),
+        movement(2, "10", "purchase"),
+    ];
+
+    let usage = ingredient_usage(&movements);
+
+    assert_eq!(
+        usage,
+        vec![IngredientUsage {
+            ingredient_id: 1,
+            theoretical: dec("3"),
+            waste: dec("0.5"),
+            actual: dec("3.75"),
+        }]
+    );
+    assert_eq!(usage[0].variance(), dec("0.75"));
+}